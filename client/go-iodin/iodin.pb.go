@@ -21,11 +21,21 @@ const _ = proto.ProtoPackageIsVersion2 // please upgrade the proto package
 type Request_Command int32
 
 const (
-	Request_INVALID   Request_Command = 0
-	Request_MDB_OPEN  Request_Command = 1
-	Request_MDB_RESET Request_Command = 2
-	Request_MDB_TX    Request_Command = 3
-	Request_STOP      Request_Command = 100
+	Request_INVALID         Request_Command = 0
+	Request_MDB_OPEN        Request_Command = 1
+	Request_MDB_RESET       Request_Command = 2
+	Request_MDB_TX          Request_Command = 3
+	Request_HELLO           Request_Command = 4
+	Request_GET_INFO        Request_Command = 5
+	Request_MDB_BATCH       Request_Command = 6
+	Request_SUBSCRIBE       Request_Command = 7
+	Request_GPIO_WATCH      Request_Command = 8
+	Request_MDB_POLL_ADD    Request_Command = 9
+	Request_MDB_POLL_REMOVE Request_Command = 10
+	Request_MDB_COIN        Request_Command = 11
+	Request_MDB_BILL        Request_Command = 12
+	Request_MDB_CASHLESS    Request_Command = 13
+	Request_STOP            Request_Command = 100
 )
 
 var Request_Command_name = map[int32]string{
@@ -33,21 +43,74 @@ var Request_Command_name = map[int32]string{
 	1:   "MDB_OPEN",
 	2:   "MDB_RESET",
 	3:   "MDB_TX",
+	4:   "HELLO",
+	5:   "GET_INFO",
+	6:   "MDB_BATCH",
+	7:   "SUBSCRIBE",
+	8:   "GPIO_WATCH",
+	9:   "MDB_POLL_ADD",
+	10:  "MDB_POLL_REMOVE",
+	11:  "MDB_COIN",
+	12:  "MDB_BILL",
+	13:  "MDB_CASHLESS",
 	100: "STOP",
 }
 var Request_Command_value = map[string]int32{
-	"INVALID":   0,
-	"MDB_OPEN":  1,
-	"MDB_RESET": 2,
-	"MDB_TX":    3,
-	"STOP":      100,
+	"INVALID":         0,
+	"MDB_OPEN":        1,
+	"MDB_RESET":       2,
+	"MDB_TX":          3,
+	"HELLO":           4,
+	"GET_INFO":        5,
+	"MDB_BATCH":       6,
+	"SUBSCRIBE":       7,
+	"GPIO_WATCH":      8,
+	"MDB_POLL_ADD":    9,
+	"MDB_POLL_REMOVE": 10,
+	"MDB_COIN":        11,
+	"MDB_BILL":        12,
+	"MDB_CASHLESS":    13,
+	"STOP":            100,
 }
 
 func (x Request_Command) String() string {
 	return proto.EnumName(Request_Command_name, int32(x))
 }
 func (Request_Command) EnumDescriptor() ([]byte, []int) {
-	return fileDescriptor_iodin_4e2934b64013cba2, []int{0, 0}
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{0, 0}
+}
+
+type MdbOpen_Transport int32
+
+const (
+	// SIM in mock mode, GPIO otherwise.
+	MdbOpen_DEFAULT MdbOpen_Transport = 0
+	// Bit-banged serial on rx_pin/tx_pin via pigpio.
+	MdbOpen_GPIO MdbOpen_Transport = 1
+	// Simulated bus without hardware.
+	MdbOpen_SIM MdbOpen_Transport = 2
+	// Serial port at device, 9th bit via mark/space parity.
+	MdbOpen_UART MdbOpen_Transport = 3
+)
+
+var MdbOpen_Transport_name = map[int32]string{
+	0: "DEFAULT",
+	1: "GPIO",
+	2: "SIM",
+	3: "UART",
+}
+var MdbOpen_Transport_value = map[string]int32{
+	"DEFAULT": 0,
+	"GPIO":    1,
+	"SIM":     2,
+	"UART":    3,
+}
+
+func (x MdbOpen_Transport) String() string {
+	return proto.EnumName(MdbOpen_Transport_name, int32(x))
+}
+func (MdbOpen_Transport) EnumDescriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{1, 0}
 }
 
 type Response_Status int32
@@ -60,6 +123,8 @@ const (
 	Response_ERR_CHECKSUM Response_Status = 4
 	Response_ERR_TIMEOUT  Response_Status = 5
 	Response_ERR_HARDWARE Response_Status = 6
+	// Peripheral replied NAK.
+	Response_ERR_NAK Response_Status = 7
 )
 
 var Response_Status_name = map[int32]string{
@@ -70,6 +135,7 @@ var Response_Status_name = map[int32]string{
 	4: "ERR_CHECKSUM",
 	5: "ERR_TIMEOUT",
 	6: "ERR_HARDWARE",
+	7: "ERR_NAK",
 }
 var Response_Status_value = map[string]int32{
 	"INVALID":      0,
@@ -79,31 +145,685 @@ var Response_Status_value = map[string]int32{
 	"ERR_CHECKSUM": 4,
 	"ERR_TIMEOUT":  5,
 	"ERR_HARDWARE": 6,
+	"ERR_NAK":      7,
 }
 
 func (x Response_Status) String() string {
 	return proto.EnumName(Response_Status_name, int32(x))
 }
 func (Response_Status) EnumDescriptor() ([]byte, []int) {
-	return fileDescriptor_iodin_4e2934b64013cba2, []int{1, 0}
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{7, 0}
+}
+
+type Event_Kind int32
+
+const (
+	Event_INVALID Event_Kind = 0
+	// Non-empty response to MDB poll.
+	Event_MDB_POLL Event_Kind = 1
+	// Level change on watched GPIO pin.
+	Event_GPIO_EDGE Event_Kind = 2
+	// MDB transaction failed.
+	Event_BUS_ERROR Event_Kind = 3
+	// MDB transaction took much longer than its timeout.
+	Event_WATCHDOG Event_Kind = 4
+)
+
+var Event_Kind_name = map[int32]string{
+	0: "INVALID",
+	1: "MDB_POLL",
+	2: "GPIO_EDGE",
+	3: "BUS_ERROR",
+	4: "WATCHDOG",
+}
+var Event_Kind_value = map[string]int32{
+	"INVALID":   0,
+	"MDB_POLL":  1,
+	"GPIO_EDGE": 2,
+	"BUS_ERROR": 3,
+	"WATCHDOG":  4,
+}
+
+func (x Event_Kind) String() string {
+	return proto.EnumName(Event_Kind_name, int32(x))
+}
+func (Event_Kind) EnumDescriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{11, 0}
+}
+
+type ErrorDetail_Kind int32
+
+const (
+	ErrorDetail_INVALID              ErrorDetail_Kind = 0
+	ErrorDetail_OTHER                ErrorDetail_Kind = 1
+	ErrorDetail_INPUT                ErrorDetail_Kind = 2
+	ErrorDetail_IO                   ErrorDetail_Kind = 3
+	ErrorDetail_MDB_NAK              ErrorDetail_Kind = 4
+	ErrorDetail_MDB_INVALID_RESPONSE ErrorDetail_Kind = 5
+	ErrorDetail_MDB_CHECKSUM         ErrorDetail_Kind = 6
+	ErrorDetail_MDB_TIMEOUT          ErrorDetail_Kind = 7
+)
+
+var ErrorDetail_Kind_name = map[int32]string{
+	0: "INVALID",
+	1: "OTHER",
+	2: "INPUT",
+	3: "IO",
+	4: "MDB_NAK",
+	5: "MDB_INVALID_RESPONSE",
+	6: "MDB_CHECKSUM",
+	7: "MDB_TIMEOUT",
+}
+var ErrorDetail_Kind_value = map[string]int32{
+	"INVALID":              0,
+	"OTHER":                1,
+	"INPUT":                2,
+	"IO":                   3,
+	"MDB_NAK":              4,
+	"MDB_INVALID_RESPONSE": 5,
+	"MDB_CHECKSUM":         6,
+	"MDB_TIMEOUT":          7,
+}
+
+func (x ErrorDetail_Kind) String() string {
+	return proto.EnumName(ErrorDetail_Kind_name, int32(x))
+}
+func (ErrorDetail_Kind) EnumDescriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{13, 0}
+}
+
+type ErrorDetail_Stage int32
+
+const (
+	ErrorDetail_NONE    ErrorDetail_Stage = 0
+	ErrorDetail_SEND    ErrorDetail_Stage = 1
+	ErrorDetail_RECEIVE ErrorDetail_Stage = 2
+	ErrorDetail_ACK     ErrorDetail_Stage = 3
+)
+
+var ErrorDetail_Stage_name = map[int32]string{
+	0: "NONE",
+	1: "SEND",
+	2: "RECEIVE",
+	3: "ACK",
+}
+var ErrorDetail_Stage_value = map[string]int32{
+	"NONE":    0,
+	"SEND":    1,
+	"RECEIVE": 2,
+	"ACK":     3,
+}
+
+func (x ErrorDetail_Stage) String() string {
+	return proto.EnumName(ErrorDetail_Stage_name, int32(x))
+}
+func (ErrorDetail_Stage) EnumDescriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{13, 1}
+}
+
+type MdbCoin_Op int32
+
+const (
+	MdbCoin_INVALID MdbCoin_Op = 0
+	MdbCoin_RESET   MdbCoin_Op = 1
+	// Result in setup.
+	MdbCoin_SETUP MdbCoin_Op = 2
+	// Result in tubes.
+	MdbCoin_TUBE_STATUS MdbCoin_Op = 3
+	// Enable coin types for acceptance and manual dispense.
+	MdbCoin_COIN_TYPE MdbCoin_Op = 4
+	// Dispense count coins of coin_type from tube.
+	MdbCoin_DISPENSE MdbCoin_Op = 5
+	// Result in events, empty if changer has nothing to report.
+	MdbCoin_POLL MdbCoin_Op = 6
+	// Level 3 expansion, result in identification.
+	MdbCoin_IDENTIFICATION MdbCoin_Op = 7
+)
+
+var MdbCoin_Op_name = map[int32]string{
+	0: "INVALID",
+	1: "RESET",
+	2: "SETUP",
+	3: "TUBE_STATUS",
+	4: "COIN_TYPE",
+	5: "DISPENSE",
+	6: "POLL",
+	7: "IDENTIFICATION",
+}
+var MdbCoin_Op_value = map[string]int32{
+	"INVALID":        0,
+	"RESET":          1,
+	"SETUP":          2,
+	"TUBE_STATUS":    3,
+	"COIN_TYPE":      4,
+	"DISPENSE":       5,
+	"POLL":           6,
+	"IDENTIFICATION": 7,
+}
+
+func (x MdbCoin_Op) String() string {
+	return proto.EnumName(MdbCoin_Op_name, int32(x))
+}
+func (MdbCoin_Op) EnumDescriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{17, 0}
+}
+
+type MdbCoinEvent_Kind int32
+
+const (
+	MdbCoinEvent_INVALID   MdbCoinEvent_Kind = 0
+	MdbCoinEvent_DEPOSITED MdbCoinEvent_Kind = 1
+	// Coins paid out with manual dispense buttons.
+	MdbCoinEvent_DISPENSED MdbCoinEvent_Kind = 2
+	// Invalid coins since last POLL.
+	MdbCoinEvent_SLUG   MdbCoinEvent_Kind = 3
+	MdbCoinEvent_STATUS MdbCoinEvent_Kind = 4
+)
+
+var MdbCoinEvent_Kind_name = map[int32]string{
+	0: "INVALID",
+	1: "DEPOSITED",
+	2: "DISPENSED",
+	3: "SLUG",
+	4: "STATUS",
+}
+var MdbCoinEvent_Kind_value = map[string]int32{
+	"INVALID":   0,
+	"DEPOSITED": 1,
+	"DISPENSED": 2,
+	"SLUG":      3,
+	"STATUS":    4,
+}
+
+func (x MdbCoinEvent_Kind) String() string {
+	return proto.EnumName(MdbCoinEvent_Kind_name, int32(x))
+}
+func (MdbCoinEvent_Kind) EnumDescriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{20, 0}
+}
+
+type MdbCoinEvent_Routing int32
+
+const (
+	MdbCoinEvent_CASH_BOX MdbCoinEvent_Routing = 0
+	MdbCoinEvent_TUBES    MdbCoinEvent_Routing = 1
+	MdbCoinEvent_NOT_USED MdbCoinEvent_Routing = 2
+	MdbCoinEvent_REJECT   MdbCoinEvent_Routing = 3
+)
+
+var MdbCoinEvent_Routing_name = map[int32]string{
+	0: "CASH_BOX",
+	1: "TUBES",
+	2: "NOT_USED",
+	3: "REJECT",
+}
+var MdbCoinEvent_Routing_value = map[string]int32{
+	"CASH_BOX": 0,
+	"TUBES":    1,
+	"NOT_USED": 2,
+	"REJECT":   3,
+}
+
+func (x MdbCoinEvent_Routing) String() string {
+	return proto.EnumName(MdbCoinEvent_Routing_name, int32(x))
+}
+func (MdbCoinEvent_Routing) EnumDescriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{20, 1}
+}
+
+type MdbCoinEvent_Status int32
+
+const (
+	MdbCoinEvent_STATUS_NONE           MdbCoinEvent_Status = 0
+	MdbCoinEvent_ESCROW_REQUEST        MdbCoinEvent_Status = 1
+	MdbCoinEvent_PAYOUT_BUSY           MdbCoinEvent_Status = 2
+	MdbCoinEvent_NO_CREDIT             MdbCoinEvent_Status = 3
+	MdbCoinEvent_DEFECTIVE_TUBE_SENSOR MdbCoinEvent_Status = 4
+	MdbCoinEvent_DOUBLE_ARRIVAL        MdbCoinEvent_Status = 5
+	MdbCoinEvent_ACCEPTOR_UNPLUGGED    MdbCoinEvent_Status = 6
+	MdbCoinEvent_TUBE_JAM              MdbCoinEvent_Status = 7
+	MdbCoinEvent_ROM_CHECKSUM_ERROR    MdbCoinEvent_Status = 8
+	MdbCoinEvent_ROUTING_ERROR         MdbCoinEvent_Status = 9
+	MdbCoinEvent_BUSY                  MdbCoinEvent_Status = 10
+	MdbCoinEvent_JUST_RESET            MdbCoinEvent_Status = 11
+	MdbCoinEvent_COIN_JAM              MdbCoinEvent_Status = 12
+	MdbCoinEvent_CREDITED_COIN_REMOVAL MdbCoinEvent_Status = 13
+)
+
+var MdbCoinEvent_Status_name = map[int32]string{
+	0:  "STATUS_NONE",
+	1:  "ESCROW_REQUEST",
+	2:  "PAYOUT_BUSY",
+	3:  "NO_CREDIT",
+	4:  "DEFECTIVE_TUBE_SENSOR",
+	5:  "DOUBLE_ARRIVAL",
+	6:  "ACCEPTOR_UNPLUGGED",
+	7:  "TUBE_JAM",
+	8:  "ROM_CHECKSUM_ERROR",
+	9:  "ROUTING_ERROR",
+	10: "BUSY",
+	11: "JUST_RESET",
+	12: "COIN_JAM",
+	13: "CREDITED_COIN_REMOVAL",
+}
+var MdbCoinEvent_Status_value = map[string]int32{
+	"STATUS_NONE":           0,
+	"ESCROW_REQUEST":        1,
+	"PAYOUT_BUSY":           2,
+	"NO_CREDIT":             3,
+	"DEFECTIVE_TUBE_SENSOR": 4,
+	"DOUBLE_ARRIVAL":        5,
+	"ACCEPTOR_UNPLUGGED":    6,
+	"TUBE_JAM":              7,
+	"ROM_CHECKSUM_ERROR":    8,
+	"ROUTING_ERROR":         9,
+	"BUSY":                  10,
+	"JUST_RESET":            11,
+	"COIN_JAM":              12,
+	"CREDITED_COIN_REMOVAL": 13,
+}
+
+func (x MdbCoinEvent_Status) String() string {
+	return proto.EnumName(MdbCoinEvent_Status_name, int32(x))
+}
+func (MdbCoinEvent_Status) EnumDescriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{20, 2}
+}
+
+type MdbBill_Op int32
+
+const (
+	MdbBill_INVALID MdbBill_Op = 0
+	MdbBill_RESET   MdbBill_Op = 1
+	// Result in setup.
+	MdbBill_SETUP MdbBill_Op = 2
+	// High security for bill types in security_mask.
+	MdbBill_SECURITY MdbBill_Op = 3
+	// Enable bill types for acceptance and escrow.
+	MdbBill_BILL_TYPE MdbBill_Op = 4
+	// Stack or return bill held in escrow.
+	MdbBill_ESCROW MdbBill_Op = 5
+	// Result in stacker.
+	MdbBill_STACKER MdbBill_Op = 6
+	// Result in events, empty if validator has nothing to report.
+	MdbBill_POLL MdbBill_Op = 7
+	// Level 1 expansion, result in identification.
+	MdbBill_IDENTIFICATION MdbBill_Op = 8
+	// Level 2 expansion, identification with optional features.
+	MdbBill_IDENTIFICATION_FEATURES MdbBill_Op = 9
+)
+
+var MdbBill_Op_name = map[int32]string{
+	0: "INVALID",
+	1: "RESET",
+	2: "SETUP",
+	3: "SECURITY",
+	4: "BILL_TYPE",
+	5: "ESCROW",
+	6: "STACKER",
+	7: "POLL",
+	8: "IDENTIFICATION",
+	9: "IDENTIFICATION_FEATURES",
+}
+var MdbBill_Op_value = map[string]int32{
+	"INVALID":                 0,
+	"RESET":                   1,
+	"SETUP":                   2,
+	"SECURITY":                3,
+	"BILL_TYPE":               4,
+	"ESCROW":                  5,
+	"STACKER":                 6,
+	"POLL":                    7,
+	"IDENTIFICATION":          8,
+	"IDENTIFICATION_FEATURES": 9,
+}
+
+func (x MdbBill_Op) String() string {
+	return proto.EnumName(MdbBill_Op_name, int32(x))
+}
+func (MdbBill_Op) EnumDescriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{23, 0}
+}
+
+type MdbBillEvent_Kind int32
+
+const (
+	MdbBillEvent_INVALID MdbBillEvent_Kind = 0
+	// Bill of bill_type moved as routing says.
+	MdbBillEvent_BILL MdbBillEvent_Kind = 1
+	// Attempts to insert bill while validator was disabled.
+	MdbBillEvent_DISABLED_ATTEMPTS MdbBillEvent_Kind = 2
+	MdbBillEvent_STATUS            MdbBillEvent_Kind = 3
+)
+
+var MdbBillEvent_Kind_name = map[int32]string{
+	0: "INVALID",
+	1: "BILL",
+	2: "DISABLED_ATTEMPTS",
+	3: "STATUS",
+}
+var MdbBillEvent_Kind_value = map[string]int32{
+	"INVALID":           0,
+	"BILL":              1,
+	"DISABLED_ATTEMPTS": 2,
+	"STATUS":            3,
+}
+
+func (x MdbBillEvent_Kind) String() string {
+	return proto.EnumName(MdbBillEvent_Kind_name, int32(x))
+}
+func (MdbBillEvent_Kind) EnumDescriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{26, 0}
+}
+
+type MdbBillEvent_Routing int32
+
+const (
+	MdbBillEvent_STACKED              MdbBillEvent_Routing = 0
+	MdbBillEvent_ESCROW               MdbBillEvent_Routing = 1
+	MdbBillEvent_RETURNED             MdbBillEvent_Routing = 2
+	MdbBillEvent_TO_RECYCLER          MdbBillEvent_Routing = 3
+	MdbBillEvent_DISABLED_REJECTED    MdbBillEvent_Routing = 4
+	MdbBillEvent_TO_RECYCLER_MANUAL   MdbBillEvent_Routing = 5
+	MdbBillEvent_DISPENSED_MANUAL     MdbBillEvent_Routing = 6
+	MdbBillEvent_RECYCLER_TO_CASH_BOX MdbBillEvent_Routing = 7
+)
+
+var MdbBillEvent_Routing_name = map[int32]string{
+	0: "STACKED",
+	1: "ESCROW",
+	2: "RETURNED",
+	3: "TO_RECYCLER",
+	4: "DISABLED_REJECTED",
+	5: "TO_RECYCLER_MANUAL",
+	6: "DISPENSED_MANUAL",
+	7: "RECYCLER_TO_CASH_BOX",
+}
+var MdbBillEvent_Routing_value = map[string]int32{
+	"STACKED":              0,
+	"ESCROW":               1,
+	"RETURNED":             2,
+	"TO_RECYCLER":          3,
+	"DISABLED_REJECTED":    4,
+	"TO_RECYCLER_MANUAL":   5,
+	"DISPENSED_MANUAL":     6,
+	"RECYCLER_TO_CASH_BOX": 7,
+}
+
+func (x MdbBillEvent_Routing) String() string {
+	return proto.EnumName(MdbBillEvent_Routing_name, int32(x))
+}
+func (MdbBillEvent_Routing) EnumDescriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{26, 1}
+}
+
+type MdbBillEvent_Status int32
+
+const (
+	MdbBillEvent_STATUS_NONE            MdbBillEvent_Status = 0
+	MdbBillEvent_DEFECTIVE_MOTOR        MdbBillEvent_Status = 1
+	MdbBillEvent_SENSOR_PROBLEM         MdbBillEvent_Status = 2
+	MdbBillEvent_BUSY                   MdbBillEvent_Status = 3
+	MdbBillEvent_ROM_CHECKSUM_ERROR     MdbBillEvent_Status = 4
+	MdbBillEvent_JAMMED                 MdbBillEvent_Status = 5
+	MdbBillEvent_JUST_RESET             MdbBillEvent_Status = 6
+	MdbBillEvent_BILL_REMOVED           MdbBillEvent_Status = 7
+	MdbBillEvent_CASH_BOX_REMOVED       MdbBillEvent_Status = 8
+	MdbBillEvent_DISABLED               MdbBillEvent_Status = 9
+	MdbBillEvent_INVALID_ESCROW_REQUEST MdbBillEvent_Status = 10
+	MdbBillEvent_BILL_REJECTED          MdbBillEvent_Status = 11
+	MdbBillEvent_CREDITED_BILL_REMOVAL  MdbBillEvent_Status = 12
+)
+
+var MdbBillEvent_Status_name = map[int32]string{
+	0:  "STATUS_NONE",
+	1:  "DEFECTIVE_MOTOR",
+	2:  "SENSOR_PROBLEM",
+	3:  "BUSY",
+	4:  "ROM_CHECKSUM_ERROR",
+	5:  "JAMMED",
+	6:  "JUST_RESET",
+	7:  "BILL_REMOVED",
+	8:  "CASH_BOX_REMOVED",
+	9:  "DISABLED",
+	10: "INVALID_ESCROW_REQUEST",
+	11: "BILL_REJECTED",
+	12: "CREDITED_BILL_REMOVAL",
+}
+var MdbBillEvent_Status_value = map[string]int32{
+	"STATUS_NONE":            0,
+	"DEFECTIVE_MOTOR":        1,
+	"SENSOR_PROBLEM":         2,
+	"BUSY":                   3,
+	"ROM_CHECKSUM_ERROR":     4,
+	"JAMMED":                 5,
+	"JUST_RESET":             6,
+	"BILL_REMOVED":           7,
+	"CASH_BOX_REMOVED":       8,
+	"DISABLED":               9,
+	"INVALID_ESCROW_REQUEST": 10,
+	"BILL_REJECTED":          11,
+	"CREDITED_BILL_REMOVAL":  12,
+}
+
+func (x MdbBillEvent_Status) String() string {
+	return proto.EnumName(MdbBillEvent_Status_name, int32(x))
+}
+func (MdbBillEvent_Status) EnumDescriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{26, 2}
+}
+
+type MdbCashless_Op int32
+
+const (
+	MdbCashless_INVALID MdbCashless_Op = 0
+	// Legal in any state, reader reports JUST_RESET and waits for SETUP.
+	MdbCashless_RESET MdbCashless_Op = 1
+	// Config data and max/min prices, result in setup.
+	MdbCashless_SETUP   MdbCashless_Op = 2
+	MdbCashless_ENABLE  MdbCashless_Op = 3
+	MdbCashless_DISABLE MdbCashless_Op = 4
+	// Abort card reading while enabled, reader reports CANCELLED.
+	MdbCashless_CANCEL MdbCashless_Op = 5
+	// Result in events, empty if reader has nothing to report.
+	MdbCashless_POLL MdbCashless_Op = 6
+	// Ask approval for item at price, reader answers VEND_APPROVED or VEND_DENIED.
+	MdbCashless_VEND_REQUEST MdbCashless_Op = 7
+	// Withdraw VEND_REQUEST before approval.
+	MdbCashless_VEND_CANCEL  MdbCashless_Op = 8
+	MdbCashless_VEND_SUCCESS MdbCashless_Op = 9
+	MdbCashless_VEND_FAILURE MdbCashless_Op = 10
+	// End session, reader reports END_SESSION.
+	MdbCashless_SESSION_COMPLETE MdbCashless_Op = 11
+	// Result in identification.
+	MdbCashless_IDENTIFICATION MdbCashless_Op = 12
+	// Only report state, no bus transaction.
+	MdbCashless_STATE MdbCashless_Op = 13
+)
+
+var MdbCashless_Op_name = map[int32]string{
+	0:  "INVALID",
+	1:  "RESET",
+	2:  "SETUP",
+	3:  "ENABLE",
+	4:  "DISABLE",
+	5:  "CANCEL",
+	6:  "POLL",
+	7:  "VEND_REQUEST",
+	8:  "VEND_CANCEL",
+	9:  "VEND_SUCCESS",
+	10: "VEND_FAILURE",
+	11: "SESSION_COMPLETE",
+	12: "IDENTIFICATION",
+	13: "STATE",
+}
+var MdbCashless_Op_value = map[string]int32{
+	"INVALID":          0,
+	"RESET":            1,
+	"SETUP":            2,
+	"ENABLE":           3,
+	"DISABLE":          4,
+	"CANCEL":           5,
+	"POLL":             6,
+	"VEND_REQUEST":     7,
+	"VEND_CANCEL":      8,
+	"VEND_SUCCESS":     9,
+	"VEND_FAILURE":     10,
+	"SESSION_COMPLETE": 11,
+	"IDENTIFICATION":   12,
+	"STATE":            13,
+}
+
+func (x MdbCashless_Op) String() string {
+	return proto.EnumName(MdbCashless_Op_name, int32(x))
+}
+func (MdbCashless_Op) EnumDescriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{28, 0}
+}
+
+type MdbCashlessEvent_Kind int32
+
+const (
+	MdbCashlessEvent_INVALID    MdbCashlessEvent_Kind = 0
+	MdbCashlessEvent_JUST_RESET MdbCashlessEvent_Kind = 1
+	// setup
+	MdbCashlessEvent_READER_CONFIG MdbCashlessEvent_Kind = 2
+	// display_time, display_data
+	MdbCashlessEvent_DISPLAY_REQUEST MdbCashlessEvent_Kind = 3
+	// amount is funds available
+	MdbCashlessEvent_BEGIN_SESSION MdbCashlessEvent_Kind = 4
+	// VMC should send SESSION_COMPLETE.
+	MdbCashlessEvent_SESSION_CANCEL_REQUEST MdbCashlessEvent_Kind = 5
+	// amount charged
+	MdbCashlessEvent_VEND_APPROVED MdbCashlessEvent_Kind = 6
+	MdbCashlessEvent_VEND_DENIED   MdbCashlessEvent_Kind = 7
+	MdbCashlessEvent_END_SESSION   MdbCashlessEvent_Kind = 8
+	MdbCashlessEvent_CANCELLED     MdbCashlessEvent_Kind = 9
+	// identification
+	MdbCashlessEvent_PERIPHERAL_ID MdbCashlessEvent_Kind = 10
+	// code is error code, VMC should reset reader.
+	MdbCashlessEvent_MALFUNCTION MdbCashlessEvent_Kind = 11
+	// Reader fell back to inactive, VMC should reset it.
+	MdbCashlessEvent_OUT_OF_SEQUENCE MdbCashlessEvent_Kind = 12
+)
+
+var MdbCashlessEvent_Kind_name = map[int32]string{
+	0:  "INVALID",
+	1:  "JUST_RESET",
+	2:  "READER_CONFIG",
+	3:  "DISPLAY_REQUEST",
+	4:  "BEGIN_SESSION",
+	5:  "SESSION_CANCEL_REQUEST",
+	6:  "VEND_APPROVED",
+	7:  "VEND_DENIED",
+	8:  "END_SESSION",
+	9:  "CANCELLED",
+	10: "PERIPHERAL_ID",
+	11: "MALFUNCTION",
+	12: "OUT_OF_SEQUENCE",
+}
+var MdbCashlessEvent_Kind_value = map[string]int32{
+	"INVALID":                0,
+	"JUST_RESET":             1,
+	"READER_CONFIG":          2,
+	"DISPLAY_REQUEST":        3,
+	"BEGIN_SESSION":          4,
+	"SESSION_CANCEL_REQUEST": 5,
+	"VEND_APPROVED":          6,
+	"VEND_DENIED":            7,
+	"END_SESSION":            8,
+	"CANCELLED":              9,
+	"PERIPHERAL_ID":          10,
+	"MALFUNCTION":            11,
+	"OUT_OF_SEQUENCE":        12,
+}
+
+func (x MdbCashlessEvent_Kind) String() string {
+	return proto.EnumName(MdbCashlessEvent_Kind_name, int32(x))
+}
+func (MdbCashlessEvent_Kind) EnumDescriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{30, 0}
+}
+
+type MdbCashlessResult_State int32
+
+const (
+	// After reset, waits for SETUP.
+	MdbCashlessResult_INACTIVE MdbCashlessResult_State = 0
+	MdbCashlessResult_DISABLED MdbCashlessResult_State = 1
+	// Waits for BEGIN_SESSION.
+	MdbCashlessResult_ENABLED MdbCashlessResult_State = 2
+	// Waits for VEND_REQUEST or SESSION_COMPLETE.
+	MdbCashlessResult_SESSION_IDLE MdbCashlessResult_State = 3
+	// Waits for VEND_APPROVED or VEND_DENIED.
+	MdbCashlessResult_VEND_REQUESTED MdbCashlessResult_State = 4
+	// Waits for VEND_SUCCESS or VEND_FAILURE.
+	MdbCashlessResult_VEND_APPROVED MdbCashlessResult_State = 5
+	// Waits for END_SESSION.
+	MdbCashlessResult_SESSION_END MdbCashlessResult_State = 6
+)
+
+var MdbCashlessResult_State_name = map[int32]string{
+	0: "INACTIVE",
+	1: "DISABLED",
+	2: "ENABLED",
+	3: "SESSION_IDLE",
+	4: "VEND_REQUESTED",
+	5: "VEND_APPROVED",
+	6: "SESSION_END",
+}
+var MdbCashlessResult_State_value = map[string]int32{
+	"INACTIVE":       0,
+	"DISABLED":       1,
+	"ENABLED":        2,
+	"SESSION_IDLE":   3,
+	"VEND_REQUESTED": 4,
+	"VEND_APPROVED":  5,
+	"SESSION_END":    6,
+}
+
+func (x MdbCashlessResult_State) String() string {
+	return proto.EnumName(MdbCashlessResult_State_name, int32(x))
+}
+func (MdbCashlessResult_State) EnumDescriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{31, 0}
 }
 
 type Request struct {
-	Version              uint32          `protobuf:"varint,1,opt,name=version,proto3" json:"version,omitempty"`
-	Command              Request_Command `protobuf:"varint,2,opt,name=command,proto3,enum=iodin.Request_Command" json:"command,omitempty"`
-	Timeout              uint32          `protobuf:"varint,3,opt,name=timeout,proto3" json:"timeout,omitempty"`
-	ArgUint              uint32          `protobuf:"varint,4,opt,name=arg_uint,json=argUint,proto3" json:"arg_uint,omitempty"`
-	ArgBytes             []byte          `protobuf:"bytes,5,opt,name=arg_bytes,json=argBytes,proto3" json:"arg_bytes,omitempty"`
-	XXX_NoUnkeyedLiteral struct{}        `json:"-"`
-	XXX_unrecognized     []byte          `json:"-"`
-	XXX_sizecache        int32           `json:"-"`
+	// Protocol version spoken by client, 0 for legacy clients.
+	Version uint32          `protobuf:"varint,1,opt,name=version,proto3" json:"version,omitempty"`
+	Command Request_Command `protobuf:"varint,2,opt,name=command,proto3,enum=iodin.Request_Command" json:"command,omitempty"`
+	// MDB_TX response timeout in milliseconds, 0 means server default.
+	Timeout  uint32 `protobuf:"varint,3,opt,name=timeout,proto3" json:"timeout,omitempty"`
+	ArgUint  uint32 `protobuf:"varint,4,opt,name=arg_uint,json=argUint,proto3" json:"arg_uint,omitempty"`
+	ArgBytes []byte `protobuf:"bytes,5,opt,name=arg_bytes,json=argBytes,proto3" json:"arg_bytes,omitempty"`
+	// Client chosen correlation id, echoed in Response.id.
+	Id uint32 `protobuf:"varint,6,opt,name=id,proto3" json:"id,omitempty"`
+	// Typed arguments, preferred over legacy arg_uint/arg_bytes.
+	// Command may be left INVALID, it is implied by args.
+	//
+	// Types that are valid to be assigned to Args:
+	//	*Request_MdbOpen
+	//	*Request_MdbReset
+	//	*Request_MdbTx
+	//	*Request_MdbBatch
+	//	*Request_Subscribe
+	//	*Request_GpioWatch
+	//	*Request_MdbPoll
+	//	*Request_MdbCoin
+	//	*Request_MdbBill
+	//	*Request_MdbCashless
+	Args                 isRequest_Args `protobuf_oneof:"args"`
+	XXX_NoUnkeyedLiteral struct{}       `json:"-"`
+	XXX_unrecognized     []byte         `json:"-"`
+	XXX_sizecache        int32          `json:"-"`
 }
 
 func (m *Request) Reset()         { *m = Request{} }
 func (m *Request) String() string { return proto.CompactTextString(m) }
 func (*Request) ProtoMessage()    {}
 func (*Request) Descriptor() ([]byte, []int) {
-	return fileDescriptor_iodin_4e2934b64013cba2, []int{0}
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{0}
 }
 func (m *Request) XXX_Unmarshal(b []byte) error {
 	return xxx_messageInfo_Request.Unmarshal(m, b)
@@ -123,6 +843,59 @@ func (m *Request) XXX_DiscardUnknown() {
 
 var xxx_messageInfo_Request proto.InternalMessageInfo
 
+type isRequest_Args interface {
+	isRequest_Args()
+}
+
+type Request_MdbOpen struct {
+	MdbOpen *MdbOpen `protobuf:"bytes,10,opt,name=mdb_open,json=mdbOpen,proto3,oneof"`
+}
+type Request_MdbReset struct {
+	MdbReset *MdbReset `protobuf:"bytes,11,opt,name=mdb_reset,json=mdbReset,proto3,oneof"`
+}
+type Request_MdbTx struct {
+	MdbTx *MdbTx `protobuf:"bytes,12,opt,name=mdb_tx,json=mdbTx,proto3,oneof"`
+}
+type Request_MdbBatch struct {
+	MdbBatch *MdbBatch `protobuf:"bytes,13,opt,name=mdb_batch,json=mdbBatch,proto3,oneof"`
+}
+type Request_Subscribe struct {
+	Subscribe *Subscribe `protobuf:"bytes,14,opt,name=subscribe,proto3,oneof"`
+}
+type Request_GpioWatch struct {
+	GpioWatch *GpioWatch `protobuf:"bytes,15,opt,name=gpio_watch,json=gpioWatch,proto3,oneof"`
+}
+type Request_MdbPoll struct {
+	MdbPoll *MdbPoll `protobuf:"bytes,16,opt,name=mdb_poll,json=mdbPoll,proto3,oneof"`
+}
+type Request_MdbCoin struct {
+	MdbCoin *MdbCoin `protobuf:"bytes,17,opt,name=mdb_coin,json=mdbCoin,proto3,oneof"`
+}
+type Request_MdbBill struct {
+	MdbBill *MdbBill `protobuf:"bytes,18,opt,name=mdb_bill,json=mdbBill,proto3,oneof"`
+}
+type Request_MdbCashless struct {
+	MdbCashless *MdbCashless `protobuf:"bytes,19,opt,name=mdb_cashless,json=mdbCashless,proto3,oneof"`
+}
+
+func (*Request_MdbOpen) isRequest_Args()     {}
+func (*Request_MdbReset) isRequest_Args()    {}
+func (*Request_MdbTx) isRequest_Args()       {}
+func (*Request_MdbBatch) isRequest_Args()    {}
+func (*Request_Subscribe) isRequest_Args()   {}
+func (*Request_GpioWatch) isRequest_Args()   {}
+func (*Request_MdbPoll) isRequest_Args()     {}
+func (*Request_MdbCoin) isRequest_Args()     {}
+func (*Request_MdbBill) isRequest_Args()     {}
+func (*Request_MdbCashless) isRequest_Args() {}
+
+func (m *Request) GetArgs() isRequest_Args {
+	if m != nil {
+		return m.Args
+	}
+	return nil
+}
+
 func (m *Request) GetVersion() uint32 {
 	if m != nil {
 		return m.Version
@@ -158,100 +931,2944 @@ func (m *Request) GetArgBytes() []byte {
 	return nil
 }
 
-type Response struct {
-	Version              uint32          `protobuf:"varint,1,opt,name=version,proto3" json:"version,omitempty"`
-	Status               Response_Status `protobuf:"varint,2,opt,name=status,proto3,enum=iodin.Response_Status" json:"status,omitempty"`
-	Error                string          `protobuf:"bytes,3,opt,name=error,proto3" json:"error,omitempty"`
-	DataBytes            []byte          `protobuf:"bytes,4,opt,name=data_bytes,json=dataBytes,proto3" json:"data_bytes,omitempty"`
-	XXX_NoUnkeyedLiteral struct{}        `json:"-"`
-	XXX_unrecognized     []byte          `json:"-"`
-	XXX_sizecache        int32           `json:"-"`
+func (m *Request) GetId() uint32 {
+	if m != nil {
+		return m.Id
+	}
+	return 0
 }
 
-func (m *Response) Reset()         { *m = Response{} }
-func (m *Response) String() string { return proto.CompactTextString(m) }
-func (*Response) ProtoMessage()    {}
-func (*Response) Descriptor() ([]byte, []int) {
-	return fileDescriptor_iodin_4e2934b64013cba2, []int{1}
+func (m *Request) GetMdbOpen() *MdbOpen {
+	if x, ok := m.GetArgs().(*Request_MdbOpen); ok {
+		return x.MdbOpen
+	}
+	return nil
 }
-func (m *Response) XXX_Unmarshal(b []byte) error {
-	return xxx_messageInfo_Response.Unmarshal(m, b)
+
+func (m *Request) GetMdbReset() *MdbReset {
+	if x, ok := m.GetArgs().(*Request_MdbReset); ok {
+		return x.MdbReset
+	}
+	return nil
 }
-func (m *Response) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
-	return xxx_messageInfo_Response.Marshal(b, m, deterministic)
+
+func (m *Request) GetMdbTx() *MdbTx {
+	if x, ok := m.GetArgs().(*Request_MdbTx); ok {
+		return x.MdbTx
+	}
+	return nil
 }
-func (dst *Response) XXX_Merge(src proto.Message) {
-	xxx_messageInfo_Response.Merge(dst, src)
+
+func (m *Request) GetMdbBatch() *MdbBatch {
+	if x, ok := m.GetArgs().(*Request_MdbBatch); ok {
+		return x.MdbBatch
+	}
+	return nil
 }
-func (m *Response) XXX_Size() int {
-	return xxx_messageInfo_Response.Size(m)
+
+func (m *Request) GetSubscribe() *Subscribe {
+	if x, ok := m.GetArgs().(*Request_Subscribe); ok {
+		return x.Subscribe
+	}
+	return nil
 }
-func (m *Response) XXX_DiscardUnknown() {
-	xxx_messageInfo_Response.DiscardUnknown(m)
+
+func (m *Request) GetGpioWatch() *GpioWatch {
+	if x, ok := m.GetArgs().(*Request_GpioWatch); ok {
+		return x.GpioWatch
+	}
+	return nil
 }
 
-var xxx_messageInfo_Response proto.InternalMessageInfo
+func (m *Request) GetMdbPoll() *MdbPoll {
+	if x, ok := m.GetArgs().(*Request_MdbPoll); ok {
+		return x.MdbPoll
+	}
+	return nil
+}
 
-func (m *Response) GetVersion() uint32 {
-	if m != nil {
-		return m.Version
+func (m *Request) GetMdbCoin() *MdbCoin {
+	if x, ok := m.GetArgs().(*Request_MdbCoin); ok {
+		return x.MdbCoin
 	}
-	return 0
+	return nil
 }
 
-func (m *Response) GetStatus() Response_Status {
-	if m != nil {
-		return m.Status
+func (m *Request) GetMdbBill() *MdbBill {
+	if x, ok := m.GetArgs().(*Request_MdbBill); ok {
+		return x.MdbBill
 	}
-	return Response_INVALID
+	return nil
 }
 
-func (m *Response) GetError() string {
-	if m != nil {
-		return m.Error
+func (m *Request) GetMdbCashless() *MdbCashless {
+	if x, ok := m.GetArgs().(*Request_MdbCashless); ok {
+		return x.MdbCashless
 	}
-	return ""
+	return nil
 }
 
-func (m *Response) GetDataBytes() []byte {
-	if m != nil {
-		return m.DataBytes
+// XXX_OneofFuncs is for the internal use of the proto package.
+func (*Request) XXX_OneofFuncs() (func(msg proto.Message, b *proto.Buffer) error, func(msg proto.Message, tag, wire int, b *proto.Buffer) (bool, error), func(msg proto.Message) (n int), []interface{}) {
+	return _Request_OneofMarshaler, _Request_OneofUnmarshaler, _Request_OneofSizer, []interface{}{
+		(*Request_MdbOpen)(nil),
+		(*Request_MdbReset)(nil),
+		(*Request_MdbTx)(nil),
+		(*Request_MdbBatch)(nil),
+		(*Request_Subscribe)(nil),
+		(*Request_GpioWatch)(nil),
+		(*Request_MdbPoll)(nil),
+		(*Request_MdbCoin)(nil),
+		(*Request_MdbBill)(nil),
+		(*Request_MdbCashless)(nil),
+	}
+}
+
+func _Request_OneofMarshaler(msg proto.Message, b *proto.Buffer) error {
+	m := msg.(*Request)
+	// args
+	switch x := m.Args.(type) {
+	case *Request_MdbOpen:
+		b.EncodeVarint(10<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.MdbOpen); err != nil {
+			return err
+		}
+	case *Request_MdbReset:
+		b.EncodeVarint(11<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.MdbReset); err != nil {
+			return err
+		}
+	case *Request_MdbTx:
+		b.EncodeVarint(12<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.MdbTx); err != nil {
+			return err
+		}
+	case *Request_MdbBatch:
+		b.EncodeVarint(13<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.MdbBatch); err != nil {
+			return err
+		}
+	case *Request_Subscribe:
+		b.EncodeVarint(14<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.Subscribe); err != nil {
+			return err
+		}
+	case *Request_GpioWatch:
+		b.EncodeVarint(15<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.GpioWatch); err != nil {
+			return err
+		}
+	case *Request_MdbPoll:
+		b.EncodeVarint(16<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.MdbPoll); err != nil {
+			return err
+		}
+	case *Request_MdbCoin:
+		b.EncodeVarint(17<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.MdbCoin); err != nil {
+			return err
+		}
+	case *Request_MdbBill:
+		b.EncodeVarint(18<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.MdbBill); err != nil {
+			return err
+		}
+	case *Request_MdbCashless:
+		b.EncodeVarint(19<<3 | proto.WireBytes)
+		if err := b.EncodeMessage(x.MdbCashless); err != nil {
+			return err
+		}
+	case nil:
+	default:
+		return fmt.Errorf("Request.Args has unexpected type %T", x)
 	}
 	return nil
 }
 
-func init() {
-	proto.RegisterType((*Request)(nil), "iodin.Request")
-	proto.RegisterType((*Response)(nil), "iodin.Response")
-	proto.RegisterEnum("iodin.Request_Command", Request_Command_name, Request_Command_value)
-	proto.RegisterEnum("iodin.Response_Status", Response_Status_name, Response_Status_value)
+func _Request_OneofUnmarshaler(msg proto.Message, tag, wire int, b *proto.Buffer) (bool, error) {
+	m := msg.(*Request)
+	switch tag {
+	case 10: // args.mdb_open
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(MdbOpen)
+		err := b.DecodeMessage(msg)
+		m.Args = &Request_MdbOpen{msg}
+		return true, err
+	case 11: // args.mdb_reset
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(MdbReset)
+		err := b.DecodeMessage(msg)
+		m.Args = &Request_MdbReset{msg}
+		return true, err
+	case 12: // args.mdb_tx
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(MdbTx)
+		err := b.DecodeMessage(msg)
+		m.Args = &Request_MdbTx{msg}
+		return true, err
+	case 13: // args.mdb_batch
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(MdbBatch)
+		err := b.DecodeMessage(msg)
+		m.Args = &Request_MdbBatch{msg}
+		return true, err
+	case 14: // args.subscribe
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(Subscribe)
+		err := b.DecodeMessage(msg)
+		m.Args = &Request_Subscribe{msg}
+		return true, err
+	case 15: // args.gpio_watch
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(GpioWatch)
+		err := b.DecodeMessage(msg)
+		m.Args = &Request_GpioWatch{msg}
+		return true, err
+	case 16: // args.mdb_poll
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(MdbPoll)
+		err := b.DecodeMessage(msg)
+		m.Args = &Request_MdbPoll{msg}
+		return true, err
+	case 17: // args.mdb_coin
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(MdbCoin)
+		err := b.DecodeMessage(msg)
+		m.Args = &Request_MdbCoin{msg}
+		return true, err
+	case 18: // args.mdb_bill
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(MdbBill)
+		err := b.DecodeMessage(msg)
+		m.Args = &Request_MdbBill{msg}
+		return true, err
+	case 19: // args.mdb_cashless
+		if wire != proto.WireBytes {
+			return true, proto.ErrInternalBadWireType
+		}
+		msg := new(MdbCashless)
+		err := b.DecodeMessage(msg)
+		m.Args = &Request_MdbCashless{msg}
+		return true, err
+	default:
+		return false, nil
+	}
+}
+
+func _Request_OneofSizer(msg proto.Message) (n int) {
+	m := msg.(*Request)
+	// args
+	switch x := m.Args.(type) {
+	case *Request_MdbOpen:
+		s := proto.Size(x.MdbOpen)
+		n += 1 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *Request_MdbReset:
+		s := proto.Size(x.MdbReset)
+		n += 1 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *Request_MdbTx:
+		s := proto.Size(x.MdbTx)
+		n += 1 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *Request_MdbBatch:
+		s := proto.Size(x.MdbBatch)
+		n += 1 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *Request_Subscribe:
+		s := proto.Size(x.Subscribe)
+		n += 1 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *Request_GpioWatch:
+		s := proto.Size(x.GpioWatch)
+		n += 1 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *Request_MdbPoll:
+		s := proto.Size(x.MdbPoll)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *Request_MdbCoin:
+		s := proto.Size(x.MdbCoin)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *Request_MdbBill:
+		s := proto.Size(x.MdbBill)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case *Request_MdbCashless:
+		s := proto.Size(x.MdbCashless)
+		n += 2 // tag and wire
+		n += proto.SizeVarint(uint64(s))
+		n += s
+	case nil:
+	default:
+		panic(fmt.Sprintf("proto: unexpected type %T in oneof", x))
+	}
+	return n
+}
+
+type MdbOpen struct {
+	RxPin     uint32            `protobuf:"varint,1,opt,name=rx_pin,json=rxPin,proto3" json:"rx_pin,omitempty"`
+	TxPin     uint32            `protobuf:"varint,2,opt,name=tx_pin,json=txPin,proto3" json:"tx_pin,omitempty"`
+	Options   *MdbOptions       `protobuf:"bytes,3,opt,name=options,proto3" json:"options,omitempty"`
+	Transport MdbOpen_Transport `protobuf:"varint,4,opt,name=transport,proto3,enum=iodin.MdbOpen_Transport" json:"transport,omitempty"`
+	// UART: tty path like /dev/ttyAMA0 or /dev/ttyUSB0.
+	Device               string   `protobuf:"bytes,5,opt,name=device,proto3" json:"device,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
 }
 
-func init() { proto.RegisterFile("iodin.proto", fileDescriptor_iodin_4e2934b64013cba2) }
-
-var fileDescriptor_iodin_4e2934b64013cba2 = []byte{
-	// 353 bytes of a gzipped FileDescriptorProto
-	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0x7c, 0x92, 0x4f, 0x6b, 0xdb, 0x30,
-	0x18, 0xc6, 0xe7, 0xc4, 0x7f, 0xdf, 0x24, 0x9b, 0x10, 0x63, 0x78, 0x8c, 0x41, 0xc8, 0x29, 0x27,
-	0x33, 0xb6, 0x4f, 0xe0, 0x24, 0x82, 0x98, 0x24, 0x76, 0x90, 0xed, 0xb5, 0xb7, 0xe0, 0xd4, 0x22,
-	0xf8, 0x10, 0x2b, 0xb5, 0xe4, 0x42, 0xbf, 0x76, 0x3f, 0x40, 0x29, 0xb2, 0xec, 0x43, 0x29, 0xf4,
-	0xf8, 0x7b, 0x9f, 0x47, 0x8f, 0xf9, 0x81, 0x61, 0x52, 0xf1, 0xb2, 0xaa, 0x83, 0x5b, 0xc3, 0x25,
-	0xc7, 0x56, 0x07, 0x8b, 0x17, 0x03, 0x1c, 0xca, 0x1e, 0x5b, 0x26, 0x24, 0xf6, 0xc1, 0x79, 0x62,
-	0x8d, 0xa8, 0x78, 0xed, 0x1b, 0x73, 0x63, 0x39, 0xa3, 0x03, 0xe2, 0x3f, 0xe0, 0x3c, 0xf0, 0xeb,
-	0xb5, 0xa8, 0x4b, 0x7f, 0x34, 0x37, 0x96, 0x5f, 0xff, 0xfe, 0x08, 0xf4, 0x56, 0xff, 0x34, 0x58,
-	0xeb, 0x94, 0x0e, 0x35, 0xb5, 0x25, 0xab, 0x2b, 0xe3, 0xad, 0xf4, 0xc7, 0x7a, 0xab, 0x47, 0xfc,
-	0x13, 0xdc, 0xa2, 0xb9, 0x9c, 0xda, 0xaa, 0x96, 0xbe, 0xa9, 0xa3, 0xa2, 0xb9, 0xe4, 0x55, 0x2d,
-	0xf1, 0x2f, 0xf0, 0x54, 0x74, 0x7e, 0x96, 0x4c, 0xf8, 0xd6, 0xdc, 0x58, 0x4e, 0xa9, 0xea, 0xae,
-	0x14, 0x2f, 0x22, 0x70, 0xfa, 0xaf, 0xe0, 0x09, 0x38, 0x51, 0xfc, 0x3f, 0xdc, 0x47, 0x1b, 0xf4,
-	0x05, 0x4f, 0xc1, 0x3d, 0x6c, 0x56, 0xa7, 0xe4, 0x48, 0x62, 0x64, 0xe0, 0x19, 0x78, 0x8a, 0x28,
-	0x49, 0x49, 0x86, 0x46, 0x18, 0xc0, 0x56, 0x98, 0xdd, 0xa3, 0x31, 0x76, 0xc1, 0x4c, 0xb3, 0xe4,
-	0x88, 0xca, 0xc5, 0xab, 0x01, 0x2e, 0x65, 0xe2, 0xc6, 0x6b, 0xc1, 0x3e, 0xb1, 0x0e, 0xc0, 0x16,
-	0xb2, 0x90, 0xad, 0xf8, 0x20, 0xad, 0x9f, 0x06, 0x69, 0x97, 0xd2, 0xbe, 0x85, 0xbf, 0x83, 0xc5,
-	0x9a, 0x86, 0x37, 0x9d, 0xb1, 0x47, 0x35, 0xe0, 0xdf, 0x00, 0x65, 0x21, 0x8b, 0xde, 0xca, 0xec,
-	0xac, 0x3c, 0x75, 0xd1, 0x5a, 0x02, 0x6c, 0x3d, 0xf3, 0xde, 0xca, 0x86, 0x51, 0xb2, 0xd3, 0x3e,
-	0x84, 0xd2, 0x53, 0x14, 0x1f, 0x73, 0xe5, 0x83, 0x60, 0xaa, 0x31, 0x23, 0x34, 0x0e, 0xf7, 0x68,
-	0x3c, 0x5c, 0xd6, 0x5b, 0xb2, 0xde, 0xa5, 0xf9, 0x01, 0x99, 0xf8, 0x1b, 0x4c, 0xd4, 0x25, 0x8b,
-	0x0e, 0x24, 0xc9, 0x33, 0x64, 0x0d, 0x95, 0x6d, 0x48, 0x37, 0x77, 0x21, 0x25, 0xc8, 0x3e, 0xdb,
-	0xdd, 0x3f, 0xf0, 0xef, 0x2d, 0x00, 0x00, 0xff, 0xff, 0xfc, 0x3f, 0x69, 0x78, 0x12, 0x02, 0x00,
-	0x00,
+func (m *MdbOpen) Reset()         { *m = MdbOpen{} }
+func (m *MdbOpen) String() string { return proto.CompactTextString(m) }
+func (*MdbOpen) ProtoMessage()    {}
+func (*MdbOpen) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{1}
+}
+func (m *MdbOpen) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_MdbOpen.Unmarshal(m, b)
+}
+func (m *MdbOpen) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_MdbOpen.Marshal(b, m, deterministic)
+}
+func (dst *MdbOpen) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_MdbOpen.Merge(dst, src)
+}
+func (m *MdbOpen) XXX_Size() int {
+	return xxx_messageInfo_MdbOpen.Size(m)
+}
+func (m *MdbOpen) XXX_DiscardUnknown() {
+	xxx_messageInfo_MdbOpen.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_MdbOpen proto.InternalMessageInfo
+
+func (m *MdbOpen) GetRxPin() uint32 {
+	if m != nil {
+		return m.RxPin
+	}
+	return 0
+}
+
+func (m *MdbOpen) GetTxPin() uint32 {
+	if m != nil {
+		return m.TxPin
+	}
+	return 0
+}
+
+func (m *MdbOpen) GetOptions() *MdbOptions {
+	if m != nil {
+		return m.Options
+	}
+	return nil
+}
+
+func (m *MdbOpen) GetTransport() MdbOpen_Transport {
+	if m != nil {
+		return m.Transport
+	}
+	return MdbOpen_DEFAULT
+}
+
+func (m *MdbOpen) GetDevice() string {
+	if m != nil {
+		return m.Device
+	}
+	return ""
+}
+
+type MdbOptions struct {
+	// Busy wait step in microseconds, 0 means server default.
+	WaitStepUs uint32 `protobuf:"varint,1,opt,name=wait_step_us,json=waitStepUs,proto3" json:"wait_step_us,omitempty"`
+	// SIM transport only, unset means env iodin_mdb_faults, see MdbFaults.
+	Faults               *MdbFaults `protobuf:"bytes,3,opt,name=faults,proto3" json:"faults,omitempty"`
+	XXX_NoUnkeyedLiteral struct{}   `json:"-"`
+	XXX_unrecognized     []byte     `json:"-"`
+	XXX_sizecache        int32      `json:"-"`
+}
+
+func (m *MdbOptions) Reset()         { *m = MdbOptions{} }
+func (m *MdbOptions) String() string { return proto.CompactTextString(m) }
+func (*MdbOptions) ProtoMessage()    {}
+func (*MdbOptions) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{2}
+}
+func (m *MdbOptions) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_MdbOptions.Unmarshal(m, b)
+}
+func (m *MdbOptions) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_MdbOptions.Marshal(b, m, deterministic)
+}
+func (dst *MdbOptions) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_MdbOptions.Merge(dst, src)
+}
+func (m *MdbOptions) XXX_Size() int {
+	return xxx_messageInfo_MdbOptions.Size(m)
+}
+func (m *MdbOptions) XXX_DiscardUnknown() {
+	xxx_messageInfo_MdbOptions.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_MdbOptions proto.InternalMessageInfo
+
+func (m *MdbOptions) GetWaitStepUs() uint32 {
+	if m != nil {
+		return m.WaitStepUs
+	}
+	return 0
+}
+
+func (m *MdbOptions) GetFaults() *MdbFaults {
+	if m != nil {
+		return m.Faults
+	}
+	return nil
+}
+
+// Fault injection for testing VMC error handling, applied to every response
+// received from the simulated bus, MDB_OPEN with other transports is INVALID.
+// Each fault is chance in percent, total at most 100.
+// Env iodin_mdb_faults format: seed=1,drop=10,checksum=5,nak=5,gap=5,garbage=5,gap_ms=20
+type MdbFaults struct {
+	// Same seed and requests give same faults, 0 means fixed default seed.
+	Seed uint64 `protobuf:"varint,1,opt,name=seed,proto3" json:"seed,omitempty"`
+	// Response is lost, MDB_TIMEOUT.
+	Drop uint32 `protobuf:"varint,2,opt,name=drop,proto3" json:"drop,omitempty"`
+	// Mode bit byte is corrupted, MDB_CHECKSUM after RET or MDB_INVALID_RESPONSE.
+	Checksum uint32 `protobuf:"varint,3,opt,name=checksum,proto3" json:"checksum,omitempty"`
+	// Peripheral answers NAK.
+	Nak uint32 `protobuf:"varint,4,opt,name=nak,proto3" json:"nak,omitempty"`
+	// Pause of gap_ms inside response, MDB_TIMEOUT with partial data if it exceeds timeout.
+	Gap uint32 `protobuf:"varint,5,opt,name=gap,proto3" json:"gap,omitempty"`
+	// Response is cut short by random mode bit byte.
+	Garbage uint32 `protobuf:"varint,6,opt,name=garbage,proto3" json:"garbage,omitempty"`
+	// 0 means pause until timeout.
+	GapMs                uint32   `protobuf:"varint,7,opt,name=gap_ms,json=gapMs,proto3" json:"gap_ms,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *MdbFaults) Reset()         { *m = MdbFaults{} }
+func (m *MdbFaults) String() string { return proto.CompactTextString(m) }
+func (*MdbFaults) ProtoMessage()    {}
+func (*MdbFaults) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{3}
+}
+func (m *MdbFaults) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_MdbFaults.Unmarshal(m, b)
+}
+func (m *MdbFaults) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_MdbFaults.Marshal(b, m, deterministic)
+}
+func (dst *MdbFaults) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_MdbFaults.Merge(dst, src)
+}
+func (m *MdbFaults) XXX_Size() int {
+	return xxx_messageInfo_MdbFaults.Size(m)
+}
+func (m *MdbFaults) XXX_DiscardUnknown() {
+	xxx_messageInfo_MdbFaults.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_MdbFaults proto.InternalMessageInfo
+
+func (m *MdbFaults) GetSeed() uint64 {
+	if m != nil {
+		return m.Seed
+	}
+	return 0
+}
+
+func (m *MdbFaults) GetDrop() uint32 {
+	if m != nil {
+		return m.Drop
+	}
+	return 0
+}
+
+func (m *MdbFaults) GetChecksum() uint32 {
+	if m != nil {
+		return m.Checksum
+	}
+	return 0
+}
+
+func (m *MdbFaults) GetNak() uint32 {
+	if m != nil {
+		return m.Nak
+	}
+	return 0
+}
+
+func (m *MdbFaults) GetGap() uint32 {
+	if m != nil {
+		return m.Gap
+	}
+	return 0
+}
+
+func (m *MdbFaults) GetGarbage() uint32 {
+	if m != nil {
+		return m.Garbage
+	}
+	return 0
+}
+
+func (m *MdbFaults) GetGapMs() uint32 {
+	if m != nil {
+		return m.GapMs
+	}
+	return 0
+}
+
+// Server side retransmission of failed MDB transaction.
+// Retried request reaches peripheral again, use with care for non-idempotent commands like VEND.
+// Server is not locked during backoff, other clients may use the bus between attempts.
+type RetryPolicy struct {
+	// Total attempts including the first one, 0 and 1 mean no retry.
+	MaxAttempts uint32 `protobuf:"varint,1,opt,name=max_attempts,json=maxAttempts,proto3" json:"max_attempts,omitempty"`
+	// Retryable errors, empty means MDB_NAK. After MDB_TIMEOUT or MDB_CHECKSUM
+	// peripheral may have acted on request, list them only for idempotent requests.
+	// Only MDB_* kinds, INPUT, IO or OTHER make request INVALID.
+	RetryOn []ErrorDetail_Kind `protobuf:"varint,2,rep,packed,name=retry_on,json=retryOn,proto3,enum=iodin.ErrorDetail_Kind" json:"retry_on,omitempty"`
+	// Pause before each retry in milliseconds.
+	BackoffMs            uint32   `protobuf:"varint,3,opt,name=backoff_ms,json=backoffMs,proto3" json:"backoff_ms,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *RetryPolicy) Reset()         { *m = RetryPolicy{} }
+func (m *RetryPolicy) String() string { return proto.CompactTextString(m) }
+func (*RetryPolicy) ProtoMessage()    {}
+func (*RetryPolicy) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{4}
+}
+func (m *RetryPolicy) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_RetryPolicy.Unmarshal(m, b)
+}
+func (m *RetryPolicy) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_RetryPolicy.Marshal(b, m, deterministic)
+}
+func (dst *RetryPolicy) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_RetryPolicy.Merge(dst, src)
+}
+func (m *RetryPolicy) XXX_Size() int {
+	return xxx_messageInfo_RetryPolicy.Size(m)
+}
+func (m *RetryPolicy) XXX_DiscardUnknown() {
+	xxx_messageInfo_RetryPolicy.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_RetryPolicy proto.InternalMessageInfo
+
+func (m *RetryPolicy) GetMaxAttempts() uint32 {
+	if m != nil {
+		return m.MaxAttempts
+	}
+	return 0
+}
+
+func (m *RetryPolicy) GetRetryOn() []ErrorDetail_Kind {
+	if m != nil {
+		return m.RetryOn
+	}
+	return nil
+}
+
+func (m *RetryPolicy) GetBackoffMs() uint32 {
+	if m != nil {
+		return m.BackoffMs
+	}
+	return 0
+}
+
+type MdbReset struct {
+	DurationMs           uint32   `protobuf:"varint,1,opt,name=duration_ms,json=durationMs,proto3" json:"duration_ms,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *MdbReset) Reset()         { *m = MdbReset{} }
+func (m *MdbReset) String() string { return proto.CompactTextString(m) }
+func (*MdbReset) ProtoMessage()    {}
+func (*MdbReset) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{5}
+}
+func (m *MdbReset) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_MdbReset.Unmarshal(m, b)
+}
+func (m *MdbReset) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_MdbReset.Marshal(b, m, deterministic)
+}
+func (dst *MdbReset) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_MdbReset.Merge(dst, src)
+}
+func (m *MdbReset) XXX_Size() int {
+	return xxx_messageInfo_MdbReset.Size(m)
+}
+func (m *MdbReset) XXX_DiscardUnknown() {
+	xxx_messageInfo_MdbReset.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_MdbReset proto.InternalMessageInfo
+
+func (m *MdbReset) GetDurationMs() uint32 {
+	if m != nil {
+		return m.DurationMs
+	}
+	return 0
+}
+
+type MdbTx struct {
+	// Request without checksum.
+	Frame []byte `protobuf:"bytes,1,opt,name=frame,proto3" json:"frame,omitempty"`
+	// Response timeout in milliseconds, 0 means Request.timeout.
+	Timeout uint32 `protobuf:"varint,2,opt,name=timeout,proto3" json:"timeout,omitempty"`
+	// Unset means no retry. Not allowed in MDB_BATCH items.
+	Retry                *RetryPolicy `protobuf:"bytes,3,opt,name=retry,proto3" json:"retry,omitempty"`
+	XXX_NoUnkeyedLiteral struct{}     `json:"-"`
+	XXX_unrecognized     []byte       `json:"-"`
+	XXX_sizecache        int32        `json:"-"`
+}
+
+func (m *MdbTx) Reset()         { *m = MdbTx{} }
+func (m *MdbTx) String() string { return proto.CompactTextString(m) }
+func (*MdbTx) ProtoMessage()    {}
+func (*MdbTx) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{6}
+}
+func (m *MdbTx) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_MdbTx.Unmarshal(m, b)
+}
+func (m *MdbTx) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_MdbTx.Marshal(b, m, deterministic)
+}
+func (dst *MdbTx) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_MdbTx.Merge(dst, src)
+}
+func (m *MdbTx) XXX_Size() int {
+	return xxx_messageInfo_MdbTx.Size(m)
+}
+func (m *MdbTx) XXX_DiscardUnknown() {
+	xxx_messageInfo_MdbTx.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_MdbTx proto.InternalMessageInfo
+
+func (m *MdbTx) GetFrame() []byte {
+	if m != nil {
+		return m.Frame
+	}
+	return nil
+}
+
+func (m *MdbTx) GetTimeout() uint32 {
+	if m != nil {
+		return m.Timeout
+	}
+	return 0
+}
+
+func (m *MdbTx) GetRetry() *RetryPolicy {
+	if m != nil {
+		return m.Retry
+	}
+	return nil
+}
+
+type Response struct {
+	Version   uint32          `protobuf:"varint,1,opt,name=version,proto3" json:"version,omitempty"`
+	Status    Response_Status `protobuf:"varint,2,opt,name=status,proto3,enum=iodin.Response_Status" json:"status,omitempty"`
+	Error     string          `protobuf:"bytes,3,opt,name=error,proto3" json:"error,omitempty"`
+	DataBytes []byte          `protobuf:"bytes,4,opt,name=data_bytes,json=dataBytes,proto3" json:"data_bytes,omitempty"`
+	// Copy of Request.id, 0 if request could not be parsed.
+	Id uint32 `protobuf:"varint,5,opt,name=id,proto3" json:"id,omitempty"`
+	// Set in reply to HELLO.
+	Hello *Hello `protobuf:"bytes,6,opt,name=hello,proto3" json:"hello,omitempty"`
+	// Set in reply to GET_INFO.
+	Info *Info `protobuf:"bytes,7,opt,name=info,proto3" json:"info,omitempty"`
+	// Machine readable form of `error`.
+	ErrorDetail *ErrorDetail `protobuf:"bytes,8,opt,name=error_detail,json=errorDetail,proto3" json:"error_detail,omitempty"`
+	// Per item results of MDB_BATCH, status is OK only if all items succeeded.
+	Batch []*BatchItem `protobuf:"bytes,9,rep,name=batch,proto3" json:"batch,omitempty"`
+	// Set only in unsolicited frames pushed to subscribed sessions, id is 0.
+	// Such frames may arrive between request and its response.
+	Event *Event `protobuf:"bytes,10,opt,name=event,proto3" json:"event,omitempty"`
+	// MDB_TX: number of transactions performed, more than 1 if retried.
+	Attempts uint32 `protobuf:"varint,11,opt,name=attempts,proto3" json:"attempts,omitempty"`
+	// Set in reply to MDB_COIN.
+	Coin *MdbCoinResult `protobuf:"bytes,12,opt,name=coin,proto3" json:"coin,omitempty"`
+	// Set in reply to MDB_BILL.
+	Bill *MdbBillResult `protobuf:"bytes,13,opt,name=bill,proto3" json:"bill,omitempty"`
+	// Set in reply to MDB_CASHLESS.
+	Cashless             *MdbCashlessResult `protobuf:"bytes,14,opt,name=cashless,proto3" json:"cashless,omitempty"`
+	XXX_NoUnkeyedLiteral struct{}           `json:"-"`
+	XXX_unrecognized     []byte             `json:"-"`
+	XXX_sizecache        int32              `json:"-"`
+}
+
+func (m *Response) Reset()         { *m = Response{} }
+func (m *Response) String() string { return proto.CompactTextString(m) }
+func (*Response) ProtoMessage()    {}
+func (*Response) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{7}
+}
+func (m *Response) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_Response.Unmarshal(m, b)
+}
+func (m *Response) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_Response.Marshal(b, m, deterministic)
+}
+func (dst *Response) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_Response.Merge(dst, src)
+}
+func (m *Response) XXX_Size() int {
+	return xxx_messageInfo_Response.Size(m)
+}
+func (m *Response) XXX_DiscardUnknown() {
+	xxx_messageInfo_Response.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_Response proto.InternalMessageInfo
+
+func (m *Response) GetVersion() uint32 {
+	if m != nil {
+		return m.Version
+	}
+	return 0
+}
+
+func (m *Response) GetStatus() Response_Status {
+	if m != nil {
+		return m.Status
+	}
+	return Response_INVALID
+}
+
+func (m *Response) GetError() string {
+	if m != nil {
+		return m.Error
+	}
+	return ""
+}
+
+func (m *Response) GetDataBytes() []byte {
+	if m != nil {
+		return m.DataBytes
+	}
+	return nil
+}
+
+func (m *Response) GetId() uint32 {
+	if m != nil {
+		return m.Id
+	}
+	return 0
+}
+
+func (m *Response) GetHello() *Hello {
+	if m != nil {
+		return m.Hello
+	}
+	return nil
+}
+
+func (m *Response) GetInfo() *Info {
+	if m != nil {
+		return m.Info
+	}
+	return nil
+}
+
+func (m *Response) GetErrorDetail() *ErrorDetail {
+	if m != nil {
+		return m.ErrorDetail
+	}
+	return nil
+}
+
+func (m *Response) GetBatch() []*BatchItem {
+	if m != nil {
+		return m.Batch
+	}
+	return nil
+}
+
+func (m *Response) GetEvent() *Event {
+	if m != nil {
+		return m.Event
+	}
+	return nil
+}
+
+func (m *Response) GetAttempts() uint32 {
+	if m != nil {
+		return m.Attempts
+	}
+	return 0
+}
+
+func (m *Response) GetCoin() *MdbCoinResult {
+	if m != nil {
+		return m.Coin
+	}
+	return nil
+}
+
+func (m *Response) GetBill() *MdbBillResult {
+	if m != nil {
+		return m.Bill
+	}
+	return nil
+}
+
+func (m *Response) GetCashless() *MdbCashlessResult {
+	if m != nil {
+		return m.Cashless
+	}
+	return nil
+}
+
+type Subscribe struct {
+	// Replaces previous subscription of this session, empty list unsubscribes.
+	Kinds                []Event_Kind `protobuf:"varint,1,rep,packed,name=kinds,proto3,enum=iodin.Event_Kind" json:"kinds,omitempty"`
+	XXX_NoUnkeyedLiteral struct{}     `json:"-"`
+	XXX_unrecognized     []byte       `json:"-"`
+	XXX_sizecache        int32        `json:"-"`
+}
+
+func (m *Subscribe) Reset()         { *m = Subscribe{} }
+func (m *Subscribe) String() string { return proto.CompactTextString(m) }
+func (*Subscribe) ProtoMessage()    {}
+func (*Subscribe) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{8}
+}
+func (m *Subscribe) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_Subscribe.Unmarshal(m, b)
+}
+func (m *Subscribe) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_Subscribe.Marshal(b, m, deterministic)
+}
+func (dst *Subscribe) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_Subscribe.Merge(dst, src)
+}
+func (m *Subscribe) XXX_Size() int {
+	return xxx_messageInfo_Subscribe.Size(m)
+}
+func (m *Subscribe) XXX_DiscardUnknown() {
+	xxx_messageInfo_Subscribe.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_Subscribe proto.InternalMessageInfo
+
+func (m *Subscribe) GetKinds() []Event_Kind {
+	if m != nil {
+		return m.Kinds
+	}
+	return nil
+}
+
+// Periodic transaction run by server, non-empty responses are pushed as MDB_POLL events.
+// MDB_POLL_ADD replaces job with same address, MDB_POLL_REMOVE only needs address.
+type MdbPoll struct {
+	// Peripheral address, i.e. 0x08 coin changer.
+	Address    uint32 `protobuf:"varint,1,opt,name=address,proto3" json:"address,omitempty"`
+	IntervalMs uint32 `protobuf:"varint,2,opt,name=interval_ms,json=intervalMs,proto3" json:"interval_ms,omitempty"`
+	// Response timeout in milliseconds, 0 means server default.
+	TimeoutMs uint32 `protobuf:"varint,3,opt,name=timeout_ms,json=timeoutMs,proto3" json:"timeout_ms,omitempty"`
+	// Request without checksum, empty means POLL command address+3.
+	// Cashless readers 0x10 and 0x60 are INVALID, use MDB_CASHLESS POLL.
+	Frame                []byte   `protobuf:"bytes,4,opt,name=frame,proto3" json:"frame,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *MdbPoll) Reset()         { *m = MdbPoll{} }
+func (m *MdbPoll) String() string { return proto.CompactTextString(m) }
+func (*MdbPoll) ProtoMessage()    {}
+func (*MdbPoll) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{9}
+}
+func (m *MdbPoll) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_MdbPoll.Unmarshal(m, b)
+}
+func (m *MdbPoll) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_MdbPoll.Marshal(b, m, deterministic)
+}
+func (dst *MdbPoll) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_MdbPoll.Merge(dst, src)
+}
+func (m *MdbPoll) XXX_Size() int {
+	return xxx_messageInfo_MdbPoll.Size(m)
+}
+func (m *MdbPoll) XXX_DiscardUnknown() {
+	xxx_messageInfo_MdbPoll.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_MdbPoll proto.InternalMessageInfo
+
+func (m *MdbPoll) GetAddress() uint32 {
+	if m != nil {
+		return m.Address
+	}
+	return 0
+}
+
+func (m *MdbPoll) GetIntervalMs() uint32 {
+	if m != nil {
+		return m.IntervalMs
+	}
+	return 0
+}
+
+func (m *MdbPoll) GetTimeoutMs() uint32 {
+	if m != nil {
+		return m.TimeoutMs
+	}
+	return 0
+}
+
+func (m *MdbPoll) GetFrame() []byte {
+	if m != nil {
+		return m.Frame
+	}
+	return nil
+}
+
+type GpioWatch struct {
+	Pin uint32 `protobuf:"varint,1,opt,name=pin,proto3" json:"pin,omitempty"`
+	// false stops watching pin.
+	Enable               bool     `protobuf:"varint,2,opt,name=enable,proto3" json:"enable,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *GpioWatch) Reset()         { *m = GpioWatch{} }
+func (m *GpioWatch) String() string { return proto.CompactTextString(m) }
+func (*GpioWatch) ProtoMessage()    {}
+func (*GpioWatch) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{10}
+}
+func (m *GpioWatch) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_GpioWatch.Unmarshal(m, b)
+}
+func (m *GpioWatch) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_GpioWatch.Marshal(b, m, deterministic)
+}
+func (dst *GpioWatch) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_GpioWatch.Merge(dst, src)
+}
+func (m *GpioWatch) XXX_Size() int {
+	return xxx_messageInfo_GpioWatch.Size(m)
+}
+func (m *GpioWatch) XXX_DiscardUnknown() {
+	xxx_messageInfo_GpioWatch.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_GpioWatch proto.InternalMessageInfo
+
+func (m *GpioWatch) GetPin() uint32 {
+	if m != nil {
+		return m.Pin
+	}
+	return 0
+}
+
+func (m *GpioWatch) GetEnable() bool {
+	if m != nil {
+		return m.Enable
+	}
+	return false
+}
+
+type Event struct {
+	Kind Event_Kind `protobuf:"varint,1,opt,name=kind,proto3,enum=iodin.Event_Kind" json:"kind,omitempty"`
+	// Microseconds since server start.
+	TimeUs uint64 `protobuf:"varint,2,opt,name=time_us,json=timeUs,proto3" json:"time_us,omitempty"`
+	// MDB_POLL response.
+	DataBytes []byte `protobuf:"bytes,3,opt,name=data_bytes,json=dataBytes,proto3" json:"data_bytes,omitempty"`
+	// MDB_POLL, BUS_ERROR, WATCHDOG: request frame.
+	MdbRequest []byte `protobuf:"bytes,4,opt,name=mdb_request,json=mdbRequest,proto3" json:"mdb_request,omitempty"`
+	// BUS_ERROR, WATCHDOG: human readable description.
+	Error string `protobuf:"bytes,5,opt,name=error,proto3" json:"error,omitempty"`
+	// BUS_ERROR.
+	ErrorDetail *ErrorDetail `protobuf:"bytes,6,opt,name=error_detail,json=errorDetail,proto3" json:"error_detail,omitempty"`
+	// GPIO_EDGE.
+	Gpio  uint32 `protobuf:"varint,7,opt,name=gpio,proto3" json:"gpio,omitempty"`
+	Level uint32 `protobuf:"varint,8,opt,name=level,proto3" json:"level,omitempty"`
+	// GPIO_EDGE: pigpio tick in microseconds, wraps every ~72 minutes.
+	Tick                 uint32   `protobuf:"varint,9,opt,name=tick,proto3" json:"tick,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *Event) Reset()         { *m = Event{} }
+func (m *Event) String() string { return proto.CompactTextString(m) }
+func (*Event) ProtoMessage()    {}
+func (*Event) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{11}
+}
+func (m *Event) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_Event.Unmarshal(m, b)
+}
+func (m *Event) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_Event.Marshal(b, m, deterministic)
+}
+func (dst *Event) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_Event.Merge(dst, src)
+}
+func (m *Event) XXX_Size() int {
+	return xxx_messageInfo_Event.Size(m)
+}
+func (m *Event) XXX_DiscardUnknown() {
+	xxx_messageInfo_Event.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_Event proto.InternalMessageInfo
+
+func (m *Event) GetKind() Event_Kind {
+	if m != nil {
+		return m.Kind
+	}
+	return Event_INVALID
+}
+
+func (m *Event) GetTimeUs() uint64 {
+	if m != nil {
+		return m.TimeUs
+	}
+	return 0
+}
+
+func (m *Event) GetDataBytes() []byte {
+	if m != nil {
+		return m.DataBytes
+	}
+	return nil
+}
+
+func (m *Event) GetMdbRequest() []byte {
+	if m != nil {
+		return m.MdbRequest
+	}
+	return nil
+}
+
+func (m *Event) GetError() string {
+	if m != nil {
+		return m.Error
+	}
+	return ""
+}
+
+func (m *Event) GetErrorDetail() *ErrorDetail {
+	if m != nil {
+		return m.ErrorDetail
+	}
+	return nil
+}
+
+func (m *Event) GetGpio() uint32 {
+	if m != nil {
+		return m.Gpio
+	}
+	return 0
+}
+
+func (m *Event) GetLevel() uint32 {
+	if m != nil {
+		return m.Level
+	}
+	return 0
+}
+
+func (m *Event) GetTick() uint32 {
+	if m != nil {
+		return m.Tick
+	}
+	return 0
+}
+
+type BatchItem struct {
+	Status               Response_Status `protobuf:"varint,1,opt,name=status,proto3,enum=iodin.Response_Status" json:"status,omitempty"`
+	Error                string          `protobuf:"bytes,2,opt,name=error,proto3" json:"error,omitempty"`
+	DataBytes            []byte          `protobuf:"bytes,3,opt,name=data_bytes,json=dataBytes,proto3" json:"data_bytes,omitempty"`
+	ErrorDetail          *ErrorDetail    `protobuf:"bytes,4,opt,name=error_detail,json=errorDetail,proto3" json:"error_detail,omitempty"`
+	Attempts             uint32          `protobuf:"varint,5,opt,name=attempts,proto3" json:"attempts,omitempty"`
+	XXX_NoUnkeyedLiteral struct{}        `json:"-"`
+	XXX_unrecognized     []byte          `json:"-"`
+	XXX_sizecache        int32           `json:"-"`
+}
+
+func (m *BatchItem) Reset()         { *m = BatchItem{} }
+func (m *BatchItem) String() string { return proto.CompactTextString(m) }
+func (*BatchItem) ProtoMessage()    {}
+func (*BatchItem) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{12}
+}
+func (m *BatchItem) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_BatchItem.Unmarshal(m, b)
+}
+func (m *BatchItem) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_BatchItem.Marshal(b, m, deterministic)
+}
+func (dst *BatchItem) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_BatchItem.Merge(dst, src)
+}
+func (m *BatchItem) XXX_Size() int {
+	return xxx_messageInfo_BatchItem.Size(m)
+}
+func (m *BatchItem) XXX_DiscardUnknown() {
+	xxx_messageInfo_BatchItem.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_BatchItem proto.InternalMessageInfo
+
+func (m *BatchItem) GetStatus() Response_Status {
+	if m != nil {
+		return m.Status
+	}
+	return Response_INVALID
+}
+
+func (m *BatchItem) GetError() string {
+	if m != nil {
+		return m.Error
+	}
+	return ""
+}
+
+func (m *BatchItem) GetDataBytes() []byte {
+	if m != nil {
+		return m.DataBytes
+	}
+	return nil
+}
+
+func (m *BatchItem) GetErrorDetail() *ErrorDetail {
+	if m != nil {
+		return m.ErrorDetail
+	}
+	return nil
+}
+
+func (m *BatchItem) GetAttempts() uint32 {
+	if m != nil {
+		return m.Attempts
+	}
+	return 0
+}
+
+type ErrorDetail struct {
+	Kind             ErrorDetail_Kind  `protobuf:"varint,1,opt,name=kind,proto3,enum=iodin.ErrorDetail_Kind" json:"kind,omitempty"`
+	Stage            ErrorDetail_Stage `protobuf:"varint,2,opt,name=stage,proto3,enum=iodin.ErrorDetail_Stage" json:"stage,omitempty"`
+	ChecksumReceived uint32            `protobuf:"varint,3,opt,name=checksum_received,json=checksumReceived,proto3" json:"checksum_received,omitempty"`
+	ChecksumComputed uint32            `protobuf:"varint,4,opt,name=checksum_computed,json=checksumComputed,proto3" json:"checksum_computed,omitempty"`
+	// Bytes received before error.
+	PartialResponse []byte `protobuf:"bytes,5,opt,name=partial_response,json=partialResponse,proto3" json:"partial_response,omitempty"`
+	// Unexpected byte in place of ACK/NAK.
+	InvalidByte          uint32   `protobuf:"varint,6,opt,name=invalid_byte,json=invalidByte,proto3" json:"invalid_byte,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *ErrorDetail) Reset()         { *m = ErrorDetail{} }
+func (m *ErrorDetail) String() string { return proto.CompactTextString(m) }
+func (*ErrorDetail) ProtoMessage()    {}
+func (*ErrorDetail) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{13}
+}
+func (m *ErrorDetail) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_ErrorDetail.Unmarshal(m, b)
+}
+func (m *ErrorDetail) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_ErrorDetail.Marshal(b, m, deterministic)
+}
+func (dst *ErrorDetail) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_ErrorDetail.Merge(dst, src)
+}
+func (m *ErrorDetail) XXX_Size() int {
+	return xxx_messageInfo_ErrorDetail.Size(m)
+}
+func (m *ErrorDetail) XXX_DiscardUnknown() {
+	xxx_messageInfo_ErrorDetail.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_ErrorDetail proto.InternalMessageInfo
+
+func (m *ErrorDetail) GetKind() ErrorDetail_Kind {
+	if m != nil {
+		return m.Kind
+	}
+	return ErrorDetail_INVALID
+}
+
+func (m *ErrorDetail) GetStage() ErrorDetail_Stage {
+	if m != nil {
+		return m.Stage
+	}
+	return ErrorDetail_NONE
+}
+
+func (m *ErrorDetail) GetChecksumReceived() uint32 {
+	if m != nil {
+		return m.ChecksumReceived
+	}
+	return 0
+}
+
+func (m *ErrorDetail) GetChecksumComputed() uint32 {
+	if m != nil {
+		return m.ChecksumComputed
+	}
+	return 0
+}
+
+func (m *ErrorDetail) GetPartialResponse() []byte {
+	if m != nil {
+		return m.PartialResponse
+	}
+	return nil
+}
+
+func (m *ErrorDetail) GetInvalidByte() uint32 {
+	if m != nil {
+		return m.InvalidByte
+	}
+	return 0
+}
+
+type MdbBatch struct {
+	Items []*MdbTx `protobuf:"bytes,1,rep,name=items,proto3" json:"items,omitempty"`
+	// Skip remaining items after first failure.
+	StopOnError          bool     `protobuf:"varint,2,opt,name=stop_on_error,json=stopOnError,proto3" json:"stop_on_error,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *MdbBatch) Reset()         { *m = MdbBatch{} }
+func (m *MdbBatch) String() string { return proto.CompactTextString(m) }
+func (*MdbBatch) ProtoMessage()    {}
+func (*MdbBatch) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{14}
+}
+func (m *MdbBatch) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_MdbBatch.Unmarshal(m, b)
+}
+func (m *MdbBatch) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_MdbBatch.Marshal(b, m, deterministic)
+}
+func (dst *MdbBatch) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_MdbBatch.Merge(dst, src)
+}
+func (m *MdbBatch) XXX_Size() int {
+	return xxx_messageInfo_MdbBatch.Size(m)
+}
+func (m *MdbBatch) XXX_DiscardUnknown() {
+	xxx_messageInfo_MdbBatch.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_MdbBatch proto.InternalMessageInfo
+
+func (m *MdbBatch) GetItems() []*MdbTx {
+	if m != nil {
+		return m.Items
+	}
+	return nil
+}
+
+func (m *MdbBatch) GetStopOnError() bool {
+	if m != nil {
+		return m.StopOnError
+	}
+	return false
+}
+
+type Hello struct {
+	ProtocolVersion      uint32            `protobuf:"varint,1,opt,name=protocol_version,json=protocolVersion,proto3" json:"protocol_version,omitempty"`
+	ProtocolVersionMin   uint32            `protobuf:"varint,2,opt,name=protocol_version_min,json=protocolVersionMin,proto3" json:"protocol_version_min,omitempty"`
+	Commands             []Request_Command `protobuf:"varint,3,rep,packed,name=commands,proto3,enum=iodin.Request_Command" json:"commands,omitempty"`
+	XXX_NoUnkeyedLiteral struct{}          `json:"-"`
+	XXX_unrecognized     []byte            `json:"-"`
+	XXX_sizecache        int32             `json:"-"`
+}
+
+func (m *Hello) Reset()         { *m = Hello{} }
+func (m *Hello) String() string { return proto.CompactTextString(m) }
+func (*Hello) ProtoMessage()    {}
+func (*Hello) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{15}
+}
+func (m *Hello) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_Hello.Unmarshal(m, b)
+}
+func (m *Hello) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_Hello.Marshal(b, m, deterministic)
+}
+func (dst *Hello) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_Hello.Merge(dst, src)
+}
+func (m *Hello) XXX_Size() int {
+	return xxx_messageInfo_Hello.Size(m)
+}
+func (m *Hello) XXX_DiscardUnknown() {
+	xxx_messageInfo_Hello.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_Hello proto.InternalMessageInfo
+
+func (m *Hello) GetProtocolVersion() uint32 {
+	if m != nil {
+		return m.ProtocolVersion
+	}
+	return 0
+}
+
+func (m *Hello) GetProtocolVersionMin() uint32 {
+	if m != nil {
+		return m.ProtocolVersionMin
+	}
+	return 0
+}
+
+func (m *Hello) GetCommands() []Request_Command {
+	if m != nil {
+		return m.Commands
+	}
+	return nil
+}
+
+type Info struct {
+	CrateVersion     string            `protobuf:"bytes,1,opt,name=crate_version,json=crateVersion,proto3" json:"crate_version,omitempty"`
+	BuildId          string            `protobuf:"bytes,2,opt,name=build_id,json=buildId,proto3" json:"build_id,omitempty"`
+	Mock             bool              `protobuf:"varint,3,opt,name=mock,proto3" json:"mock,omitempty"`
+	PigpioVersion    uint32            `protobuf:"varint,4,opt,name=pigpio_version,json=pigpioVersion,proto3" json:"pigpio_version,omitempty"`
+	HardwareRevision uint32            `protobuf:"varint,5,opt,name=hardware_revision,json=hardwareRevision,proto3" json:"hardware_revision,omitempty"`
+	MdbOpen          bool              `protobuf:"varint,6,opt,name=mdb_open,json=mdbOpen,proto3" json:"mdb_open,omitempty"`
+	MdbRxPin         uint32            `protobuf:"varint,7,opt,name=mdb_rx_pin,json=mdbRxPin,proto3" json:"mdb_rx_pin,omitempty"`
+	MdbTxPin         uint32            `protobuf:"varint,8,opt,name=mdb_tx_pin,json=mdbTxPin,proto3" json:"mdb_tx_pin,omitempty"`
+	ProtocolVersion  uint32            `protobuf:"varint,9,opt,name=protocol_version,json=protocolVersion,proto3" json:"protocol_version,omitempty"`
+	Commands         []Request_Command `protobuf:"varint,10,rep,packed,name=commands,proto3,enum=iodin.Request_Command" json:"commands,omitempty"`
+	MdbPolls         []*MdbPoll        `protobuf:"bytes,11,rep,name=mdb_polls,json=mdbPolls,proto3" json:"mdb_polls,omitempty"`
+	MdbTransport     MdbOpen_Transport `protobuf:"varint,12,opt,name=mdb_transport,json=mdbTransport,proto3,enum=iodin.MdbOpen_Transport" json:"mdb_transport,omitempty"`
+	MdbDevice        string            `protobuf:"bytes,13,opt,name=mdb_device,json=mdbDevice,proto3" json:"mdb_device,omitempty"`
+	// Active fault injection, unset when disabled.
+	MdbFaults            *MdbFaults `protobuf:"bytes,14,opt,name=mdb_faults,json=mdbFaults,proto3" json:"mdb_faults,omitempty"`
+	XXX_NoUnkeyedLiteral struct{}   `json:"-"`
+	XXX_unrecognized     []byte     `json:"-"`
+	XXX_sizecache        int32      `json:"-"`
+}
+
+func (m *Info) Reset()         { *m = Info{} }
+func (m *Info) String() string { return proto.CompactTextString(m) }
+func (*Info) ProtoMessage()    {}
+func (*Info) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{16}
+}
+func (m *Info) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_Info.Unmarshal(m, b)
+}
+func (m *Info) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_Info.Marshal(b, m, deterministic)
+}
+func (dst *Info) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_Info.Merge(dst, src)
+}
+func (m *Info) XXX_Size() int {
+	return xxx_messageInfo_Info.Size(m)
+}
+func (m *Info) XXX_DiscardUnknown() {
+	xxx_messageInfo_Info.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_Info proto.InternalMessageInfo
+
+func (m *Info) GetCrateVersion() string {
+	if m != nil {
+		return m.CrateVersion
+	}
+	return ""
+}
+
+func (m *Info) GetBuildId() string {
+	if m != nil {
+		return m.BuildId
+	}
+	return ""
+}
+
+func (m *Info) GetMock() bool {
+	if m != nil {
+		return m.Mock
+	}
+	return false
+}
+
+func (m *Info) GetPigpioVersion() uint32 {
+	if m != nil {
+		return m.PigpioVersion
+	}
+	return 0
+}
+
+func (m *Info) GetHardwareRevision() uint32 {
+	if m != nil {
+		return m.HardwareRevision
+	}
+	return 0
+}
+
+func (m *Info) GetMdbOpen() bool {
+	if m != nil {
+		return m.MdbOpen
+	}
+	return false
+}
+
+func (m *Info) GetMdbRxPin() uint32 {
+	if m != nil {
+		return m.MdbRxPin
+	}
+	return 0
+}
+
+func (m *Info) GetMdbTxPin() uint32 {
+	if m != nil {
+		return m.MdbTxPin
+	}
+	return 0
+}
+
+func (m *Info) GetProtocolVersion() uint32 {
+	if m != nil {
+		return m.ProtocolVersion
+	}
+	return 0
+}
+
+func (m *Info) GetCommands() []Request_Command {
+	if m != nil {
+		return m.Commands
+	}
+	return nil
+}
+
+func (m *Info) GetMdbPolls() []*MdbPoll {
+	if m != nil {
+		return m.MdbPolls
+	}
+	return nil
+}
+
+func (m *Info) GetMdbTransport() MdbOpen_Transport {
+	if m != nil {
+		return m.MdbTransport
+	}
+	return MdbOpen_DEFAULT
+}
+
+func (m *Info) GetMdbDevice() string {
+	if m != nil {
+		return m.MdbDevice
+	}
+	return ""
+}
+
+func (m *Info) GetMdbFaults() *MdbFaults {
+	if m != nil {
+		return m.MdbFaults
+	}
+	return nil
+}
+
+// Coin changer at address 0x08, feature level 2 and 3.
+type MdbCoin struct {
+	Op MdbCoin_Op `protobuf:"varint,1,opt,name=op,proto3,enum=iodin.MdbCoin_Op" json:"op,omitempty"`
+	// COIN_TYPE: bit per coin type.
+	CoinEnable           uint32 `protobuf:"varint,2,opt,name=coin_enable,json=coinEnable,proto3" json:"coin_enable,omitempty"`
+	ManualDispenseEnable uint32 `protobuf:"varint,3,opt,name=manual_dispense_enable,json=manualDispenseEnable,proto3" json:"manual_dispense_enable,omitempty"`
+	// DISPENSE
+	CoinType uint32 `protobuf:"varint,4,opt,name=coin_type,json=coinType,proto3" json:"coin_type,omitempty"`
+	Count    uint32 `protobuf:"varint,5,opt,name=count,proto3" json:"count,omitempty"`
+	// Response timeout in milliseconds, 0 means Request.timeout.
+	Timeout uint32 `protobuf:"varint,6,opt,name=timeout,proto3" json:"timeout,omitempty"`
+	// Unset means no retry. Only POLL, SETUP, TUBE_STATUS and IDENTIFICATION,
+	// RESET, COIN_TYPE and DISPENSE with retry are INVALID.
+	Retry                *RetryPolicy `protobuf:"bytes,7,opt,name=retry,proto3" json:"retry,omitempty"`
+	XXX_NoUnkeyedLiteral struct{}     `json:"-"`
+	XXX_unrecognized     []byte       `json:"-"`
+	XXX_sizecache        int32        `json:"-"`
+}
+
+func (m *MdbCoin) Reset()         { *m = MdbCoin{} }
+func (m *MdbCoin) String() string { return proto.CompactTextString(m) }
+func (*MdbCoin) ProtoMessage()    {}
+func (*MdbCoin) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{17}
+}
+func (m *MdbCoin) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_MdbCoin.Unmarshal(m, b)
+}
+func (m *MdbCoin) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_MdbCoin.Marshal(b, m, deterministic)
+}
+func (dst *MdbCoin) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_MdbCoin.Merge(dst, src)
+}
+func (m *MdbCoin) XXX_Size() int {
+	return xxx_messageInfo_MdbCoin.Size(m)
+}
+func (m *MdbCoin) XXX_DiscardUnknown() {
+	xxx_messageInfo_MdbCoin.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_MdbCoin proto.InternalMessageInfo
+
+func (m *MdbCoin) GetOp() MdbCoin_Op {
+	if m != nil {
+		return m.Op
+	}
+	return MdbCoin_INVALID
+}
+
+func (m *MdbCoin) GetCoinEnable() uint32 {
+	if m != nil {
+		return m.CoinEnable
+	}
+	return 0
+}
+
+func (m *MdbCoin) GetManualDispenseEnable() uint32 {
+	if m != nil {
+		return m.ManualDispenseEnable
+	}
+	return 0
+}
+
+func (m *MdbCoin) GetCoinType() uint32 {
+	if m != nil {
+		return m.CoinType
+	}
+	return 0
+}
+
+func (m *MdbCoin) GetCount() uint32 {
+	if m != nil {
+		return m.Count
+	}
+	return 0
+}
+
+func (m *MdbCoin) GetTimeout() uint32 {
+	if m != nil {
+		return m.Timeout
+	}
+	return 0
+}
+
+func (m *MdbCoin) GetRetry() *RetryPolicy {
+	if m != nil {
+		return m.Retry
+	}
+	return nil
+}
+
+type MdbCoinSetup struct {
+	FeatureLevel uint32 `protobuf:"varint,1,opt,name=feature_level,json=featureLevel,proto3" json:"feature_level,omitempty"`
+	// Telephone code or ISO 4217 with leading 1, i.e. 0x0001 or 0x1840 for USD.
+	CountryCode   uint32 `protobuf:"varint,2,opt,name=country_code,json=countryCode,proto3" json:"country_code,omitempty"`
+	ScalingFactor uint32 `protobuf:"varint,3,opt,name=scaling_factor,json=scalingFactor,proto3" json:"scaling_factor,omitempty"`
+	DecimalPlaces uint32 `protobuf:"varint,4,opt,name=decimal_places,json=decimalPlaces,proto3" json:"decimal_places,omitempty"`
+	// Bit per coin type that can be routed to tubes.
+	CoinRouting uint32 `protobuf:"varint,5,opt,name=coin_routing,json=coinRouting,proto3" json:"coin_routing,omitempty"`
+	// Credit in scaling_factor units, index is coin type.
+	Credit               []uint32 `protobuf:"varint,6,rep,packed,name=credit,proto3" json:"credit,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *MdbCoinSetup) Reset()         { *m = MdbCoinSetup{} }
+func (m *MdbCoinSetup) String() string { return proto.CompactTextString(m) }
+func (*MdbCoinSetup) ProtoMessage()    {}
+func (*MdbCoinSetup) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{18}
+}
+func (m *MdbCoinSetup) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_MdbCoinSetup.Unmarshal(m, b)
+}
+func (m *MdbCoinSetup) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_MdbCoinSetup.Marshal(b, m, deterministic)
+}
+func (dst *MdbCoinSetup) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_MdbCoinSetup.Merge(dst, src)
+}
+func (m *MdbCoinSetup) XXX_Size() int {
+	return xxx_messageInfo_MdbCoinSetup.Size(m)
+}
+func (m *MdbCoinSetup) XXX_DiscardUnknown() {
+	xxx_messageInfo_MdbCoinSetup.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_MdbCoinSetup proto.InternalMessageInfo
+
+func (m *MdbCoinSetup) GetFeatureLevel() uint32 {
+	if m != nil {
+		return m.FeatureLevel
+	}
+	return 0
+}
+
+func (m *MdbCoinSetup) GetCountryCode() uint32 {
+	if m != nil {
+		return m.CountryCode
+	}
+	return 0
+}
+
+func (m *MdbCoinSetup) GetScalingFactor() uint32 {
+	if m != nil {
+		return m.ScalingFactor
+	}
+	return 0
+}
+
+func (m *MdbCoinSetup) GetDecimalPlaces() uint32 {
+	if m != nil {
+		return m.DecimalPlaces
+	}
+	return 0
+}
+
+func (m *MdbCoinSetup) GetCoinRouting() uint32 {
+	if m != nil {
+		return m.CoinRouting
+	}
+	return 0
+}
+
+func (m *MdbCoinSetup) GetCredit() []uint32 {
+	if m != nil {
+		return m.Credit
+	}
+	return nil
+}
+
+type MdbCoinTubes struct {
+	// Bit per full tube.
+	Full uint32 `protobuf:"varint,1,opt,name=full,proto3" json:"full,omitempty"`
+	// Coins in tube, index is coin type.
+	Count                []uint32 `protobuf:"varint,2,rep,packed,name=count,proto3" json:"count,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *MdbCoinTubes) Reset()         { *m = MdbCoinTubes{} }
+func (m *MdbCoinTubes) String() string { return proto.CompactTextString(m) }
+func (*MdbCoinTubes) ProtoMessage()    {}
+func (*MdbCoinTubes) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{19}
+}
+func (m *MdbCoinTubes) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_MdbCoinTubes.Unmarshal(m, b)
+}
+func (m *MdbCoinTubes) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_MdbCoinTubes.Marshal(b, m, deterministic)
+}
+func (dst *MdbCoinTubes) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_MdbCoinTubes.Merge(dst, src)
+}
+func (m *MdbCoinTubes) XXX_Size() int {
+	return xxx_messageInfo_MdbCoinTubes.Size(m)
+}
+func (m *MdbCoinTubes) XXX_DiscardUnknown() {
+	xxx_messageInfo_MdbCoinTubes.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_MdbCoinTubes proto.InternalMessageInfo
+
+func (m *MdbCoinTubes) GetFull() uint32 {
+	if m != nil {
+		return m.Full
+	}
+	return 0
+}
+
+func (m *MdbCoinTubes) GetCount() []uint32 {
+	if m != nil {
+		return m.Count
+	}
+	return nil
+}
+
+type MdbCoinEvent struct {
+	Kind     MdbCoinEvent_Kind `protobuf:"varint,1,opt,name=kind,proto3,enum=iodin.MdbCoinEvent_Kind" json:"kind,omitempty"`
+	CoinType uint32            `protobuf:"varint,2,opt,name=coin_type,json=coinType,proto3" json:"coin_type,omitempty"`
+	// DEPOSITED
+	Routing MdbCoinEvent_Routing `protobuf:"varint,3,opt,name=routing,proto3,enum=iodin.MdbCoinEvent_Routing" json:"routing,omitempty"`
+	// DEPOSITED, DISPENSED: coins in tube after event.
+	TubeCount uint32 `protobuf:"varint,4,opt,name=tube_count,json=tubeCount,proto3" json:"tube_count,omitempty"`
+	// DISPENSED, SLUG
+	Count                uint32              `protobuf:"varint,5,opt,name=count,proto3" json:"count,omitempty"`
+	Status               MdbCoinEvent_Status `protobuf:"varint,6,opt,name=status,proto3,enum=iodin.MdbCoinEvent_Status" json:"status,omitempty"`
+	XXX_NoUnkeyedLiteral struct{}            `json:"-"`
+	XXX_unrecognized     []byte              `json:"-"`
+	XXX_sizecache        int32               `json:"-"`
+}
+
+func (m *MdbCoinEvent) Reset()         { *m = MdbCoinEvent{} }
+func (m *MdbCoinEvent) String() string { return proto.CompactTextString(m) }
+func (*MdbCoinEvent) ProtoMessage()    {}
+func (*MdbCoinEvent) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{20}
+}
+func (m *MdbCoinEvent) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_MdbCoinEvent.Unmarshal(m, b)
+}
+func (m *MdbCoinEvent) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_MdbCoinEvent.Marshal(b, m, deterministic)
+}
+func (dst *MdbCoinEvent) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_MdbCoinEvent.Merge(dst, src)
+}
+func (m *MdbCoinEvent) XXX_Size() int {
+	return xxx_messageInfo_MdbCoinEvent.Size(m)
+}
+func (m *MdbCoinEvent) XXX_DiscardUnknown() {
+	xxx_messageInfo_MdbCoinEvent.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_MdbCoinEvent proto.InternalMessageInfo
+
+func (m *MdbCoinEvent) GetKind() MdbCoinEvent_Kind {
+	if m != nil {
+		return m.Kind
+	}
+	return MdbCoinEvent_INVALID
+}
+
+func (m *MdbCoinEvent) GetCoinType() uint32 {
+	if m != nil {
+		return m.CoinType
+	}
+	return 0
+}
+
+func (m *MdbCoinEvent) GetRouting() MdbCoinEvent_Routing {
+	if m != nil {
+		return m.Routing
+	}
+	return MdbCoinEvent_CASH_BOX
+}
+
+func (m *MdbCoinEvent) GetTubeCount() uint32 {
+	if m != nil {
+		return m.TubeCount
+	}
+	return 0
+}
+
+func (m *MdbCoinEvent) GetCount() uint32 {
+	if m != nil {
+		return m.Count
+	}
+	return 0
+}
+
+func (m *MdbCoinEvent) GetStatus() MdbCoinEvent_Status {
+	if m != nil {
+		return m.Status
+	}
+	return MdbCoinEvent_STATUS_NONE
+}
+
+// Expansion identification of coin changer, bill validator and cashless reader.
+type MdbIdentification struct {
+	Manufacturer string `protobuf:"bytes,1,opt,name=manufacturer,proto3" json:"manufacturer,omitempty"`
+	Serial       string `protobuf:"bytes,2,opt,name=serial,proto3" json:"serial,omitempty"`
+	Model        string `protobuf:"bytes,3,opt,name=model,proto3" json:"model,omitempty"`
+	// BCD, i.e. 0x0102 for 1.02.
+	SoftwareVersion uint32 `protobuf:"varint,4,opt,name=software_version,json=softwareVersion,proto3" json:"software_version,omitempty"`
+	// Optional feature bits, level 3 coin changer and level 2 bill validator.
+	Features             uint32   `protobuf:"varint,5,opt,name=features,proto3" json:"features,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *MdbIdentification) Reset()         { *m = MdbIdentification{} }
+func (m *MdbIdentification) String() string { return proto.CompactTextString(m) }
+func (*MdbIdentification) ProtoMessage()    {}
+func (*MdbIdentification) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{21}
+}
+func (m *MdbIdentification) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_MdbIdentification.Unmarshal(m, b)
+}
+func (m *MdbIdentification) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_MdbIdentification.Marshal(b, m, deterministic)
+}
+func (dst *MdbIdentification) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_MdbIdentification.Merge(dst, src)
+}
+func (m *MdbIdentification) XXX_Size() int {
+	return xxx_messageInfo_MdbIdentification.Size(m)
+}
+func (m *MdbIdentification) XXX_DiscardUnknown() {
+	xxx_messageInfo_MdbIdentification.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_MdbIdentification proto.InternalMessageInfo
+
+func (m *MdbIdentification) GetManufacturer() string {
+	if m != nil {
+		return m.Manufacturer
+	}
+	return ""
+}
+
+func (m *MdbIdentification) GetSerial() string {
+	if m != nil {
+		return m.Serial
+	}
+	return ""
+}
+
+func (m *MdbIdentification) GetModel() string {
+	if m != nil {
+		return m.Model
+	}
+	return ""
+}
+
+func (m *MdbIdentification) GetSoftwareVersion() uint32 {
+	if m != nil {
+		return m.SoftwareVersion
+	}
+	return 0
+}
+
+func (m *MdbIdentification) GetFeatures() uint32 {
+	if m != nil {
+		return m.Features
+	}
+	return 0
+}
+
+type MdbCoinResult struct {
+	Setup                *MdbCoinSetup      `protobuf:"bytes,1,opt,name=setup,proto3" json:"setup,omitempty"`
+	Tubes                *MdbCoinTubes      `protobuf:"bytes,2,opt,name=tubes,proto3" json:"tubes,omitempty"`
+	Events               []*MdbCoinEvent    `protobuf:"bytes,3,rep,name=events,proto3" json:"events,omitempty"`
+	Identification       *MdbIdentification `protobuf:"bytes,4,opt,name=identification,proto3" json:"identification,omitempty"`
+	XXX_NoUnkeyedLiteral struct{}           `json:"-"`
+	XXX_unrecognized     []byte             `json:"-"`
+	XXX_sizecache        int32              `json:"-"`
+}
+
+func (m *MdbCoinResult) Reset()         { *m = MdbCoinResult{} }
+func (m *MdbCoinResult) String() string { return proto.CompactTextString(m) }
+func (*MdbCoinResult) ProtoMessage()    {}
+func (*MdbCoinResult) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{22}
+}
+func (m *MdbCoinResult) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_MdbCoinResult.Unmarshal(m, b)
+}
+func (m *MdbCoinResult) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_MdbCoinResult.Marshal(b, m, deterministic)
+}
+func (dst *MdbCoinResult) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_MdbCoinResult.Merge(dst, src)
+}
+func (m *MdbCoinResult) XXX_Size() int {
+	return xxx_messageInfo_MdbCoinResult.Size(m)
+}
+func (m *MdbCoinResult) XXX_DiscardUnknown() {
+	xxx_messageInfo_MdbCoinResult.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_MdbCoinResult proto.InternalMessageInfo
+
+func (m *MdbCoinResult) GetSetup() *MdbCoinSetup {
+	if m != nil {
+		return m.Setup
+	}
+	return nil
+}
+
+func (m *MdbCoinResult) GetTubes() *MdbCoinTubes {
+	if m != nil {
+		return m.Tubes
+	}
+	return nil
+}
+
+func (m *MdbCoinResult) GetEvents() []*MdbCoinEvent {
+	if m != nil {
+		return m.Events
+	}
+	return nil
+}
+
+func (m *MdbCoinResult) GetIdentification() *MdbIdentification {
+	if m != nil {
+		return m.Identification
+	}
+	return nil
+}
+
+// Bill validator at address 0x30, feature level 1 and 2.
+type MdbBill struct {
+	Op MdbBill_Op `protobuf:"varint,1,opt,name=op,proto3,enum=iodin.MdbBill_Op" json:"op,omitempty"`
+	// SECURITY: bit per bill type.
+	SecurityMask uint32 `protobuf:"varint,2,opt,name=security_mask,json=securityMask,proto3" json:"security_mask,omitempty"`
+	// BILL_TYPE: bit per bill type.
+	BillEnable   uint32 `protobuf:"varint,3,opt,name=bill_enable,json=billEnable,proto3" json:"bill_enable,omitempty"`
+	EscrowEnable uint32 `protobuf:"varint,4,opt,name=escrow_enable,json=escrowEnable,proto3" json:"escrow_enable,omitempty"`
+	// ESCROW: true stacks bill, false returns it to customer.
+	Stack bool `protobuf:"varint,5,opt,name=stack,proto3" json:"stack,omitempty"`
+	// Response timeout in milliseconds, 0 means Request.timeout.
+	Timeout uint32 `protobuf:"varint,6,opt,name=timeout,proto3" json:"timeout,omitempty"`
+	// Unset means no retry. Only SETUP, STACKER, POLL and IDENTIFICATION ops,
+	// RESET, SECURITY, BILL_TYPE and ESCROW with retry are INVALID.
+	Retry                *RetryPolicy `protobuf:"bytes,7,opt,name=retry,proto3" json:"retry,omitempty"`
+	XXX_NoUnkeyedLiteral struct{}     `json:"-"`
+	XXX_unrecognized     []byte       `json:"-"`
+	XXX_sizecache        int32        `json:"-"`
+}
+
+func (m *MdbBill) Reset()         { *m = MdbBill{} }
+func (m *MdbBill) String() string { return proto.CompactTextString(m) }
+func (*MdbBill) ProtoMessage()    {}
+func (*MdbBill) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{23}
+}
+func (m *MdbBill) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_MdbBill.Unmarshal(m, b)
+}
+func (m *MdbBill) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_MdbBill.Marshal(b, m, deterministic)
+}
+func (dst *MdbBill) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_MdbBill.Merge(dst, src)
+}
+func (m *MdbBill) XXX_Size() int {
+	return xxx_messageInfo_MdbBill.Size(m)
+}
+func (m *MdbBill) XXX_DiscardUnknown() {
+	xxx_messageInfo_MdbBill.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_MdbBill proto.InternalMessageInfo
+
+func (m *MdbBill) GetOp() MdbBill_Op {
+	if m != nil {
+		return m.Op
+	}
+	return MdbBill_INVALID
+}
+
+func (m *MdbBill) GetSecurityMask() uint32 {
+	if m != nil {
+		return m.SecurityMask
+	}
+	return 0
+}
+
+func (m *MdbBill) GetBillEnable() uint32 {
+	if m != nil {
+		return m.BillEnable
+	}
+	return 0
+}
+
+func (m *MdbBill) GetEscrowEnable() uint32 {
+	if m != nil {
+		return m.EscrowEnable
+	}
+	return 0
+}
+
+func (m *MdbBill) GetStack() bool {
+	if m != nil {
+		return m.Stack
+	}
+	return false
+}
+
+func (m *MdbBill) GetTimeout() uint32 {
+	if m != nil {
+		return m.Timeout
+	}
+	return 0
+}
+
+func (m *MdbBill) GetRetry() *RetryPolicy {
+	if m != nil {
+		return m.Retry
+	}
+	return nil
+}
+
+type MdbBillSetup struct {
+	FeatureLevel uint32 `protobuf:"varint,1,opt,name=feature_level,json=featureLevel,proto3" json:"feature_level,omitempty"`
+	// Telephone code or ISO 4217 with leading 1, i.e. 0x0001 or 0x1840 for USD.
+	CountryCode     uint32 `protobuf:"varint,2,opt,name=country_code,json=countryCode,proto3" json:"country_code,omitempty"`
+	ScalingFactor   uint32 `protobuf:"varint,3,opt,name=scaling_factor,json=scalingFactor,proto3" json:"scaling_factor,omitempty"`
+	DecimalPlaces   uint32 `protobuf:"varint,4,opt,name=decimal_places,json=decimalPlaces,proto3" json:"decimal_places,omitempty"`
+	StackerCapacity uint32 `protobuf:"varint,5,opt,name=stacker_capacity,json=stackerCapacity,proto3" json:"stacker_capacity,omitempty"`
+	// Bit per bill type with high security.
+	SecurityLevels uint32 `protobuf:"varint,6,opt,name=security_levels,json=securityLevels,proto3" json:"security_levels,omitempty"`
+	Escrow         bool   `protobuf:"varint,7,opt,name=escrow,proto3" json:"escrow,omitempty"`
+	// Credit in scaling_factor units, index is bill type.
+	Credit               []uint32 `protobuf:"varint,8,rep,packed,name=credit,proto3" json:"credit,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *MdbBillSetup) Reset()         { *m = MdbBillSetup{} }
+func (m *MdbBillSetup) String() string { return proto.CompactTextString(m) }
+func (*MdbBillSetup) ProtoMessage()    {}
+func (*MdbBillSetup) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{24}
+}
+func (m *MdbBillSetup) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_MdbBillSetup.Unmarshal(m, b)
+}
+func (m *MdbBillSetup) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_MdbBillSetup.Marshal(b, m, deterministic)
+}
+func (dst *MdbBillSetup) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_MdbBillSetup.Merge(dst, src)
+}
+func (m *MdbBillSetup) XXX_Size() int {
+	return xxx_messageInfo_MdbBillSetup.Size(m)
+}
+func (m *MdbBillSetup) XXX_DiscardUnknown() {
+	xxx_messageInfo_MdbBillSetup.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_MdbBillSetup proto.InternalMessageInfo
+
+func (m *MdbBillSetup) GetFeatureLevel() uint32 {
+	if m != nil {
+		return m.FeatureLevel
+	}
+	return 0
+}
+
+func (m *MdbBillSetup) GetCountryCode() uint32 {
+	if m != nil {
+		return m.CountryCode
+	}
+	return 0
+}
+
+func (m *MdbBillSetup) GetScalingFactor() uint32 {
+	if m != nil {
+		return m.ScalingFactor
+	}
+	return 0
+}
+
+func (m *MdbBillSetup) GetDecimalPlaces() uint32 {
+	if m != nil {
+		return m.DecimalPlaces
+	}
+	return 0
+}
+
+func (m *MdbBillSetup) GetStackerCapacity() uint32 {
+	if m != nil {
+		return m.StackerCapacity
+	}
+	return 0
+}
+
+func (m *MdbBillSetup) GetSecurityLevels() uint32 {
+	if m != nil {
+		return m.SecurityLevels
+	}
+	return 0
+}
+
+func (m *MdbBillSetup) GetEscrow() bool {
+	if m != nil {
+		return m.Escrow
+	}
+	return false
+}
+
+func (m *MdbBillSetup) GetCredit() []uint32 {
+	if m != nil {
+		return m.Credit
+	}
+	return nil
+}
+
+type MdbBillStacker struct {
+	Full                 bool     `protobuf:"varint,1,opt,name=full,proto3" json:"full,omitempty"`
+	Count                uint32   `protobuf:"varint,2,opt,name=count,proto3" json:"count,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *MdbBillStacker) Reset()         { *m = MdbBillStacker{} }
+func (m *MdbBillStacker) String() string { return proto.CompactTextString(m) }
+func (*MdbBillStacker) ProtoMessage()    {}
+func (*MdbBillStacker) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{25}
+}
+func (m *MdbBillStacker) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_MdbBillStacker.Unmarshal(m, b)
+}
+func (m *MdbBillStacker) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_MdbBillStacker.Marshal(b, m, deterministic)
+}
+func (dst *MdbBillStacker) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_MdbBillStacker.Merge(dst, src)
+}
+func (m *MdbBillStacker) XXX_Size() int {
+	return xxx_messageInfo_MdbBillStacker.Size(m)
+}
+func (m *MdbBillStacker) XXX_DiscardUnknown() {
+	xxx_messageInfo_MdbBillStacker.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_MdbBillStacker proto.InternalMessageInfo
+
+func (m *MdbBillStacker) GetFull() bool {
+	if m != nil {
+		return m.Full
+	}
+	return false
+}
+
+func (m *MdbBillStacker) GetCount() uint32 {
+	if m != nil {
+		return m.Count
+	}
+	return 0
+}
+
+type MdbBillEvent struct {
+	Kind     MdbBillEvent_Kind    `protobuf:"varint,1,opt,name=kind,proto3,enum=iodin.MdbBillEvent_Kind" json:"kind,omitempty"`
+	BillType uint32               `protobuf:"varint,2,opt,name=bill_type,json=billType,proto3" json:"bill_type,omitempty"`
+	Routing  MdbBillEvent_Routing `protobuf:"varint,3,opt,name=routing,proto3,enum=iodin.MdbBillEvent_Routing" json:"routing,omitempty"`
+	// DISABLED_ATTEMPTS
+	Count                uint32              `protobuf:"varint,4,opt,name=count,proto3" json:"count,omitempty"`
+	Status               MdbBillEvent_Status `protobuf:"varint,5,opt,name=status,proto3,enum=iodin.MdbBillEvent_Status" json:"status,omitempty"`
+	XXX_NoUnkeyedLiteral struct{}            `json:"-"`
+	XXX_unrecognized     []byte              `json:"-"`
+	XXX_sizecache        int32               `json:"-"`
+}
+
+func (m *MdbBillEvent) Reset()         { *m = MdbBillEvent{} }
+func (m *MdbBillEvent) String() string { return proto.CompactTextString(m) }
+func (*MdbBillEvent) ProtoMessage()    {}
+func (*MdbBillEvent) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{26}
+}
+func (m *MdbBillEvent) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_MdbBillEvent.Unmarshal(m, b)
+}
+func (m *MdbBillEvent) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_MdbBillEvent.Marshal(b, m, deterministic)
+}
+func (dst *MdbBillEvent) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_MdbBillEvent.Merge(dst, src)
+}
+func (m *MdbBillEvent) XXX_Size() int {
+	return xxx_messageInfo_MdbBillEvent.Size(m)
+}
+func (m *MdbBillEvent) XXX_DiscardUnknown() {
+	xxx_messageInfo_MdbBillEvent.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_MdbBillEvent proto.InternalMessageInfo
+
+func (m *MdbBillEvent) GetKind() MdbBillEvent_Kind {
+	if m != nil {
+		return m.Kind
+	}
+	return MdbBillEvent_INVALID
+}
+
+func (m *MdbBillEvent) GetBillType() uint32 {
+	if m != nil {
+		return m.BillType
+	}
+	return 0
+}
+
+func (m *MdbBillEvent) GetRouting() MdbBillEvent_Routing {
+	if m != nil {
+		return m.Routing
+	}
+	return MdbBillEvent_STACKED
+}
+
+func (m *MdbBillEvent) GetCount() uint32 {
+	if m != nil {
+		return m.Count
+	}
+	return 0
+}
+
+func (m *MdbBillEvent) GetStatus() MdbBillEvent_Status {
+	if m != nil {
+		return m.Status
+	}
+	return MdbBillEvent_STATUS_NONE
+}
+
+type MdbBillResult struct {
+	Setup                *MdbBillSetup      `protobuf:"bytes,1,opt,name=setup,proto3" json:"setup,omitempty"`
+	Stacker              *MdbBillStacker    `protobuf:"bytes,2,opt,name=stacker,proto3" json:"stacker,omitempty"`
+	Events               []*MdbBillEvent    `protobuf:"bytes,3,rep,name=events,proto3" json:"events,omitempty"`
+	Identification       *MdbIdentification `protobuf:"bytes,4,opt,name=identification,proto3" json:"identification,omitempty"`
+	XXX_NoUnkeyedLiteral struct{}           `json:"-"`
+	XXX_unrecognized     []byte             `json:"-"`
+	XXX_sizecache        int32              `json:"-"`
+}
+
+func (m *MdbBillResult) Reset()         { *m = MdbBillResult{} }
+func (m *MdbBillResult) String() string { return proto.CompactTextString(m) }
+func (*MdbBillResult) ProtoMessage()    {}
+func (*MdbBillResult) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{27}
+}
+func (m *MdbBillResult) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_MdbBillResult.Unmarshal(m, b)
+}
+func (m *MdbBillResult) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_MdbBillResult.Marshal(b, m, deterministic)
+}
+func (dst *MdbBillResult) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_MdbBillResult.Merge(dst, src)
+}
+func (m *MdbBillResult) XXX_Size() int {
+	return xxx_messageInfo_MdbBillResult.Size(m)
+}
+func (m *MdbBillResult) XXX_DiscardUnknown() {
+	xxx_messageInfo_MdbBillResult.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_MdbBillResult proto.InternalMessageInfo
+
+func (m *MdbBillResult) GetSetup() *MdbBillSetup {
+	if m != nil {
+		return m.Setup
+	}
+	return nil
+}
+
+func (m *MdbBillResult) GetStacker() *MdbBillStacker {
+	if m != nil {
+		return m.Stacker
+	}
+	return nil
+}
+
+func (m *MdbBillResult) GetEvents() []*MdbBillEvent {
+	if m != nil {
+		return m.Events
+	}
+	return nil
+}
+
+func (m *MdbBillResult) GetIdentification() *MdbIdentification {
+	if m != nil {
+		return m.Identification
+	}
+	return nil
+}
+
+// Cashless reader at address 0x10 (#1) or 0x60 (#2), feature level 1.
+// Server keeps session state per reader and rejects commands illegal in it.
+// Poll reader with MDB_CASHLESS POLL, MDB_POLL_ADD is INVALID for reader addresses.
+// Command that failed on bus leaves state and funds unchanged and is never retried
+// by server, repeated VEND or READER command may charge or cancel twice.
+type MdbCashless struct {
+	Op MdbCashless_Op `protobuf:"varint,1,opt,name=op,proto3,enum=iodin.MdbCashless_Op" json:"op,omitempty"`
+	// 0x10 or 0x60, 0 means 0x10.
+	Address uint32 `protobuf:"varint,2,opt,name=address,proto3" json:"address,omitempty"`
+	// SETUP: VMC display size, 0 if none.
+	DisplayColumns uint32 `protobuf:"varint,3,opt,name=display_columns,json=displayColumns,proto3" json:"display_columns,omitempty"`
+	DisplayRows    uint32 `protobuf:"varint,4,opt,name=display_rows,json=displayRows,proto3" json:"display_rows,omitempty"`
+	// SETUP: in scale_factor units, 0 max_price means unknown (0xffff).
+	MaxPrice uint32 `protobuf:"varint,5,opt,name=max_price,json=maxPrice,proto3" json:"max_price,omitempty"`
+	MinPrice uint32 `protobuf:"varint,6,opt,name=min_price,json=minPrice,proto3" json:"min_price,omitempty"`
+	// VEND_REQUEST: in scale_factor units.
+	Price uint32 `protobuf:"varint,7,opt,name=price,proto3" json:"price,omitempty"`
+	// VEND_REQUEST, VEND_SUCCESS
+	Item uint32 `protobuf:"varint,8,opt,name=item,proto3" json:"item,omitempty"`
+	// Response timeout in milliseconds, 0 means Request.timeout.
+	Timeout              uint32   `protobuf:"varint,9,opt,name=timeout,proto3" json:"timeout,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *MdbCashless) Reset()         { *m = MdbCashless{} }
+func (m *MdbCashless) String() string { return proto.CompactTextString(m) }
+func (*MdbCashless) ProtoMessage()    {}
+func (*MdbCashless) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{28}
+}
+func (m *MdbCashless) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_MdbCashless.Unmarshal(m, b)
+}
+func (m *MdbCashless) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_MdbCashless.Marshal(b, m, deterministic)
+}
+func (dst *MdbCashless) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_MdbCashless.Merge(dst, src)
+}
+func (m *MdbCashless) XXX_Size() int {
+	return xxx_messageInfo_MdbCashless.Size(m)
+}
+func (m *MdbCashless) XXX_DiscardUnknown() {
+	xxx_messageInfo_MdbCashless.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_MdbCashless proto.InternalMessageInfo
+
+func (m *MdbCashless) GetOp() MdbCashless_Op {
+	if m != nil {
+		return m.Op
+	}
+	return MdbCashless_INVALID
+}
+
+func (m *MdbCashless) GetAddress() uint32 {
+	if m != nil {
+		return m.Address
+	}
+	return 0
+}
+
+func (m *MdbCashless) GetDisplayColumns() uint32 {
+	if m != nil {
+		return m.DisplayColumns
+	}
+	return 0
+}
+
+func (m *MdbCashless) GetDisplayRows() uint32 {
+	if m != nil {
+		return m.DisplayRows
+	}
+	return 0
+}
+
+func (m *MdbCashless) GetMaxPrice() uint32 {
+	if m != nil {
+		return m.MaxPrice
+	}
+	return 0
+}
+
+func (m *MdbCashless) GetMinPrice() uint32 {
+	if m != nil {
+		return m.MinPrice
+	}
+	return 0
+}
+
+func (m *MdbCashless) GetPrice() uint32 {
+	if m != nil {
+		return m.Price
+	}
+	return 0
+}
+
+func (m *MdbCashless) GetItem() uint32 {
+	if m != nil {
+		return m.Item
+	}
+	return 0
+}
+
+func (m *MdbCashless) GetTimeout() uint32 {
+	if m != nil {
+		return m.Timeout
+	}
+	return 0
+}
+
+type MdbCashlessSetup struct {
+	FeatureLevel  uint32 `protobuf:"varint,1,opt,name=feature_level,json=featureLevel,proto3" json:"feature_level,omitempty"`
+	CountryCode   uint32 `protobuf:"varint,2,opt,name=country_code,json=countryCode,proto3" json:"country_code,omitempty"`
+	ScaleFactor   uint32 `protobuf:"varint,3,opt,name=scale_factor,json=scaleFactor,proto3" json:"scale_factor,omitempty"`
+	DecimalPlaces uint32 `protobuf:"varint,4,opt,name=decimal_places,json=decimalPlaces,proto3" json:"decimal_places,omitempty"`
+	// Seconds reader may not respond before VMC must reset it.
+	// Also limit of waiting for vend approval.
+	MaxResponseTime      uint32   `protobuf:"varint,5,opt,name=max_response_time,json=maxResponseTime,proto3" json:"max_response_time,omitempty"`
+	Options              uint32   `protobuf:"varint,6,opt,name=options,proto3" json:"options,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *MdbCashlessSetup) Reset()         { *m = MdbCashlessSetup{} }
+func (m *MdbCashlessSetup) String() string { return proto.CompactTextString(m) }
+func (*MdbCashlessSetup) ProtoMessage()    {}
+func (*MdbCashlessSetup) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{29}
+}
+func (m *MdbCashlessSetup) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_MdbCashlessSetup.Unmarshal(m, b)
+}
+func (m *MdbCashlessSetup) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_MdbCashlessSetup.Marshal(b, m, deterministic)
+}
+func (dst *MdbCashlessSetup) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_MdbCashlessSetup.Merge(dst, src)
+}
+func (m *MdbCashlessSetup) XXX_Size() int {
+	return xxx_messageInfo_MdbCashlessSetup.Size(m)
+}
+func (m *MdbCashlessSetup) XXX_DiscardUnknown() {
+	xxx_messageInfo_MdbCashlessSetup.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_MdbCashlessSetup proto.InternalMessageInfo
+
+func (m *MdbCashlessSetup) GetFeatureLevel() uint32 {
+	if m != nil {
+		return m.FeatureLevel
+	}
+	return 0
+}
+
+func (m *MdbCashlessSetup) GetCountryCode() uint32 {
+	if m != nil {
+		return m.CountryCode
+	}
+	return 0
+}
+
+func (m *MdbCashlessSetup) GetScaleFactor() uint32 {
+	if m != nil {
+		return m.ScaleFactor
+	}
+	return 0
+}
+
+func (m *MdbCashlessSetup) GetDecimalPlaces() uint32 {
+	if m != nil {
+		return m.DecimalPlaces
+	}
+	return 0
+}
+
+func (m *MdbCashlessSetup) GetMaxResponseTime() uint32 {
+	if m != nil {
+		return m.MaxResponseTime
+	}
+	return 0
+}
+
+func (m *MdbCashlessSetup) GetOptions() uint32 {
+	if m != nil {
+		return m.Options
+	}
+	return 0
+}
+
+type MdbCashlessEvent struct {
+	Kind   MdbCashlessEvent_Kind `protobuf:"varint,1,opt,name=kind,proto3,enum=iodin.MdbCashlessEvent_Kind" json:"kind,omitempty"`
+	Amount uint32                `protobuf:"varint,2,opt,name=amount,proto3" json:"amount,omitempty"`
+	Code   uint32                `protobuf:"varint,3,opt,name=code,proto3" json:"code,omitempty"`
+	// Tenths of second.
+	DisplayTime    uint32             `protobuf:"varint,4,opt,name=display_time,json=displayTime,proto3" json:"display_time,omitempty"`
+	DisplayData    []byte             `protobuf:"bytes,5,opt,name=display_data,json=displayData,proto3" json:"display_data,omitempty"`
+	Setup          *MdbCashlessSetup  `protobuf:"bytes,6,opt,name=setup,proto3" json:"setup,omitempty"`
+	Identification *MdbIdentification `protobuf:"bytes,7,opt,name=identification,proto3" json:"identification,omitempty"`
+	// VEND_DENIED answering VEND CANCEL sent by server after approval did not arrive in time.
+	Timeout              bool     `protobuf:"varint,8,opt,name=timeout,proto3" json:"timeout,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *MdbCashlessEvent) Reset()         { *m = MdbCashlessEvent{} }
+func (m *MdbCashlessEvent) String() string { return proto.CompactTextString(m) }
+func (*MdbCashlessEvent) ProtoMessage()    {}
+func (*MdbCashlessEvent) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{30}
+}
+func (m *MdbCashlessEvent) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_MdbCashlessEvent.Unmarshal(m, b)
+}
+func (m *MdbCashlessEvent) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_MdbCashlessEvent.Marshal(b, m, deterministic)
+}
+func (dst *MdbCashlessEvent) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_MdbCashlessEvent.Merge(dst, src)
+}
+func (m *MdbCashlessEvent) XXX_Size() int {
+	return xxx_messageInfo_MdbCashlessEvent.Size(m)
+}
+func (m *MdbCashlessEvent) XXX_DiscardUnknown() {
+	xxx_messageInfo_MdbCashlessEvent.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_MdbCashlessEvent proto.InternalMessageInfo
+
+func (m *MdbCashlessEvent) GetKind() MdbCashlessEvent_Kind {
+	if m != nil {
+		return m.Kind
+	}
+	return MdbCashlessEvent_INVALID
+}
+
+func (m *MdbCashlessEvent) GetAmount() uint32 {
+	if m != nil {
+		return m.Amount
+	}
+	return 0
+}
+
+func (m *MdbCashlessEvent) GetCode() uint32 {
+	if m != nil {
+		return m.Code
+	}
+	return 0
+}
+
+func (m *MdbCashlessEvent) GetDisplayTime() uint32 {
+	if m != nil {
+		return m.DisplayTime
+	}
+	return 0
+}
+
+func (m *MdbCashlessEvent) GetDisplayData() []byte {
+	if m != nil {
+		return m.DisplayData
+	}
+	return nil
+}
+
+func (m *MdbCashlessEvent) GetSetup() *MdbCashlessSetup {
+	if m != nil {
+		return m.Setup
+	}
+	return nil
+}
+
+func (m *MdbCashlessEvent) GetIdentification() *MdbIdentification {
+	if m != nil {
+		return m.Identification
+	}
+	return nil
+}
+
+func (m *MdbCashlessEvent) GetTimeout() bool {
+	if m != nil {
+		return m.Timeout
+	}
+	return false
+}
+
+type MdbCashlessResult struct {
+	// After command and events.
+	State          MdbCashlessResult_State `protobuf:"varint,1,opt,name=state,proto3,enum=iodin.MdbCashlessResult_State" json:"state,omitempty"`
+	Setup          *MdbCashlessSetup       `protobuf:"bytes,2,opt,name=setup,proto3" json:"setup,omitempty"`
+	Events         []*MdbCashlessEvent     `protobuf:"bytes,3,rep,name=events,proto3" json:"events,omitempty"`
+	Identification *MdbIdentification      `protobuf:"bytes,4,opt,name=identification,proto3" json:"identification,omitempty"`
+	// Funds of current session.
+	Funds                uint32   `protobuf:"varint,5,opt,name=funds,proto3" json:"funds,omitempty"`
+	XXX_NoUnkeyedLiteral struct{} `json:"-"`
+	XXX_unrecognized     []byte   `json:"-"`
+	XXX_sizecache        int32    `json:"-"`
+}
+
+func (m *MdbCashlessResult) Reset()         { *m = MdbCashlessResult{} }
+func (m *MdbCashlessResult) String() string { return proto.CompactTextString(m) }
+func (*MdbCashlessResult) ProtoMessage()    {}
+func (*MdbCashlessResult) Descriptor() ([]byte, []int) {
+	return fileDescriptor_iodin_da42ac78aa9912b0, []int{31}
+}
+func (m *MdbCashlessResult) XXX_Unmarshal(b []byte) error {
+	return xxx_messageInfo_MdbCashlessResult.Unmarshal(m, b)
+}
+func (m *MdbCashlessResult) XXX_Marshal(b []byte, deterministic bool) ([]byte, error) {
+	return xxx_messageInfo_MdbCashlessResult.Marshal(b, m, deterministic)
+}
+func (dst *MdbCashlessResult) XXX_Merge(src proto.Message) {
+	xxx_messageInfo_MdbCashlessResult.Merge(dst, src)
+}
+func (m *MdbCashlessResult) XXX_Size() int {
+	return xxx_messageInfo_MdbCashlessResult.Size(m)
+}
+func (m *MdbCashlessResult) XXX_DiscardUnknown() {
+	xxx_messageInfo_MdbCashlessResult.DiscardUnknown(m)
+}
+
+var xxx_messageInfo_MdbCashlessResult proto.InternalMessageInfo
+
+func (m *MdbCashlessResult) GetState() MdbCashlessResult_State {
+	if m != nil {
+		return m.State
+	}
+	return MdbCashlessResult_INACTIVE
+}
+
+func (m *MdbCashlessResult) GetSetup() *MdbCashlessSetup {
+	if m != nil {
+		return m.Setup
+	}
+	return nil
+}
+
+func (m *MdbCashlessResult) GetEvents() []*MdbCashlessEvent {
+	if m != nil {
+		return m.Events
+	}
+	return nil
+}
+
+func (m *MdbCashlessResult) GetIdentification() *MdbIdentification {
+	if m != nil {
+		return m.Identification
+	}
+	return nil
+}
+
+func (m *MdbCashlessResult) GetFunds() uint32 {
+	if m != nil {
+		return m.Funds
+	}
+	return 0
+}
+
+func init() {
+	proto.RegisterType((*Request)(nil), "iodin.Request")
+	proto.RegisterType((*MdbOpen)(nil), "iodin.MdbOpen")
+	proto.RegisterType((*MdbOptions)(nil), "iodin.MdbOptions")
+	proto.RegisterType((*MdbFaults)(nil), "iodin.MdbFaults")
+	proto.RegisterType((*RetryPolicy)(nil), "iodin.RetryPolicy")
+	proto.RegisterType((*MdbReset)(nil), "iodin.MdbReset")
+	proto.RegisterType((*MdbTx)(nil), "iodin.MdbTx")
+	proto.RegisterType((*Response)(nil), "iodin.Response")
+	proto.RegisterType((*Subscribe)(nil), "iodin.Subscribe")
+	proto.RegisterType((*MdbPoll)(nil), "iodin.MdbPoll")
+	proto.RegisterType((*GpioWatch)(nil), "iodin.GpioWatch")
+	proto.RegisterType((*Event)(nil), "iodin.Event")
+	proto.RegisterType((*BatchItem)(nil), "iodin.BatchItem")
+	proto.RegisterType((*ErrorDetail)(nil), "iodin.ErrorDetail")
+	proto.RegisterType((*MdbBatch)(nil), "iodin.MdbBatch")
+	proto.RegisterType((*Hello)(nil), "iodin.Hello")
+	proto.RegisterType((*Info)(nil), "iodin.Info")
+	proto.RegisterType((*MdbCoin)(nil), "iodin.MdbCoin")
+	proto.RegisterType((*MdbCoinSetup)(nil), "iodin.MdbCoinSetup")
+	proto.RegisterType((*MdbCoinTubes)(nil), "iodin.MdbCoinTubes")
+	proto.RegisterType((*MdbCoinEvent)(nil), "iodin.MdbCoinEvent")
+	proto.RegisterType((*MdbIdentification)(nil), "iodin.MdbIdentification")
+	proto.RegisterType((*MdbCoinResult)(nil), "iodin.MdbCoinResult")
+	proto.RegisterType((*MdbBill)(nil), "iodin.MdbBill")
+	proto.RegisterType((*MdbBillSetup)(nil), "iodin.MdbBillSetup")
+	proto.RegisterType((*MdbBillStacker)(nil), "iodin.MdbBillStacker")
+	proto.RegisterType((*MdbBillEvent)(nil), "iodin.MdbBillEvent")
+	proto.RegisterType((*MdbBillResult)(nil), "iodin.MdbBillResult")
+	proto.RegisterType((*MdbCashless)(nil), "iodin.MdbCashless")
+	proto.RegisterType((*MdbCashlessSetup)(nil), "iodin.MdbCashlessSetup")
+	proto.RegisterType((*MdbCashlessEvent)(nil), "iodin.MdbCashlessEvent")
+	proto.RegisterType((*MdbCashlessResult)(nil), "iodin.MdbCashlessResult")
+	proto.RegisterEnum("iodin.Request_Command", Request_Command_name, Request_Command_value)
+	proto.RegisterEnum("iodin.MdbOpen_Transport", MdbOpen_Transport_name, MdbOpen_Transport_value)
+	proto.RegisterEnum("iodin.Response_Status", Response_Status_name, Response_Status_value)
+	proto.RegisterEnum("iodin.Event_Kind", Event_Kind_name, Event_Kind_value)
+	proto.RegisterEnum("iodin.ErrorDetail_Kind", ErrorDetail_Kind_name, ErrorDetail_Kind_value)
+	proto.RegisterEnum("iodin.ErrorDetail_Stage", ErrorDetail_Stage_name, ErrorDetail_Stage_value)
+	proto.RegisterEnum("iodin.MdbCoin_Op", MdbCoin_Op_name, MdbCoin_Op_value)
+	proto.RegisterEnum("iodin.MdbCoinEvent_Kind", MdbCoinEvent_Kind_name, MdbCoinEvent_Kind_value)
+	proto.RegisterEnum("iodin.MdbCoinEvent_Routing", MdbCoinEvent_Routing_name, MdbCoinEvent_Routing_value)
+	proto.RegisterEnum("iodin.MdbCoinEvent_Status", MdbCoinEvent_Status_name, MdbCoinEvent_Status_value)
+	proto.RegisterEnum("iodin.MdbBill_Op", MdbBill_Op_name, MdbBill_Op_value)
+	proto.RegisterEnum("iodin.MdbBillEvent_Kind", MdbBillEvent_Kind_name, MdbBillEvent_Kind_value)
+	proto.RegisterEnum("iodin.MdbBillEvent_Routing", MdbBillEvent_Routing_name, MdbBillEvent_Routing_value)
+	proto.RegisterEnum("iodin.MdbBillEvent_Status", MdbBillEvent_Status_name, MdbBillEvent_Status_value)
+	proto.RegisterEnum("iodin.MdbCashless_Op", MdbCashless_Op_name, MdbCashless_Op_value)
+	proto.RegisterEnum("iodin.MdbCashlessEvent_Kind", MdbCashlessEvent_Kind_name, MdbCashlessEvent_Kind_value)
+	proto.RegisterEnum("iodin.MdbCashlessResult_State", MdbCashlessResult_State_name, MdbCashlessResult_State_value)
+}
+
+func init() { proto.RegisterFile("iodin.proto", fileDescriptor_iodin_da42ac78aa9912b0) }
+
+var fileDescriptor_iodin_da42ac78aa9912b0 = []byte{
+	// 3685 bytes of a gzipped FileDescriptorProto
+	0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0xcd, 0x59, 0x4b, 0x8f, 0x23, 0x49,
+	0x11, 0x5e, 0xbf, 0xed, 0xb4, 0xbb, 0xdb, 0x5d, 0xf3, 0x32, 0xfb, 0x80, 0xdd, 0x42, 0xb0, 0x0b,
+	0x0b, 0xbd, 0xbb, 0xcd, 0x2e, 0x20, 0x04, 0xd2, 0xba, 0xcb, 0xd5, 0xdd, 0x35, 0x63, 0xbb, 0x4c,
+	0x56, 0x79, 0x66, 0xe7, 0x54, 0xaa, 0xb6, 0xab, 0x7b, 0x4a, 0xe3, 0x17, 0x55, 0xe5, 0x79, 0xc0,
+	0x65, 0x25, 0x6e, 0x88, 0x03, 0x12, 0x17, 0x8e, 0x7b, 0xe0, 0xc0, 0x81, 0x7f, 0xc0, 0x6d, 0xf9,
+	0x07, 0x88, 0x13, 0x17, 0xc4, 0x81, 0x0b, 0x08, 0x71, 0xe4, 0x84, 0x44, 0x44, 0x64, 0x66, 0xd9,
+	0x65, 0x77, 0xcf, 0xcc, 0x4a, 0x73, 0xe0, 0x96, 0x19, 0x19, 0x99, 0x95, 0x99, 0x11, 0xf1, 0x7d,
+	0x11, 0x59, 0xac, 0x1e, 0xce, 0xc7, 0xe1, 0xec, 0x60, 0x11, 0xcd, 0x93, 0xb9, 0x56, 0xa2, 0x8e,
+	0xfe, 0x69, 0x85, 0x55, 0x78, 0xf0, 0x93, 0x65, 0x10, 0x27, 0x5a, 0x8b, 0x55, 0x1e, 0x05, 0x51,
+	0x1c, 0xce, 0x67, 0xad, 0xdc, 0x9b, 0xb9, 0x77, 0x76, 0xb8, 0xea, 0x6a, 0xef, 0xb3, 0xca, 0x68,
+	0x3e, 0x9d, 0xfa, 0xb3, 0x71, 0x2b, 0x0f, 0x23, 0xbb, 0x87, 0x37, 0x0f, 0xc4, 0x5a, 0x72, 0xea,
+	0x81, 0x21, 0x46, 0xb9, 0x52, 0xc3, 0xb5, 0x92, 0x70, 0x1a, 0xcc, 0x97, 0x49, 0xab, 0x20, 0xd6,
+	0x92, 0x5d, 0xed, 0x4b, 0xac, 0xea, 0x47, 0x17, 0xde, 0x32, 0x9c, 0x25, 0xad, 0xa2, 0x18, 0x82,
+	0xfe, 0x10, 0xba, 0xda, 0x6b, 0xac, 0x86, 0x43, 0x67, 0x4f, 0x93, 0x20, 0x6e, 0x95, 0x60, 0xac,
+	0xc1, 0x51, 0xf7, 0x08, 0xfb, 0xda, 0x2e, 0xcb, 0x87, 0xe3, 0x56, 0x99, 0x66, 0x40, 0x4b, 0x7b,
+	0x97, 0x55, 0xa7, 0xe3, 0x33, 0x6f, 0xbe, 0x08, 0x66, 0x2d, 0x06, 0xd2, 0xfa, 0xe1, 0xae, 0xdc,
+	0x54, 0x6f, 0x7c, 0x66, 0x83, 0xf4, 0xf4, 0x15, 0x5e, 0x99, 0x8a, 0xa6, 0x76, 0xc0, 0x6a, 0xa8,
+	0x1c, 0x05, 0x71, 0x90, 0xb4, 0xea, 0xa4, 0xbd, 0xb7, 0xd2, 0xe6, 0x28, 0x06, 0x75, 0x5c, 0x90,
+	0xda, 0xda, 0xd7, 0x58, 0x19, 0xf5, 0x93, 0x27, 0xad, 0x06, 0x29, 0x37, 0x56, 0xca, 0xee, 0x13,
+	0xd0, 0x2c, 0x4d, 0xb1, 0xa1, 0x96, 0x3d, 0xf3, 0x93, 0xd1, 0x83, 0xd6, 0xce, 0xe6, 0xb2, 0x47,
+	0x28, 0x96, 0xcb, 0x52, 0x1b, 0xee, 0xb1, 0x16, 0x2f, 0xcf, 0xe2, 0x51, 0x14, 0x9e, 0x05, 0xad,
+	0x5d, 0xd2, 0x6f, 0x4a, 0x7d, 0x47, 0xc9, 0x61, 0xc2, 0x4a, 0x49, 0xfb, 0x80, 0xb1, 0x8b, 0x45,
+	0x38, 0xf7, 0x1e, 0xd3, 0x27, 0xf6, 0x32, 0x53, 0x4e, 0x60, 0xe0, 0x9e, 0xfc, 0x46, 0xed, 0x42,
+	0x75, 0xd4, 0xc5, 0x2c, 0xe6, 0x93, 0x49, 0xab, 0xb9, 0x79, 0x31, 0x03, 0x90, 0xca, 0x8b, 0xc1,
+	0xa6, 0x52, 0x1e, 0xcd, 0xc3, 0x59, 0x6b, 0x7f, 0x53, 0xd9, 0x00, 0xa9, 0x54, 0xc6, 0xa6, 0x52,
+	0x3e, 0x0b, 0x61, 0x65, 0x6d, 0x53, 0xf9, 0x28, 0x4c, 0x57, 0xc6, 0xa6, 0xf6, 0x3d, 0xd6, 0xa0,
+	0x95, 0xfd, 0xf8, 0xc1, 0x24, 0x88, 0xe3, 0xd6, 0x35, 0x9a, 0xa0, 0xad, 0xad, 0x2e, 0x47, 0x60,
+	0x52, 0x7d, 0xba, 0xea, 0xea, 0x7f, 0xcf, 0xb1, 0x8a, 0xf4, 0x27, 0xad, 0xce, 0x2a, 0x56, 0xff,
+	0x6e, 0xbb, 0x6b, 0x75, 0x9a, 0xaf, 0x68, 0x0d, 0x56, 0xed, 0x75, 0x8e, 0x3c, 0x7b, 0x60, 0xf6,
+	0x9b, 0x39, 0x6d, 0x87, 0xd5, 0xb0, 0xc7, 0x4d, 0xc7, 0x74, 0x9b, 0x79, 0x8d, 0xb1, 0x32, 0x76,
+	0xdd, 0x4f, 0x9a, 0x05, 0xad, 0xc6, 0x4a, 0xa7, 0x66, 0xb7, 0x6b, 0x37, 0x8b, 0x38, 0xe7, 0xc4,
+	0x74, 0x3d, 0xab, 0x7f, 0x6c, 0x37, 0x4b, 0x6a, 0xce, 0x51, 0xdb, 0x35, 0x4e, 0x9b, 0x65, 0xec,
+	0x3a, 0xc3, 0x23, 0xc7, 0xe0, 0xd6, 0x91, 0xd9, 0xac, 0x80, 0x87, 0xb1, 0x93, 0x81, 0x65, 0x7b,
+	0xf7, 0x68, 0xb8, 0xaa, 0x35, 0x59, 0x03, 0xb5, 0x07, 0x76, 0xb7, 0xeb, 0xb5, 0x3b, 0x9d, 0x66,
+	0x4d, 0xbb, 0xc6, 0xf6, 0x52, 0x09, 0x37, 0x7b, 0xf6, 0x5d, 0xb3, 0xc9, 0xd4, 0xb6, 0x0c, 0xdb,
+	0xea, 0x37, 0xeb, 0xaa, 0x77, 0x64, 0x75, 0xbb, 0xcd, 0x86, 0x5a, 0xc2, 0x68, 0x3b, 0xa7, 0x5d,
+	0xd3, 0x71, 0x9a, 0x3b, 0x5a, 0x95, 0x15, 0x1d, 0xd7, 0x1e, 0x34, 0xc7, 0x47, 0x65, 0x56, 0x04,
+	0xe7, 0x8e, 0xf5, 0x7f, 0xc2, 0x79, 0xa5, 0xcb, 0x6a, 0x37, 0x58, 0x39, 0x7a, 0xe2, 0x2d, 0x42,
+	0x15, 0x81, 0xa5, 0xe8, 0xc9, 0x20, 0x24, 0x71, 0x22, 0xc4, 0x79, 0x21, 0x4e, 0x48, 0xfc, 0x2e,
+	0xab, 0xcc, 0x17, 0x09, 0x04, 0x68, 0x4c, 0x41, 0x56, 0x3f, 0xdc, 0x5f, 0x8f, 0x00, 0x1a, 0xe0,
+	0x4a, 0x43, 0xfb, 0x2e, 0xab, 0x25, 0x91, 0x3f, 0x8b, 0x17, 0xf3, 0x48, 0x04, 0xde, 0xee, 0x61,
+	0x2b, 0x1b, 0x30, 0x07, 0xae, 0x1a, 0xe7, 0x2b, 0x55, 0xed, 0x26, 0x2b, 0x8f, 0x83, 0x47, 0xe1,
+	0x28, 0xa0, 0x88, 0xac, 0x71, 0xd9, 0xd3, 0x3f, 0x62, 0xb5, 0x54, 0x1f, 0xed, 0xd4, 0x31, 0x8f,
+	0xdb, 0xc3, 0xae, 0x0b, 0x76, 0x82, 0x23, 0xe2, 0x3d, 0x82, 0x8d, 0x2a, 0xac, 0xe0, 0x58, 0x3d,
+	0xb0, 0x0e, 0x88, 0x86, 0x6d, 0xee, 0x36, 0x0b, 0xfa, 0x27, 0x8c, 0xad, 0x76, 0xa7, 0xbd, 0xc9,
+	0x1a, 0x8f, 0xfd, 0x30, 0xf1, 0xe2, 0x24, 0x58, 0x78, 0xcb, 0x58, 0x9e, 0x9a, 0xa1, 0xcc, 0x01,
+	0xd1, 0x30, 0xd6, 0xde, 0x61, 0xe5, 0x73, 0x7f, 0x39, 0x49, 0xd4, 0x11, 0x9b, 0xab, 0x3d, 0x1f,
+	0x93, 0x9c, 0xcb, 0x71, 0xfd, 0xb7, 0x39, 0xb0, 0xae, 0x92, 0x6a, 0x1a, 0x2b, 0xc6, 0x41, 0x30,
+	0xa6, 0x15, 0x8b, 0x9c, 0xda, 0x28, 0x1b, 0x47, 0xf3, 0x85, 0xbc, 0x44, 0x6a, 0x6b, 0xaf, 0xb2,
+	0xea, 0xe8, 0x41, 0x30, 0x7a, 0x18, 0x2f, 0xa7, 0x12, 0xa9, 0xd2, 0x3e, 0x58, 0xaf, 0x30, 0xf3,
+	0x1f, 0x4a, 0x94, 0xc2, 0x26, 0x4a, 0x2e, 0xfc, 0x05, 0xdd, 0x04, 0x48, 0xa0, 0x89, 0x40, 0x77,
+	0xe1, 0x47, 0x67, 0xfe, 0x45, 0x20, 0xb1, 0x49, 0x75, 0xd1, 0x68, 0xa0, 0xe0, 0x4d, 0xe3, 0x56,
+	0x45, 0x18, 0x0d, 0x7a, 0xbd, 0x58, 0xff, 0x79, 0x8e, 0xd5, 0x79, 0x90, 0x44, 0x4f, 0x21, 0xfe,
+	0xc2, 0xd1, 0x53, 0xed, 0x2d, 0x88, 0x13, 0xff, 0x89, 0xe7, 0x27, 0x49, 0x30, 0x5d, 0x24, 0xea,
+	0x0a, 0xea, 0x20, 0x6b, 0x4b, 0x91, 0x76, 0xc8, 0xaa, 0x11, 0xce, 0xf0, 0xe6, 0xe8, 0x00, 0x05,
+	0xb0, 0xdc, 0x2d, 0x79, 0x0b, 0x66, 0x14, 0xcd, 0xa3, 0x4e, 0x90, 0xf8, 0xe1, 0xe4, 0xe0, 0x4e,
+	0x88, 0x00, 0x4c, 0x8a, 0xf6, 0x4c, 0x7b, 0x83, 0xb1, 0x33, 0x7f, 0xf4, 0x70, 0x7e, 0x7e, 0x8e,
+	0x3b, 0x10, 0x27, 0xab, 0x49, 0x09, 0xec, 0x02, 0x42, 0x59, 0x01, 0x9f, 0xf6, 0x15, 0x56, 0x1f,
+	0x2f, 0x23, 0x1f, 0x2d, 0x82, 0xba, 0xd2, 0x06, 0x4a, 0x04, 0xca, 0x3e, 0x2b, 0x11, 0xf0, 0x69,
+	0xd7, 0x59, 0xe9, 0x3c, 0xf2, 0xa7, 0x01, 0xe9, 0x34, 0xb8, 0xe8, 0xac, 0x63, 0x7d, 0x3e, 0x8b,
+	0xf5, 0xef, 0xb0, 0x12, 0xed, 0x47, 0xda, 0x4e, 0x4b, 0x59, 0x23, 0x3d, 0x3e, 0x17, 0x0a, 0xfa,
+	0x2f, 0x4a, 0xac, 0x0a, 0xbb, 0x59, 0x80, 0x53, 0x04, 0xcf, 0x20, 0xa2, 0x03, 0x56, 0x8e, 0x13,
+	0x3f, 0x01, 0x4f, 0xd9, 0xe4, 0x21, 0x31, 0xf5, 0xc0, 0xa1, 0x51, 0x2e, 0xb5, 0x70, 0xc3, 0x01,
+	0x5e, 0x11, 0x6d, 0xa0, 0xc6, 0x45, 0x07, 0xef, 0x66, 0xec, 0x27, 0xbe, 0x24, 0x9a, 0x22, 0x9d,
+	0xa5, 0x86, 0x92, 0x75, 0xa6, 0x29, 0xa5, 0x4c, 0xa3, 0xb3, 0xd2, 0x83, 0x60, 0x32, 0x99, 0x93,
+	0x81, 0x57, 0x5c, 0x70, 0x8a, 0x32, 0x2e, 0x86, 0xe0, 0x0e, 0x8b, 0xe1, 0xec, 0x7c, 0x4e, 0xa6,
+	0xae, 0x1f, 0xd6, 0xa5, 0x8a, 0x05, 0x22, 0x4e, 0x03, 0xda, 0x47, 0xac, 0x41, 0x1f, 0xf7, 0xc6,
+	0x64, 0xad, 0x56, 0x35, 0x73, 0x23, 0x6b, 0x76, 0xe4, 0xf5, 0x60, 0xd5, 0xd1, 0xbe, 0xce, 0x4a,
+	0x82, 0x5d, 0x6a, 0x60, 0xf7, 0x95, 0xf7, 0x13, 0x9d, 0x58, 0xe0, 0x1e, 0x5c, 0x0c, 0xe3, 0x1e,
+	0x83, 0x47, 0x01, 0x50, 0x2a, 0xcb, 0xec, 0xd1, 0x44, 0x19, 0x17, 0x43, 0xe8, 0xea, 0xa9, 0x97,
+	0xd5, 0x85, 0xab, 0xab, 0x3e, 0x58, 0xaa, 0x48, 0x1c, 0x20, 0xe8, 0xee, 0x7a, 0x96, 0x03, 0xe0,
+	0x76, 0x21, 0xa6, 0x38, 0x69, 0xa0, 0x26, 0x11, 0xc0, 0xce, 0xa6, 0x26, 0xa2, 0xbe, 0xd2, 0x44,
+	0x0d, 0xed, 0x43, 0x08, 0x2d, 0x85, 0xfe, 0x82, 0xec, 0x5a, 0xdb, 0xe8, 0x2f, 0x67, 0xa4, 0x9a,
+	0xfa, 0xa7, 0x39, 0x56, 0x16, 0x56, 0xcc, 0xa2, 0x7f, 0x99, 0xe5, 0xed, 0x3b, 0x02, 0xf7, 0x4d,
+	0xce, 0x01, 0xd1, 0x07, 0x43, 0xc4, 0x7d, 0x40, 0x58, 0xd1, 0x75, 0x4d, 0xde, 0x6f, 0x77, 0x01,
+	0xfd, 0xa5, 0xc4, 0x38, 0x35, 0x8d, 0x3b, 0xce, 0xb0, 0x07, 0x24, 0xb0, 0xc7, 0xea, 0x28, 0x71,
+	0xad, 0x9e, 0x69, 0xc3, 0xa4, 0x92, 0x52, 0x39, 0x6d, 0xf3, 0xce, 0xbd, 0x36, 0x37, 0x81, 0x0a,
+	0xe0, 0x53, 0x28, 0xe9, 0xb7, 0xef, 0x34, 0x2b, 0xfa, 0x87, 0xc0, 0x0b, 0x29, 0x03, 0xbf, 0xcd,
+	0x4a, 0x0f, 0x21, 0xb2, 0x30, 0x2e, 0x30, 0xf2, 0xf6, 0xd7, 0x6f, 0x56, 0xc4, 0x9c, 0x18, 0xd7,
+	0x9f, 0x12, 0x8c, 0x13, 0xab, 0x82, 0x03, 0xfb, 0xe3, 0x71, 0x84, 0x07, 0x97, 0x0e, 0x2c, 0xbb,
+	0x18, 0x6b, 0x90, 0xe9, 0x04, 0xd1, 0x23, 0x7f, 0x82, 0xb1, 0x26, 0xe2, 0x85, 0x29, 0x51, 0x2f,
+	0x46, 0xdf, 0x94, 0xd1, 0xb3, 0x16, 0xb7, 0x52, 0xd2, 0x8b, 0x57, 0x11, 0x58, 0x5c, 0x8b, 0x40,
+	0xc4, 0xe2, 0x34, 0x19, 0x40, 0x8c, 0x5a, 0x11, 0x08, 0x36, 0x11, 0xc2, 0x83, 0x99, 0x7f, 0x36,
+	0x09, 0xe8, 0x7b, 0x55, 0x2e, 0x7b, 0xfa, 0xdf, 0xf2, 0xac, 0x44, 0xe7, 0x80, 0x7c, 0xa7, 0x88,
+	0x87, 0xa0, 0x49, 0x97, 0x9e, 0x91, 0x86, 0xb5, 0x5b, 0x22, 0xd2, 0x3d, 0x19, 0x7f, 0x45, 0x5e,
+	0xc6, 0xee, 0x30, 0xde, 0x88, 0xa8, 0xc2, 0x66, 0x44, 0xc1, 0xa9, 0x45, 0xfa, 0x45, 0xd9, 0xa2,
+	0xdc, 0x3b, 0xa3, 0x6c, 0x4b, 0xa4, 0x9e, 0x69, 0x9c, 0x96, 0xd6, 0xe3, 0x74, 0x33, 0x66, 0xca,
+	0x2f, 0x16, 0x33, 0x00, 0xf3, 0x98, 0x0d, 0x49, 0xd8, 0xa5, 0x36, 0x7e, 0x60, 0x02, 0x51, 0x20,
+	0xe2, 0x0e, 0xb0, 0x98, 0x3a, 0xa8, 0x99, 0x84, 0xa3, 0x87, 0x10, 0x5c, 0xa4, 0x89, 0x6d, 0xbd,
+	0xc7, 0x8a, 0x78, 0xe2, 0x4b, 0x53, 0x0f, 0x24, 0x7e, 0xe1, 0x82, 0x94, 0x28, 0x98, 0x9d, 0x13,
+	0x13, 0x5c, 0x10, 0xba, 0x47, 0x43, 0xc7, 0x03, 0xff, 0xb1, 0x39, 0xf8, 0x1f, 0xe8, 0x52, 0x06,
+	0xd1, 0xb1, 0x4f, 0x9a, 0x45, 0xfd, 0x8f, 0xc0, 0x4a, 0x69, 0xb4, 0xae, 0xe1, 0x57, 0xee, 0x8b,
+	0xe1, 0x57, 0xfe, 0x6a, 0xfc, 0xda, 0xba, 0xed, 0xcd, 0x6b, 0x2b, 0xbe, 0xd8, 0xb5, 0xad, 0xc3,
+	0x43, 0x29, 0x0b, 0x0f, 0xfa, 0xe7, 0x05, 0x08, 0xa1, 0x35, 0xdd, 0x77, 0x33, 0xfe, 0x72, 0x25,
+	0x1b, 0x09, 0xaf, 0x39, 0x60, 0x25, 0x38, 0xce, 0x45, 0x20, 0x31, 0xbb, 0x75, 0x89, 0xb6, 0x83,
+	0xe3, 0x5c, 0xa8, 0xc1, 0xe2, 0xfb, 0x8a, 0x82, 0xc1, 0x65, 0x46, 0x41, 0xf8, 0x08, 0x78, 0x5c,
+	0x44, 0x42, 0x53, 0x0d, 0x70, 0x29, 0xcf, 0x28, 0x43, 0xf1, 0xb1, 0x58, 0x26, 0xa0, 0x5c, 0xcc,
+	0x2a, 0x1b, 0x52, 0xae, 0x7d, 0x83, 0x35, 0x17, 0x7e, 0x94, 0x84, 0x10, 0x7c, 0x91, 0xbc, 0x71,
+	0x59, 0x67, 0xec, 0x49, 0x79, 0xca, 0x41, 0x40, 0xcb, 0xe1, 0x0c, 0x42, 0x32, 0x1c, 0xd3, 0x35,
+	0x4b, 0x72, 0xaf, 0x4b, 0x19, 0x5e, 0xb4, 0xfe, 0xb3, 0xcb, 0x3c, 0x05, 0x72, 0x4f, 0xdb, 0x3d,
+	0x35, 0x39, 0xb8, 0x09, 0x34, 0x15, 0x4a, 0x01, 0x78, 0x41, 0x42, 0x54, 0x40, 0x55, 0xf4, 0x23,
+	0x84, 0x99, 0x22, 0xa0, 0xc4, 0x75, 0xec, 0xc8, 0xb9, 0x98, 0xc9, 0x0e, 0xec, 0xbe, 0x63, 0x0a,
+	0x7c, 0xa2, 0xb4, 0x51, 0x41, 0x58, 0x19, 0x21, 0x8c, 0xd2, 0x5b, 0x09, 0x61, 0x15, 0xfd, 0x03,
+	0x56, 0xa2, 0x4b, 0xc3, 0xd4, 0xaa, 0x6f, 0xf7, 0x4d, 0x91, 0x77, 0x39, 0x66, 0xbf, 0x03, 0x5f,
+	0x86, 0xcf, 0x70, 0xd3, 0x30, 0xad, 0xbb, 0xe8, 0x9e, 0x90, 0x84, 0xb5, 0x8d, 0x3b, 0x90, 0x7a,
+	0x71, 0xe2, 0xfc, 0x23, 0xc5, 0x17, 0x21, 0xd8, 0x56, 0xa0, 0xda, 0x46, 0x7d, 0xc3, 0xc5, 0x10,
+	0xe8, 0xec, 0xc4, 0xc9, 0x7c, 0x01, 0x59, 0x87, 0xb7, 0x72, 0xc2, 0x2a, 0xaf, 0xa3, 0xd0, 0x9e,
+	0x91, 0xf9, 0xf4, 0xdf, 0xe4, 0x20, 0xd7, 0x26, 0x06, 0xc4, 0xbb, 0xc5, 0xca, 0x72, 0x34, 0x9f,
+	0x78, 0x59, 0xf6, 0xde, 0x53, 0xf2, 0xbb, 0x69, 0x39, 0x79, 0x7d, 0x53, 0xd5, 0x9b, 0xa6, 0xc9,
+	0xad, 0xb6, 0xa1, 0xde, 0x03, 0x04, 0x83, 0x0c, 0x48, 0x56, 0x96, 0xe8, 0xef, 0x85, 0x67, 0x54,
+	0xa0, 0xa9, 0x9e, 0xfe, 0x59, 0x91, 0x15, 0x91, 0x80, 0xb5, 0xaf, 0xb2, 0x9d, 0x11, 0xe4, 0x32,
+	0x41, 0x66, 0x5b, 0x35, 0xde, 0x20, 0xa1, 0xda, 0x13, 0x94, 0xa5, 0x67, 0xcb, 0x70, 0x32, 0xf6,
+	0xc2, 0xb1, 0x0c, 0xb6, 0x0a, 0xf5, 0x2d, 0x4a, 0x1b, 0xa7, 0x73, 0x40, 0x89, 0x02, 0x1d, 0x9f,
+	0xda, 0x00, 0x98, 0xbb, 0x8b, 0x90, 0x2a, 0x33, 0xb5, 0xa8, 0xf0, 0xb9, 0x1d, 0x21, 0x55, 0xab,
+	0x82, 0x77, 0x3e, 0xf0, 0xa3, 0xf1, 0x63, 0x3f, 0x0a, 0xc0, 0xe3, 0x1e, 0x85, 0xa4, 0x29, 0x82,
+	0xab, 0xa9, 0x06, 0xb8, 0x94, 0xe3, 0x16, 0xd2, 0x8a, 0xb6, 0x4c, 0xdf, 0x4a, 0xeb, 0xd7, 0xd7,
+	0x19, 0x23, 0x00, 0x15, 0x45, 0x80, 0x00, 0x36, 0xaa, 0x56, 0xa9, 0x0e, 0x90, 0xa3, 0xb2, 0x44,
+	0xa8, 0xa6, 0xa3, 0x2e, 0x8d, 0x5e, 0x66, 0x98, 0xda, 0xe5, 0x86, 0x59, 0xbf, 0x66, 0xf6, 0x62,
+	0xd7, 0x0c, 0x47, 0xac, 0xa9, 0x72, 0x13, 0xd3, 0x8a, 0xc2, 0x76, 0xbd, 0x49, 0x7b, 0xc1, 0x46,
+	0xac, 0xfd, 0x88, 0xed, 0xd0, 0x4e, 0xd3, 0x42, 0xa4, 0xf1, 0x9c, 0x42, 0x04, 0x6b, 0xc8, 0x55,
+	0x99, 0xf1, 0x86, 0x38, 0xa8, 0xac, 0x47, 0x76, 0xc8, 0x4c, 0xf8, 0xf5, 0x0e, 0x09, 0xb4, 0xf7,
+	0xc4, 0xb0, 0xac, 0x17, 0x76, 0xaf, 0xa8, 0x17, 0x70, 0x82, 0x68, 0xea, 0xff, 0xce, 0x13, 0x67,
+	0x53, 0x71, 0xfb, 0x16, 0xcb, 0x43, 0x69, 0x90, 0x25, 0x40, 0x39, 0x76, 0x60, 0x2f, 0x38, 0x0c,
+	0x22, 0x8d, 0x61, 0x0a, 0xe4, 0xad, 0x91, 0x29, 0x90, 0x37, 0x8a, 0x4c, 0x92, 0x40, 0xc6, 0x73,
+	0x13, 0x2e, 0x65, 0x09, 0xf0, 0x32, 0x0e, 0x63, 0x38, 0x48, 0x1c, 0x28, 0x5d, 0x01, 0x5f, 0xd7,
+	0xc5, 0x68, 0x47, 0x0e, 0xca, 0x59, 0xaf, 0xb1, 0x1a, 0x2d, 0x9b, 0x3c, 0x5d, 0x04, 0xd2, 0x8d,
+	0xaa, 0x28, 0x70, 0xa1, 0x8f, 0x0c, 0x30, 0x9a, 0x2f, 0x21, 0xb1, 0x13, 0x5e, 0x23, 0x3a, 0xeb,
+	0x29, 0x77, 0xf9, 0x8a, 0x94, 0xbb, 0xf2, 0xbc, 0x94, 0x3b, 0x86, 0x84, 0x6a, 0xb1, 0x05, 0x5e,
+	0xa2, 0x9e, 0x26, 0xf0, 0x82, 0xc6, 0x70, 0x00, 0x00, 0x02, 0xd8, 0xe3, 0x0e, 0x8f, 0x4c, 0xcf,
+	0x71, 0xdb, 0xee, 0xd0, 0x01, 0x14, 0x03, 0xc2, 0xc3, 0x6a, 0xd7, 0x73, 0xef, 0x0f, 0x4c, 0x51,
+	0x63, 0x77, 0x2c, 0x07, 0xaa, 0x72, 0xc2, 0x2e, 0x40, 0x21, 0xa2, 0xc9, 0x32, 0xc4, 0xcd, 0xae,
+	0xd5, 0x31, 0xfb, 0xae, 0x75, 0x6c, 0x19, 0x6d, 0xd7, 0xb2, 0xfb, 0x00, 0x5b, 0x7f, 0xcd, 0x01,
+	0xb4, 0x89, 0x5b, 0x75, 0x82, 0x64, 0xb9, 0xc0, 0xe0, 0x3c, 0x0f, 0x80, 0xeb, 0x20, 0x40, 0x04,
+	0x41, 0x0b, 0xcc, 0x68, 0x48, 0x61, 0x97, 0x78, 0x1a, 0xc0, 0x98, 0xce, 0x0d, 0x25, 0xd0, 0x68,
+	0x3e, 0x56, 0x37, 0x5f, 0x97, 0x32, 0x03, 0x44, 0x18, 0x90, 0xf1, 0x08, 0xa0, 0x79, 0x76, 0x01,
+	0xf6, 0x1f, 0x25, 0x32, 0xe5, 0x87, 0x80, 0x94, 0xd2, 0x63, 0x12, 0xa2, 0xda, 0x38, 0x18, 0x85,
+	0x53, 0x30, 0xd1, 0x62, 0xe2, 0x8f, 0x64, 0xfa, 0x0f, 0x6a, 0x52, 0x3a, 0x20, 0xa1, 0xf8, 0x20,
+	0x98, 0x24, 0x82, 0x2b, 0x85, 0xc9, 0xf2, 0xf2, 0xc9, 0xfa, 0x5c, 0x88, 0x30, 0xa9, 0x1a, 0x45,
+	0xc1, 0x38, 0x44, 0x0b, 0x14, 0x60, 0x50, 0xf6, 0xf4, 0xef, 0xa7, 0x07, 0x74, 0x97, 0x67, 0x01,
+	0x15, 0xa2, 0xe7, 0xcb, 0x89, 0x3a, 0x17, 0xb5, 0x57, 0x46, 0xcd, 0xd3, 0x54, 0xd1, 0xd1, 0x7f,
+	0x5d, 0x4a, 0xa7, 0x8a, 0xac, 0xec, 0x5b, 0x19, 0x96, 0x6d, 0x65, 0x9d, 0x72, 0x2b, 0x39, 0xcb,
+	0xb8, 0x51, 0x7e, 0xc3, 0x8d, 0x3e, 0x62, 0x15, 0x75, 0x96, 0x02, 0xad, 0xf6, 0xda, 0x65, 0xab,
+	0xc9, 0xb3, 0x71, 0xa5, 0x4b, 0xd9, 0x28, 0x9c, 0xc2, 0x13, 0xbb, 0x2d, 0xca, 0x6c, 0x14, 0x24,
+	0x06, 0xb9, 0xe1, 0xe5, 0xce, 0x79, 0x98, 0x26, 0x39, 0x65, 0xfa, 0xd4, 0xab, 0x97, 0x7d, 0x2a,
+	0x9b, 0xe8, 0xe8, 0x27, 0x97, 0x71, 0x29, 0xf8, 0x59, 0xc7, 0x1c, 0xd8, 0x8e, 0xe5, 0x9a, 0x1d,
+	0x91, 0x76, 0x29, 0x3f, 0xeb, 0x88, 0x37, 0x05, 0xa7, 0x3b, 0x3c, 0x01, 0x7f, 0x64, 0x50, 0x31,
+	0x08, 0xdf, 0x2c, 0xea, 0x3f, 0x04, 0xea, 0x93, 0x9b, 0x07, 0xbf, 0xc4, 0x87, 0x17, 0xef, 0xc8,
+	0xfe, 0x44, 0xf8, 0x36, 0x7a, 0xb1, 0x03, 0x0b, 0xc1, 0x40, 0xdf, 0x76, 0xbd, 0xa1, 0x58, 0x07,
+	0x66, 0x73, 0xf3, 0xb6, 0x69, 0xe0, 0xeb, 0xc4, 0xaf, 0xf2, 0x69, 0xf1, 0x01, 0x5e, 0x2f, 0x16,
+	0xf5, 0x24, 0xbd, 0x82, 0x3b, 0x9b, 0x8e, 0xc1, 0xed, 0x7b, 0xc0, 0xd4, 0x3f, 0x1e, 0x9a, 0x0e,
+	0x46, 0x09, 0x28, 0x0d, 0xda, 0xf7, 0x81, 0x91, 0x3d, 0xc8, 0x00, 0xef, 0x8b, 0x5c, 0xb0, 0x6f,
+	0x7b, 0x06, 0x37, 0x3b, 0x16, 0xac, 0x07, 0x90, 0x7e, 0xa3, 0x63, 0x1e, 0xc3, 0xda, 0x40, 0xc5,
+	0x9e, 0x08, 0x22, 0xd8, 0x3e, 0xa4, 0x89, 0x45, 0x5c, 0xae, 0x63, 0x0f, 0x8f, 0xba, 0xa6, 0xd7,
+	0xe6, 0xdc, 0x82, 0x23, 0x43, 0xec, 0xdc, 0x64, 0x5a, 0xdb, 0x30, 0xcc, 0x81, 0x6b, 0x73, 0x6f,
+	0xd8, 0x1f, 0xc0, 0xe9, 0x4e, 0x60, 0x8b, 0x65, 0xdc, 0x30, 0x4d, 0xbe, 0xdd, 0xee, 0x35, 0x2b,
+	0xa8, 0xc5, 0xed, 0x5e, 0x9a, 0x1d, 0xc8, 0xc4, 0xb3, 0xaa, 0xed, 0xb3, 0x1d, 0x0e, 0x5b, 0xb1,
+	0xfa, 0x27, 0x52, 0x54, 0xc3, 0x3b, 0xa2, 0x8d, 0x31, 0x7c, 0xdc, 0xba, 0x3d, 0x74, 0x5c, 0xf9,
+	0x5e, 0x46, 0xef, 0x54, 0x14, 0xc3, 0xb8, 0x64, 0x03, 0xf7, 0x29, 0xf6, 0x6c, 0x76, 0xe8, 0x21,
+	0x4b, 0xbc, 0x6e, 0xc1, 0x9e, 0x76, 0xf4, 0xdf, 0xe7, 0xd8, 0x3e, 0x58, 0xce, 0x1a, 0x83, 0xd5,
+	0xc2, 0xf3, 0x70, 0x44, 0x8f, 0x02, 0x90, 0x1b, 0x34, 0x10, 0xcb, 0x30, 0xd4, 0x20, 0x4a, 0x23,
+	0x45, 0xa9, 0xeb, 0x32, 0x8c, 0x90, 0x38, 0x88, 0x20, 0xa9, 0x92, 0x84, 0x2a, 0x7b, 0xe8, 0x35,
+	0x53, 0x08, 0xd9, 0x89, 0x2a, 0xca, 0xa9, 0x83, 0x34, 0x15, 0xcf, 0xcf, 0x13, 0xa2, 0xca, 0x2c,
+	0xa7, 0xee, 0x29, 0xb9, 0xa2, 0x29, 0xc8, 0x54, 0x25, 0x3c, 0xa4, 0x99, 0xaa, 0xea, 0xeb, 0x7f,
+	0xce, 0xb1, 0x9d, 0x4c, 0xd9, 0x0a, 0x0b, 0x97, 0x62, 0x84, 0x1a, 0xda, 0x63, 0xfd, 0xf0, 0x5a,
+	0xd6, 0x1b, 0x09, 0x85, 0xb8, 0xd0, 0x40, 0x55, 0x74, 0x6e, 0x51, 0xdd, 0x6c, 0xa9, 0x52, 0x3c,
+	0x73, 0xa1, 0x01, 0xb4, 0x57, 0xa6, 0xaa, 0x5a, 0xe4, 0x23, 0x5b, 0xba, 0xa2, 0xf0, 0x96, 0x2a,
+	0xda, 0xc7, 0x6c, 0x37, 0xcc, 0xdc, 0x9f, 0xcc, 0xc9, 0xd7, 0x42, 0x3a, 0x7b, 0xbf, 0x7c, 0x43,
+	0x5f, 0xff, 0x65, 0x81, 0x98, 0x8a, 0x5e, 0x56, 0xaf, 0x60, 0x2a, 0x1c, 0x53, 0x4c, 0x05, 0xa8,
+	0x1a, 0x07, 0xa3, 0x65, 0x14, 0x26, 0x4f, 0xbd, 0xa9, 0x1f, 0x3f, 0x94, 0x78, 0xd0, 0x50, 0xc2,
+	0x1e, 0xc8, 0x90, 0xce, 0xb0, 0x4e, 0xcf, 0x52, 0x14, 0x43, 0x91, 0x24, 0x26, 0x58, 0x25, 0x80,
+	0x2a, 0x78, 0xfe, 0x58, 0xa9, 0x08, 0x7b, 0x34, 0x84, 0x50, 0x2a, 0x5d, 0xa7, 0xec, 0x1e, 0xd2,
+	0xa3, 0x12, 0xa5, 0x2c, 0xa2, 0xf3, 0x52, 0x08, 0x0a, 0x72, 0xcb, 0x17, 0x64, 0x28, 0x70, 0x66,
+	0xc7, 0x34, 0x86, 0xdc, 0x72, 0xef, 0x0b, 0x7a, 0xc2, 0xe7, 0x57, 0x45, 0x4f, 0x10, 0xdf, 0x22,
+	0x6e, 0x21, 0xc0, 0x60, 0x2d, 0x08, 0x6a, 0xe3, 0x0e, 0xe4, 0xe7, 0xe5, 0x94, 0xa9, 0x2a, 0x97,
+	0x30, 0x55, 0x15, 0xe0, 0xf4, 0x56, 0x56, 0xe6, 0x1d, 0x9b, 0x00, 0x07, 0xf0, 0xf5, 0x66, 0x4d,
+	0xff, 0x5d, 0x9e, 0xa0, 0x1a, 0xaf, 0xfc, 0xff, 0x99, 0xc6, 0x30, 0xa6, 0xd0, 0x1c, 0x41, 0xe4,
+	0x8d, 0xfc, 0x85, 0x3f, 0x02, 0xcb, 0xcb, 0x80, 0xd9, 0x93, 0x72, 0x43, 0x8a, 0xb5, 0xb7, 0xd9,
+	0x5e, 0xea, 0x31, 0x74, 0x82, 0x58, 0x1a, 0x6e, 0x57, 0x89, 0xe9, 0x0c, 0x31, 0x3d, 0x26, 0x90,
+	0xfd, 0xc9, 0x80, 0xf8, 0x98, 0x40, 0xbd, 0x35, 0x3e, 0xac, 0x66, 0xf8, 0xf0, 0x07, 0x6c, 0x57,
+	0xdd, 0x94, 0xf8, 0x64, 0x86, 0x11, 0xab, 0xdb, 0x8c, 0xb8, 0x62, 0x12, 0xfd, 0xf3, 0x52, 0x7a,
+	0xcd, 0xcf, 0x61, 0xc4, 0x54, 0x65, 0x83, 0x11, 0xc9, 0xc1, 0xd7, 0x19, 0x11, 0x05, 0xcf, 0x65,
+	0xc4, 0xd5, 0x6a, 0x5b, 0x8c, 0x98, 0x6e, 0xb4, 0x78, 0x39, 0xe5, 0x95, 0x36, 0x29, 0x6f, 0xb5,
+	0xd6, 0x06, 0xe5, 0x7d, 0x7c, 0x19, 0xe5, 0x21, 0x60, 0x5b, 0xf4, 0xc8, 0x70, 0x83, 0xed, 0x03,
+	0xdb, 0xb5, 0x81, 0x20, 0x3a, 0x5e, 0xdb, 0x75, 0xcd, 0xde, 0xc0, 0x75, 0x04, 0x5b, 0xa9, 0x3c,
+	0x4c, 0xff, 0x2c, 0xb7, 0x22, 0xbb, 0xd4, 0xb3, 0x71, 0x95, 0x95, 0xcb, 0x13, 0xd9, 0x71, 0x08,
+	0x13, 0xde, 0x27, 0xb2, 0xc3, 0x5c, 0xce, 0x06, 0x74, 0x37, 0xee, 0x1b, 0x5d, 0x13, 0x5f, 0x2b,
+	0xd6, 0x3f, 0x23, 0x68, 0x10, 0xf4, 0x8a, 0xc8, 0x31, 0x6b, 0x7a, 0x5e, 0xaf, 0xdd, 0x1f, 0x12,
+	0x43, 0x5d, 0x67, 0xcd, 0x94, 0x83, 0x95, 0xb4, 0x8c, 0x95, 0x6c, 0xaa, 0x0a, 0xd3, 0x52, 0xd6,
+	0xad, 0xe8, 0x9f, 0x3e, 0x83, 0x50, 0xaf, 0xb1, 0xbd, 0x15, 0x39, 0xf6, 0x6c, 0x20, 0x3d, 0xd8,
+	0x2e, 0x84, 0xa2, 0xa0, 0x48, 0x6f, 0xc0, 0x6d, 0xd8, 0x95, 0xfc, 0x7b, 0x40, 0x2c, 0x56, 0xb8,
+	0x82, 0xfa, 0x28, 0xc6, 0x81, 0xc8, 0x7a, 0xb0, 0xf5, 0xd2, 0x06, 0xd3, 0x95, 0xb1, 0x98, 0x26,
+	0x38, 0x10, 0x3f, 0x6c, 0x3a, 0x10, 0xee, 0x70, 0x08, 0xb5, 0xc5, 0x54, 0x5a, 0x95, 0x69, 0x2c,
+	0xdd, 0x04, 0x30, 0xe7, 0xab, 0xec, 0xa6, 0x2a, 0xcc, 0x37, 0x58, 0x9f, 0x21, 0xd1, 0xca, 0x15,
+	0xe5, 0x7d, 0xd5, 0x33, 0x04, 0xba, 0xfa, 0x1a, 0x5c, 0x4e, 0x43, 0xff, 0x8b, 0x60, 0xa4, 0xd5,
+	0xf3, 0xe8, 0x33, 0x18, 0x29, 0x05, 0x14, 0xc5, 0x48, 0xef, 0xb1, 0x8a, 0x8c, 0x54, 0xc9, 0x49,
+	0x37, 0x36, 0x94, 0xc5, 0x20, 0x57, 0x5a, 0xcf, 0xe2, 0xa5, 0xd4, 0x13, 0x5f, 0x22, 0x2f, 0xfd,
+	0xab, 0xc0, 0xea, 0x6b, 0xaf, 0xb9, 0x00, 0x4c, 0x2b, 0x6e, 0xba, 0xb1, 0xfd, 0xda, 0xab, 0xf8,
+	0x69, 0xed, 0x81, 0x34, 0x9f, 0x7d, 0x20, 0x05, 0x1c, 0xc2, 0xda, 0x69, 0xe2, 0x23, 0x46, 0x4e,
+	0x96, 0xd3, 0x99, 0x7a, 0x04, 0xdd, 0x95, 0x62, 0x43, 0x48, 0x11, 0x4c, 0x95, 0x22, 0xc0, 0x8f,
+	0x02, 0xc0, 0xba, 0x94, 0x71, 0x10, 0x61, 0xfc, 0xe3, 0xaf, 0x95, 0x45, 0xa4, 0xfe, 0x5e, 0x61,
+	0x59, 0xec, 0x3f, 0x19, 0x60, 0x9f, 0x06, 0x21, 0x5b, 0x16, 0x83, 0x65, 0x39, 0x18, 0xce, 0xc4,
+	0x20, 0x44, 0xb9, 0x18, 0x90, 0xbf, 0x6e, 0xa8, 0x83, 0xc0, 0x85, 0x2f, 0x23, 0xb2, 0xc2, 0xa6,
+	0xf6, 0x3a, 0xd1, 0xd5, 0x32, 0x44, 0xa7, 0xff, 0xe9, 0x85, 0xe9, 0x0b, 0xc3, 0xb5, 0x8f, 0x8e,
+	0x27, 0x5e, 0x88, 0xa4, 0x17, 0x0a, 0xb7, 0x36, 0xda, 0x7d, 0xc3, 0xec, 0x66, 0xea, 0x2a, 0x70,
+	0xe8, 0xbb, 0x66, 0xbf, 0x93, 0x3a, 0x64, 0x05, 0x43, 0x8b, 0x24, 0x52, 0xb9, 0x9a, 0xaa, 0x38,
+	0x43, 0x48, 0x27, 0x1d, 0x60, 0xac, 0x54, 0x72, 0xdc, 0xb6, 0xba, 0x40, 0x62, 0xe0, 0xc5, 0x10,
+	0x05, 0x0e, 0x8c, 0x21, 0xb3, 0x19, 0x76, 0x6f, 0xd0, 0x35, 0x5d, 0x13, 0x1c, 0x79, 0x9b, 0x0a,
+	0x1b, 0xb4, 0x55, 0x88, 0x5c, 0x13, 0xb2, 0xc1, 0x7f, 0xe4, 0x58, 0x73, 0xcd, 0x9e, 0x2f, 0x97,
+	0xfc, 0x40, 0x05, 0x69, 0x2e, 0xc8, 0x52, 0x5f, 0x9d, 0x64, 0x5f, 0x8c, 0xf8, 0xbe, 0xc9, 0xf6,
+	0xd1, 0xf2, 0xea, 0x91, 0xcf, 0x43, 0x9b, 0x28, 0xe6, 0x83, 0x01, 0xf5, 0xca, 0xe7, 0x86, 0xe2,
+	0xf7, 0x95, 0xfa, 0x8b, 0x2a, 0x53, 0x15, 0xd9, 0xd5, 0xff, 0x50, 0xcc, 0x1c, 0x56, 0x50, 0xd0,
+	0xfb, 0x19, 0x0a, 0x7a, 0x7d, 0xdb, 0xc7, 0xb7, 0x68, 0x08, 0x98, 0xd1, 0x9f, 0xae, 0x91, 0x9b,
+	0xec, 0xa1, 0x3b, 0xd1, 0x4d, 0x88, 0x63, 0x52, 0x7b, 0xdd, 0xab, 0x69, 0xcf, 0x59, 0xaf, 0xa6,
+	0xfd, 0xae, 0xa9, 0xe0, 0x9b, 0xaf, 0x7c, 0xc0, 0x54, 0x2a, 0x1d, 0x10, 0x69, 0xdf, 0x56, 0x00,
+	0x23, 0x5e, 0xcc, 0x6f, 0x6d, 0x6f, 0x32, 0x03, 0x32, 0xdb, 0x30, 0x50, 0xf9, 0x62, 0x30, 0xb0,
+	0x1e, 0x05, 0x55, 0xf1, 0x72, 0xa5, 0xa2, 0xe0, 0x3f, 0xb9, 0xcb, 0x68, 0x2e, 0x8b, 0xd1, 0x39,
+	0x2a, 0x5d, 0xcc, 0x76, 0x07, 0xe8, 0xc3, 0xb0, 0xfb, 0xc7, 0xd6, 0x09, 0x04, 0x05, 0xb2, 0x03,
+	0x30, 0x4d, 0xb7, 0x7d, 0x3f, 0x75, 0xf4, 0x02, 0x21, 0xaf, 0x79, 0x02, 0xf5, 0x89, 0xf4, 0x5c,
+	0x88, 0x11, 0x00, 0xea, 0xd4, 0x8d, 0xc9, 0xfd, 0x53, 0xf5, 0x12, 0xaa, 0x93, 0xd3, 0xb7, 0x07,
+	0x40, 0x26, 0x77, 0xa9, 0x94, 0x52, 0xa1, 0x02, 0x3e, 0x6e, 0x11, 0x19, 0xe0, 0xcf, 0x21, 0x8c,
+	0x14, 0xb9, 0x60, 0x95, 0x5e, 0x37, 0x68, 0x21, 0x41, 0x04, 0xb0, 0xc6, 0xc0, 0xe4, 0xd6, 0xe0,
+	0xd4, 0xe4, 0xed, 0xae, 0x07, 0xbb, 0x67, 0xf4, 0x18, 0xdb, 0xee, 0x1e, 0x0f, 0xfb, 0x06, 0x05,
+	0x48, 0x1d, 0xf7, 0x8a, 0x35, 0xa0, 0x7d, 0x0c, 0xcb, 0xc0, 0xb7, 0x61, 0x32, 0xe0, 0xfe, 0x7f,
+	0xf3, 0x54, 0x38, 0x65, 0x7f, 0x74, 0x69, 0x1f, 0x52, 0xba, 0x9c, 0x04, 0xd2, 0x7f, 0xbe, 0x7c,
+	0xd5, 0x1f, 0x31, 0xca, 0x16, 0xc4, 0x93, 0x78, 0x12, 0xac, 0x0c, 0x9a, 0x7f, 0x21, 0x83, 0xbe,
+	0xb7, 0x41, 0x02, 0xb7, 0xae, 0xf0, 0xd2, 0x97, 0x47, 0x04, 0xf4, 0x63, 0x6a, 0x89, 0xef, 0x86,
+	0xf2, 0x29, 0x80, 0x3a, 0xfa, 0x4f, 0xe9, 0x95, 0x1a, 0x0e, 0x00, 0xe4, 0x6a, 0xf5, 0xdb, 0x44,
+	0xf5, 0xe2, 0x77, 0x4a, 0x4a, 0xb5, 0xf4, 0x5a, 0x2d, 0xe0, 0xaf, 0x23, 0xfe, 0xe7, 0x29, 0x73,
+	0x5a, 0x1d, 0x42, 0x44, 0x40, 0xa4, 0x75, 0xb8, 0xa3, 0xf4, 0x64, 0xcb, 0xb0, 0x25, 0x4a, 0x2f,
+	0xe4, 0x44, 0x7c, 0x04, 0x2f, 0x9f, 0x95, 0xe9, 0x75, 0xf3, 0x3b, 0xff, 0x03, 0x6d, 0x5a, 0x7d,
+	0x81, 0xf7, 0x24, 0x00, 0x00,
 }
@@ -0,0 +1,544 @@
+//! Client for iodin server: spawns `iodin` process or connects to its unix socket.
+#[macro_use]
+extern crate error_chain;
+#[macro_use]
+extern crate log;
+extern crate protobuf;
+
+// Same parser as server env iodin_mdb_faults
+#[path = "../../../cmd/iodin/src/mdb/fault_spec.rs"]
+pub mod faults;
+pub mod proto;
+
+pub mod error {
+    use crate::proto::iodin::response::Status;
+    use std::time::Duration;
+
+    error_chain! {
+        foreign_links {
+            IoError(::std::io::Error);
+            Protobuf(protobuf::Error);
+        }
+
+        errors {
+            Timeout(timeout: Duration) {
+                description("iodin response timeout")
+                display("iodin response timeout={:?}", timeout)
+            }
+            InvalidInput(e: String) {
+                description("invalid input")
+                display("invalid input: {}", e)
+            }
+            Disconnected {
+                description("iodin disconnected")
+                display("iodin connection closed")
+            }
+            Response(status: Status, error: String) {
+                description("iodin error response")
+                display("iodin status={:?} error={}", status, error)
+            }
+        }
+    }
+}
+use self::error::*;
+
+use crate::proto::iodin::event::Kind as EventKind;
+use crate::proto::iodin::request::Command;
+use crate::proto::iodin::response::Status;
+use crate::proto::iodin::*;
+use protobuf::Message;
+use std::convert::TryFrom;
+use std::io::{self, Read, Write};
+use std::os::unix::net::UnixStream;
+use std::path::Path;
+use std::process;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+/// Protocol version sent in every request.
+pub const PROTOCOL_VERSION: u32 = 2;
+/// Default time to wait for response.
+pub const TIMEOUT: Duration = Duration::from_secs(5);
+/// Longer response frame is treated as stream corruption.
+pub const FRAME_MAX_LENGTH: u32 = 1 << 20;
+
+pub struct Client {
+    child: Option<process::Child>,
+    w: Box<dyn Write + Send>,
+    responses: mpsc::Receiver<Result<Response>>,
+    events: mpsc::Receiver<Event>,
+    next_id: u32,
+    timeout: Duration,
+}
+
+impl Client {
+    /// Start `iodin` at `path` talking over its stdin/stdout.
+    pub fn spawn(path: &Path) -> Result<Client> {
+        let mut child = process::Command::new(path)
+            .stdin(process::Stdio::piped())
+            .stdout(process::Stdio::piped())
+            .spawn()?;
+        let w = child.stdin.take().expect("piped stdin");
+        let r = child.stdout.take().expect("piped stdout");
+        let mut c = Client::from_io(Box::new(r), Box::new(w));
+        c.child = Some(child);
+        Ok(c)
+    }
+
+    /// Connect to `iodin` listening on unix socket (env `iodin_listen`).
+    pub fn connect(path: &Path) -> Result<Client> {
+        let stream = UnixStream::connect(path)?;
+        let r = stream.try_clone()?;
+        Ok(Client::from_io(Box::new(r), Box::new(stream)))
+    }
+
+    /// Use arbitrary byte stream to server.
+    pub fn from_io(r: Box<dyn Read + Send>, w: Box<dyn Write + Send>) -> Client {
+        let (tx, rx) = mpsc::channel();
+        let (event_tx, event_rx) = mpsc::channel();
+        thread::spawn(move || read_loop(r, tx, event_tx));
+        Client {
+            child: None,
+            w,
+            responses: rx,
+            events: event_rx,
+            next_id: 1,
+            timeout: TIMEOUT,
+        }
+    }
+
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Send request and wait for response with matching id.
+    /// Sets `Request.id` and `Request.version`. Response is returned regardless of status.
+    pub fn call(&mut self, mut request: Request) -> Result<Response> {
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1).max(1);
+        request.id = id;
+        request.version = PROTOCOL_VERSION;
+
+        let mut buf = Vec::with_capacity(request.compute_size() as usize + 4);
+        buf.extend_from_slice(&(request.compute_size() as u32).to_le_bytes());
+        request.write_to_vec(&mut buf)?;
+        self.w.write_all(&buf)?;
+        self.w.flush()?;
+
+        loop {
+            let response = match self.responses.recv_timeout(self.timeout) {
+                Ok(r) => r?,
+                Err(mpsc::RecvTimeoutError::Timeout) => bail!(ErrorKind::Timeout(self.timeout)),
+                Err(mpsc::RecvTimeoutError::Disconnected) => bail!(ErrorKind::Disconnected),
+            };
+            if response.id == id {
+                return Ok(response);
+            }
+            if response.id == 0 {
+                // server could not read request frame, it has no id to echo
+                bail!(ErrorKind::Response(
+                    response.status.enum_value_or_default(),
+                    response.error
+                ));
+            }
+            // late response to request that timed out earlier
+            debug!(
+                "iodin skip stale response id={} expected={}",
+                response.id, id
+            );
+        }
+    }
+
+    /// Like `call` but non-OK status is returned as error.
+    pub fn call_ok(&mut self, request: Request) -> Result<Response> {
+        let response = self.call(request)?;
+        match response.status.enum_value_or_default() {
+            Status::OK => Ok(response),
+            status => bail!(ErrorKind::Response(status, response.error.clone())),
+        }
+    }
+
+    pub fn hello(&mut self) -> Result<Hello> {
+        let response = self.call_ok(command(Command::HELLO))?;
+        Ok(response.hello.unwrap_or_default())
+    }
+
+    pub fn get_info(&mut self) -> Result<Info> {
+        let response = self.call_ok(command(Command::GET_INFO))?;
+        Ok(response.info.unwrap_or_default())
+    }
+
+    pub fn mdb_open(&mut self, rx_pin: u32, tx_pin: u32) -> Result<()> {
+        self.mdb_open_options(rx_pin, tx_pin, MdbOptions::new())
+    }
+
+    /// Like `mdb_open` with bus options, i.e. fault injection.
+    pub fn mdb_open_options(
+        &mut self,
+        rx_pin: u32,
+        tx_pin: u32,
+        options: MdbOptions,
+    ) -> Result<()> {
+        let mut open = MdbOpen::new();
+        open.rx_pin = rx_pin;
+        open.tx_pin = tx_pin;
+        open.options = Some(options).into();
+        self.mdb_open_args(open)
+    }
+
+    /// Open MDB bus with full set of arguments, i.e. to choose transport.
+    pub fn mdb_open_args(&mut self, open: MdbOpen) -> Result<()> {
+        let mut request = Request::new();
+        request.set_mdb_open(open);
+        self.call_ok(request)?;
+        Ok(())
+    }
+
+    pub fn mdb_reset(&mut self, duration: Duration) -> Result<()> {
+        let mut reset = MdbReset::new();
+        reset.duration_ms = millis(duration)?;
+        let mut request = Request::new();
+        request.set_mdb_reset(reset);
+        self.call_ok(request)?;
+        Ok(())
+    }
+
+    /// Send MDB frame (without checksum) and return peripheral response.
+    /// `timeout` of `None` uses server default.
+    pub fn mdb_tx(&mut self, frame: &[u8], timeout: Option<Duration>) -> Result<Vec<u8>> {
+        let mut tx = MdbTx::new();
+        tx.frame = frame.to_vec();
+        tx.timeout = timeout.map_or(Ok(0), millis)?;
+        let mut request = Request::new();
+        request.set_mdb_tx(tx);
+        let response = self.call_ok(request)?;
+        Ok(response.data_bytes)
+    }
+
+    /// Like `mdb_tx`, server repeats request per `retry` policy.
+    /// Returns response and number of attempts made.
+    pub fn mdb_tx_retry(
+        &mut self,
+        frame: &[u8],
+        timeout: Option<Duration>,
+        retry: RetryPolicy,
+    ) -> Result<(Vec<u8>, u32)> {
+        let mut tx = MdbTx::new();
+        tx.frame = frame.to_vec();
+        tx.timeout = timeout.map_or(Ok(0), millis)?;
+        tx.retry = Some(retry).into();
+        let mut request = Request::new();
+        request.set_mdb_tx(tx);
+        let response = self.call_ok(request)?;
+        Ok((response.data_bytes, response.attempts))
+    }
+
+    /// Coin changer command, see `MdbCoin.Op` for which result field is set.
+    pub fn mdb_coin(&mut self, coin: MdbCoin) -> Result<MdbCoinResult> {
+        let mut request = Request::new();
+        request.set_mdb_coin(coin);
+        let response = self.call_ok(request)?;
+        Ok(response.coin.unwrap_or_default())
+    }
+
+    /// Bill validator command, see `MdbBill.Op` for which result field is set.
+    pub fn mdb_bill(&mut self, bill: MdbBill) -> Result<MdbBillResult> {
+        let mut request = Request::new();
+        request.set_mdb_bill(bill);
+        let response = self.call_ok(request)?;
+        Ok(response.bill.unwrap_or_default())
+    }
+
+    /// Cashless reader command, server keeps session state and rejects illegal transitions.
+    pub fn mdb_cashless(&mut self, cashless: MdbCashless) -> Result<MdbCashlessResult> {
+        let mut request = Request::new();
+        request.set_mdb_cashless(cashless);
+        let response = self.call_ok(request)?;
+        Ok(response.cashless.unwrap_or_default())
+    }
+
+    /// Send frames in one request. Items are returned even if some failed,
+    /// check `BatchItem.status`.
+    pub fn mdb_batch(&mut self, frames: &[&[u8]], stop_on_error: bool) -> Result<Vec<BatchItem>> {
+        let mut batch = MdbBatch::new();
+        for f in frames {
+            let mut tx = MdbTx::new();
+            tx.frame = f.to_vec();
+            batch.items.push(tx);
+        }
+        batch.stop_on_error = stop_on_error;
+        let mut request = Request::new();
+        request.set_mdb_batch(batch);
+        let response = self.call(request)?;
+        if response.batch.is_empty() && response.status != Status::OK.into() {
+            bail!(ErrorKind::Response(
+                response.status.enum_value_or_default(),
+                response.error
+            ));
+        }
+        Ok(response.batch)
+    }
+
+    /// Let server send `frame` (empty means POLL for `address`) every `interval`,
+    /// non-empty responses arrive as MDB_POLL events. Replaces job for same address.
+    pub fn mdb_poll_add(
+        &mut self,
+        address: u8,
+        frame: &[u8],
+        interval: Duration,
+        timeout: Option<Duration>,
+    ) -> Result<()> {
+        let mut poll = MdbPoll::new();
+        poll.address = address.into();
+        poll.frame = frame.to_vec();
+        poll.interval_ms = millis(interval)?;
+        poll.timeout_ms = timeout.map_or(Ok(0), millis)?;
+        let mut request = command(Command::MDB_POLL_ADD);
+        request.set_mdb_poll(poll);
+        self.call_ok(request)?;
+        Ok(())
+    }
+
+    pub fn mdb_poll_remove(&mut self, address: u8) -> Result<()> {
+        let mut poll = MdbPoll::new();
+        poll.address = address.into();
+        let mut request = command(Command::MDB_POLL_REMOVE);
+        request.set_mdb_poll(poll);
+        self.call_ok(request)?;
+        Ok(())
+    }
+
+    /// Receive events of `kinds` pushed by server, empty slice unsubscribes.
+    /// Events are queued until taken with `recv_event`.
+    pub fn subscribe(&mut self, kinds: &[EventKind]) -> Result<()> {
+        let mut sub = Subscribe::new();
+        sub.kinds = kinds.iter().map(|&k| k.into()).collect();
+        let mut request = Request::new();
+        request.set_subscribe(sub);
+        self.call_ok(request)?;
+        Ok(())
+    }
+
+    /// Start or stop GPIO_EDGE events for `pin`.
+    pub fn gpio_watch(&mut self, pin: u32, enable: bool) -> Result<()> {
+        let mut watch = GpioWatch::new();
+        watch.pin = pin;
+        watch.enable = enable;
+        let mut request = Request::new();
+        request.set_gpio_watch(watch);
+        self.call_ok(request)?;
+        Ok(())
+    }
+
+    /// Wait for next subscribed event, `None` on timeout.
+    pub fn recv_event(&mut self, timeout: Duration) -> Result<Option<Event>> {
+        match self.events.recv_timeout(timeout) {
+            Ok(e) => Ok(Some(e)),
+            Err(mpsc::RecvTimeoutError::Timeout) => Ok(None),
+            Err(mpsc::RecvTimeoutError::Disconnected) => bail!(ErrorKind::Disconnected),
+        }
+    }
+
+    /// Ask server to end session. Spawned process exits after that.
+    pub fn stop(&mut self) -> Result<()> {
+        self.call_ok(command(Command::STOP))?;
+        if let Some(mut child) = self.child.take() {
+            child.wait()?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for Client {
+    fn drop(&mut self) {
+        if let Some(mut child) = self.child.take() {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+}
+
+/// Duration in protocol milliseconds, non-zero duration below 1ms is rounded up
+/// so it does not turn into 0 which means server default.
+fn millis(d: Duration) -> Result<u32> {
+    if d > Duration::from_millis(0) && d < Duration::from_millis(1) {
+        return Ok(1);
+    }
+    u32::try_from(d.as_millis())
+        .map_err(|_| ErrorKind::InvalidInput(format!("duration={:?} exceeds u32 millis", d)).into())
+}
+
+fn command(c: Command) -> Request {
+    let mut request = Request::new();
+    request.command = c.into();
+    request
+}
+
+/// Read length-prefixed responses until error, passing them to `tx`.
+/// Unsolicited event frames go to `events` so they never match a request.
+fn read_loop(
+    mut r: Box<dyn Read + Send>,
+    tx: mpsc::Sender<Result<Response>>,
+    events: mpsc::Sender<Event>,
+) {
+    loop {
+        let result = read_frame(&mut r);
+        if let Ok(response) = &result {
+            if let Some(event) = response.event.as_ref() {
+                // nobody listening is fine, client may not care about events
+                let _ = events.send(event.clone());
+                continue;
+            }
+        }
+        let stop = result.is_err();
+        if tx.send(result).is_err() || stop {
+            return;
+        }
+    }
+}
+
+fn read_frame(r: &mut dyn Read) -> Result<Response> {
+    let mut len = [0u8; 4];
+    r.read_exact(&mut len)?;
+    let len = u32::from_le_bytes(len);
+    if len > FRAME_MAX_LENGTH {
+        bail!(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "response frame length={} exceeds max={}",
+                len, FRAME_MAX_LENGTH
+            )
+        ));
+    }
+    let mut buf = vec![0u8; len as usize];
+    r.read_exact(&mut buf)?;
+    Ok(Response::parse_from_bytes(&buf)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Answer each request with `reply` responses, writing them one byte at a time.
+    fn fake_server<F>(reply: F) -> Client
+    where
+        F: Fn(Request) -> Vec<Response> + Send + 'static,
+    {
+        let (client, server) = UnixStream::pair().unwrap();
+        thread::spawn(move || {
+            let mut r = server.try_clone().unwrap();
+            let mut w = server;
+            loop {
+                let mut len = [0u8; 4];
+                if r.read_exact(&mut len).is_err() {
+                    return;
+                }
+                let mut buf = vec![0u8; u32::from_le_bytes(len) as usize];
+                r.read_exact(&mut buf).unwrap();
+                let request = Request::parse_from_bytes(&buf).unwrap();
+                for response in reply(request) {
+                    let mut out = (response.compute_size() as u32).to_le_bytes().to_vec();
+                    response.write_to_vec(&mut out).unwrap();
+                    for b in out {
+                        w.write_all(&[b]).unwrap();
+                        w.flush().unwrap();
+                    }
+                }
+            }
+        });
+        let r = client.try_clone().unwrap();
+        Client::from_io(Box::new(r), Box::new(client))
+    }
+
+    #[test]
+    fn client_mdb_tx_partial_reads() {
+        let mut c = fake_server(|request| {
+            let mut response = Response::new();
+            response.id = request.id;
+            response.status = Status::OK.into();
+            response.data_bytes = request.mdb_tx().frame.repeat(20);
+            vec![response]
+        });
+        assert_eq!(c.mdb_tx(&[0x0b], None).unwrap(), vec![0x0b; 20]);
+        assert_eq!(c.mdb_tx(&[0x33], None).unwrap(), vec![0x33; 20]);
+    }
+
+    #[test]
+    fn client_timeout_skips_stale_response() {
+        let mut c = fake_server(|request| {
+            let mut response = Response::new();
+            response.id = request.id;
+            if request.has_mdb_reset() {
+                // first request is answered late, after second request was sent
+                thread::sleep(Duration::from_millis(100));
+            } else if request.has_mdb_tx() {
+                response.status = Status::ERR_NAK.into();
+            }
+            vec![response]
+        });
+        c.set_timeout(Duration::from_millis(20));
+        match c.mdb_reset(Duration::from_millis(200)) {
+            Err(Error(ErrorKind::Timeout(_), _)) => (),
+            r => panic!("expected timeout, got {:?}", r),
+        }
+        c.set_timeout(TIMEOUT);
+        match c.mdb_tx(&[0x0b], None) {
+            Err(Error(ErrorKind::Response(Status::ERR_NAK, _), _)) => (),
+            r => panic!("expected NAK, got {:?}", r),
+        }
+    }
+
+    #[test]
+    fn client_millis() {
+        assert_eq!(millis(Duration::from_millis(0)).unwrap(), 0);
+        assert_eq!(millis(Duration::from_micros(1)).unwrap(), 1);
+        assert_eq!(millis(Duration::from_micros(1500)).unwrap(), 1);
+        assert_eq!(millis(Duration::from_secs(5)).unwrap(), 5000);
+        match millis(Duration::from_secs(u64::from(u32::MAX))) {
+            Err(Error(ErrorKind::InvalidInput(_), _)) => (),
+            r => panic!("expected invalid input, got {:?}", r),
+        }
+    }
+
+    #[test]
+    fn client_server_error_without_id() {
+        let mut c = fake_server(|_| {
+            let mut response = Response::new();
+            response.status = Status::ERR_INPUT.into();
+            response.error = "frame length=9999 exceeds max=4096".to_string();
+            vec![response]
+        });
+        c.set_timeout(Duration::from_secs(60));
+        match c.mdb_tx(&[0x0b], None) {
+            Err(Error(ErrorKind::Response(Status::ERR_INPUT, e), _)) => {
+                assert!(e.contains("frame"))
+            }
+            r => panic!("expected input error, got {:?}", r),
+        }
+    }
+
+    #[test]
+    fn client_events_between_responses() {
+        let mut c = fake_server(|request| {
+            let mut event = Event::new();
+            event.kind = EventKind::MDB_POLL.into();
+            event.data_bytes = vec![0x02];
+            let mut pushed = Response::new();
+            pushed.status = Status::OK.into();
+            pushed.event = Some(event).into();
+            let mut response = Response::new();
+            response.id = request.id;
+            response.status = Status::OK.into();
+            // event arrives before response to request
+            vec![pushed, response]
+        });
+        c.subscribe(&[EventKind::MDB_POLL]).unwrap();
+        let e = c.recv_event(Duration::from_secs(1)).unwrap().unwrap();
+        assert_eq!(e.kind, EventKind::MDB_POLL.into());
+        assert_eq!(e.data_bytes, vec![0x02]);
+        c.gpio_watch(4, true).unwrap();
+        assert!(c.recv_event(Duration::from_secs(1)).unwrap().is_some());
+        assert!(c.recv_event(Duration::from_millis(10)).unwrap().is_none());
+    }
+}
@@ -0,0 +1,3 @@
+// Same generated code as server, see cmd/iodin/build.rs
+#[path = "../../../../cmd/iodin/src/proto/iodin.rs"]
+pub mod iodin;
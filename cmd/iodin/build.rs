@@ -1,4 +1,5 @@
 use protobuf_codegen::Codegen;
+use std::process::Command;
 
 fn main() {
     let inputs = &[
@@ -9,4 +10,16 @@ fn main() {
         .inputs(inputs)
         .include("../../protobuf")
         .run_from_script();
+
+    // Build id reported by GET_INFO.
+    let build_id = Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .and_then(|o| String::from_utf8(o.stdout).ok())
+        .map(|s| s.trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=IODIN_BUILD_ID={}", build_id);
+    println!("cargo:rerun-if-changed=../../.git/HEAD");
 }
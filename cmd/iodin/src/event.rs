@@ -0,0 +1,163 @@
+//! Unsolicited event delivery to subscribed sessions.
+use crate::error::*;
+pub use crate::proto::iodin::event::Kind;
+use crate::proto::iodin::*;
+use std::os::raw::{c_int, c_void};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{mpsc, Arc, Mutex, MutexGuard};
+use std::time::Instant;
+
+/// Responses and events queued for one session writer.
+/// Events beyond this are dropped so slow client does not stall hardware callbacks.
+pub const SESSION_QUEUE_LENGTH: usize = 64;
+
+struct Subscriber {
+    session: u64,
+    // bit per event::Kind value
+    kinds: u32,
+    tx: mpsc::SyncSender<Response>,
+}
+
+/// Shared between server, sessions and hardware callbacks, clone is cheap.
+#[derive(Clone)]
+pub struct Hub {
+    start: Instant,
+    next_session: Arc<AtomicU64>,
+    subscribers: Arc<Mutex<Vec<Subscriber>>>,
+}
+
+impl Hub {
+    pub fn new() -> Hub {
+        Hub {
+            start: Instant::now(),
+            next_session: Arc::new(AtomicU64::new(1)),
+            subscribers: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Make session able to subscribe, returns session id (never 0).
+    /// Events are written to session through `tx`.
+    pub fn register(&self, tx: mpsc::SyncSender<Response>) -> u64 {
+        let session = self.next_session.fetch_add(1, Ordering::Relaxed);
+        let mut subs = self.lock();
+        subs.push(Subscriber {
+            session,
+            kinds: 0,
+            tx,
+        });
+        session
+    }
+
+    pub fn unregister(&self, session: u64) {
+        let mut subs = self.lock();
+        subs.retain(|s| s.session != session);
+    }
+
+    /// Replace session subscription with `kinds`, empty slice unsubscribes.
+    pub fn subscribe(&self, session: u64, kinds: &[Kind]) -> Result<()> {
+        let mut subs = self.lock();
+        match subs.iter_mut().find(|s| s.session == session) {
+            Some(s) => {
+                s.kinds = kinds.iter().fold(0, |acc, &k| acc | kind_bit(k));
+                Ok(())
+            }
+            None => {
+                Err(ErrorKind::InvalidInput("subscribe requires stream session".to_string()).into())
+            }
+        }
+    }
+
+    /// Send event to subscribed sessions, forgetting closed ones.
+    pub fn emit(&self, mut event: Event) {
+        let bit = kind_bit(event.kind.enum_value_or_default());
+        if event.time_us == 0 {
+            event.time_us = self.start.elapsed().as_micros() as u64;
+        }
+        let mut subs = self.lock();
+        if !subs.iter().any(|s| s.kinds & bit != 0) {
+            return;
+        }
+        let mut response = Response::new();
+        response.status = response::Status::OK.into();
+        response.event = Some(event).into();
+        subs.retain(|s| {
+            if s.kinds & bit == 0 {
+                return true;
+            }
+            match s.tx.try_send(response.clone()) {
+                Ok(()) => true,
+                Err(mpsc::TrySendError::Full(_)) => {
+                    warn!("session={} queue full, event dropped", s.session);
+                    true
+                }
+                Err(mpsc::TrySendError::Disconnected(_)) => false,
+            }
+        });
+    }
+
+    // Subscriber list is valid after panic in another thread, so poison is ignored.
+    fn lock(&self) -> MutexGuard<'_, Vec<Subscriber>> {
+        self.subscribers.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn kind_bit(k: Kind) -> u32 {
+    1u32.checked_shl(k as u32).unwrap_or(0)
+}
+
+// Hub by pin of armed watches. pigpio may still run alert callback after it is
+// unregistered, so callback looks up pin here instead of dereferencing userdata,
+// and holds the lock while emitting so removal waits for callback in flight.
+static WATCHES: Mutex<Vec<(u16, Hub)>> = Mutex::new(Vec::new());
+
+fn watches() -> MutexGuard<'static, Vec<(u16, Hub)>> {
+    WATCHES.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Emits GPIO_EDGE events for one pin while alive.
+pub struct GpioWatch {
+    pin: u16,
+    armed: bool,
+}
+
+impl GpioWatch {
+    /// With `armed=false` (mock mode) pigpio is not touched and no events are produced.
+    pub fn new(hub: Hub, pin: u16, armed: bool) -> Result<GpioWatch> {
+        if armed {
+            watches().push((pin, hub));
+            let rc = unsafe {
+                pigpio::gpioSetAlertFuncEx(pin.into(), Some(gpio_alert), std::ptr::null_mut())
+            };
+            if let Err(e) = pigpio::check(rc) {
+                watches().retain(|(p, _)| *p != pin);
+                return Err(e.into());
+            }
+        }
+        Ok(GpioWatch { pin, armed })
+    }
+}
+
+impl Drop for GpioWatch {
+    fn drop(&mut self) {
+        if self.armed {
+            unsafe { pigpio::gpioSetAlertFuncEx(self.pin.into(), None, std::ptr::null_mut()) };
+            watches().retain(|(p, _)| *p != self.pin);
+        }
+    }
+}
+
+/// Called from pigpio thread.
+unsafe extern "C" fn gpio_alert(gpio: c_int, level: c_int, tick: u32, _userdata: *mut c_void) {
+    let watches = watches();
+    let hub = match watches.iter().find(|(p, _)| c_int::from(*p) == gpio) {
+        Some((_, hub)) => hub,
+        // callback raced with GpioWatch drop
+        None => return,
+    };
+    let mut event = Event::new();
+    event.kind = Kind::GPIO_EDGE.into();
+    event.gpio = gpio as u32;
+    event.level = level as u32;
+    event.tick = tick;
+    hub.emit(event);
+}
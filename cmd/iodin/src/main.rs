@@ -1,15 +1,18 @@
 // #![feature(test)]
 #[macro_use]
 extern crate error_chain;
+extern crate libc;
 #[macro_use]
 extern crate log;
 extern crate pigpio;
 extern crate protobuf;
 
+mod event;
 mod logger;
 mod mdb;
 mod proto;
 mod server;
+mod signal;
 
 mod error {
     error_chain! {
@@ -23,6 +26,10 @@ mod error {
         }
 
         errors {
+            InvalidInput(s: String) {
+                description("invalid input")
+                display("{}", s)
+            }
             MdbNak {
                 description("MDB NAK")
                 display("MDB received NAK, probably invalid command")
@@ -35,14 +42,63 @@ mod error {
                 description("MDB invalid checksum")
                 display("MDB invalid checksum recv={:02x} comp={:02x} response={:02x?}", received, computed, response.as_slice())
             }
+            MdbTimeout(stage: crate::mdb::Stage, response: Vec<u8>) {
+                description("MDB timeout")
+                display("MDB {} timeout response(part)={:02x?}", stage, response.as_slice())
+            }
+            MdbDecode(reason: String, response: Vec<u8>) {
+                description("MDB unexpected response")
+                display("MDB {} response={:02x?}", reason, response.as_slice())
+            }
         }
     }
 }
 use self::error::*;
 
+fn main() {
+    logger::init(log::LevelFilter::Info).unwrap();
+
+    if let Err(ref e) = run() {
+        // Write the top-level error message
+        error!("error: {}", e);
+        // Trace back through the chained errors
+        for e in e.iter().skip(1) {
+            error!("caused by: {}", e);
+        }
+        ::std::process::exit(1);
+    }
+}
+
+/// The actual main(), but with the ability to use ? for easy early return
+fn run() -> Result<()> {
+    use std::fs::File;
+    use std::os::unix::io::FromRawFd;
+    use std::sync::{Arc, Mutex};
+
+    // before pigpio starts its threads
+    signal::block()?;
+    // Mock mode for tools like iodinctl, no hardware access.
+    // MDB bus is simulated, peripherals answer commands but no customer inserts money.
+    let mock = std::env::var("iodin_mock").unwrap_or_default() == "1";
+    let server = Arc::new(Mutex::new(server::Server::new(mock)?));
+    signal::spawn_handler(server.clone());
+    server::spawn_poller(server.clone());
+    // Optional unix socket for extra clients (diagnostics), stdio session still defines process lifetime.
+    if let Ok(path) = std::env::var("iodin_listen") {
+        server::listen_unix(server.clone(), std::path::Path::new(&path))?;
+    }
+
+    let mut stdin = unsafe { File::from_raw_fd(0) };
+    let mut stdout = unsafe { File::from_raw_fd(1) };
+    let result = server::Server::serve(&server, &mut stdin, &mut stdout);
+    let mut s = server.lock().map_err(|_| "server mutex poisoned")?;
+    s.shutdown()?;
+    result
+}
+
 #[cfg(test)]
 mod tests {
-    use std::{convert::TryInto, error::Error};
+    use std::convert::TryInto;
 
     // extern crate test;
     use super::*;
@@ -59,14 +115,270 @@ mod tests {
         assert_eq!(r.err().unwrap().to_string(), "invalid command");
     }
 
+    #[test]
+    fn server_exec_echo_id() {
+        let mut s = server::Server::new(true).unwrap();
+        let mut req = Request::new();
+        req.id = 42;
+        let mut resp = Response::new();
+        assert!(s.exec(&req, &mut resp).is_err());
+        assert_eq!(resp.id, 42);
+        req.command = request::Command::STOP.into();
+        req.id = 43;
+        s.exec(&req, &mut resp).unwrap();
+        assert_eq!(resp.id, 43);
+    }
+
+    #[test]
+    fn server_exec_version() {
+        let mut s = server::Server::new(true).unwrap();
+        let mut req = Request::new();
+        req.command = request::Command::STOP.into();
+        let mut resp = Response::new();
+        s.exec(&req, &mut resp).unwrap();
+        assert_eq!(resp.version, server::PROTOCOL_VERSION_MIN);
+
+        req.version = server::PROTOCOL_VERSION + 1;
+        let mut resp = Response::new();
+        assert!(s.exec(&req, &mut resp).is_err());
+        assert_eq!(resp.status, response::Status::ERR_INPUT.into());
+        assert_eq!(resp.version, server::PROTOCOL_VERSION);
+
+        req.command = request::Command::HELLO.into();
+        let mut resp = Response::new();
+        s.exec(&req, &mut resp).unwrap();
+        assert_eq!(resp.hello.protocol_version, server::PROTOCOL_VERSION);
+        assert!(resp
+            .hello
+            .commands
+            .contains(&request::Command::MDB_TX.into()));
+    }
+
+    #[test]
+    fn server_exec_get_info() {
+        let mut s = server::Server::new(true).unwrap();
+        let mut req = Request::new();
+        req.command = request::Command::GET_INFO.into();
+        let mut resp = Response::new();
+        s.exec(&req, &mut resp).unwrap();
+        assert!(resp.info.mock);
+        assert!(!resp.info.mdb_open);
+        assert_eq!(resp.info.crate_version, env!("CARGO_PKG_VERSION"));
+
+        let mut open = Request::new();
+        open.command = request::Command::MDB_OPEN.into();
+        open.arg_bytes = vec![15, 14];
+        s.exec(&open, &mut Response::new()).unwrap();
+        let mut resp = Response::new();
+        s.exec(&req, &mut resp).unwrap();
+        assert!(resp.info.mdb_open);
+        assert_eq!((resp.info.mdb_rx_pin, resp.info.mdb_tx_pin), (15, 14));
+    }
+
+    #[test]
+    fn server_error_detail() {
+        use crate::proto::iodin::error_detail::{Kind, Stage};
+
+        let e: crate::error::Error = ErrorKind::MdbChecksum(0x10, 0x20, vec![1, 2]).into();
+        let d = server::error_detail(&e);
+        assert_eq!(d.kind, Kind::MDB_CHECKSUM.into());
+        assert_eq!(d.stage, Stage::RECEIVE.into());
+        assert_eq!((d.checksum_computed, d.checksum_received), (0x10, 0x20));
+        assert_eq!(d.partial_response, vec![1, 2]);
+
+        let e: crate::error::Error = ErrorKind::MdbTimeout(mdb::Stage::Send, Vec::new()).into();
+        let d = server::error_detail(&e);
+        assert_eq!(d.kind, Kind::MDB_TIMEOUT.into());
+        assert_eq!(d.stage, Stage::SEND.into());
+
+        let mut s = server::Server::new(true).unwrap();
+        let mut resp = Response::new();
+        assert!(s.exec(&Request::new(), &mut resp).is_err());
+        assert_eq!(resp.error_detail.kind, Kind::INPUT.into());
+    }
+
+    #[test]
+    fn server_error_status() {
+        use crate::proto::iodin::response::Status;
+
+        for (e, status) in [
+            (ErrorKind::MdbNak, Status::ERR_NAK),
+            (
+                ErrorKind::MdbTimeout(mdb::Stage::Receive, Vec::new()),
+                Status::ERR_TIMEOUT,
+            ),
+            (ErrorKind::MdbChecksum(1, 2, vec![3]), Status::ERR_CHECKSUM),
+            (ErrorKind::MdbInvalidResponse(0x55), Status::ERR_HARDWARE),
+            (ErrorKind::InvalidInput("x".to_string()), Status::ERR_INPUT),
+            (ErrorKind::Msg("x".to_string()), Status::ERR_INTERNAL),
+        ] {
+            assert_eq!(server::error_status(&e.into()), status);
+        }
+    }
+
+    #[test]
+    fn server_exec_typed_args() {
+        let mut s = server::Server::new(true).unwrap();
+        let mut open = MdbOpen::new();
+        open.rx_pin = 15;
+        open.tx_pin = 14;
+        let mut req = Request::new();
+        req.set_mdb_open(open);
+        s.exec(&req, &mut Response::new()).unwrap();
+
+        let mut tx = MdbTx::new();
+        tx.frame = vec![0x0b];
+        let mut req = Request::new();
+        req.set_mdb_tx(tx);
+        let mut resp = Response::new();
+        s.exec(&req, &mut resp).unwrap();
+        // coin changer JUST RESET
+        assert_eq!(resp.data_bytes, vec![0x0b]);
+
+        // legacy arg_bytes are ignored when typed args present,
+        // bill validator would report JUST RESET
+        req.arg_bytes = vec![0x33];
+        req.command = request::Command::MDB_TX.into();
+        let mut resp = Response::new();
+        s.exec(&req, &mut resp).unwrap();
+        assert!(resp.data_bytes.is_empty());
+
+        req.command = request::Command::MDB_RESET.into();
+        let mut resp = Response::new();
+        assert!(s.exec(&req, &mut resp).is_err());
+        assert_eq!(resp.status, response::Status::ERR_INPUT.into());
+    }
+
+    #[test]
+    fn server_exec_mdb_reset_overflow() {
+        use crate::proto::iodin::response::Status;
+        use std::time::Duration;
+
+        for d in [Duration::from_secs(5000), Duration::from_secs(u64::MAX)] {
+            let e = mdb::duration_as_micros32(d).unwrap_err();
+            assert_eq!(server::error_status(&e), Status::ERR_INPUT);
+        }
+        assert_eq!(
+            mdb::duration_as_micros32(Duration::from_millis(100)).unwrap(),
+            100_000
+        );
+
+        let mut s = server::Server::new(true).unwrap();
+        let mut req = Request::new();
+        req.command = request::Command::MDB_OPEN.into();
+        req.arg_bytes = vec![15, 14];
+        s.exec(&req, &mut Response::new()).unwrap();
+        let mut reset = MdbReset::new();
+        reset.duration_ms = u32::MAX;
+        let mut req = Request::new();
+        req.set_mdb_reset(reset);
+        let mut resp = Response::new();
+        assert!(s.exec(&req, &mut resp).is_err());
+        assert_eq!(resp.status, Status::ERR_INPUT.into());
+    }
+
+    #[test]
+    fn server_exec_mdb_batch() {
+        let mut s = server::Server::new(true).unwrap();
+        let mut req = Request::new();
+        req.command = request::Command::MDB_OPEN.into();
+        req.arg_bytes = vec![15, 14];
+        s.exec(&req, &mut Response::new()).unwrap();
+
+        let mut batch = MdbBatch::new();
+        for (frame, timeout) in [(vec![0x0b], 0), (vec![0x33], 1), (vec![0x0a], 0)] {
+            let mut tx = MdbTx::new();
+            tx.frame = frame;
+            tx.timeout = timeout;
+            batch.items.push(tx);
+        }
+        let mut req = Request::new();
+        req.set_mdb_batch(batch.clone());
+        let mut resp = Response::new();
+        assert!(s.exec(&req, &mut resp).is_err());
+        assert_eq!(resp.status, response::Status::ERR_INPUT.into());
+        let statuses: Vec<_> = resp
+            .batch
+            .iter()
+            .map(|i| i.status.enum_value_or_default())
+            .collect();
+        use response::Status;
+        assert_eq!(statuses, vec![Status::OK, Status::ERR_INPUT, Status::OK]);
+        assert_eq!(resp.batch[0].data_bytes, vec![0x0b]);
+        // tube status
+        assert_eq!(resp.batch[2].data_bytes.len(), 18);
+
+        batch.stop_on_error = true;
+        req.set_mdb_batch(batch.clone());
+        let mut resp = Response::new();
+        assert!(s.exec(&req, &mut resp).is_err());
+        assert_eq!(resp.batch.len(), 2);
+
+        // batch holds the bus, items are not retried
+        batch.items[0].retry.mut_or_insert_default().max_attempts = 2;
+        req.set_mdb_batch(batch);
+        let mut resp = Response::new();
+        assert!(s.exec(&req, &mut resp).is_err());
+        assert_eq!(resp.status, response::Status::ERR_INPUT.into());
+        assert!(resp.batch.is_empty());
+    }
+
+    #[test]
+    fn server_exec_mdb_tx_timeout_range() {
+        let mut s = server::Server::new(true).unwrap();
+        let mut open = Request::new();
+        open.command = request::Command::MDB_OPEN.into();
+        open.arg_bytes = vec![15, 14];
+        s.exec(&open, &mut Response::new()).unwrap();
+
+        let mut req = Request::new();
+        req.command = request::Command::MDB_TX.into();
+        req.arg_bytes = vec![0x0b];
+        for &(timeout, ok) in &[
+            (0, true),
+            (1, false),
+            (50, true),
+            (10_000, true),
+            (60_000, false),
+        ] {
+            req.timeout = timeout;
+            let mut resp = Response::new();
+            let r = s.exec(&req, &mut resp);
+            assert_eq!(r.is_ok(), ok, "timeout={}", timeout);
+            if !ok {
+                assert_eq!(resp.status, response::Status::ERR_INPUT.into());
+            }
+        }
+    }
+
+    #[test]
+    fn server_shutdown_closes_mdb() {
+        let mut s = server::Server::new(true).unwrap();
+        let mut open = Request::new();
+        open.command = request::Command::MDB_OPEN.into();
+        open.arg_bytes = vec![15, 14];
+        s.exec(&open, &mut Response::new()).unwrap();
+        s.shutdown().unwrap();
+
+        let mut req = Request::new();
+        req.command = request::Command::MDB_TX.into();
+        req.arg_bytes = vec![0x0b];
+        let mut resp = Response::new();
+        assert!(s.exec(&req, &mut resp).is_err());
+        assert_eq!(resp.error, "server is shut down");
+        // other sessions can not reopen released hardware
+        assert!(s.exec(&open, &mut Response::new()).is_err());
+        s.shutdown().unwrap();
+    }
+
     #[test]
     fn server_run_eof() {
         use crate::error::ErrorKind::Protobuf;
 
         let rv: Vec<u8> = Vec::new();
         let mut wv: Vec<u8> = Vec::new();
-        let mut s = server::Server::new(true).unwrap();
-        let r = s.run(&mut rv.as_slice(), &mut wv);
+        let s = std::sync::Mutex::new(server::Server::new(true).unwrap());
+        let r = server::Server::serve(&s, &mut rv.as_slice(), &mut wv);
         assert!(r.is_err());
         let err = r.err().unwrap();
         assert!(match err {
@@ -88,11 +400,1815 @@ mod tests {
         request.write_to_with_cached_sizes(&mut os).unwrap();
         os.flush().unwrap();
         drop(os);
-        let mut s = server::Server::new(true).unwrap();
-        let r = s.run(&mut rv.as_slice(), &mut wv);
+        let s = std::sync::Mutex::new(server::Server::new(true).unwrap());
+        let r = server::Server::serve(&s, &mut rv.as_slice(), &mut wv);
         assert!(r.is_ok());
     }
 
+    #[test]
+    fn server_parse_max_frame() {
+        assert_eq!(server::parse_max_frame("65536").unwrap(), 65536);
+        let e = server::parse_max_frame("4294967295").unwrap_err();
+        assert!(matches!(e.kind(), ErrorKind::InvalidInput(..)), "{}", e);
+        assert!(server::parse_max_frame("big").is_err());
+    }
+
+    #[test]
+    fn server_run_bad_frame_recover() {
+        use std::io::Read;
+
+        // Returns one chunk per read() like separate client writes to pipe.
+        struct Chunks(Vec<Vec<u8>>);
+        impl Read for Chunks {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                if self.0.is_empty() {
+                    return Ok(0);
+                }
+                let chunk = self.0.remove(0);
+                buf[..chunk.len()].copy_from_slice(&chunk);
+                Ok(chunk.len())
+            }
+        }
+
+        let mut request = Request::new();
+        request.command = request::Command::STOP.into();
+        let mut stop = Vec::new();
+        stop.extend_from_slice(&(request.compute_size() as u32).to_le_bytes());
+        request.write_to_vec(&mut stop).unwrap();
+        // valid length, invalid protobuf, then valid frame in same write
+        let mut bad_proto = vec![2, 0, 0, 0, 0xff, 0xff];
+        bad_proto.extend_from_slice(&stop);
+        let mut r = Chunks(vec![b"garbage text\n".to_vec(), bad_proto]);
+        let mut wv: Vec<u8> = Vec::new();
+        let s = std::sync::Mutex::new(server::Server::new(true).unwrap());
+        server::Server::serve(&s, &mut r, &mut wv).unwrap();
+
+        let mut is = protobuf::CodedInputStream::from_bytes(&wv);
+        let mut statuses = Vec::new();
+        let mut kinds = Vec::new();
+        while !is.eof().unwrap() {
+            let len = is.read_fixed32().unwrap();
+            let old_limit = is.push_limit(len.into()).unwrap();
+            let mut response = Response::new();
+            response.merge_from(&mut is).unwrap();
+            is.pop_limit(old_limit);
+            statuses.push(response.status.enum_value_or_default());
+            kinds.push(response.error_detail.kind.enum_value_or_default());
+        }
+        use crate::proto::iodin::error_detail::Kind;
+        use response::Status;
+        assert_eq!(
+            statuses,
+            vec![Status::ERR_INPUT, Status::ERR_INPUT, Status::OK]
+        );
+        assert_eq!(kinds, vec![Kind::INPUT, Kind::INPUT, Kind::INVALID]);
+    }
+
+    #[test]
+    fn server_listen_unix_shared() {
+        use std::os::unix::net::UnixStream;
+        use std::sync::{Arc, Mutex};
+
+        let path = std::env::temp_dir().join(format!("iodin-test-{}.sock", std::process::id()));
+        let s = Arc::new(Mutex::new(server::Server::new(true).unwrap()));
+        server::listen_unix(s.clone(), &path).unwrap();
+
+        let mut open = Request::new();
+        open.command = request::Command::MDB_OPEN.into();
+        open.arg_bytes = vec![15, 14];
+        let mut tx = Request::new();
+        tx.command = request::Command::MDB_TX.into();
+        tx.arg_bytes = vec![0x0b];
+
+        // bus opened by one client is available to another
+        let mut c1 = UnixStream::connect(&path).unwrap();
+        assert_eq!(
+            roundtrip(&mut c1, &open).status,
+            response::Status::OK.into()
+        );
+        let mut c2 = UnixStream::connect(&path).unwrap();
+        let r = roundtrip(&mut c2, &tx);
+        assert_eq!(r.status, response::Status::OK.into());
+        assert_eq!(r.data_bytes, vec![0x0b]);
+
+        // live socket is not replaced
+        let other = Arc::new(Mutex::new(server::Server::new(true).unwrap()));
+        assert!(server::listen_unix(other, &path).is_err());
+        s.lock().unwrap().shutdown().unwrap();
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn server_listen_unix_not_socket() {
+        use std::sync::{Arc, Mutex};
+
+        let path = std::env::temp_dir().join(format!("iodin-test-{}.file", std::process::id()));
+        std::fs::write(&path, b"data").unwrap();
+        let s = Arc::new(Mutex::new(server::Server::new(true).unwrap()));
+        assert!(server::listen_unix(s, &path).is_err());
+        assert_eq!(std::fs::read(&path).unwrap(), b"data");
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn server_with_retry() {
+        use crate::proto::iodin::error_detail::Kind;
+
+        let mut policy = RetryPolicy::new();
+        policy.max_attempts = 3;
+        let mut attempts = 0;
+        // fails twice with retryable error, then succeeds
+        let mut calls = 0;
+        let r = server::with_retry(&policy, &mut attempts, || {
+            calls += 1;
+            match calls {
+                1 | 2 => Err(ErrorKind::MdbNak.into()),
+                _ => Ok(calls),
+            }
+        });
+        assert_eq!((r.unwrap(), attempts), (3, 3));
+
+        // peripheral may have acted on request, not retried by default
+        let r: Result<()> = server::with_retry(&policy, &mut attempts, || {
+            Err(ErrorKind::MdbTimeout(mdb::Stage::Receive, vec![]).into())
+        });
+        assert!(r.is_err());
+        assert_eq!(attempts, 1);
+        let r: Result<()> = server::with_retry(&policy, &mut attempts, || {
+            Err(ErrorKind::MdbChecksum(1, 2, vec![]).into())
+        });
+        assert!(r.is_err());
+        assert_eq!(attempts, 1);
+
+        policy.retry_on = vec![Kind::MDB_TIMEOUT.into()];
+        let r: Result<()> = server::with_retry(&policy, &mut attempts, || {
+            Err(ErrorKind::MdbTimeout(mdb::Stage::Receive, vec![]).into())
+        });
+        assert!(r.is_err());
+        assert_eq!(attempts, 3);
+
+        let r: Result<()> = server::with_retry(&policy, &mut attempts, || {
+            Err(ErrorKind::MdbInvalidResponse(0x55).into())
+        });
+        assert!(r.is_err());
+        assert_eq!(attempts, 1);
+
+        policy.retry_on = vec![Kind::MDB_INVALID_RESPONSE.into()];
+        let r: Result<()> =
+            server::with_retry(&policy, &mut attempts, || Err(ErrorKind::MdbNak.into()));
+        assert!(r.is_err());
+        assert_eq!(attempts, 1);
+
+        // default policy is single attempt
+        let r: Result<()> = server::with_retry(&RetryPolicy::new(), &mut attempts, || {
+            Err(ErrorKind::MdbNak.into())
+        });
+        assert!(r.is_err());
+        assert_eq!(attempts, 1);
+    }
+
+    #[test]
+    fn server_serve_mdb_tx_retry() {
+        use crate::proto::iodin::response::Status;
+        use std::sync::Mutex;
+        use std::time::{Duration, Instant};
+
+        let s = Mutex::new(server::Server::new(true).unwrap());
+        let mut open = MdbOpen::new();
+        open.transport = mdb_open::Transport::SIM.into();
+        open.options
+            .mut_or_insert_default()
+            .faults
+            .mut_or_insert_default()
+            .nak = 100;
+        let mut req = Request::new();
+        req.set_mdb_open(open);
+        s.lock().unwrap().exec(&req, &mut Response::new()).unwrap();
+
+        let mut tx = MdbTx::new();
+        tx.frame = vec![0x0b];
+        let retry = tx.retry.mut_or_insert_default();
+        retry.max_attempts = 3;
+        retry.backoff_ms = 200;
+        let mut input = Vec::new();
+        for (id, backoff) in [(1, 200), (2, 5000)] {
+            tx.retry.mut_or_insert_default().backoff_ms = backoff;
+            let mut req = Request::new();
+            req.id = id;
+            req.set_mdb_tx(tx.clone());
+            input.extend_from_slice(&(req.compute_size() as u32).to_le_bytes());
+            req.write_to_vec(&mut input).unwrap();
+        }
+        let mut output = Vec::new();
+        std::thread::scope(|scope| {
+            let serve =
+                scope.spawn(|| server::Server::serve(&s, &mut input.as_slice(), &mut output));
+            // other sessions can use server during backoff
+            std::thread::sleep(Duration::from_millis(100));
+            let start = Instant::now();
+            drop(s.lock().unwrap());
+            assert!(start.elapsed() < Duration::from_millis(150));
+            assert!(serve.join().unwrap().is_err());
+        });
+
+        let mut out = output.as_slice();
+        let mut responses = Vec::new();
+        while !out.is_empty() {
+            let len = u32::from_le_bytes(out[..4].try_into().unwrap()) as usize;
+            responses.push(Response::parse_from_bytes(&out[4..4 + len]).unwrap());
+            out = &out[4 + len..];
+        }
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[0].status, Status::ERR_NAK.into());
+        assert_eq!(responses[0].attempts, 3);
+        // backoff over limit
+        assert_eq!(responses[1].status, Status::ERR_INPUT.into());
+        assert_eq!(responses[1].attempts, 1);
+    }
+
+    #[test]
+    fn server_serve_retry_invalid_policy() {
+        use crate::proto::iodin::error_detail::Kind;
+        use crate::proto::iodin::response::Status;
+        use std::sync::Mutex;
+        use std::time::{Duration, Instant};
+
+        let s = Mutex::new(server::Server::new(true).unwrap());
+        let mut req = Request::new();
+        req.set_mdb_open(MdbOpen::new());
+        s.lock().unwrap().exec(&req, &mut Response::new()).unwrap();
+
+        // policy must not decide whether its own validation error is retried
+        let mut input = Vec::new();
+        for (max_attempts, backoff_ms) in [(u32::MAX, u32::MAX), (3, 1)] {
+            let mut tx = MdbTx::new();
+            tx.frame = vec![0x0b];
+            let retry = tx.retry.mut_or_insert_default();
+            retry.retry_on = vec![Kind::INPUT.into()];
+            retry.max_attempts = max_attempts;
+            retry.backoff_ms = backoff_ms;
+            let mut req = Request::new();
+            req.set_mdb_tx(tx);
+            input.extend_from_slice(&(req.compute_size() as u32).to_le_bytes());
+            req.write_to_vec(&mut input).unwrap();
+        }
+        let mut output = Vec::new();
+        let start = Instant::now();
+        assert!(server::Server::serve(&s, &mut input.as_slice(), &mut output).is_err());
+        assert!(start.elapsed() < Duration::from_secs(1));
+        let mut out = output.as_slice();
+        let mut responses = Vec::new();
+        while !out.is_empty() {
+            let len = u32::from_le_bytes(out[..4].try_into().unwrap()) as usize;
+            responses.push(Response::parse_from_bytes(&out[4..4 + len]).unwrap());
+            out = &out[4 + len..];
+        }
+        assert_eq!(responses.len(), 2);
+        for r in &responses {
+            assert_eq!(r.status, Status::ERR_INPUT.into());
+            assert_eq!(r.attempts, 1);
+        }
+        assert!(
+            responses[1].error.contains("retry_on"),
+            "{}",
+            responses[1].error
+        );
+    }
+
+    /// Peripheral replying `data` to every request, first `corrupt` transmissions have bad checksum.
+    struct SimPeripheral {
+        data: Vec<u8>,
+        corrupt: usize,
+        received: Vec<Vec<u8>>,
+        controls: Vec<u8>,
+    }
+
+    impl mdb::Link for SimPeripheral {
+        fn send(&mut self, block: &[u8]) -> Result<()> {
+            self.received.push(block.to_vec());
+            Ok(())
+        }
+
+        fn send_control(&mut self, byte: u8, _: std::time::Duration) -> Result<()> {
+            self.controls.push(byte);
+            Ok(())
+        }
+
+        fn receive(&mut self, _: std::time::Duration, response: &mut Vec<u8>) -> Result<u8> {
+            if self.data.is_empty() {
+                return Ok(mdb::RESPONSE_ACK);
+            }
+            response.extend_from_slice(&self.data);
+            let chk = self.data.iter().fold(0u8, |sum, &x| sum.wrapping_add(x));
+            if self.corrupt > 0 {
+                self.corrupt -= 1;
+                return Ok(chk ^ 0x01);
+            }
+            Ok(chk)
+        }
+    }
+
+    fn sim_peripheral(data: &[u8], corrupt: usize) -> SimPeripheral {
+        SimPeripheral {
+            data: data.to_vec(),
+            corrupt,
+            received: Vec::new(),
+            controls: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn mdb_transact() {
+        use mdb::RESPONSE_ACK;
+        let timeout = std::time::Duration::from_millis(10);
+
+        let mut p = sim_peripheral(&[0x01, 0x02], 0);
+        let mut response = Vec::new();
+        mdb::transact(&mut p, &[0x0f, 0x00], &mut response, timeout).unwrap();
+        assert_eq!(p.received, vec![vec![0x0f, 0x00, 0x0f]]);
+        assert_eq!(response, vec![0x01, 0x02]);
+        assert_eq!(p.controls, vec![RESPONSE_ACK]);
+
+        // ACK only response is not confirmed
+        let mut p = sim_peripheral(&[], 0);
+        let mut response = Vec::new();
+        mdb::transact(&mut p, &[0x0b], &mut response, timeout).unwrap();
+        assert!(response.is_empty());
+        assert!(p.controls.is_empty());
+    }
+
+    #[test]
+    fn mdb_transact_ret() {
+        use mdb::{RESPONSE_ACK, RESPONSE_NAK, RESPONSE_RET};
+        let timeout = std::time::Duration::from_millis(10);
+
+        // peripheral resends after RET, response is not duplicated
+        let mut p = sim_peripheral(&[0x01, 0x02], 1);
+        let mut response = Vec::new();
+        mdb::transact(&mut p, &[0x0b], &mut response, timeout).unwrap();
+        assert_eq!(response, vec![0x01, 0x02]);
+        assert_eq!(p.controls, vec![RESPONSE_RET, RESPONSE_ACK]);
+        assert_eq!(p.received.len(), 1);
+
+        // still broken after RET
+        let mut p = sim_peripheral(&[0x01, 0x02], 2);
+        let mut response = Vec::new();
+        let e = mdb::transact(&mut p, &[0x0b], &mut response, timeout).unwrap_err();
+        match e.kind() {
+            ErrorKind::MdbChecksum(0x03, 0x02, r) => assert_eq!(r, &vec![0x01, 0x02]),
+            _ => panic!("expected checksum error, got {}", e),
+        }
+        assert_eq!(p.controls, vec![RESPONSE_RET, RESPONSE_NAK]);
+    }
+
+    /// Counts POLLs confirmed by VMC, NAKs unknown commands.
+    struct CountPeripheral {
+        polls: u8,
+        resets: u32,
+    }
+
+    impl mdb::sim::Peripheral for CountPeripheral {
+        fn address(&self) -> u8 {
+            0x08
+        }
+
+        fn reset(&mut self) {
+            self.resets += 1;
+        }
+
+        fn request(&mut self, request: &[u8]) -> mdb::sim::Reply {
+            use mdb::sim::Reply;
+            match request {
+                [0x08] => Reply::Ack,
+                [0x0b] => Reply::Data(vec![0x20, self.polls]),
+                [0x0f, ..] => Reply::Silent,
+                _ => Reply::Nak,
+            }
+        }
+
+        fn ack(&mut self) {
+            self.polls += 1;
+        }
+    }
+
+    #[test]
+    fn mdb_sim_bus() {
+        use mdb::MdbBus;
+        let timeout = std::time::Duration::from_millis(10);
+
+        let p = CountPeripheral {
+            polls: 0,
+            resets: 0,
+        };
+        let mut bus = mdb::sim::SimBus::new(vec![Box::new(p)]);
+        let mut response = Vec::new();
+        bus.tx(&[0x08], &mut response, timeout).unwrap();
+        assert!(response.is_empty());
+        for i in 0..2 {
+            response.clear();
+            bus.tx(&[0x0b], &mut response, timeout).unwrap();
+            assert_eq!(response, vec![0x20, i]);
+        }
+        let e = bus.tx(&[0x0c], &mut response, timeout).unwrap_err();
+        assert!(matches!(e.kind(), ErrorKind::MdbNak));
+        let e = bus.tx(&[0x0f, 0x00], &mut response, timeout).unwrap_err();
+        assert!(matches!(
+            e.kind(),
+            ErrorKind::MdbTimeout(mdb::Stage::Receive, _)
+        ));
+        // nobody at address
+        let e = bus.tx(&[0x33], &mut response, timeout).unwrap_err();
+        assert!(matches!(e.kind(), ErrorKind::MdbTimeout(..)));
+        bus.bus_reset(timeout).unwrap();
+    }
+
+    #[test]
+    fn mdb_sim_coin_changer() {
+        use mdb::sim::{CoinChanger, Peripheral, Reply};
+
+        let mut c = CoinChanger::new();
+        // JUST RESET is repeated until confirmed
+        assert_eq!(c.request(&[0x0b]), Reply::Data(vec![0x0b]));
+        assert_eq!(c.request(&[0x0b]), Reply::Data(vec![0x0b]));
+        c.ack();
+        assert_eq!(c.request(&[0x0b]), Reply::Ack);
+        match c.request(&[0x09]) {
+            Reply::Data(d) => assert_eq!((d.len(), d[0]), (23, 3)),
+            r => panic!("setup reply={:?}", r),
+        }
+        c.ack();
+
+        c.deposit(2);
+        assert_eq!(c.request(&[0x0c, 0x00, 0x0f, 0x00, 0x00]), Reply::Ack);
+        c.deposit(2);
+        c.deposit(3);
+        assert_eq!(
+            c.request(&[0x0b]),
+            Reply::Data(vec![0x72, 20, 0x52, 21, 0x43, 0])
+        );
+        c.ack();
+        assert_eq!(c.request(&[0x0d, 0x32]), Reply::Ack);
+        assert_eq!(c.tube(2), 18);
+        match c.request(&[0x0a]) {
+            Reply::Data(d) => assert_eq!(&d[..5], &[0x00, 0x00, 20, 20, 18]),
+            r => panic!("tube status reply={:?}", r),
+        }
+        match c.request(&[0x0f, 0x00]) {
+            Reply::Data(d) => assert_eq!(d.len(), 33),
+            r => panic!("identification reply={:?}", r),
+        }
+        assert_eq!(c.request(&[0x0e]), Reply::Nak);
+        assert_eq!(c.request(&[0x0b, 0x00]), Reply::Nak);
+        assert_eq!(c.request(&[0x08]), Reply::Ack);
+        assert_eq!(c.request(&[0x0b]), Reply::Data(vec![0x0b]));
+    }
+
+    #[test]
+    fn mdb_sim_bill_validator() {
+        use mdb::sim::{BillValidator, Peripheral, Reply};
+
+        let mut b = BillValidator::new();
+        assert_eq!(b.request(&[0x33]), Reply::Data(vec![0x06]));
+        b.ack();
+        match b.request(&[0x31]) {
+            Reply::Data(d) => assert_eq!(d.len(), 27),
+            r => panic!("setup reply={:?}", r),
+        }
+        // $1 enabled without escrow, $5 with escrow
+        assert_eq!(b.request(&[0x34, 0x00, 0x03, 0x00, 0x02]), Reply::Ack);
+        b.insert(0);
+        b.insert(1);
+        b.insert(2);
+        assert_eq!(b.request(&[0x33]), Reply::Data(vec![0x80, 0x91, 0xc2]));
+        b.ack();
+        assert_eq!(b.request(&[0x35, 0x01]), Reply::Ack);
+        assert_eq!(b.request(&[0x33]), Reply::Data(vec![0x81]));
+        b.ack();
+        assert_eq!(b.stacker(), 2);
+        assert_eq!(b.request(&[0x36]), Reply::Data(vec![0x00, 0x02]));
+        b.insert(1);
+        assert_eq!(b.request(&[0x35, 0x00]), Reply::Ack);
+        assert_eq!(b.request(&[0x33]), Reply::Data(vec![0x91, 0xa1]));
+        match b.request(&[0x37, 0x02]) {
+            Reply::Data(d) => assert_eq!(d.len(), 33),
+            r => panic!("identification reply={:?}", r),
+        }
+    }
+
+    #[test]
+    fn mdb_sim_cashless_session() {
+        use mdb::sim::{Cashless, CashlessState, Peripheral, Reply};
+
+        let mut c = Cashless::new();
+        assert_eq!(c.request(&[0x12]), Reply::Data(vec![0x00]));
+        c.ack();
+        // enable before setup is ignored
+        assert_eq!(c.request(&[0x14, 0x01]), Reply::Ack);
+        assert_eq!(c.state(), CashlessState::Inactive);
+        match c.request(&[0x11, 0x00, 0x01, 0x00, 0x00, 0x00]) {
+            Reply::Data(d) => assert_eq!((d.len(), d[0]), (8, 0x01)),
+            r => panic!("setup reply={:?}", r),
+        }
+        c.present(500);
+        assert_eq!(c.state(), CashlessState::Disabled);
+        assert_eq!(c.request(&[0x14, 0x01]), Reply::Ack);
+        assert_eq!(c.request(&[0x12]), Reply::Data(vec![0x03, 0x01, 0xf4]));
+        c.ack();
+
+        // too expensive
+        assert_eq!(c.request(&[0x13, 0x00, 0x02, 0x00, 0x00, 0x01]), Reply::Ack);
+        assert_eq!(c.request(&[0x12]), Reply::Data(vec![0x06]));
+        c.ack();
+        assert_eq!(c.request(&[0x13, 0x00, 0x00, 0x96, 0x00, 0x01]), Reply::Ack);
+        assert_eq!(c.request(&[0x12]), Reply::Data(vec![0x05, 0x00, 0x96]));
+        c.ack();
+        assert_eq!(c.state(), CashlessState::Vend);
+        assert_eq!(c.request(&[0x13, 0x02, 0x00, 0x01]), Reply::Ack);
+        assert_eq!(c.funds(), 350);
+        assert_eq!(c.request(&[0x13, 0x04]), Reply::Ack);
+        assert_eq!(c.request(&[0x12]), Reply::Data(vec![0x07]));
+        c.ack();
+        assert_eq!(c.state(), CashlessState::Enabled);
+        // vend outside of session
+        assert_eq!(c.request(&[0x13, 0x00, 0x00, 0x01, 0x00, 0x01]), Reply::Ack);
+        assert_eq!(c.request(&[0x12]), Reply::Data(vec![0x0b]));
+    }
+
+    #[test]
+    fn mdb_sim_vending_bus() {
+        use mdb::MdbBus;
+        let timeout = std::time::Duration::from_millis(10);
+
+        let mut bus = mdb::sim::SimBus::vending();
+        for (poll, just_reset) in [(0x0b, 0x0b), (0x33, 0x06), (0x12, 0x00)] {
+            let mut response = Vec::new();
+            bus.tx(&[poll], &mut response, timeout).unwrap();
+            assert_eq!(response, vec![just_reset]);
+            bus.tx(&[poll], &mut response, timeout).unwrap();
+            assert!(response.is_empty());
+        }
+        bus.bus_reset(timeout).unwrap();
+        let mut response = Vec::new();
+        bus.tx(&[0x0b], &mut response, timeout).unwrap();
+        assert_eq!(response, vec![0x0b]);
+        let e = bus.tx(&[0x60], &mut response, timeout).unwrap_err();
+        assert!(matches!(e.kind(), ErrorKind::MdbTimeout(..)));
+    }
+
+    #[test]
+    fn mdb_fault_parse() {
+        let f = mdb::fault::parse("seed=1, drop=10,nak=5,gap_ms=20").unwrap();
+        assert_eq!((f.seed, f.drop, f.nak, f.gap_ms), (1, 10, 5, 20));
+        assert!(mdb::fault::parse("").is_ok());
+        assert!(mdb::fault::parse("drop=60,nak=50").is_err());
+        assert!(mdb::fault::parse("noise=1").is_err());
+        assert!(mdb::fault::parse("drop").is_err());
+        let e = mdb::fault::parse("drop=x").unwrap_err();
+        assert!(matches!(e.kind(), ErrorKind::InvalidInput(..)), "{}", e);
+        let e = mdb::fault::parse("seed=-1").unwrap_err();
+        assert!(matches!(e.kind(), ErrorKind::InvalidInput(..)), "{}", e);
+    }
+
+    #[test]
+    fn mdb_fault_bus() {
+        use mdb::fault::FaultBus;
+        use mdb::sim::SimBus;
+        use mdb::MdbBus;
+        let timeout = std::time::Duration::from_millis(10);
+
+        let bus = |f: &dyn Fn(&mut MdbFaults)| {
+            let mut faults = MdbFaults::new();
+            f(&mut faults);
+            FaultBus::new(SimBus::vending(), faults)
+        };
+        let mut response = Vec::new();
+        let e = bus(&|f| f.drop = 100)
+            .tx(&[0x0b], &mut response, timeout)
+            .unwrap_err();
+        assert!(matches!(e.kind(), ErrorKind::MdbTimeout(..)));
+        let e = bus(&|f| f.checksum = 100)
+            .tx(&[0x0b], &mut response, timeout)
+            .unwrap_err();
+        assert!(matches!(e.kind(), ErrorKind::MdbChecksum(..)));
+        let e = bus(&|f| f.nak = 100)
+            .tx(&[0x0b], &mut response, timeout)
+            .unwrap_err();
+        assert!(matches!(e.kind(), ErrorKind::MdbNak));
+        let e = bus(&|f| f.garbage = 100)
+            .tx(&[0x0f, 0x00], &mut response, timeout)
+            .unwrap_err();
+        assert!(matches!(e.kind(), ErrorKind::MdbChecksum(..)));
+        let e = bus(&|f| f.gap = 100)
+            .tx(&[0x0f, 0x00], &mut response, timeout)
+            .unwrap_err();
+        match e.kind() {
+            ErrorKind::MdbTimeout(_, part) => assert!(part.len() < 33),
+            _ => panic!("expected timeout, got {}", e),
+        }
+        bus(&|f| {
+            f.gap = 100;
+            f.gap_ms = 1;
+        })
+        .tx(&[0x0b], &mut response, timeout)
+        .unwrap();
+        assert_eq!(response, vec![0x0b]);
+
+        // same seed, same faults
+        let run = || {
+            let mut b = bus(&|f| {
+                f.seed = 42;
+                f.drop = 10;
+                f.checksum = 10;
+                f.nak = 10;
+                f.garbage = 10;
+            });
+            let mut response = Vec::new();
+            (0..100)
+                .map(|_| match b.tx(&[0x0a], &mut response, timeout) {
+                    Ok(()) => "ok".to_string(),
+                    Err(e) => format!("{:?}", e.kind()),
+                })
+                .collect::<Vec<_>>()
+        };
+        let results = run();
+        assert_eq!(results, run());
+        let ok = results.iter().filter(|r| *r == "ok").count();
+        assert!(ok > 40 && ok < 100, "ok={}", ok);
+    }
+
+    #[test]
+    fn server_exec_mdb_open_faults() {
+        use crate::proto::iodin::response::Status;
+
+        let mut s = server::Server::new(true).unwrap();
+        let mut open = MdbOpen::new();
+        let faults = open
+            .options
+            .mut_or_insert_default()
+            .faults
+            .mut_or_insert_default();
+        faults.nak = 80;
+        faults.drop = 30;
+        let mut req = Request::new();
+        req.set_mdb_open(open.clone());
+        let mut rsp = Response::new();
+        assert!(s.exec(&req, &mut rsp).is_err());
+        assert_eq!(rsp.status, Status::ERR_INPUT.into());
+
+        open.options
+            .mut_or_insert_default()
+            .faults
+            .mut_or_insert_default()
+            .drop = 20;
+        req.set_mdb_open(open);
+        s.exec(&req, &mut Response::new()).unwrap();
+        let mut info = Request::new();
+        info.command = request::Command::GET_INFO.into();
+        let mut rsp = Response::new();
+        s.exec(&info, &mut rsp).unwrap();
+        assert_eq!(rsp.info.mdb_faults.nak, 80);
+
+        let mut tx = MdbTx::new();
+        tx.frame = vec![0x0b];
+        let mut req = Request::new();
+        req.set_mdb_tx(tx);
+        let mut rsp = Response::new();
+        assert!(s.exec(&req, &mut rsp).is_err());
+        assert_ne!(rsp.status, Status::ERR_INPUT.into());
+
+        // never on hardware transports
+        let mut open = MdbOpen::new();
+        open.transport = mdb_open::Transport::UART.into();
+        open.device = "/dev/null".to_string();
+        open.options
+            .mut_or_insert_default()
+            .faults
+            .mut_or_insert_default()
+            .nak = 10;
+        let mut req = Request::new();
+        req.set_mdb_open(open);
+        let mut rsp = Response::new();
+        let e = s.exec(&req, &mut rsp).unwrap_err();
+        assert_eq!(rsp.status, Status::ERR_INPUT.into());
+        assert!(e.to_string().contains("SIM"), "{}", e);
+    }
+
+    /// Transaction with simulated peripheral without bus, data is confirmed.
+    fn sim_tx(p: &mut dyn mdb::sim::Peripheral, frame: &[u8]) -> Result<Vec<u8>> {
+        use mdb::sim::Reply;
+        match p.request(frame) {
+            Reply::Ack => Ok(Vec::new()),
+            Reply::Nak => Err(ErrorKind::MdbNak.into()),
+            Reply::Data(d) => {
+                p.ack();
+                Ok(d)
+            }
+            Reply::Silent => Err(ErrorKind::MdbTimeout(mdb::Stage::Receive, Vec::new()).into()),
+        }
+    }
+
+    #[test]
+    fn mdb_coin_parse_poll() {
+        use crate::proto::iodin::mdb_coin_event::{Kind, Routing, Status};
+
+        let events = mdb::coin::parse_poll(&[0x0b, 0x52, 21, 0x92, 7, 0x23]).unwrap();
+        let kinds: Vec<_> = events
+            .iter()
+            .map(|e| e.kind.enum_value_or_default())
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![Kind::STATUS, Kind::DEPOSITED, Kind::DISPENSED, Kind::SLUG]
+        );
+        assert_eq!(events[0].status, Status::JUST_RESET.into());
+        assert_eq!(events[1].routing, Routing::TUBES.into());
+        assert_eq!((events[1].coin_type, events[1].tube_count), (2, 21));
+        assert_eq!((events[2].count, events[2].tube_count), (1, 7));
+        assert_eq!(events[3].count, 3);
+        assert!(mdb::coin::parse_poll(&[]).unwrap().is_empty());
+        let e = mdb::coin::parse_poll(&[0x0b, 0x52]).unwrap_err();
+        assert!(matches!(e.kind(), ErrorKind::MdbDecode(..)));
+    }
+
+    #[test]
+    fn mdb_coin_driver() {
+        use crate::proto::iodin::mdb_coin_event::{Kind, Routing};
+        use mdb::coin;
+        use mdb::sim::CoinChanger;
+
+        let mut c = CoinChanger::new();
+        let events = coin::poll(&mut |f| sim_tx(&mut c, f)).unwrap();
+        assert_eq!(events.len(), 1);
+        coin::reset(&mut |f| sim_tx(&mut c, f)).unwrap();
+        let setup = coin::setup(&mut |f| sim_tx(&mut c, f)).unwrap();
+        assert_eq!((setup.feature_level, setup.scaling_factor), (3, 5));
+        assert_eq!(&setup.credit[..4], &[1, 2, 5, 20]);
+        assert_eq!(setup.coin_routing, 0x0007);
+        coin::coin_type(&mut |f| sim_tx(&mut c, f), 0x000f, 0x0000).unwrap();
+        coin::poll(&mut |f| sim_tx(&mut c, f)).unwrap();
+
+        c.deposit(1);
+        c.deposit(3);
+        let events = coin::poll(&mut |f| sim_tx(&mut c, f)).unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].kind, Kind::DEPOSITED.into());
+        assert_eq!(events[0].routing, Routing::TUBES.into());
+        assert_eq!(events[1].routing, Routing::CASH_BOX.into());
+        assert!(coin::poll(&mut |f| sim_tx(&mut c, f)).unwrap().is_empty());
+
+        coin::dispense(&mut |f| sim_tx(&mut c, f), 1, 5).unwrap();
+        let tubes = coin::tube_status(&mut |f| sim_tx(&mut c, f)).unwrap();
+        assert_eq!(&tubes.count[..3], &[20, 16, 20]);
+        assert_eq!(tubes.full, 0);
+        let e = coin::dispense(&mut |f| sim_tx(&mut c, f), 1, 16).unwrap_err();
+        assert!(matches!(e.kind(), ErrorKind::InvalidInput(_)));
+
+        let id = coin::identification(&mut |f| sim_tx(&mut c, f)).unwrap();
+        assert_eq!(
+            (id.manufacturer.as_str(), id.model.as_str()),
+            ("IOD", "coin")
+        );
+        assert_eq!(id.software_version, 0x0100);
+        // ACK only where data is expected
+        let e = coin::setup(&mut |_| Ok(Vec::new())).unwrap_err();
+        assert!(matches!(e.kind(), ErrorKind::MdbDecode(..)));
+    }
+
+    #[test]
+    fn server_exec_mdb_coin() {
+        use crate::proto::iodin::mdb_coin::Op;
+        use crate::proto::iodin::mdb_coin_event::Status;
+
+        let mut s = server::Server::new(true).unwrap();
+        let mut open = Request::new();
+        open.set_mdb_open(MdbOpen::new());
+        s.exec(&open, &mut Response::new()).unwrap();
+        let mut exec = |coin: MdbCoin| {
+            let mut req = Request::new();
+            req.set_mdb_coin(coin);
+            let mut rsp = Response::new();
+            let _ = s.exec(&req, &mut rsp);
+            rsp
+        };
+        let mut coin = MdbCoin::new();
+        coin.op = Op::POLL.into();
+        let rsp = exec(coin.clone());
+        assert_eq!(rsp.status, response::Status::OK.into());
+        assert_eq!(rsp.coin.events[0].status, Status::JUST_RESET.into());
+        coin.op = Op::SETUP.into();
+        assert_eq!(exec(coin.clone()).coin.setup.feature_level, 3);
+
+        coin.op = Op::COIN_TYPE.into();
+        coin.coin_enable = 0x10000;
+        let rsp = exec(coin.clone());
+        assert_eq!(rsp.status, response::Status::ERR_INPUT.into());
+        coin.op = Op::INVALID.into();
+        let rsp = exec(coin);
+        assert_eq!(rsp.status, response::Status::ERR_INPUT.into());
+
+        // changer state or payout must not repeat
+        for op in [Op::RESET, Op::COIN_TYPE, Op::DISPENSE] {
+            let mut coin = MdbCoin::new();
+            coin.op = op.into();
+            coin.coin_type = 1;
+            coin.count = 1;
+            coin.retry.mut_or_insert_default().max_attempts = 3;
+            let rsp = exec(coin);
+            assert_eq!(rsp.status, response::Status::ERR_INPUT.into(), "{:?}", op);
+            assert!(rsp.error.contains("can not be retried"), "{}", rsp.error);
+        }
+        let mut coin = MdbCoin::new();
+        coin.op = Op::TUBE_STATUS.into();
+        coin.retry.mut_or_insert_default().max_attempts = 3;
+        assert_eq!(exec(coin).status, response::Status::OK.into());
+    }
+
+    #[test]
+    fn server_serve_mdb_coin_retry() {
+        use crate::proto::iodin::mdb_coin::Op;
+        use crate::proto::iodin::response::Status;
+        use std::sync::Mutex;
+
+        let s = Mutex::new(server::Server::new(true).unwrap());
+        let mut open = MdbOpen::new();
+        open.options
+            .mut_or_insert_default()
+            .faults
+            .mut_or_insert_default()
+            .nak = 100;
+        let mut req = Request::new();
+        req.set_mdb_open(open);
+        s.lock().unwrap().exec(&req, &mut Response::new()).unwrap();
+
+        let mut input = Vec::new();
+        for (op, max_attempts) in [(Op::POLL, 3), (Op::DISPENSE, 0)] {
+            let mut coin = MdbCoin::new();
+            coin.op = op.into();
+            coin.coin_type = 1;
+            coin.count = 1;
+            if max_attempts != 0 {
+                coin.retry.mut_or_insert_default().max_attempts = max_attempts;
+            }
+            let mut req = Request::new();
+            req.set_mdb_coin(coin);
+            input.extend_from_slice(&(req.compute_size() as u32).to_le_bytes());
+            req.write_to_vec(&mut input).unwrap();
+        }
+        let mut output = Vec::new();
+        assert!(server::Server::serve(&s, &mut input.as_slice(), &mut output).is_err());
+        let mut out = output.as_slice();
+        let mut responses = Vec::new();
+        while !out.is_empty() {
+            let len = u32::from_le_bytes(out[..4].try_into().unwrap()) as usize;
+            responses.push(Response::parse_from_bytes(&out[4..4 + len]).unwrap());
+            out = &out[4 + len..];
+        }
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[0].status, Status::ERR_NAK.into());
+        assert_eq!(responses[0].attempts, 3);
+        assert_eq!(responses[1].attempts, 1);
+        assert_eq!(responses[1].status, Status::ERR_NAK.into());
+    }
+
+    #[test]
+    fn mdb_bill_parse_poll() {
+        use crate::proto::iodin::mdb_bill_event::{Kind, Routing, Status};
+
+        let events = mdb::bill::parse_poll(&[0x06, 0x91, 0xc3, 0x43, 0x05, 0x08]);
+        let kinds: Vec<_> = events
+            .iter()
+            .map(|e| e.kind.enum_value_or_default())
+            .collect();
+        use Kind::*;
+        assert_eq!(
+            kinds,
+            vec![STATUS, BILL, BILL, DISABLED_ATTEMPTS, STATUS, STATUS]
+        );
+        assert_eq!(events[0].status, Status::JUST_RESET.into());
+        assert_eq!(events[1].routing, Routing::ESCROW.into());
+        assert_eq!(events[1].bill_type, 1);
+        assert_eq!(events[2].routing, Routing::DISABLED_REJECTED.into());
+        assert_eq!(events[3].count, 3);
+        assert_eq!(events[4].status, Status::JAMMED.into());
+        assert_eq!(events[5].status, Status::CASH_BOX_REMOVED.into());
+    }
+
+    #[test]
+    fn mdb_bill_driver() {
+        use crate::proto::iodin::mdb_bill_event::Routing;
+        use mdb::bill;
+        use mdb::sim::BillValidator;
+
+        let mut b = BillValidator::new();
+        bill::reset(&mut |f| sim_tx(&mut b, f)).unwrap();
+        assert_eq!(bill::poll(&mut |f| sim_tx(&mut b, f)).unwrap().len(), 1);
+        let setup = bill::setup(&mut |f| sim_tx(&mut b, f)).unwrap();
+        assert_eq!((setup.scaling_factor, setup.stacker_capacity), (100, 500));
+        assert!(setup.escrow);
+        assert_eq!(&setup.credit[..4], &[1, 5, 10, 20]);
+        bill::security(&mut |f| sim_tx(&mut b, f), 0x0000).unwrap();
+        bill::bill_type(&mut |f| sim_tx(&mut b, f), 0x000f, 0x000f).unwrap();
+
+        b.insert(2);
+        let events = bill::poll(&mut |f| sim_tx(&mut b, f)).unwrap();
+        assert_eq!(events[0].routing, Routing::ESCROW.into());
+        bill::escrow(&mut |f| sim_tx(&mut b, f), true).unwrap();
+        let events = bill::poll(&mut |f| sim_tx(&mut b, f)).unwrap();
+        assert_eq!(events[0].routing, Routing::STACKED.into());
+        assert_eq!(events[0].bill_type, 2);
+        b.insert(1);
+        bill::poll(&mut |f| sim_tx(&mut b, f)).unwrap();
+        bill::escrow(&mut |f| sim_tx(&mut b, f), false).unwrap();
+        let events = bill::poll(&mut |f| sim_tx(&mut b, f)).unwrap();
+        assert_eq!(events[0].routing, Routing::RETURNED.into());
+
+        let s = bill::stacker(&mut |f| sim_tx(&mut b, f)).unwrap();
+        assert_eq!((s.count, s.full), (1, false));
+        let id = bill::identification(&mut |f| sim_tx(&mut b, f), false).unwrap();
+        assert_eq!(id.model, "bill");
+        bill::identification(&mut |f| sim_tx(&mut b, f), true).unwrap();
+    }
+
+    #[test]
+    fn server_exec_mdb_bill_retry() {
+        use crate::proto::iodin::mdb_bill::Op;
+        use crate::proto::iodin::response::Status;
+
+        let mut s = server::Server::new(true).unwrap();
+        let mut open = Request::new();
+        open.set_mdb_open(MdbOpen::new());
+        s.exec(&open, &mut Response::new()).unwrap();
+        let mut exec = |op: Op| {
+            let mut bill = MdbBill::new();
+            bill.op = op.into();
+            bill.retry.mut_or_insert_default().max_attempts = 3;
+            let mut req = Request::new();
+            req.set_mdb_bill(bill);
+            let mut rsp = Response::new();
+            let _ = s.exec(&req, &mut rsp);
+            rsp
+        };
+        // repeated ESCROW may act on next bill
+        for op in [Op::RESET, Op::SECURITY, Op::BILL_TYPE, Op::ESCROW] {
+            let rsp = exec(op);
+            assert_eq!(rsp.status, Status::ERR_INPUT.into(), "{:?}", op);
+            assert!(rsp.error.contains("can not be retried"), "{}", rsp.error);
+        }
+        for op in [Op::SETUP, Op::STACKER, Op::POLL] {
+            assert_eq!(exec(op).status, Status::OK.into(), "{:?}", op);
+        }
+
+        let mut req = Request::new();
+        req.command = request::Command::MDB_BILL.into();
+        let mut rsp = Response::new();
+        let e = s.exec(&req, &mut rsp).unwrap_err();
+        assert_eq!(rsp.status, Status::ERR_INPUT.into());
+        assert!(
+            e.to_string().contains("MDB_BILL requires mdb_bill args"),
+            "{}",
+            e
+        );
+    }
+
+    #[test]
+    fn mdb_cashless_parse_poll() {
+        use crate::proto::iodin::mdb_cashless_event::Kind;
+        use mdb::cashless::parse_poll;
+
+        let d = [
+            0x00, 0x03, 0x01, 0xf4, 0x02, 50, b'h', b'i', 0x0a, 0x11, 0x07,
+        ];
+        let events = parse_poll(&d, 1, 2).unwrap();
+        let kinds: Vec<_> = events
+            .iter()
+            .map(|e| e.kind.enum_value_or_default())
+            .collect();
+        use Kind::*;
+        assert_eq!(
+            kinds,
+            vec![
+                JUST_RESET,
+                BEGIN_SESSION,
+                DISPLAY_REQUEST,
+                MALFUNCTION,
+                END_SESSION
+            ]
+        );
+        assert_eq!(events[1].amount, 500);
+        assert_eq!(events[2].display_time, 50);
+        assert_eq!(events[2].display_data, b"hi");
+        assert_eq!(events[3].code, 0x11);
+        let setup = &parse_poll(&[0x01, 1, 0x00, 0x01, 1, 2, 5, 0x00], 1, 0).unwrap()[0].setup;
+        assert_eq!((setup.decimal_places, setup.max_response_time), (2, 5));
+        // level 2 BEGIN SESSION carries payment media id
+        assert_eq!(parse_poll(&[0x03; 10], 2, 0).unwrap().len(), 1);
+        for d in [&[0x05, 0x00][..], &[0x0c]] {
+            let e = parse_poll(d, 1, 0).unwrap_err();
+            assert!(matches!(e.kind(), ErrorKind::MdbDecode(..)));
+        }
+    }
+
+    #[test]
+    fn mdb_cashless_session() {
+        use crate::proto::iodin::mdb_cashless_event::Kind;
+        use crate::proto::iodin::mdb_cashless_result::State;
+        use mdb::cashless::{Cashless, Command as C};
+        use mdb::sim::Cashless as Reader;
+        use std::time::{Duration, Instant};
+
+        let mut c = Reader::new();
+        let t0 = Instant::now();
+        let mut m = Cashless::new(0x10, t0).unwrap();
+        let mut exec = |c: &mut Reader, cmd, now| m.exec(&mut |f| sim_tx(c, f), cmd, now);
+        let setup = C::Setup {
+            columns: 0,
+            rows: 0,
+            max_price: 0xffff,
+            min_price: 0,
+        };
+        let e = exec(&mut c, C::Enable, t0).unwrap_err();
+        assert!(matches!(e.kind(), ErrorKind::InvalidInput(_)));
+        let r = exec(&mut c, C::Poll, t0).unwrap();
+        assert_eq!(r.events[0].kind, Kind::JUST_RESET.into());
+        let r = exec(&mut c, setup, t0).unwrap();
+        assert_eq!(r.state, State::DISABLED.into());
+        assert_eq!(r.setup.max_response_time, 5);
+        assert_eq!(
+            exec(&mut c, C::Identification, t0)
+                .unwrap()
+                .identification
+                .model,
+            "cashless"
+        );
+        exec(&mut c, C::Enable, t0).unwrap();
+
+        c.present(500);
+        let r = exec(&mut c, C::Poll, t0).unwrap();
+        assert_eq!(r.events[0].kind, Kind::BEGIN_SESSION.into());
+        assert_eq!((r.state, r.funds), (State::SESSION_IDLE.into(), 500));
+        let e = exec(&mut c, C::VendSuccess { item: 1 }, t0).unwrap_err();
+        assert!(matches!(e.kind(), ErrorKind::InvalidInput(_)));
+        let vend = C::VendRequest {
+            price: 120,
+            item: 7,
+        };
+        let r = exec(&mut c, vend, t0).unwrap();
+        assert_eq!(r.state, State::VEND_REQUESTED.into());
+        let r = exec(&mut c, C::Poll, t0).unwrap();
+        assert_eq!(r.events[0].amount, 120);
+        assert_eq!(r.state, State::VEND_APPROVED.into());
+        let r = exec(&mut c, C::VendSuccess { item: 7 }, t0).unwrap();
+        assert_eq!((r.state, r.funds), (State::SESSION_IDLE.into(), 380));
+        assert_eq!(c.funds(), 380);
+
+        // approval does not arrive within reader response time
+        exec(&mut c, vend, t0).unwrap();
+        let late = t0 + Duration::from_secs(6);
+        let r = m
+            .exec(
+                &mut |f| {
+                    if f == [0x12] {
+                        Ok(Vec::new())
+                    } else {
+                        sim_tx(&mut c, f)
+                    }
+                },
+                C::Poll,
+                late,
+            )
+            .unwrap();
+        // reader answers VEND CANCEL with VEND DENIED, server only marks it
+        let denied: Vec<_> = r
+            .events
+            .iter()
+            .filter(|e| e.kind == Kind::VEND_DENIED.into())
+            .collect();
+        assert_eq!(denied.len(), 1);
+        assert!(denied[0].timeout);
+        assert_eq!(r.state, State::SESSION_IDLE.into());
+
+        let mut exec = |c: &mut Reader, cmd, now| m.exec(&mut |f| sim_tx(c, f), cmd, now);
+        let r = exec(&mut c, C::SessionComplete, late).unwrap();
+        assert_eq!(r.state, State::SESSION_END.into());
+        // late approval left in reader is ignored
+        let r = exec(&mut c, C::Poll, late).unwrap();
+        assert_eq!(r.events[0].kind, Kind::VEND_APPROVED.into());
+        assert_eq!(r.events[1].kind, Kind::END_SESSION.into());
+        assert_eq!((r.state, r.funds), (State::ENABLED.into(), 0));
+
+        // reader silent longer than response time must be reset
+        let silent = |_: &[u8]| -> Result<Vec<u8>> {
+            Err(ErrorKind::MdbTimeout(mdb::Stage::Receive, Vec::new()).into())
+        };
+        m.exec(&mut silent.clone(), C::Poll, late + Duration::from_secs(1))
+            .unwrap_err();
+        assert_eq!(
+            m.exec(&mut silent.clone(), C::State, late).unwrap().state,
+            State::ENABLED.into()
+        );
+        m.exec(&mut silent.clone(), C::Poll, late + Duration::from_secs(6))
+            .unwrap_err();
+        assert_eq!(
+            m.exec(&mut silent.clone(), C::State, late).unwrap().state,
+            State::INACTIVE.into()
+        );
+        assert!(Cashless::new(0x30, t0).is_err());
+    }
+
+    #[test]
+    fn mdb_cashless_feature_level() {
+        use crate::proto::iodin::mdb_cashless_event::Kind;
+        use crate::proto::iodin::mdb_cashless_result::State;
+        use mdb::cashless::{Cashless, Command as C};
+        use std::time::Instant;
+
+        // level 3 reader formats poll data for level 1 VMC
+        let now = Instant::now();
+        let mut m = Cashless::new(0x10, now).unwrap();
+        let mut reader = |f: &[u8]| -> Result<Vec<u8>> {
+            Ok(match f {
+                [0x11, 0x00, ..] => vec![0x01, 3, 0x00, 0x01, 1, 2, 5, 0x00],
+                [0x12] => vec![0x03, 0x01, 0xf4, 0x07],
+                _ => Vec::new(),
+            })
+        };
+        let setup = C::Setup {
+            columns: 0,
+            rows: 0,
+            max_price: 0xffff,
+            min_price: 0,
+        };
+        let r = m.exec(&mut reader, setup, now).unwrap();
+        assert_eq!(r.setup.feature_level, 3);
+        m.exec(&mut reader, C::Enable, now).unwrap();
+        let r = m.exec(&mut reader, C::Poll, now).unwrap();
+        let kinds: Vec<_> = r
+            .events
+            .iter()
+            .map(|e| e.kind.enum_value_or_default())
+            .collect();
+        assert_eq!(kinds, vec![Kind::BEGIN_SESSION, Kind::END_SESSION]);
+        assert_eq!(r.events[0].amount, 500);
+        assert_eq!(r.state, State::ENABLED.into());
+    }
+
+    #[test]
+    fn mdb_cashless_tx_failure() {
+        use crate::proto::iodin::mdb_cashless_result::State;
+        use mdb::cashless::{Cashless, Command as C};
+        use mdb::sim::Cashless as Reader;
+        use std::time::Instant;
+
+        let mut c = Reader::new();
+        let t0 = Instant::now();
+        let mut m = Cashless::new(0x10, t0).unwrap();
+        let ok =
+            |m: &mut Cashless, c: &mut Reader, cmd| m.exec(&mut |f| sim_tx(c, f), cmd, t0).unwrap();
+        // state and funds are the same after failed transaction
+        let fails = |m: &mut Cashless, cmd| {
+            let mut nak = |_: &[u8]| -> Result<Vec<u8>> { Err(ErrorKind::MdbNak.into()) };
+            let before = m.exec(&mut nak, C::State, t0).unwrap();
+            let e = m.exec(&mut nak, cmd, t0).unwrap_err();
+            assert!(matches!(e.kind(), ErrorKind::MdbNak), "{:?} {}", cmd, e);
+            let after = m.exec(&mut nak, C::State, t0).unwrap();
+            assert_eq!(
+                (after.state, after.funds),
+                (before.state, before.funds),
+                "{:?}",
+                cmd
+            );
+            after
+        };
+        let setup = C::Setup {
+            columns: 0,
+            rows: 0,
+            max_price: 0xffff,
+            min_price: 0,
+        };
+        ok(&mut m, &mut c, C::Poll);
+        ok(&mut m, &mut c, setup);
+        assert_eq!(fails(&mut m, C::Enable).state, State::DISABLED.into());
+        ok(&mut m, &mut c, C::Enable);
+        assert_eq!(fails(&mut m, C::Disable).state, State::ENABLED.into());
+
+        c.present(500);
+        ok(&mut m, &mut c, C::Poll);
+        let vend = C::VendRequest {
+            price: 120,
+            item: 7,
+        };
+        let r = fails(&mut m, vend);
+        assert_eq!((r.state, r.funds), (State::SESSION_IDLE.into(), 500));
+        ok(&mut m, &mut c, vend);
+        let r = ok(&mut m, &mut c, C::Poll);
+        assert_eq!(r.state, State::VEND_APPROVED.into());
+        let r = fails(&mut m, C::VendSuccess { item: 7 });
+        assert_eq!((r.state, r.funds), (State::VEND_APPROVED.into(), 500));
+        fails(&mut m, C::VendFailure);
+        let r = ok(&mut m, &mut c, C::VendSuccess { item: 7 });
+        assert_eq!((r.state, r.funds), (State::SESSION_IDLE.into(), 380));
+        assert_eq!(
+            fails(&mut m, C::SessionComplete).state,
+            State::SESSION_IDLE.into()
+        );
+        let r = fails(&mut m, C::Reset);
+        assert_eq!((r.state, r.funds), (State::SESSION_IDLE.into(), 380));
+        let r = ok(&mut m, &mut c, C::Reset);
+        assert_eq!((r.state, r.funds), (State::INACTIVE.into(), 0));
+    }
+
+    #[test]
+    fn server_exec_mdb_cashless() {
+        use crate::proto::iodin::mdb_cashless::Op;
+        use crate::proto::iodin::mdb_cashless_result::State;
+
+        let mut s = server::Server::new(true).unwrap();
+        let mut open = Request::new();
+        open.set_mdb_open(MdbOpen::new());
+        s.exec(&open, &mut Response::new()).unwrap();
+        let mut exec = |op: Op, f: &dyn Fn(&mut MdbCashless)| {
+            let mut c = MdbCashless::new();
+            c.op = op.into();
+            f(&mut c);
+            let mut req = Request::new();
+            req.set_mdb_cashless(c);
+            let mut rsp = Response::new();
+            let _ = s.exec(&req, &mut rsp);
+            rsp
+        };
+        let rsp = exec(Op::SETUP, &|_| {});
+        assert_eq!(rsp.status, response::Status::OK.into());
+        assert_eq!(rsp.cashless.state, State::DISABLED.into());
+        let rsp = exec(Op::ENABLE, &|_| {});
+        assert_eq!(rsp.cashless.state, State::ENABLED.into());
+        let rsp = exec(Op::STATE, &|_| {});
+        assert_eq!(rsp.cashless.state, State::ENABLED.into());
+        let rsp = exec(Op::VEND_REQUEST, &|c| c.price = 100);
+        assert_eq!(rsp.status, response::Status::ERR_INPUT.into());
+        let rsp = exec(Op::POLL, &|c| c.address = 0x60);
+        assert_ne!(rsp.status, response::Status::OK.into());
+        let rsp = exec(Op::STATE, &|c| c.address = 0x11);
+        assert_eq!(rsp.status, response::Status::ERR_INPUT.into());
+    }
+
+    #[test]
+    fn mdb_uart_parmrk() {
+        let mut d = mdb::uart::Parmrk::default();
+        let input = [0x01, 0xff, 0xff, 0xff, 0x00, 0x02, 0x03];
+        let out: Vec<_> = input.iter().filter_map(|&b| d.push(b)).collect();
+        assert_eq!(
+            out,
+            vec![(0x01, false), (0xff, false), (0x02, true), (0x03, false)]
+        );
+        assert_eq!(d, mdb::uart::Parmrk::Data);
+    }
+
+    /// Read `n` bytes from pty master, fewer on timeout.
+    fn pty_read(f: &mut std::fs::File, n: usize, timeout: std::time::Duration) -> Vec<u8> {
+        use std::io::Read;
+        use std::os::unix::io::AsRawFd;
+        let mut result = vec![0; n];
+        let mut got = 0;
+        while got < n {
+            let mut pfd = libc::pollfd {
+                fd: f.as_raw_fd(),
+                events: libc::POLLIN,
+                revents: 0,
+            };
+            if unsafe { libc::poll(&mut pfd, 1, timeout.as_millis() as libc::c_int) } <= 0 {
+                break;
+            }
+            got += f.read(&mut result[got..]).unwrap();
+        }
+        result.truncate(got);
+        result
+    }
+
+    /// Peripheral block as it arrives through PARMRK: literal `ff` doubled,
+    /// last byte with mode bit is `ff 00 x`.
+    fn pty_block(data: &[u8], chk: u8) -> Vec<u8> {
+        let mut b = Vec::new();
+        for &x in data {
+            b.push(x);
+            if x == 0xff {
+                b.push(0xff);
+            }
+        }
+        b.extend_from_slice(&[0xff, 0x00, chk]);
+        b
+    }
+
+    #[test]
+    fn mdb_uart_pty() {
+        use mdb::{MdbBus, RESPONSE_ACK, RESPONSE_RET};
+        use std::io::Write;
+        use std::os::unix::io::FromRawFd;
+        let timeout = std::time::Duration::from_millis(500);
+
+        let fd = unsafe { libc::posix_openpt(libc::O_RDWR | libc::O_NOCTTY) };
+        assert!(fd >= 0);
+        let mut name = [0 as libc::c_char; 64];
+        unsafe {
+            assert_eq!(libc::grantpt(fd), 0);
+            assert_eq!(libc::unlockpt(fd), 0);
+            assert_eq!(libc::ptsname_r(fd, name.as_mut_ptr(), name.len()), 0);
+        }
+        let mut master = unsafe { std::fs::File::from_raw_fd(fd) };
+        let path = unsafe { std::ffi::CStr::from_ptr(name.as_ptr()) };
+        let path = std::path::Path::new(path.to_str().unwrap());
+        let mut bus = mdb::uart::UartMdb::open(path).unwrap();
+        // pty has no parity, peripheral writes PARMRK sequences itself and
+        // they must not be escaped again; master termios is the slave's
+        let mut t: libc::termios = unsafe { std::mem::zeroed() };
+        unsafe {
+            assert_eq!(libc::tcgetattr(fd, &mut t), 0);
+            t.c_iflag &= !libc::PARMRK;
+            assert_eq!(libc::tcsetattr(fd, libc::TCSANOW, &t), 0);
+        }
+
+        let peripheral = std::thread::spawn(move || {
+            let mut seen = Vec::new();
+            let data = [0x20, 0xff];
+            let chk = mdb::checksum(&data);
+            // POLL, first reply corrupted
+            seen.push(pty_read(&mut master, 2, timeout));
+            master.write_all(&pty_block(&data, chk ^ 1)).unwrap();
+            seen.push(pty_read(&mut master, 1, timeout));
+            master.write_all(&pty_block(&data, chk)).unwrap();
+            seen.push(pty_read(&mut master, 1, timeout));
+            // RESET, ACK only
+            seen.push(pty_read(&mut master, 2, timeout));
+            master.write_all(&pty_block(&[], RESPONSE_ACK)).unwrap();
+            (seen, master)
+        });
+
+        let mut response = Vec::new();
+        bus.tx(&[0x0b], &mut response, timeout).unwrap();
+        assert_eq!(response, vec![0x20, 0xff]);
+        bus.tx(&[0x08], &mut response, timeout).unwrap();
+        assert!(response.is_empty());
+        let (seen, _master) = peripheral.join().unwrap();
+        assert_eq!(
+            seen,
+            vec![
+                vec![0x0b, 0x0b],
+                vec![RESPONSE_RET],
+                vec![RESPONSE_ACK],
+                vec![0x08, 0x08],
+            ]
+        );
+
+        // peripheral silent
+        let short = std::time::Duration::from_millis(20);
+        let e = bus.tx(&[0x0b], &mut response, short).unwrap_err();
+        assert!(matches!(
+            e.kind(),
+            ErrorKind::MdbTimeout(mdb::Stage::Receive, _)
+        ));
+        bus.close().unwrap();
+    }
+
+    #[test]
+    fn server_exec_mdb_open_transport() {
+        use crate::proto::iodin::mdb_open::Transport;
+        use crate::proto::iodin::response::Status;
+
+        let mut s = server::Server::new(true).unwrap();
+        let mut open = MdbOpen::new();
+        open.transport = Transport::GPIO.into();
+        let mut req = Request::new();
+        req.set_mdb_open(open.clone());
+        let mut rsp = Response::new();
+        assert!(s.exec(&req, &mut rsp).is_err());
+        assert_eq!(rsp.status, Status::ERR_INPUT.into());
+
+        open.transport = Transport::DEFAULT.into();
+        req.set_mdb_open(open);
+        s.exec(&req, &mut Response::new()).unwrap();
+        let mut info = Request::new();
+        info.command = request::Command::GET_INFO.into();
+        let mut rsp = Response::new();
+        s.exec(&info, &mut rsp).unwrap();
+        assert!(rsp.info.mdb_open);
+        assert_eq!(rsp.info.mdb_transport, Transport::SIM.into());
+
+        let mut open = MdbOpen::new();
+        open.transport = Transport::UART.into();
+        req.set_mdb_open(open.clone());
+        let mut rsp = Response::new();
+        assert!(s.exec(&req, &mut rsp).is_err());
+        assert_eq!(rsp.status, Status::ERR_INPUT.into());
+        open.device = "/nonexistent/tty".to_string();
+        req.set_mdb_open(open);
+        assert!(s.exec(&req, &mut Response::new()).is_err());
+    }
+
+    /// xorshift64, fixed seed keeps failures reproducible.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: u64) -> u64 {
+            self.next() % n
+        }
+
+        /// Mostly small interesting values, sometimes anything.
+        fn uint(&mut self) -> u32 {
+            const EDGE: &[u32] = &[0, 1, 2, 5, 14, 15, 100, 300, 1000, 10_001, u32::MAX];
+            match self.below(4) {
+                0 => self.next() as u32,
+                _ => EDGE[self.below(EDGE.len() as u64) as usize],
+            }
+        }
+
+        fn bytes(&mut self, max: u64) -> Vec<u8> {
+            (0..self.below(max + 1))
+                .map(|_| self.next() as u8)
+                .collect()
+        }
+    }
+
+    fn random_request(rng: &mut Rng) -> Request {
+        let mut r = Request::new();
+        r.version = rng.below(5) as u32;
+        r.command = protobuf::EnumOrUnknown::from_i32(match rng.below(3) {
+            0 => rng.uint() as i32,
+            _ => rng.below(12) as i32,
+        });
+        r.timeout = rng.uint();
+        r.arg_uint = rng.below(3) as u32;
+        r.arg_bytes = rng.bytes(mdb::BLOCK_MAX_LENGTH as u64 + 2);
+        r.id = rng.next() as u32;
+        let mut tx = MdbTx::new();
+        tx.frame = rng.bytes(mdb::BLOCK_MAX_LENGTH as u64 + 2);
+        tx.timeout = rng.uint();
+        if rng.below(2) == 0 {
+            let retry = tx.retry.mut_or_insert_default();
+            retry.max_attempts = rng.uint();
+            retry.backoff_ms = rng.below(3) as u32;
+        }
+        match rng.below(12) {
+            0 => {
+                let mut o = MdbOpen::new();
+                o.rx_pin = rng.uint();
+                o.tx_pin = rng.uint();
+                r.set_mdb_open(o);
+            }
+            1 => {
+                let mut reset = MdbReset::new();
+                reset.duration_ms = rng.below(3) as u32;
+                r.set_mdb_reset(reset);
+            }
+            2 => r.set_mdb_tx(tx),
+            3 => {
+                let mut b = MdbBatch::new();
+                for _ in 0..rng.below(20) {
+                    b.items.push(tx.clone());
+                }
+                b.stop_on_error = rng.below(2) == 0;
+                r.set_mdb_batch(b);
+            }
+            4 => {
+                let mut p = MdbPoll::new();
+                p.address = rng.uint();
+                p.interval_ms = rng.uint();
+                p.timeout_ms = rng.uint();
+                p.frame = tx.frame;
+                r.set_mdb_poll(p);
+            }
+            5 => {
+                let mut w = GpioWatch::new();
+                w.pin = rng.uint();
+                w.enable = rng.below(2) == 0;
+                r.set_gpio_watch(w);
+            }
+            6 => {
+                let mut sub = Subscribe::new();
+                for _ in 0..rng.below(4) {
+                    sub.kinds
+                        .push(protobuf::EnumOrUnknown::from_i32(rng.below(8) as i32));
+                }
+                r.set_subscribe(sub);
+            }
+            7 => {
+                let mut c = MdbCoin::new();
+                c.op = protobuf::EnumOrUnknown::from_i32(rng.below(9) as i32);
+                c.coin_enable = rng.uint();
+                c.coin_type = rng.uint();
+                c.count = rng.uint();
+                c.timeout = rng.uint();
+                r.set_mdb_coin(c);
+            }
+            8 => {
+                let mut b = MdbBill::new();
+                b.op = protobuf::EnumOrUnknown::from_i32(rng.below(11) as i32);
+                b.security_mask = rng.uint();
+                b.bill_enable = rng.uint();
+                b.stack = rng.below(2) == 0;
+                b.timeout = rng.uint();
+                r.set_mdb_bill(b);
+            }
+            9 => {
+                let mut c = MdbCashless::new();
+                c.op = protobuf::EnumOrUnknown::from_i32(rng.below(15) as i32);
+                c.address = rng.uint();
+                c.max_price = rng.uint();
+                c.price = rng.uint();
+                c.item = rng.uint();
+                c.timeout = rng.uint();
+                r.set_mdb_cashless(c);
+            }
+            _ => (),
+        }
+        r
+    }
+
+    #[test]
+    fn server_exec_fuzz() {
+        use crate::proto::iodin::response::Status;
+
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+        let mut s = server::Server::new(true).unwrap();
+        for i in 0..20_000 {
+            let request = random_request(&mut rng);
+            let mut response = Response::new();
+            let _ = s.exec(&request, &mut response);
+            assert_ne!(
+                response.status,
+                Status::INVALID.into(),
+                "i={} {:?}",
+                i,
+                request
+            );
+            assert_eq!(response.id, request.id);
+
+            // arbitrary bytes that happen to parse
+            if let Ok(request) = Request::parse_from_bytes(&rng.bytes(64)) {
+                let mut response = Response::new();
+                let _ = s.exec(&request, &mut response);
+                assert_ne!(
+                    response.status,
+                    Status::INVALID.into(),
+                    "i={} {:?}",
+                    i,
+                    request
+                );
+            }
+            s.poll(std::time::Instant::now());
+        }
+    }
+
+    #[test]
+    fn server_exec_mdb_tx_length() {
+        use crate::proto::iodin::response::Status;
+
+        let mut s = server::Server::new(true).unwrap();
+        let mut open = Request::new();
+        open.command = request::Command::MDB_OPEN.into();
+        open.arg_bytes = vec![15, 14];
+        s.exec(&open, &mut Response::new()).unwrap();
+        // long POLL is sent to coin changer and NAKed
+        for (len, bad) in [
+            (0, true),
+            (1, false),
+            (mdb::BLOCK_MAX_LENGTH - 1, false),
+            (mdb::BLOCK_MAX_LENGTH, true),
+        ] {
+            let mut tx = Request::new();
+            tx.command = request::Command::MDB_TX.into();
+            tx.arg_bytes = vec![0x0b; len];
+            let mut rsp = Response::new();
+            let _ = s.exec(&tx, &mut rsp);
+            assert_eq!(rsp.status == Status::ERR_INPUT.into(), bad, "len={}", len);
+        }
+    }
+
+    #[test]
+    fn event_hub_subscribe() {
+        use crate::event::{Hub, Kind};
+        use std::sync::mpsc;
+
+        let hub = Hub::new();
+        assert!(hub.subscribe(1, &[Kind::MDB_POLL]).is_err());
+        let (tx1, rx1) = mpsc::sync_channel(2);
+        let (tx2, rx2) = mpsc::sync_channel(2);
+        let s1 = hub.register(tx1);
+        let s2 = hub.register(tx2);
+        assert_ne!(s1, s2);
+        hub.subscribe(s1, &[Kind::MDB_POLL, Kind::BUS_ERROR])
+            .unwrap();
+        hub.subscribe(s2, &[Kind::GPIO_EDGE]).unwrap();
+
+        let mut e = Event::new();
+        e.kind = Kind::MDB_POLL.into();
+        e.data_bytes = vec![0x01];
+        hub.emit(e);
+        let r = rx1.try_recv().unwrap();
+        assert_eq!(r.id, 0);
+        assert_eq!(r.event.data_bytes, vec![0x01]);
+        assert!(rx2.try_recv().is_err());
+
+        // full queue drops events, session stays subscribed
+        for i in 0..3 {
+            let mut e = Event::new();
+            e.kind = Kind::MDB_POLL.into();
+            e.data_bytes = vec![i];
+            hub.emit(e);
+        }
+        assert_eq!(rx1.try_recv().unwrap().event.data_bytes, vec![0]);
+        assert_eq!(rx1.try_recv().unwrap().event.data_bytes, vec![1]);
+        assert!(rx1.try_recv().is_err());
+        let mut e = Event::new();
+        e.kind = Kind::MDB_POLL.into();
+        hub.emit(e);
+        assert!(rx1.try_recv().is_ok());
+
+        // empty list unsubscribes
+        hub.subscribe(s1, &[]).unwrap();
+        let mut e = Event::new();
+        e.kind = Kind::MDB_POLL.into();
+        hub.emit(e);
+        assert!(rx1.try_recv().is_err());
+
+        hub.unregister(s2);
+        assert!(hub.subscribe(s2, &[Kind::GPIO_EDGE]).is_err());
+    }
+
+    #[test]
+    fn server_exec_subscribe_requires_session() {
+        let mut s = server::Server::new(true).unwrap();
+        let mut req = Request::new();
+        let mut sub = Subscribe::new();
+        sub.kinds = vec![crate::event::Kind::MDB_POLL.into()];
+        req.set_subscribe(sub);
+        let mut rsp = Response::new();
+        assert!(s.exec(&req, &mut rsp).is_err());
+        assert_eq!(rsp.status, response::Status::ERR_INPUT.into());
+    }
+
+    #[test]
+    fn server_run_subscribe_gpio_watch() {
+        use crate::proto::iodin::response::Status;
+
+        let mut sub = Subscribe::new();
+        sub.kinds = vec![crate::event::Kind::GPIO_EDGE.into()];
+        let mut subscribe = Request::new();
+        subscribe.set_subscribe(sub);
+        let mut w = GpioWatch::new();
+        w.pin = 4;
+        w.enable = true;
+        let mut watch = Request::new();
+        watch.set_gpio_watch(w);
+        let mut stop = Request::new();
+        stop.command = request::Command::STOP.into();
+
+        let mut input = Vec::new();
+        for r in &[&subscribe, &watch, &stop] {
+            input.extend_from_slice(&(r.compute_size() as u32).to_le_bytes());
+            r.write_to_vec(&mut input).unwrap();
+        }
+        let s = std::sync::Mutex::new(server::Server::new(true).unwrap());
+        let mut output = Vec::new();
+        server::Server::serve(&s, &mut input.as_slice(), &mut output).unwrap();
+
+        let mut out = output.as_slice();
+        for _ in 0..3 {
+            let len = u32::from_le_bytes(out[..4].try_into().unwrap()) as usize;
+            let r = Response::parse_from_bytes(&out[4..4 + len]).unwrap();
+            assert_eq!(r.status, Status::OK.into(), "{:?}", r);
+            out = &out[4 + len..];
+        }
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn server_exec_mdb_poll_add() {
+        use crate::proto::iodin::response::Status;
+
+        let mut s = server::Server::new(true).unwrap();
+        let mut poll = MdbPoll::new();
+        poll.address = 0x08;
+        poll.interval_ms = 100;
+        let mut add = Request::new();
+        add.set_mdb_poll(poll.clone());
+        let mut rsp = Response::new();
+        // bus must be open
+        assert!(s.exec(&add, &mut rsp).is_err());
+
+        let mut open = Request::new();
+        open.command = request::Command::MDB_OPEN.into();
+        open.arg_bytes = vec![15, 14];
+        s.exec(&open, &mut Response::new()).unwrap();
+        s.exec(&add, &mut Response::new()).unwrap();
+        for (address, interval_ms, frame) in [
+            (0x00, 100, vec![]),
+            (0x09, 100, vec![]),
+            (0x30, 1, vec![]),
+            (0x30, 60_001, vec![]),
+            // cashless session belongs to MDB_CASHLESS
+            (0x10, 100, vec![]),
+            (0x60, 100, vec![]),
+            (0x08, 100, vec![0x12]),
+        ] {
+            let mut p = poll.clone();
+            p.address = address;
+            p.interval_ms = interval_ms;
+            p.frame = frame;
+            let mut bad = Request::new();
+            bad.set_mdb_poll(p);
+            let mut rsp = Response::new();
+            assert!(s.exec(&bad, &mut rsp).is_err());
+            assert_eq!(rsp.status, Status::ERR_INPUT.into());
+        }
+
+        let mut info = Request::new();
+        info.command = request::Command::GET_INFO.into();
+        let mut rsp = Response::new();
+        s.exec(&info, &mut rsp).unwrap();
+        assert_eq!(rsp.info.mdb_polls.len(), 1);
+        assert_eq!(rsp.info.mdb_polls[0].frame, vec![0x0b]);
+
+        let mut remove = Request::new();
+        remove.command = request::Command::MDB_POLL_REMOVE.into();
+        remove.set_mdb_poll(poll);
+        s.exec(&remove, &mut Response::new()).unwrap();
+        assert_eq!(s.poll(std::time::Instant::now()), None);
+    }
+
+    #[test]
+    fn server_listen_unix_mdb_poll_event() {
+        use crate::proto::iodin::response::Status;
+        use std::os::unix::net::UnixStream;
+        use std::sync::{Arc, Mutex};
+
+        let path =
+            std::env::temp_dir().join(format!("iodin-test-poll-{}.sock", std::process::id()));
+        let s = Arc::new(Mutex::new(server::Server::new(true).unwrap()));
+        server::listen_unix(s.clone(), &path).unwrap();
+        server::spawn_poller(s);
+
+        let mut c = UnixStream::connect(&path).unwrap();
+        let mut open = Request::new();
+        open.command = request::Command::MDB_OPEN.into();
+        open.arg_bytes = vec![15, 14];
+        let mut sub = Subscribe::new();
+        sub.kinds = vec![crate::event::Kind::MDB_POLL.into()];
+        let mut subscribe = Request::new();
+        subscribe.set_subscribe(sub);
+        let mut poll = MdbPoll::new();
+        // coin changer TUBE STATUS always has data, unlike POLL
+        poll.address = 0x08;
+        poll.interval_ms = 10;
+        poll.frame = vec![0x0a];
+        let mut add = Request::new();
+        add.id = 3;
+        add.set_mdb_poll(poll);
+        assert_eq!(roundtrip(&mut c, &open).status, Status::OK.into());
+        assert_eq!(roundtrip(&mut c, &subscribe).status, Status::OK.into());
+
+        // event may arrive before response to MDB_POLL_ADD
+        let mut r = roundtrip(&mut c, &add);
+        let mut events = 0;
+        while events < 2 {
+            if r.event.is_some() {
+                assert_eq!(r.id, 0);
+                assert_eq!(r.event.mdb_request, vec![0x0a]);
+                assert_eq!(r.event.data_bytes.len(), 18);
+                events += 1;
+            } else {
+                assert_eq!((r.id, r.status), (3, Status::OK.into()));
+            }
+            r = read_frame(&mut c);
+        }
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    fn roundtrip(stream: &mut std::os::unix::net::UnixStream, request: &Request) -> Response {
+        use std::io::Write;
+
+        let mut buf = Vec::new();
+        buf.extend_from_slice(&(request.compute_size() as u32).to_le_bytes());
+        request.write_to_vec(&mut buf).unwrap();
+        stream.write_all(&buf).unwrap();
+        read_frame(stream)
+    }
+
+    fn read_frame(stream: &mut std::os::unix::net::UnixStream) -> Response {
+        use std::io::Read;
+
+        let mut len = [0u8; 4];
+        stream.read_exact(&mut len).unwrap();
+        let mut msg = vec![0u8; u32::from_le_bytes(len) as usize];
+        stream.read_exact(&mut msg).unwrap();
+        Response::parse_from_bytes(&msg).unwrap()
+    }
+
     /*
         #[bench]
         fn bench_server_run_mdb_tx(b: &mut test::Bencher) {
@@ -118,8 +2234,8 @@ mod tests {
                 .unwrap();
             request_stop.write_to_with_cached_sizes(&mut os).unwrap();
             os.flush().unwrap();
-            let mut s = server::Server::new(true).unwrap();
-            let r = s.run(&mut rv.as_slice(), &mut wv);
+            let s = std::sync::Mutex::new(server::Server::new(true).unwrap());
+            let r = server::Server::serve(&s, &mut rv.as_slice(), &mut wv);
             let mut response = Response::new();
             let mut is = protobuf::CodedInputStream::from_bytes(wv.as_slice());
             let len = is.read_fixed32().unwrap();
@@ -132,34 +2248,9 @@ mod tests {
 
             b.iter(|| {
                 wv.clear();
-                let r = s.run(&mut rv.as_slice(), &mut wv);
+                let r = server::Server::serve(&s, &mut rv.as_slice(), &mut wv);
                 r
             });
         }
     */
 }
-
-fn main() {
-    logger::init(log::LevelFilter::Info).unwrap();
-
-    if let Err(ref e) = run() {
-        // Write the top-level error message
-        error!("error: {}", e);
-        // Trace back through the chained errors
-        for e in e.iter().skip(1) {
-            error!("caused by: {}", e);
-        }
-        ::std::process::exit(1);
-    }
-}
-
-/// The actual main(), but with the ability to use ? for easy early return
-fn run() -> Result<()> {
-    use std::fs::File;
-    use std::os::unix::io::FromRawFd;
-
-    let mut stdin = unsafe { File::from_raw_fd(0) };
-    let mut stdout = unsafe { File::from_raw_fd(1) };
-    server::Server::new(false)?.run(&mut stdin, &mut stdout)?;
-    Ok(())
-}
@@ -1,13 +1,23 @@
 use crate::error::*;
 use crate::pigpio;
+use crate::proto::iodin::MdbIdentification;
+use std::fmt;
 use std::time::Duration;
 
+pub mod bill;
+pub mod cashless;
+pub mod coin;
+pub mod fault;
+mod fault_spec;
+pub mod sim;
+pub mod uart;
+
 pub const BLOCK_MAX_LENGTH: usize = 40;
 pub const BAUD: u32 = 9600;
 pub const DATA_BITS: u32 = 9;
 pub const STOP_BITS: u32 = 2;
 pub const RESPONSE_ACK: u8 = 0x00;
-// pub const RESPONSE_RET: u8 = 0xaa;
+pub const RESPONSE_RET: u8 = 0xaa;
 pub const RESPONSE_NAK: u8 = 0xff;
 
 // pigpio way to handle data_bits > 8
@@ -20,6 +30,162 @@ const TIMEOUT_SMALL_US: u32 = 10_000;
 const TIMEOUT_CHAR_US: u32 = ((1e6 as u32) * (DATA_BITS + 2/*start+stop*/) / BAUD) + 2000;
 // time to receive whole response *not including* wait for first byte
 const TIMEOUT_RECEIVE_US: u32 = BLOCK_MAX_LENGTH as u32 * TIMEOUT_CHAR_US;
+// RET requests after checksum mismatch before giving up with NAK
+const RET_MAX: usize = 1;
+
+/// Transaction step where MDB error occurred.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Stage {
+    /// Sending request.
+    Send,
+    /// Waiting for or receiving peripheral response.
+    Receive,
+    /// Sending ACK/NAK/RET to peripheral.
+    Ack,
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Stage::Send => "send request",
+            Stage::Receive => "recv",
+            Stage::Ack => "send ACK/NAK/RET",
+        })
+    }
+}
+
+/// MDB transport used by server, selected at MDB_OPEN.
+/// Constructor of implementation opens the bus.
+pub trait MdbBus: Send {
+    /// Send `request` (without checksum) and receive peripheral response into `response`.
+    fn tx(&mut self, request: &[u8], response: &mut Vec<u8>, timeout: Duration) -> Result<()>;
+    /// Hold bus in reset state for `duration`.
+    fn bus_reset(&mut self, duration: Duration) -> Result<()>;
+    /// Leave bus lines idle, i.e. before process exit.
+    fn idle(&mut self) -> Result<()> {
+        Ok(())
+    }
+    fn close(&mut self) -> Result<()>;
+}
+
+/// Byte level access to MDB bus, implemented by hardware backends and simulations.
+/// Protocol logic (ACK/NAK/RET, checksum) is in `transact`.
+pub trait Link {
+    /// Send request block with mode bit set on first byte. `block` ends with checksum.
+    fn send(&mut self, block: &[u8]) -> Result<()>;
+    /// Send single ACK, NAK or RET byte to peripheral.
+    fn send_control(&mut self, byte: u8, timeout: Duration) -> Result<()>;
+    /// Receive peripheral block, appending data bytes to `response`.
+    /// Returns last byte (one with mode bit set): checksum or ACK/NAK for empty response.
+    fn receive(&mut self, timeout: Duration, response: &mut Vec<u8>) -> Result<u8>;
+}
+
+/// One transaction for device drivers: request without checksum to response data.
+pub type Tx<'a> = dyn FnMut(&[u8]) -> Result<Vec<u8>> + 'a;
+
+/// Driver command answered with ACK only.
+pub fn tx_ack(tx: &mut Tx, request: &[u8]) -> Result<()> {
+    let response = tx(request)?;
+    if !response.is_empty() {
+        let e = format!("expected ACK to {:02x?}", request);
+        return Err(ErrorKind::MdbDecode(e, response).into());
+    }
+    Ok(())
+}
+
+/// Driver command answered with at least `min` bytes of data.
+pub fn tx_data(tx: &mut Tx, request: &[u8], min: usize) -> Result<Vec<u8>> {
+    let response = tx(request)?;
+    if response.len() < min {
+        let e = format!("expected {} bytes to {:02x?}", min, request);
+        return Err(ErrorKind::MdbDecode(e, response).into());
+    }
+    Ok(response)
+}
+
+/// Expansion identification: manufacturer code, serial number, model, software version
+/// and optional feature bits.
+pub fn parse_identification(data: &[u8]) -> Result<MdbIdentification> {
+    if data.len() < 29 {
+        let e = "identification too short".to_string();
+        return Err(ErrorKind::MdbDecode(e, data.to_vec()).into());
+    }
+    let text = |b: &[u8]| String::from_utf8_lossy(b).trim_end().to_string();
+    let mut id = MdbIdentification::new();
+    id.manufacturer = text(&data[0..3]);
+    id.serial = text(&data[3..15]);
+    id.model = text(&data[15..27]);
+    id.software_version = u16::from_be_bytes([data[27], data[28]]).into();
+    if let Some(f) = data.get(29..33) {
+        id.features = u32::from_be_bytes([f[0], f[1], f[2], f[3]]);
+    }
+    Ok(id)
+}
+
+/// Request must fit in block together with checksum.
+pub fn check_request(request: &[u8]) -> Result<()> {
+    if request.is_empty() {
+        return Err(ErrorKind::InvalidInput("empty MDB request".to_string()).into());
+    }
+    if request.len() >= BLOCK_MAX_LENGTH {
+        let e = format!(
+            "MDB request length={} exceeds max={}",
+            request.len(),
+            BLOCK_MAX_LENGTH - 1
+        );
+        return Err(ErrorKind::InvalidInput(e).into());
+    }
+    Ok(())
+}
+
+/// VMC side of one MDB transaction: send request, receive and confirm response.
+/// Previous content of `response` is discarded.
+/// On checksum mismatch asks peripheral to retransmit with RET, then gives up with NAK.
+pub fn transact(
+    link: &mut dyn Link,
+    request: &[u8],
+    response: &mut Vec<u8>,
+    timeout: Duration,
+) -> Result<()> {
+    check_request(request)?;
+    response.clear();
+    let mut block = Vec::with_capacity(request.len() + 1);
+    block.extend_from_slice(request);
+    block.push(checksum(request));
+    let timeout_small = std::cmp::min(timeout, Duration::from_micros(TIMEOUT_SMALL_US.into()));
+
+    link.send(&block)?;
+    let mut end_byte = link.receive(timeout, response)?;
+    if response.is_empty() {
+        // received ACK/NAK in end_byte
+        return match end_byte {
+            RESPONSE_ACK => Ok(()),
+            RESPONSE_NAK => Err(ErrorKind::MdbNak.into()),
+            _ => Err(ErrorKind::MdbInvalidResponse(end_byte).into()),
+        };
+    }
+    let mut ret_count = 0;
+    loop {
+        let computed_chk = checksum(response.as_slice());
+        if end_byte == computed_chk {
+            return link.send_control(RESPONSE_ACK, timeout_small);
+        }
+        if ret_count >= RET_MAX {
+            link.send_control(RESPONSE_NAK, timeout_small)?;
+            return Err(ErrorKind::MdbChecksum(computed_chk, end_byte, response.clone()).into());
+        }
+        ret_count += 1;
+        debug!(
+            "mdb checksum computed={:02x} received={:02x} response={:02x?}, send RET",
+            computed_chk,
+            end_byte,
+            response.as_slice()
+        );
+        link.send_control(RESPONSE_RET, timeout_small)?;
+        response.clear();
+        end_byte = link.receive(timeout, response)?;
+    }
+}
 
 #[inline]
 fn mdb_wave_create(pin: u16, s: &[u8]) -> Result<pigpio::Wave> {
@@ -28,7 +194,7 @@ fn mdb_wave_create(pin: u16, s: &[u8]) -> Result<pigpio::Wave> {
     Ok(w)
 }
 
-fn mdb_wave_send_wait(w: &pigpio::Wave, deadline: u32, wait_step: u32, err: &str) -> Result<()> {
+fn mdb_wave_send_wait(w: &pigpio::Wave, deadline: u32, wait_step: u32, stage: Stage) -> Result<()> {
     let start = pigpio::tick_since(0);
     let mut total: u32;
     w.send(pigpio::PI_WAVE_MODE_ONE_SHOT_SYNC)?;
@@ -40,7 +206,7 @@ fn mdb_wave_send_wait(w: &pigpio::Wave, deadline: u32, wait_step: u32, err: &str
         }
         total = pigpio::tick_since(start);
         if total > deadline {
-            return Err(err.into());
+            return Err(ErrorKind::MdbTimeout(stage, Vec::new()).into());
         }
     }
     // check(unsafe { gpioWrite(self.tx_pin.into(), 0) })?;
@@ -54,17 +220,22 @@ pub struct GpioMdb {
     tx_pin: u16,
     wave_ack: pigpio::Wave,
     wave_nak: pigpio::Wave,
-    // wave_ret: Wave,
+    wave_ret: pigpio::Wave,
     wait_step: u32,
     buf: [u8; BUF_SIZE],
+    // close is called by shutdown and again by Drop
+    closed: bool,
 }
 
 impl GpioMdb {
-    pub fn new(rx_pin: u16, tx_pin: u16) -> Result<GpioMdb> {
-        let wait_step: u32 = std::env::var("iodin_mdb_wait_step")
-            .unwrap_or("101".to_string())
-            .parse()
-            .expect("env iodin_mdb_wait_step expect integer");
+    /// `wait_step` is busy wait delay in microseconds, `None` to use env `iodin_mdb_wait_step` or default.
+    pub fn new(rx_pin: u16, tx_pin: u16, wait_step: Option<u32>) -> Result<GpioMdb> {
+        let wait_step: u32 = match wait_step {
+            Some(x) => x,
+            None => std::env::var("iodin_mdb_wait_step")
+                .unwrap_or("101".to_string())
+                .parse()?,
+        };
 
         debug!("GpioMdb::new rx={} tx={}", rx_pin, tx_pin);
         pigpio::check(unsafe { pigpio::gpioSetMode(rx_pin.into(), pigpio::PI_INPUT) })?;
@@ -76,21 +247,34 @@ impl GpioMdb {
             rx_pin: rx_pin,
             tx_pin: tx_pin,
             wave_ack: mdb_wave_create(tx_pin, &[RESPONSE_ACK, 0x00])?,
-            // wave_ret: mdb_wave_create(tx_pin, &[RESPONSE_RET, 0x00])?,
+            wave_ret: mdb_wave_create(tx_pin, &[RESPONSE_RET, 0x00])?,
             wave_nak: mdb_wave_create(tx_pin, &[RESPONSE_NAK, 0x00])?,
             wait_step: wait_step,
-            buf: unsafe { std::mem::MaybeUninit::uninit().assume_init() },
+            buf: [0; BUF_SIZE],
+            closed: false,
         };
         Ok(m)
     }
 
     #[cold]
-    pub fn close(&self) -> Result<()> {
+    pub fn close(&mut self) -> Result<()> {
+        if self.closed {
+            return Ok(());
+        }
+        self.closed = true;
         pigpio::check(unsafe { pigpio::gpioWaveTxStop() })?;
         pigpio::check(unsafe { pigpio::gpioSerialReadClose(self.rx_pin.into()) })?;
         Ok(())
     }
 
+    /// Stop any wave and leave TX line in idle state.
+    #[cold]
+    pub fn idle(&self) -> Result<()> {
+        pigpio::check(unsafe { pigpio::gpioWaveTxStop() })?;
+        pigpio::check(unsafe { pigpio::gpioWrite(self.tx_pin.into(), 0) })?;
+        Ok(())
+    }
+
     pub fn bus_reset(&self, duration: Duration) -> Result<()> {
         if duration < Duration::from_millis(100) {
             // warn!("mdb bus_reset duration < 100ms as per MDB spec");
@@ -103,69 +287,10 @@ impl GpioMdb {
     }
 
     pub fn tx(&mut self, request: &[u8], response: &mut Vec<u8>, timeout: Duration) -> Result<()> {
-        const REQUEST_TIMEOUT: &str = "send request timeout";
-        const NAK_TIMEOUT: &str = "send NAK timeout";
-        const ACK_TIMEOUT: &str = "send ACK timeout";
-        // const RET_TIMEOUT: &str = "send RET timeout";
-
-        assert!(!request.is_empty());
-        assert!(request.len() < BLOCK_MAX_LENGTH);
-        assert!(response.capacity() >= BLOCK_MAX_LENGTH);
-
+        response.reserve(BLOCK_MAX_LENGTH);
         debug!("mdb tx request={:02x?} timeout={:?}", request, timeout);
-        self.buf[WORD_SIZE - 1] = 1; // first byte with 9bit set
-        for (i, b) in request.iter().enumerate() {
-            self.buf[i * WORD_SIZE] = *b;
-        }
-        let buf_req_len = request.len() * WORD_SIZE;
-        self.buf[buf_req_len] = checksum(request);
-
-        let wave = mdb_wave_create(self.tx_pin, &self.buf[..buf_req_len + WORD_SIZE])?;
-
-        // Calculate deadlines after other CPU work, just before hardware IO.
-        let timeout_us: u32 = duration_as_micros32(timeout)?; // FIXME use u32::try_from(timeout.as_micro())?
-        let timeout_small_us: u32 = std::cmp::min(timeout_us, TIMEOUT_SMALL_US);
         let io_start_us = pigpio::tick_since(0);
-        let receive_wait_deadline_us = io_start_us + timeout_us;
-        let send_deadline_us: u32 = io_start_us + (TIMEOUT_CHAR_US * request.len() as u32);
-        // TODO maybe yield to OS scheduler to reset process time slice?
-
-        // critical section begin
-        mdb_wave_send_wait(&wave, send_deadline_us, self.wait_step, REQUEST_TIMEOUT)?;
-
-        let end_byte;
-        let mut received_count = self.wait_receive(receive_wait_deadline_us, response)?;
-        let receive_deadline = pigpio::tick_since(0) + TIMEOUT_RECEIVE_US;
-        'receive: loop {
-            for i in (0..received_count).step_by(2) {
-                let (bvalue, bflag) = (self.buf[i], self.buf[i + 1]);
-                if 1 == bflag {
-                    end_byte = bvalue;
-                    break 'receive;
-                }
-                response.push(bvalue);
-            }
-            received_count = self.wait_receive(receive_deadline, response)?;
-        }
-        if response.is_empty() {
-            // received ACK/RET/NAK in end_byte
-            match end_byte {
-                RESPONSE_ACK => (),
-                RESPONSE_NAK => return Err(ErrorKind::MdbNak.into()),
-                _ => return Err(ErrorKind::MdbInvalidResponse(end_byte).into()),
-            }
-        } else {
-            let deadline_us = pigpio::tick_since(0) + timeout_small_us;
-            let computed_chk = checksum(response.as_slice());
-            if end_byte != computed_chk {
-                mdb_wave_send_wait(&self.wave_nak, deadline_us, self.wait_step, NAK_TIMEOUT)?;
-                return Err(ErrorKind::MdbChecksum(computed_chk, end_byte, response.clone()).into());
-            } else {
-                mdb_wave_send_wait(&self.wave_ack, deadline_us, self.wait_step, ACK_TIMEOUT)?;
-            }
-        }
-        // critical section end
-
+        transact(self, request, response, timeout)?;
         debug!(
             "mdb tx success request={:02x?} response={:02x?} io_time={}us",
             request,
@@ -191,13 +316,74 @@ impl GpioMdb {
             }
             if pigpio::tick_since(0) > deadline {
                 debug!("mdb response(part)={:02x?}", debug_response);
-                return Err("recv timeout".into());
+                return Err(ErrorKind::MdbTimeout(Stage::Receive, debug_response.to_vec()).into());
             }
             unsafe { pigpio::gpioDelay(self.wait_step) };
         }
     }
 }
 
+impl MdbBus for GpioMdb {
+    fn tx(&mut self, request: &[u8], response: &mut Vec<u8>, timeout: Duration) -> Result<()> {
+        GpioMdb::tx(self, request, response, timeout)
+    }
+
+    fn bus_reset(&mut self, duration: Duration) -> Result<()> {
+        GpioMdb::bus_reset(self, duration)
+    }
+
+    fn idle(&mut self) -> Result<()> {
+        GpioMdb::idle(self)
+    }
+
+    fn close(&mut self) -> Result<()> {
+        GpioMdb::close(self)
+    }
+}
+
+impl Link for GpioMdb {
+    fn send(&mut self, block: &[u8]) -> Result<()> {
+        for (i, b) in block.iter().enumerate() {
+            self.buf[i * WORD_SIZE] = *b;
+            self.buf[i * WORD_SIZE + 1] = 0;
+        }
+        self.buf[WORD_SIZE - 1] = 1; // first byte with 9bit set
+        let wave = mdb_wave_create(self.tx_pin, &self.buf[..block.len() * WORD_SIZE])?;
+
+        // Calculate deadline after other CPU work, just before hardware IO.
+        let deadline_us = pigpio::tick_since(0) + (TIMEOUT_CHAR_US * block.len() as u32);
+        mdb_wave_send_wait(&wave, deadline_us, self.wait_step, Stage::Send)
+    }
+
+    fn send_control(&mut self, byte: u8, timeout: Duration) -> Result<()> {
+        let deadline_us = pigpio::tick_since(0) + duration_as_micros32(timeout)?;
+        let wave = match byte {
+            RESPONSE_ACK => &self.wave_ack,
+            RESPONSE_NAK => &self.wave_nak,
+            RESPONSE_RET => &self.wave_ret,
+            _ => return Err(format!("mdb invalid control byte={:02x}", byte).into()),
+        };
+        mdb_wave_send_wait(wave, deadline_us, self.wait_step, Stage::Ack)
+    }
+
+    fn receive(&mut self, timeout: Duration, response: &mut Vec<u8>) -> Result<u8> {
+        let timeout_us: u32 = duration_as_micros32(timeout)?; // FIXME use u32::try_from(timeout.as_micro())?
+        let wait_deadline_us = pigpio::tick_since(0) + timeout_us;
+        let mut received_count = self.wait_receive(wait_deadline_us, response)?;
+        let receive_deadline = pigpio::tick_since(0) + TIMEOUT_RECEIVE_US;
+        loop {
+            for i in (0..received_count).step_by(2) {
+                let (bvalue, bflag) = (self.buf[i], self.buf[i + 1]);
+                if 1 == bflag {
+                    return Ok(bvalue);
+                }
+                response.push(bvalue);
+            }
+            received_count = self.wait_receive(receive_deadline, response)?;
+        }
+    }
+}
+
 impl Drop for GpioMdb {
     fn drop(&mut self) {
         let _ = self.close();
@@ -205,21 +391,22 @@ impl Drop for GpioMdb {
 }
 
 #[inline]
-fn checksum(b: &[u8]) -> u8 {
+pub fn checksum(b: &[u8]) -> u8 {
     b.iter().fold(0, |sum, &x| sum.wrapping_add(x))
 }
 
 // FIXME use u32::try_from(Duration.as_micro())?
-fn duration_as_micros32(d: Duration) -> Result<u32> {
-    const OVERFLOW: &str = "duration_as_micros32 overflow";
+/// Durations come from requests, so overflow is invalid input.
+pub fn duration_as_micros32(d: Duration) -> Result<u32> {
+    let overflow = || ErrorKind::InvalidInput(format!("duration={:?} overflows u32 micros", d));
     let x: u64 = d
         .as_secs()
         .checked_mul(1_000_000)
-        .ok_or(OVERFLOW)?
+        .ok_or_else(overflow)?
         .checked_add(d.subsec_micros().into())
-        .ok_or(OVERFLOW)?;
-    if x >= u32::max_value().into() {
-        return Err(OVERFLOW.into());
+        .ok_or_else(overflow)?;
+    if x >= u32::MAX.into() {
+        return Err(overflow().into());
     }
     Ok(x as u32)
 }
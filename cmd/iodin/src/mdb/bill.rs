@@ -0,0 +1,120 @@
+//! Bill validator driver, VMC side of feature level 1 and 2.
+use super::{parse_identification, tx_ack, tx_data, Tx};
+use crate::error::*;
+use crate::proto::iodin::mdb_bill_event::Kind;
+use crate::proto::iodin::*;
+use protobuf::EnumOrUnknown;
+
+const ADDRESS: u8 = 0x30;
+const RESET: u8 = ADDRESS;
+const SETUP: u8 = ADDRESS + 1;
+const SECURITY: u8 = ADDRESS + 2;
+const POLL: u8 = ADDRESS + 3;
+const BILL_TYPE: u8 = ADDRESS + 4;
+const ESCROW: u8 = ADDRESS + 5;
+const STACKER: u8 = ADDRESS + 6;
+const EXPANSION: u8 = ADDRESS + 7;
+const EXPANSION_IDENTIFICATION: u8 = 0x00;
+const EXPANSION_IDENTIFICATION_FEATURES: u8 = 0x02;
+
+/// Ops safe to send again after lost or damaged response.
+/// Repeated ESCROW may stack or return next bill, others change validator state.
+pub fn retryable(op: mdb_bill::Op) -> bool {
+    use mdb_bill::Op::*;
+    matches!(
+        op,
+        SETUP | STACKER | POLL | IDENTIFICATION | IDENTIFICATION_FEATURES
+    )
+}
+
+pub fn reset(tx: &mut Tx) -> Result<()> {
+    tx_ack(tx, &[RESET])
+}
+
+pub fn setup(tx: &mut Tx) -> Result<MdbBillSetup> {
+    parse_setup(&tx_data(tx, &[SETUP], 11)?)
+}
+
+/// Level, country, scaling factor, decimal places, stacker capacity, security levels,
+/// escrow capability, then credit per bill type.
+pub fn parse_setup(d: &[u8]) -> Result<MdbBillSetup> {
+    if d.len() < 11 {
+        let e = "bill setup too short".to_string();
+        return Err(ErrorKind::MdbDecode(e, d.to_vec()).into());
+    }
+    let mut s = MdbBillSetup::new();
+    s.feature_level = d[0].into();
+    s.country_code = u16::from_be_bytes([d[1], d[2]]).into();
+    s.scaling_factor = u16::from_be_bytes([d[3], d[4]]).into();
+    s.decimal_places = d[5].into();
+    s.stacker_capacity = u16::from_be_bytes([d[6], d[7]]).into();
+    s.security_levels = u16::from_be_bytes([d[8], d[9]]).into();
+    s.escrow = d[10] == 0xff;
+    s.credit = d[11..].iter().map(|&x| x.into()).collect();
+    Ok(s)
+}
+
+/// Bit per bill type, set bits use high security.
+pub fn security(tx: &mut Tx, mask: u16) -> Result<()> {
+    let m = mask.to_be_bytes();
+    tx_ack(tx, &[SECURITY, m[0], m[1]])
+}
+
+/// Bit per bill type, all zero disables acceptance.
+pub fn bill_type(tx: &mut Tx, enable: u16, escrow: u16) -> Result<()> {
+    let e = enable.to_be_bytes();
+    let s = escrow.to_be_bytes();
+    tx_ack(tx, &[BILL_TYPE, e[0], e[1], s[0], s[1]])
+}
+
+/// Stack bill held in escrow, or return it to customer.
+pub fn escrow(tx: &mut Tx, stack: bool) -> Result<()> {
+    tx_ack(tx, &[ESCROW, stack.into()])
+}
+
+pub fn stacker(tx: &mut Tx) -> Result<MdbBillStacker> {
+    let d = tx_data(tx, &[STACKER], 2)?;
+    let x = u16::from_be_bytes([d[0], d[1]]);
+    let mut s = MdbBillStacker::new();
+    s.full = x & 0x8000 != 0;
+    s.count = (x & 0x7fff).into();
+    Ok(s)
+}
+
+/// Activity since last POLL, empty for ACK only response.
+pub fn poll(tx: &mut Tx) -> Result<Vec<MdbBillEvent>> {
+    Ok(parse_poll(&tx(&[POLL])?))
+}
+
+/// Every byte is complete event, so any data decodes.
+pub fn parse_poll(d: &[u8]) -> Vec<MdbBillEvent> {
+    d.iter()
+        .map(|&b| {
+            let mut e = MdbBillEvent::new();
+            if b & 0x80 != 0 {
+                // 1yyyxxxx: bill of type x routed as y
+                e.kind = Kind::BILL.into();
+                e.bill_type = (b & 0x0f).into();
+                e.routing = EnumOrUnknown::from_i32((b >> 4 & 0x07).into());
+            } else if b & 0xe0 == 0x40 {
+                // 010xxxxx
+                e.kind = Kind::DISABLED_ATTEMPTS.into();
+                e.count = (b & 0x1f).into();
+            } else {
+                e.kind = Kind::STATUS.into();
+                e.status = EnumOrUnknown::from_i32(b.into());
+            }
+            e
+        })
+        .collect()
+}
+
+/// Level 1 identification, or level 2 with optional feature bits.
+pub fn identification(tx: &mut Tx, features: bool) -> Result<MdbIdentification> {
+    let (sub, min) = if features {
+        (EXPANSION_IDENTIFICATION_FEATURES, 33)
+    } else {
+        (EXPANSION_IDENTIFICATION, 29)
+    };
+    parse_identification(&tx_data(tx, &[EXPANSION, sub], min)?)
+}
@@ -0,0 +1,406 @@
+//! Cashless reader session state machine, VMC side of feature level 1.
+use super::{parse_identification, Tx};
+use crate::error::*;
+use crate::proto::iodin::mdb_cashless_event::Kind;
+use crate::proto::iodin::mdb_cashless_result::State;
+use crate::proto::iodin::*;
+use std::time::{Duration, Instant};
+
+pub const ADDRESS_1: u8 = 0x10;
+pub const ADDRESS_2: u8 = 0x60;
+const RESET: u8 = 0;
+const SETUP: u8 = 1;
+const SETUP_CONFIG: u8 = 0x00;
+const SETUP_PRICES: u8 = 0x01;
+const POLL: u8 = 2;
+const VEND: u8 = 3;
+const VEND_REQUEST: u8 = 0x00;
+const VEND_CANCEL: u8 = 0x01;
+const VEND_SUCCESS: u8 = 0x02;
+const VEND_FAILURE: u8 = 0x03;
+const VEND_SESSION_COMPLETE: u8 = 0x04;
+const READER: u8 = 4;
+const READER_DISABLE: u8 = 0x00;
+const READER_ENABLE: u8 = 0x01;
+const READER_CANCEL: u8 = 0x02;
+const EXPANSION: u8 = 7;
+const EXPANSION_REQUEST_ID: u8 = 0x00;
+const VMC_FEATURE_LEVEL: u8 = 1;
+/// Manufacturer, serial number, model, software version sent with REQUEST ID.
+const VMC_IDENTIFICATION: &[u8; 29] = b"IOD000000000001iodin       \x00\x01";
+/// Non-response time until reader reports its own in SETUP.
+const RESPONSE_TIME_DEFAULT: Duration = Duration::from_secs(5);
+
+/// VMC command, prices in reader scale factor units.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Command {
+    Reset,
+    /// Config data with VMC display size, then max/min prices.
+    Setup {
+        columns: u8,
+        rows: u8,
+        max_price: u16,
+        min_price: u16,
+    },
+    Enable,
+    Disable,
+    Cancel,
+    Poll,
+    VendRequest {
+        price: u16,
+        item: u16,
+    },
+    VendCancel,
+    VendSuccess {
+        item: u16,
+    },
+    VendFailure,
+    SessionComplete,
+    Identification,
+    /// Report state without bus transaction.
+    State,
+}
+
+/// Reader state kept by server between MDB_CASHLESS commands.
+pub struct Cashless {
+    address: u8,
+    state: State,
+    setup: Option<MdbCashlessSetup>,
+    display_size: usize,
+    funds: u32,
+    // amount of last VEND_APPROVED
+    approved: u32,
+    // VEND_REQUESTED since
+    vend_at: Instant,
+    last_response: Instant,
+}
+
+impl Cashless {
+    pub fn new(address: u8, now: Instant) -> Result<Cashless> {
+        if address != ADDRESS_1 && address != ADDRESS_2 {
+            let e = format!("cashless address={:#04x} must be 0x10 or 0x60", address);
+            return Err(ErrorKind::InvalidInput(e).into());
+        }
+        Ok(Cashless {
+            address,
+            state: State::INACTIVE,
+            setup: None,
+            display_size: 0,
+            funds: 0,
+            approved: 0,
+            vend_at: now,
+            last_response: now,
+        })
+    }
+
+    /// Reader maximum response time, applies to non-response and vend approval.
+    fn response_time(&self) -> Duration {
+        match &self.setup {
+            Some(s) if s.max_response_time != 0 => Duration::from_secs(s.max_response_time.into()),
+            _ => RESPONSE_TIME_DEFAULT,
+        }
+    }
+
+    fn require(&self, command: Command, states: &[State]) -> Result<()> {
+        if !states.contains(&self.state) {
+            let e = format!("cashless {:?} illegal in state {:?}", command, self.state);
+            return Err(ErrorKind::InvalidInput(e).into());
+        }
+        Ok(())
+    }
+
+    pub fn exec(
+        &mut self,
+        tx: &mut Tx,
+        command: Command,
+        now: Instant,
+    ) -> Result<MdbCashlessResult> {
+        use State::*;
+        let mut r = MdbCashlessResult::new();
+        let a = self.address;
+        match command {
+            Command::Reset => {
+                r.events = self.tx(tx, &[a + RESET], now)?;
+                self.state = INACTIVE;
+                self.funds = 0;
+            }
+            Command::Setup {
+                columns,
+                rows,
+                max_price,
+                min_price,
+            } => {
+                self.require(command, &[INACTIVE, DISABLED])?;
+                self.display_size = usize::from(columns) * usize::from(rows);
+                let config = [
+                    a + SETUP,
+                    SETUP_CONFIG,
+                    VMC_FEATURE_LEVEL,
+                    columns,
+                    rows,
+                    0x00,
+                ];
+                r.events = self.tx(tx, &config, now)?;
+                if self.state != DISABLED {
+                    let e = "expected READER_CONFIG to SETUP".to_string();
+                    return Err(ErrorKind::MdbDecode(e, Vec::new()).into());
+                }
+                let max = max_price.to_be_bytes();
+                let min = min_price.to_be_bytes();
+                let prices = [a + SETUP, SETUP_PRICES, max[0], max[1], min[0], min[1]];
+                r.events.extend(self.tx(tx, &prices, now)?);
+                r.setup = self.setup.clone().into();
+            }
+            Command::Enable => {
+                self.require(command, &[DISABLED, ENABLED])?;
+                let request = [a + READER, READER_ENABLE];
+                r.events = self.tx_change(tx, &request, now, |c| c.state = ENABLED)?;
+            }
+            Command::Disable => {
+                self.require(command, &[DISABLED, ENABLED])?;
+                let request = [a + READER, READER_DISABLE];
+                r.events = self.tx_change(tx, &request, now, |c| c.state = DISABLED)?;
+            }
+            Command::Cancel => {
+                self.require(command, &[ENABLED])?;
+                r.events = self.tx(tx, &[a + READER, READER_CANCEL], now)?;
+            }
+            Command::Poll => {
+                r.events = self.tx(tx, &[a + POLL], now)?;
+                if self.state == VEND_REQUESTED
+                    && now.duration_since(self.vend_at) > self.response_time()
+                {
+                    warn!("cashless {:#04x} vend approval timeout, cancel", a);
+                    let mut events = self.vend_cancel(tx, now)?;
+                    let denied = Kind::VEND_DENIED.into();
+                    if !events.iter().any(|e| e.kind == denied) {
+                        let mut e = MdbCashlessEvent::new();
+                        e.kind = denied;
+                        events.push(e);
+                    }
+                    for e in events.iter_mut().filter(|e| e.kind == denied) {
+                        e.timeout = true;
+                    }
+                    r.events.extend(events);
+                }
+            }
+            Command::VendRequest { price, item } => {
+                self.require(command, &[SESSION_IDLE])?;
+                let p = price.to_be_bytes();
+                let i = item.to_be_bytes();
+                let request = [a + VEND, VEND_REQUEST, p[0], p[1], i[0], i[1]];
+                r.events = self.tx_change(tx, &request, now, |c| {
+                    c.state = VEND_REQUESTED;
+                    c.vend_at = now;
+                })?;
+            }
+            Command::VendCancel => {
+                self.require(command, &[VEND_REQUESTED])?;
+                r.events = self.vend_cancel(tx, now)?;
+            }
+            Command::VendSuccess { item } => {
+                self.require(command, &[VEND_APPROVED])?;
+                let i = item.to_be_bytes();
+                let request = [a + VEND, VEND_SUCCESS, i[0], i[1]];
+                r.events = self.tx_change(tx, &request, now, |c| {
+                    c.state = SESSION_IDLE;
+                    c.funds = c.funds.saturating_sub(c.approved);
+                })?;
+            }
+            Command::VendFailure => {
+                self.require(command, &[VEND_APPROVED])?;
+                let request = [a + VEND, VEND_FAILURE];
+                r.events = self.tx_change(tx, &request, now, |c| c.state = SESSION_IDLE)?;
+            }
+            Command::SessionComplete => {
+                self.require(command, &[SESSION_IDLE])?;
+                let request = [a + VEND, VEND_SESSION_COMPLETE];
+                r.events = self.tx_change(tx, &request, now, |c| c.state = SESSION_END)?;
+            }
+            Command::Identification => {
+                self.require(command, &[DISABLED, ENABLED])?;
+                let mut request = vec![a + EXPANSION, EXPANSION_REQUEST_ID];
+                request.extend_from_slice(VMC_IDENTIFICATION);
+                r.events = self.tx(tx, &request, now)?;
+                let id = r
+                    .events
+                    .iter()
+                    .find(|e| e.kind.enum_value() == Ok(Kind::PERIPHERAL_ID));
+                r.identification = match id {
+                    Some(e) => e.identification.clone(),
+                    None => {
+                        let e = "expected PERIPHERAL_ID to REQUEST ID".to_string();
+                        return Err(ErrorKind::MdbDecode(e, Vec::new()).into());
+                    }
+                };
+            }
+            Command::State => {}
+        }
+        r.state = self.state.into();
+        r.funds = self.funds;
+        Ok(r)
+    }
+
+    /// Reader answers VEND CANCEL with VEND DENIED.
+    fn vend_cancel(&mut self, tx: &mut Tx, now: Instant) -> Result<Vec<MdbCashlessEvent>> {
+        let events = self.tx(tx, &[self.address + VEND, VEND_CANCEL], now)?;
+        self.state = State::SESSION_IDLE;
+        Ok(events)
+    }
+
+    /// Transaction of command that moves reader to new state. `change` applies before
+    /// transaction, so events in response see new state, and is undone if it fails,
+    /// unless reader was found not responding meanwhile.
+    fn tx_change(
+        &mut self,
+        tx: &mut Tx,
+        request: &[u8],
+        now: Instant,
+        change: impl FnOnce(&mut Cashless),
+    ) -> Result<Vec<MdbCashlessEvent>> {
+        let saved = (self.state, self.funds, self.vend_at);
+        change(self);
+        let result = self.tx(tx, request, now);
+        if result.is_err() && self.state != State::INACTIVE {
+            (self.state, self.funds, self.vend_at) = saved;
+        }
+        result
+    }
+
+    /// Reader may answer any command with poll data, so every response updates state.
+    fn tx(&mut self, tx: &mut Tx, request: &[u8], now: Instant) -> Result<Vec<MdbCashlessEvent>> {
+        let response = match tx(request) {
+            Ok(r) => r,
+            Err(e) => {
+                if let ErrorKind::MdbTimeout(..) = e.kind() {
+                    if now.duration_since(self.last_response) > self.response_time() {
+                        warn!(
+                            "cashless {:#04x} not responding, reset required",
+                            self.address
+                        );
+                        self.state = State::INACTIVE;
+                        self.funds = 0;
+                    }
+                }
+                return Err(e);
+            }
+        };
+        self.last_response = now;
+        let events = parse_poll(&response, self.feature_level(), self.display_size)?;
+        for e in &events {
+            self.apply(e);
+        }
+        Ok(events)
+    }
+
+    /// Reader formats poll data for lower of its own and VMC feature level.
+    fn feature_level(&self) -> u32 {
+        self.setup
+            .as_ref()
+            .map_or(1, |s| s.feature_level)
+            .min(VMC_FEATURE_LEVEL.into())
+    }
+
+    fn apply(&mut self, e: &MdbCashlessEvent) {
+        use State::*;
+        match (e.kind.enum_value_or_default(), self.state) {
+            (Kind::JUST_RESET, _) | (Kind::OUT_OF_SEQUENCE, _) => {
+                self.state = INACTIVE;
+                self.funds = 0;
+            }
+            (Kind::READER_CONFIG, _) => {
+                self.setup = e.setup.clone().into_option();
+                if self.state == INACTIVE {
+                    self.state = DISABLED;
+                }
+            }
+            (Kind::BEGIN_SESSION, ENABLED) => {
+                self.state = SESSION_IDLE;
+                self.funds = e.amount;
+            }
+            (Kind::VEND_APPROVED, VEND_REQUESTED) => {
+                self.state = VEND_APPROVED;
+                self.approved = e.amount;
+            }
+            (Kind::VEND_DENIED, VEND_REQUESTED) => self.state = SESSION_IDLE,
+            (Kind::END_SESSION, SESSION_IDLE)
+            | (Kind::END_SESSION, VEND_REQUESTED)
+            | (Kind::END_SESSION, VEND_APPROVED)
+            | (Kind::END_SESSION, SESSION_END) => {
+                self.state = ENABLED;
+                self.funds = 0;
+            }
+            (kind, state) if kind != Kind::CANCELLED && kind != Kind::DISPLAY_REQUEST => {
+                debug!(
+                    "cashless {:#04x} event {:?} in state {:?}",
+                    self.address, kind, state
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Events of poll data, their lengths depend on reader level and VMC display size.
+pub fn parse_poll(d: &[u8], level: u32, display_size: usize) -> Result<Vec<MdbCashlessEvent>> {
+    let mut events = Vec::new();
+    let mut i = 0;
+    while i < d.len() {
+        let mut e = MdbCashlessEvent::new();
+        let (kind, len) = match d[i] {
+            0x00 => (Kind::JUST_RESET, 1),
+            0x01 => (Kind::READER_CONFIG, 8),
+            0x02 => (Kind::DISPLAY_REQUEST, 2 + display_size),
+            0x03 if level < 2 => (Kind::BEGIN_SESSION, 3),
+            0x03 => (Kind::BEGIN_SESSION, 10),
+            0x04 => (Kind::SESSION_CANCEL_REQUEST, 1),
+            0x05 => (Kind::VEND_APPROVED, 3),
+            0x06 => (Kind::VEND_DENIED, 1),
+            0x07 => (Kind::END_SESSION, 1),
+            0x08 => (Kind::CANCELLED, 1),
+            0x09 if level < 3 => (Kind::PERIPHERAL_ID, 30),
+            0x09 => (Kind::PERIPHERAL_ID, 34),
+            0x0a => (Kind::MALFUNCTION, 2),
+            0x0b if level < 2 => (Kind::OUT_OF_SEQUENCE, 1),
+            0x0b => (Kind::OUT_OF_SEQUENCE, 2),
+            x => {
+                let e = format!("unknown cashless event {:#04x}", x);
+                return Err(ErrorKind::MdbDecode(e, d.to_vec()).into());
+            }
+        };
+        let x = match d.get(i..i + len) {
+            Some(x) => x,
+            None => {
+                let e = format!("truncated cashless event {:?}", kind);
+                return Err(ErrorKind::MdbDecode(e, d.to_vec()).into());
+            }
+        };
+        e.kind = kind.into();
+        match kind {
+            Kind::READER_CONFIG => {
+                let mut s = MdbCashlessSetup::new();
+                s.feature_level = x[1].into();
+                s.country_code = u16::from_be_bytes([x[2], x[3]]).into();
+                s.scale_factor = x[4].into();
+                s.decimal_places = x[5].into();
+                s.max_response_time = x[6].into();
+                s.options = x[7].into();
+                e.setup = Some(s).into();
+            }
+            Kind::DISPLAY_REQUEST => {
+                e.display_time = x[1].into();
+                e.display_data = x[2..].to_vec();
+            }
+            Kind::BEGIN_SESSION | Kind::VEND_APPROVED => {
+                e.amount = u16::from_be_bytes([x[1], x[2]]).into();
+            }
+            Kind::PERIPHERAL_ID => e.identification = Some(parse_identification(&x[1..])?).into(),
+            Kind::MALFUNCTION => e.code = x[1].into(),
+            Kind::OUT_OF_SEQUENCE if len > 1 => e.code = x[1].into(),
+            _ => {}
+        }
+        events.push(e);
+        i += len;
+    }
+    Ok(events)
+}
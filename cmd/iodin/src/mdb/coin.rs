@@ -0,0 +1,123 @@
+//! Coin changer driver, VMC side of feature level 2 and 3.
+use super::{parse_identification, tx_ack, tx_data, Tx};
+use crate::error::*;
+use crate::proto::iodin::mdb_coin_event::Kind;
+use crate::proto::iodin::*;
+use protobuf::EnumOrUnknown;
+
+const ADDRESS: u8 = 0x08;
+const RESET: u8 = ADDRESS;
+const SETUP: u8 = ADDRESS + 1;
+const TUBE_STATUS: u8 = ADDRESS + 2;
+const POLL: u8 = ADDRESS + 3;
+const COIN_TYPE: u8 = ADDRESS + 4;
+const DISPENSE: u8 = ADDRESS + 5;
+const EXPANSION: u8 = ADDRESS + 7;
+const EXPANSION_IDENTIFICATION: u8 = 0x00;
+const COIN_TYPES: u8 = 16;
+
+/// Ops safe to send again after lost or damaged response.
+/// Repeated DISPENSE pays out twice, RESET and COIN_TYPE change changer state.
+pub fn retryable(op: mdb_coin::Op) -> bool {
+    use mdb_coin::Op::*;
+    matches!(op, POLL | SETUP | TUBE_STATUS | IDENTIFICATION)
+}
+
+pub fn reset(tx: &mut Tx) -> Result<()> {
+    tx_ack(tx, &[RESET])
+}
+
+pub fn setup(tx: &mut Tx) -> Result<MdbCoinSetup> {
+    parse_setup(&tx_data(tx, &[SETUP], 7)?)
+}
+
+/// Level, country, scaling factor, decimal places, routing, then credit per coin type.
+pub fn parse_setup(d: &[u8]) -> Result<MdbCoinSetup> {
+    if d.len() < 7 {
+        let e = "coin setup too short".to_string();
+        return Err(ErrorKind::MdbDecode(e, d.to_vec()).into());
+    }
+    let mut s = MdbCoinSetup::new();
+    s.feature_level = d[0].into();
+    s.country_code = u16::from_be_bytes([d[1], d[2]]).into();
+    s.scaling_factor = d[3].into();
+    s.decimal_places = d[4].into();
+    s.coin_routing = u16::from_be_bytes([d[5], d[6]]).into();
+    s.credit = d[7..].iter().map(|&x| x.into()).collect();
+    Ok(s)
+}
+
+pub fn tube_status(tx: &mut Tx) -> Result<MdbCoinTubes> {
+    let d = tx_data(tx, &[TUBE_STATUS], 2)?;
+    let mut t = MdbCoinTubes::new();
+    t.full = u16::from_be_bytes([d[0], d[1]]).into();
+    t.count = d[2..].iter().map(|&x| x.into()).collect();
+    Ok(t)
+}
+
+/// Bit per coin type, all zero disables acceptance.
+pub fn coin_type(tx: &mut Tx, enable: u16, manual_dispense: u16) -> Result<()> {
+    let e = enable.to_be_bytes();
+    let m = manual_dispense.to_be_bytes();
+    tx_ack(tx, &[COIN_TYPE, e[0], e[1], m[0], m[1]])
+}
+
+pub fn dispense(tx: &mut Tx, coin_type: u8, count: u8) -> Result<()> {
+    if coin_type >= COIN_TYPES || count == 0 || count > 15 {
+        let e = format!(
+            "dispense coin_type={} count={} out of range",
+            coin_type, count
+        );
+        return Err(ErrorKind::InvalidInput(e).into());
+    }
+    tx_ack(tx, &[DISPENSE, count << 4 | coin_type])
+}
+
+/// Activity since last POLL, empty for ACK only response.
+pub fn poll(tx: &mut Tx) -> Result<Vec<MdbCoinEvent>> {
+    parse_poll(&tx(&[POLL])?)
+}
+
+pub fn parse_poll(d: &[u8]) -> Result<Vec<MdbCoinEvent>> {
+    let mut events = Vec::new();
+    let mut i = 0;
+    while i < d.len() {
+        let b = d[i];
+        let mut e = MdbCoinEvent::new();
+        if b & 0x80 != 0 {
+            // 1yyyxxxx: y coins of type x dispensed manually, then tube count
+            e.kind = Kind::DISPENSED.into();
+            e.count = (b >> 4 & 0x07).into();
+        } else if b & 0x40 != 0 {
+            // 01yyxxxx: coin of type x deposited with routing y, then tube count
+            e.kind = Kind::DEPOSITED.into();
+            e.routing = EnumOrUnknown::from_i32((b >> 4 & 0x03).into());
+        } else if b & 0x20 != 0 {
+            e.kind = Kind::SLUG.into();
+            e.count = (b & 0x1f).into();
+        } else {
+            e.kind = Kind::STATUS.into();
+            e.status = EnumOrUnknown::from_i32(b.into());
+        }
+        if b & 0xc0 != 0 {
+            let tube = match d.get(i + 1) {
+                Some(&x) => x,
+                None => {
+                    let e = "truncated coin event".to_string();
+                    return Err(ErrorKind::MdbDecode(e, d.to_vec()).into());
+                }
+            };
+            e.coin_type = (b & 0x0f).into();
+            e.tube_count = tube.into();
+            i += 1;
+        }
+        i += 1;
+        events.push(e);
+    }
+    Ok(events)
+}
+
+/// Level 3 expansion identification with optional feature bits.
+pub fn identification(tx: &mut Tx) -> Result<MdbIdentification> {
+    parse_identification(&tx_data(tx, &[EXPANSION, EXPANSION_IDENTIFICATION], 29)?)
+}
@@ -0,0 +1,140 @@
+//! Fault injection around MDB transport, reproduces flaky wiring deterministically.
+use super::fault_spec as spec;
+use super::{transact, Link, MdbBus, Stage, RESPONSE_NAK};
+use crate::error::*;
+use crate::proto::iodin::MdbFaults;
+use std::time::Duration;
+
+/// xorshift state must not be zero.
+const SEED_DEFAULT: u64 = 0x9e37_79b9_7f4a_7c15;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Fault {
+    Drop,
+    Checksum,
+    Nak,
+    Gap,
+    Garbage,
+}
+
+/// Parse env `iodin_mdb_faults` format: `seed=1,drop=10,gap_ms=20`.
+pub fn parse(s: &str) -> Result<MdbFaults> {
+    spec::parse(s).map_err(|e| ErrorKind::InvalidInput(e).into())
+}
+
+pub fn check(f: &MdbFaults) -> Result<()> {
+    spec::check(f).map_err(|e| ErrorKind::InvalidInput(e).into())
+}
+
+/// Wraps transport and damages responses it receives.
+pub struct FaultBus<B> {
+    inner: B,
+    faults: MdbFaults,
+    rng: u64,
+}
+
+impl<B: MdbBus + Link> FaultBus<B> {
+    pub fn new(inner: B, faults: MdbFaults) -> FaultBus<B> {
+        let rng = if faults.seed == 0 {
+            SEED_DEFAULT
+        } else {
+            faults.seed
+        };
+        FaultBus { inner, faults, rng }
+    }
+
+    fn random(&mut self) -> u64 {
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+        self.rng
+    }
+
+    fn pick(&mut self) -> Option<Fault> {
+        let f = &self.faults;
+        let chances = [
+            (f.drop, Fault::Drop),
+            (f.checksum, Fault::Checksum),
+            (f.nak, Fault::Nak),
+            (f.gap, Fault::Gap),
+            (f.garbage, Fault::Garbage),
+        ];
+        let mut x = (self.random() % 100) as u32;
+        for (chance, fault) in chances {
+            if x < chance {
+                return Some(fault);
+            }
+            x -= chance;
+        }
+        None
+    }
+}
+
+impl<B: MdbBus + Link> MdbBus for FaultBus<B> {
+    fn tx(&mut self, request: &[u8], response: &mut Vec<u8>, timeout: Duration) -> Result<()> {
+        transact(self, request, response, timeout)
+    }
+
+    fn bus_reset(&mut self, duration: Duration) -> Result<()> {
+        self.inner.bus_reset(duration)
+    }
+
+    fn idle(&mut self) -> Result<()> {
+        self.inner.idle()
+    }
+
+    fn close(&mut self) -> Result<()> {
+        self.inner.close()
+    }
+}
+
+impl<B: MdbBus + Link> Link for FaultBus<B> {
+    fn send(&mut self, block: &[u8]) -> Result<()> {
+        self.inner.send(block)
+    }
+
+    fn send_control(&mut self, byte: u8, timeout: Duration) -> Result<()> {
+        self.inner.send_control(byte, timeout)
+    }
+
+    fn receive(&mut self, timeout: Duration, response: &mut Vec<u8>) -> Result<u8> {
+        let start = response.len();
+        let end = self.inner.receive(timeout, response)?;
+        let fault = match self.pick() {
+            Some(f) => f,
+            None => return Ok(end),
+        };
+        debug!(
+            "mdb fault inject {:?} response={:02x?}",
+            fault,
+            &response[start..]
+        );
+        // random position inside received data
+        let at = start + (self.random() as usize) % (response.len() - start + 1);
+        match fault {
+            Fault::Drop => {
+                response.truncate(start);
+                Err(ErrorKind::MdbTimeout(Stage::Receive, response.clone()).into())
+            }
+            Fault::Checksum => Ok(end ^ (1 + (self.random() % 255) as u8)),
+            Fault::Nak => {
+                response.truncate(start);
+                Ok(RESPONSE_NAK)
+            }
+            Fault::Gap => {
+                let gap = Duration::from_millis(self.faults.gap_ms.into());
+                if gap == Duration::ZERO || gap >= timeout {
+                    std::thread::sleep(timeout);
+                    response.truncate(at);
+                    return Err(ErrorKind::MdbTimeout(Stage::Receive, response.clone()).into());
+                }
+                std::thread::sleep(gap);
+                Ok(end)
+            }
+            Fault::Garbage => {
+                response.truncate(at);
+                Ok(self.random() as u8)
+            }
+        }
+    }
+}
@@ -0,0 +1,37 @@
+//! MDB fault injection spec `seed=1,drop=10,gap_ms=20`, same format as server env `iodin_mdb_faults`.
+// Client includes this file too, see client/rust-iodin/src/lib.rs
+use crate::proto::iodin::MdbFaults;
+
+pub fn parse(s: &str) -> Result<MdbFaults, String> {
+    let mut f = MdbFaults::new();
+    for w in s.split(',').map(str::trim).filter(|w| !w.is_empty()) {
+        let (key, value) = w
+            .split_once('=')
+            .ok_or_else(|| format!("mdb faults: expected key=value, got {}", w))?;
+        let invalid = |e| format!("mdb faults: invalid {}={}: {}", key, value, e);
+        match key {
+            "seed" => f.seed = value.parse().map_err(invalid)?,
+            "drop" => f.drop = value.parse().map_err(invalid)?,
+            "checksum" => f.checksum = value.parse().map_err(invalid)?,
+            "nak" => f.nak = value.parse().map_err(invalid)?,
+            "gap" => f.gap = value.parse().map_err(invalid)?,
+            "garbage" => f.garbage = value.parse().map_err(invalid)?,
+            "gap_ms" => f.gap_ms = value.parse().map_err(invalid)?,
+            _ => return Err(format!("mdb faults: unknown key {}", key)),
+        }
+    }
+    check(&f)?;
+    Ok(f)
+}
+
+/// Fault chances are percent, total must not exceed 100.
+pub fn check(f: &MdbFaults) -> Result<(), String> {
+    let total: u64 = [f.drop, f.checksum, f.nak, f.gap, f.garbage]
+        .iter()
+        .map(|&x| u64::from(x))
+        .sum();
+    if total > 100 {
+        return Err(format!("mdb faults total={}% exceeds 100%", total));
+    }
+    Ok(())
+}
@@ -0,0 +1,203 @@
+//! Simulated MDB bus with virtual peripherals, no hardware required.
+//! Peripherals answer VMC commands, but customer actions (coins, bills, cards)
+//! are only available to tests, so in mock mode POLL never reports credit.
+use super::{checksum, transact, Link, MdbBus, Stage, RESPONSE_ACK, RESPONSE_NAK, RESPONSE_RET};
+use crate::error::*;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+mod bill;
+mod cashless;
+mod coin;
+
+pub use self::bill::BillValidator;
+pub use self::cashless::Cashless;
+#[cfg(test)]
+pub use self::cashless::CashlessState;
+pub use self::coin::CoinChanger;
+
+/// Max length of POLL response data.
+const POLL_MAX: usize = 16;
+
+/// Peripheral answer to one request.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Reply {
+    Ack,
+    Nak,
+    Data(Vec<u8>),
+    /// No answer, VMC times out.
+    Silent,
+}
+
+/// Virtual device attached to `SimBus`.
+pub trait Peripheral: Send {
+    /// Base address, i.e. 0x08 for coin changer.
+    fn address(&self) -> u8;
+    /// Bus reset, RESET command is handled by `request`.
+    fn reset(&mut self);
+    /// Request addressed to this peripheral, checksum is verified and stripped.
+    fn request(&mut self, request: &[u8]) -> Reply;
+    /// VMC confirmed last `Reply::Data` with ACK.
+    fn ack(&mut self) {}
+}
+
+/// Byte level simulation of MDB wire: blocks with bad checksum are ignored like
+/// real peripherals do, RET repeats last data block.
+pub struct SimBus {
+    peripherals: Vec<Box<dyn Peripheral>>,
+    // data and mode bit byte peripheral sends on next receive
+    pending: Option<(Vec<u8>, u8)>,
+    last_data: Option<(Vec<u8>, u8)>,
+    // index of peripheral in current transaction
+    current: Option<usize>,
+}
+
+impl SimBus {
+    pub fn new(peripherals: Vec<Box<dyn Peripheral>>) -> SimBus {
+        SimBus {
+            peripherals,
+            pending: None,
+            last_data: None,
+            current: None,
+        }
+    }
+
+    /// Vending machine bus: coin changer, bill validator and cashless reader.
+    /// Used by mock mode and MDB_OPEN transport=SIM.
+    pub fn vending() -> SimBus {
+        SimBus::new(vec![
+            Box::new(CoinChanger::new()),
+            Box::new(BillValidator::new()),
+            Box::new(Cashless::new()),
+        ])
+    }
+}
+
+impl MdbBus for SimBus {
+    fn tx(&mut self, request: &[u8], response: &mut Vec<u8>, timeout: Duration) -> Result<()> {
+        transact(self, request, response, timeout)
+    }
+
+    fn bus_reset(&mut self, _duration: Duration) -> Result<()> {
+        for p in self.peripherals.iter_mut() {
+            p.reset();
+        }
+        Ok(())
+    }
+
+    fn close(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl Link for SimBus {
+    fn send(&mut self, block: &[u8]) -> Result<()> {
+        self.pending = None;
+        self.current = None;
+        let (request, chk) = match block.split_last() {
+            Some((&chk, request)) if !request.is_empty() => (request, chk),
+            _ => return Ok(()),
+        };
+        if checksum(request) != chk {
+            debug!("mdb sim ignore bad checksum block={:02x?}", block);
+            return Ok(());
+        }
+        let address = request[0] & 0xf8;
+        let reply = match self.peripherals.iter().position(|p| p.address() == address) {
+            Some(i) => {
+                self.current = Some(i);
+                self.peripherals[i].request(request)
+            }
+            None => Reply::Silent,
+        };
+        self.pending = match reply {
+            Reply::Ack => Some((Vec::new(), RESPONSE_ACK)),
+            Reply::Nak => Some((Vec::new(), RESPONSE_NAK)),
+            Reply::Data(data) => {
+                let chk = checksum(&data);
+                self.last_data = Some((data.clone(), chk));
+                Some((data, chk))
+            }
+            Reply::Silent => None,
+        };
+        Ok(())
+    }
+
+    fn send_control(&mut self, byte: u8, _timeout: Duration) -> Result<()> {
+        match byte {
+            RESPONSE_ACK => {
+                if let Some(i) = self.current.take() {
+                    self.peripherals[i].ack();
+                }
+            }
+            RESPONSE_NAK => self.current = None,
+            RESPONSE_RET => self.pending = self.last_data.clone(),
+            _ => return Err(format!("mdb invalid control byte={:02x}", byte).into()),
+        }
+        Ok(())
+    }
+
+    fn receive(&mut self, _timeout: Duration, response: &mut Vec<u8>) -> Result<u8> {
+        match self.pending.take() {
+            Some((data, end)) => {
+                response.extend_from_slice(&data);
+                Ok(end)
+            }
+            None => Err(ErrorKind::MdbTimeout(Stage::Receive, response.clone()).into()),
+        }
+    }
+}
+
+/// POLL activity queue. Events stay queued until VMC confirms POLL data with ACK.
+#[derive(Debug, Default)]
+struct Events {
+    queue: VecDeque<Vec<u8>>,
+    // events in last POLL response
+    sent: usize,
+}
+
+impl Events {
+    fn push(&mut self, event: &[u8]) {
+        self.queue.push_back(event.to_vec());
+    }
+
+    fn clear(&mut self) {
+        self.queue.clear();
+        self.sent = 0;
+    }
+
+    /// Called on every request, ACK after non-POLL data confirms nothing.
+    fn forget(&mut self) {
+        self.sent = 0;
+    }
+
+    fn poll(&mut self) -> Reply {
+        let mut data = Vec::new();
+        self.sent = 0;
+        for e in &self.queue {
+            if data.len() + e.len() > POLL_MAX {
+                break;
+            }
+            data.extend_from_slice(e);
+            self.sent += 1;
+        }
+        if data.is_empty() {
+            Reply::Ack
+        } else {
+            Reply::Data(data)
+        }
+    }
+
+    fn ack(&mut self) {
+        self.queue.drain(..self.sent);
+        self.sent = 0;
+    }
+}
+
+/// Expansion identification: manufacturer code, serial number, model, software version.
+fn identification(model: &str) -> Vec<u8> {
+    let mut r = b"IOD000000000001".to_vec();
+    r.extend(model.bytes().chain(std::iter::repeat(b' ')).take(12));
+    r.extend_from_slice(&[0x01, 0x00]);
+    r
+}
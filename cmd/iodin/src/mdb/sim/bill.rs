@@ -0,0 +1,129 @@
+//! Bill validator, feature level 1 with level 2 identification.
+use super::{identification, Events, Peripheral, Reply};
+
+const ADDRESS: u8 = 0x30;
+const STACKER_CAPACITY: u16 = 500;
+/// Bill type credit in units of scaling factor (1 dollar): $1, $5, $10, $20.
+const CREDIT: [u8; 4] = [1, 5, 10, 20];
+
+pub struct BillValidator {
+    events: Events,
+    enabled: u16,
+    escrow_enabled: u16,
+    // bill type held in escrow
+    escrow: Option<u8>,
+    stacker: u16,
+}
+
+impl BillValidator {
+    pub fn new() -> BillValidator {
+        let mut b = BillValidator {
+            events: Events::default(),
+            enabled: 0,
+            escrow_enabled: 0,
+            escrow: None,
+            stacker: 0,
+        };
+        b.reset();
+        b
+    }
+
+    fn setup(&self) -> Vec<u8> {
+        // level 1, USD, scaling factor 100, 2 decimal places
+        let mut r = vec![1, 0x00, 0x01, 0x00, 100, 2];
+        r.extend_from_slice(&STACKER_CAPACITY.to_be_bytes());
+        // security levels, escrow capable
+        r.extend_from_slice(&[0x00, 0x00, 0xff]);
+        let mut credit = [0; 16];
+        credit[..CREDIT.len()].copy_from_slice(&CREDIT);
+        r.extend_from_slice(&credit);
+        r
+    }
+
+    fn stack(&mut self, t: u8) {
+        self.stacker += 1;
+        self.events.push(&[0x80 | t]);
+    }
+
+    fn stacker_status(&self) -> Vec<u8> {
+        let full = if self.stacker >= STACKER_CAPACITY {
+            0x8000
+        } else {
+            0
+        };
+        (full | self.stacker).to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+impl BillValidator {
+    /// Customer inserts bill of `bill_type`.
+    pub fn insert(&mut self, bill_type: u8) {
+        let t = bill_type & 0x0f;
+        if self.enabled & 1 << t == 0 {
+            self.events.push(&[0xc0 | t]); // disabled bill rejected
+        } else if self.escrow_enabled & 1 << t != 0 && self.escrow.is_none() {
+            self.escrow = Some(t);
+            self.events.push(&[0x90 | t]);
+        } else {
+            self.stack(t);
+        }
+    }
+
+    pub fn stacker(&self) -> u16 {
+        self.stacker
+    }
+}
+
+impl Peripheral for BillValidator {
+    fn address(&self) -> u8 {
+        ADDRESS
+    }
+
+    fn reset(&mut self) {
+        self.enabled = 0;
+        self.escrow_enabled = 0;
+        self.escrow = None;
+        self.events.clear();
+        self.events.push(&[0x06]); // JUST RESET
+    }
+
+    fn request(&mut self, request: &[u8]) -> Reply {
+        self.events.forget();
+        match (request[0] & 0x07, &request[1..]) {
+            (0, []) => {
+                self.reset();
+                Reply::Ack
+            }
+            (1, []) => Reply::Data(self.setup()),
+            (2, [_, _]) => Reply::Ack,
+            (3, []) => self.events.poll(),
+            (4, [a, b, c, d]) => {
+                self.enabled = u16::from_be_bytes([*a, *b]);
+                self.escrow_enabled = u16::from_be_bytes([*c, *d]);
+                Reply::Ack
+            }
+            (5, [y]) => {
+                match self.escrow.take() {
+                    Some(t) if *y == 0x01 => self.stack(t),
+                    Some(t) => self.events.push(&[0xa0 | t]), // returned
+                    None => (),
+                }
+                Reply::Ack
+            }
+            (6, []) => Reply::Data(self.stacker_status()),
+            (7, [0x00]) => Reply::Data(identification("bill")),
+            (7, [0x01, _, _, _, _]) => Reply::Ack,
+            (7, [0x02]) => {
+                let mut r = identification("bill");
+                r.extend_from_slice(&[0, 0, 0, 0]);
+                Reply::Data(r)
+            }
+            _ => Reply::Nak,
+        }
+    }
+
+    fn ack(&mut self) {
+        self.events.ack();
+    }
+}
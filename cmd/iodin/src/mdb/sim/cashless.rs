@@ -0,0 +1,188 @@
+//! Cashless reader #1, feature level 1.
+use super::{identification, Events, Peripheral, Reply};
+
+const ADDRESS: u8 = 0x10;
+
+/// Reader state as seen from the peripheral side.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CashlessState {
+    /// After reset, waits for SETUP.
+    Inactive,
+    Disabled,
+    Enabled,
+    /// Session started, waits for VEND REQUEST or SESSION COMPLETE.
+    SessionIdle,
+    /// Vend approved, waits for VEND SUCCESS or FAILURE.
+    Vend,
+}
+
+pub struct Cashless {
+    events: Events,
+    state: CashlessState,
+    // card presented while reader was not enabled
+    card: Option<u16>,
+    funds: u16,
+    price: u16,
+}
+
+impl Cashless {
+    pub fn new() -> Cashless {
+        let mut c = Cashless {
+            events: Events::default(),
+            state: CashlessState::Inactive,
+            card: None,
+            funds: 0,
+            price: 0,
+        };
+        c.reset();
+        c
+    }
+
+    fn begin_session(&mut self) {
+        if self.state != CashlessState::Enabled {
+            return;
+        }
+        if let Some(funds) = self.card.take() {
+            self.funds = funds;
+            self.state = CashlessState::SessionIdle;
+            let f = funds.to_be_bytes();
+            self.events.push(&[0x03, f[0], f[1]]);
+        }
+    }
+
+    fn vend_request(&mut self, price: u16) {
+        if self.state != CashlessState::SessionIdle {
+            self.events.push(&[0x0b]); // command out of sequence
+        } else if price > self.funds {
+            self.events.push(&[0x06]); // vend denied
+        } else {
+            self.price = price;
+            self.state = CashlessState::Vend;
+            let p = price.to_be_bytes();
+            self.events.push(&[0x05, p[0], p[1]]);
+        }
+    }
+
+    fn vend_end(&mut self, success: bool) {
+        if self.state == CashlessState::Vend {
+            if success {
+                self.funds -= self.price;
+            }
+            self.state = CashlessState::SessionIdle;
+        }
+    }
+
+    fn session_complete(&mut self) {
+        match self.state {
+            CashlessState::SessionIdle | CashlessState::Vend => {
+                self.state = CashlessState::Enabled;
+                self.funds = 0;
+                self.events.push(&[0x07]); // end session
+            }
+            _ => self.events.push(&[0x0b]),
+        }
+    }
+}
+
+#[cfg(test)]
+impl Cashless {
+    /// Customer presents card with `funds`, session begins when reader is enabled.
+    pub fn present(&mut self, funds: u16) {
+        self.card = Some(funds);
+        self.begin_session();
+    }
+
+    pub fn state(&self) -> CashlessState {
+        self.state
+    }
+
+    pub fn funds(&self) -> u16 {
+        self.funds
+    }
+}
+
+impl Peripheral for Cashless {
+    fn address(&self) -> u8 {
+        ADDRESS
+    }
+
+    fn reset(&mut self) {
+        self.state = CashlessState::Inactive;
+        self.funds = 0;
+        self.events.clear();
+        self.events.push(&[0x00]); // JUST RESET
+    }
+
+    fn request(&mut self, request: &[u8]) -> Reply {
+        use CashlessState::*;
+        self.events.forget();
+        match (request[0] & 0x07, &request[1..]) {
+            (0, []) => {
+                self.reset();
+                Reply::Ack
+            }
+            // config data: level 1, USD, scale factor 1, 2 decimal places,
+            // max response time 5s, no options
+            (1, [0x00, _, _, _, _]) => {
+                if self.state == Inactive {
+                    self.state = Disabled;
+                }
+                Reply::Data(vec![0x01, 1, 0x00, 0x01, 1, 2, 5, 0x00])
+            }
+            // max/min prices
+            (1, [0x01, _, _, _, _]) => Reply::Ack,
+            (2, []) => self.events.poll(),
+            (3, [0x00, p0, p1, _, _]) => {
+                self.vend_request(u16::from_be_bytes([*p0, *p1]));
+                Reply::Ack
+            }
+            // vend cancel is answered with vend denied
+            (3, [0x01]) => {
+                self.vend_end(false);
+                Reply::Data(vec![0x06])
+            }
+            (3, [0x02, _, _]) => {
+                self.vend_end(true);
+                Reply::Ack
+            }
+            (3, [0x03]) => {
+                self.vend_end(false);
+                Reply::Ack
+            }
+            (3, [0x04]) => {
+                self.session_complete();
+                Reply::Ack
+            }
+            // cash sale
+            (3, [0x05, _, _, _, _]) => Reply::Ack,
+            (4, [0x00]) => {
+                if self.state == Enabled {
+                    self.state = Disabled;
+                }
+                Reply::Ack
+            }
+            (4, [0x01]) => {
+                if self.state == Disabled {
+                    self.state = Enabled;
+                    self.begin_session();
+                }
+                Reply::Ack
+            }
+            (4, [0x02]) => {
+                self.events.push(&[0x08]); // cancelled
+                Reply::Ack
+            }
+            // request ID with 29 bytes of VMC identification
+            (7, [0x00, vmc @ ..]) if vmc.len() == 29 => {
+                let mut r = vec![0x09];
+                r.extend(identification("cashless"));
+                Reply::Data(r)
+            }
+            _ => Reply::Nak,
+        }
+    }
+
+    fn ack(&mut self) {
+        self.events.ack();
+    }
+}
@@ -0,0 +1,122 @@
+//! Coin changer, feature level 3.
+use super::{identification, Events, Peripheral, Reply};
+
+const ADDRESS: u8 = 0x08;
+/// Coins in tube to report it full.
+const TUBE_CAPACITY: u8 = 50;
+/// Coin type credit in units of scaling factor (5 cents): 5c, 10c, 25c, $1.
+const CREDIT: [u8; 4] = [1, 2, 5, 20];
+/// Coin types routed to tubes, others go to cash box.
+const ROUTING: u16 = 0b0111;
+
+pub struct CoinChanger {
+    events: Events,
+    tubes: [u8; 16],
+    enabled: u16,
+}
+
+impl CoinChanger {
+    pub fn new() -> CoinChanger {
+        let mut c = CoinChanger {
+            events: Events::default(),
+            tubes: [0; 16],
+            enabled: 0,
+        };
+        c.tubes[..3].copy_from_slice(&[20, 20, 20]);
+        c.reset();
+        c
+    }
+
+    fn setup(&self) -> Vec<u8> {
+        // level 3, USD, scaling factor 5, 2 decimal places
+        let mut r = vec![3, 0x00, 0x01, 5, 2];
+        r.extend_from_slice(&ROUTING.to_be_bytes());
+        let mut credit = [0; 16];
+        credit[..CREDIT.len()].copy_from_slice(&CREDIT);
+        r.extend_from_slice(&credit);
+        r
+    }
+
+    fn tube_status(&self) -> Vec<u8> {
+        let full = (0..16)
+            .filter(|&i| self.tubes[i] >= TUBE_CAPACITY)
+            .fold(0u16, |m, i| m | 1 << i);
+        let mut r = full.to_be_bytes().to_vec();
+        r.extend_from_slice(&self.tubes);
+        r
+    }
+
+    fn dispense(&mut self, y: u8) {
+        let t = (y & 0x0f) as usize;
+        self.tubes[t] -= (y >> 4).min(self.tubes[t]);
+    }
+}
+
+#[cfg(test)]
+impl CoinChanger {
+    /// Customer inserts coin of `coin_type`.
+    pub fn deposit(&mut self, coin_type: u8) {
+        let t = (coin_type & 0x0f) as usize;
+        let routing = if self.enabled & 1 << t == 0 {
+            3 // reject
+        } else if ROUTING & 1 << t != 0 && self.tubes[t] < TUBE_CAPACITY {
+            self.tubes[t] += 1;
+            1 // tube
+        } else {
+            0 // cash box
+        };
+        self.events
+            .push(&[0x40 | routing << 4 | t as u8, self.tubes[t]]);
+    }
+
+    pub fn tube(&self, coin_type: u8) -> u8 {
+        self.tubes[(coin_type & 0x0f) as usize]
+    }
+}
+
+impl Peripheral for CoinChanger {
+    fn address(&self) -> u8 {
+        ADDRESS
+    }
+
+    fn reset(&mut self) {
+        self.enabled = 0;
+        self.events.clear();
+        self.events.push(&[0x0b]); // JUST RESET
+    }
+
+    fn request(&mut self, request: &[u8]) -> Reply {
+        self.events.forget();
+        match (request[0] & 0x07, &request[1..]) {
+            (0, []) => {
+                self.reset();
+                Reply::Ack
+            }
+            (1, []) => Reply::Data(self.setup()),
+            (2, []) => Reply::Data(self.tube_status()),
+            (3, []) => self.events.poll(),
+            (4, [a, b, _, _]) => {
+                self.enabled = u16::from_be_bytes([*a, *b]);
+                Reply::Ack
+            }
+            (5, [y]) => {
+                self.dispense(*y);
+                Reply::Ack
+            }
+            // expansion identification with optional features
+            (7, [0x00]) => {
+                let mut r = identification("coin");
+                r.extend_from_slice(&[0, 0, 0, 0]);
+                Reply::Data(r)
+            }
+            (7, [0x01, _, _, _, _]) => Reply::Ack,
+            // diagnostic status: changer fully operational
+            (7, [0x05]) => Reply::Data(vec![0x03, 0x00]),
+            _ => Reply::Nak,
+        }
+    }
+
+    fn ack(&mut self) {
+        self.events.ack();
+    }
+}
@@ -0,0 +1,202 @@
+//! MDB over Linux tty, 9th (mode) bit is carried by mark/space parity.
+//! VMC sends address byte with mark parity and the rest with space parity.
+//! Input uses space parity with PARMRK so peripheral byte with mode bit set
+//! arrives as parity error `ff 00 x`, literal `ff` as `ff ff`.
+use super::{transact, Link, MdbBus, Stage};
+use crate::error::*;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::os::unix::fs::OpenOptionsExt;
+use std::os::unix::io::AsRawFd;
+use std::path::Path;
+use std::time::Duration;
+
+/// Max silence between bytes of peripheral block. MDB allows 1ms,
+/// USB serial adapters deliver input in batches so there is slack.
+const RECEIVE_GAP: Duration = Duration::from_millis(20);
+
+pub struct UartMdb {
+    file: File,
+    decoder: Parmrk,
+}
+
+impl UartMdb {
+    pub fn open(path: &Path) -> Result<UartMdb> {
+        debug!("UartMdb::open {}", path.display());
+        // O_NONBLOCK so open does not wait for modem lines
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .custom_flags(libc::O_NOCTTY | libc::O_NONBLOCK)
+            .open(path)?;
+        let fd = file.as_raw_fd();
+        let flags = check(unsafe { libc::fcntl(fd, libc::F_GETFL) })?;
+        check(unsafe { libc::fcntl(fd, libc::F_SETFL, flags & !libc::O_NONBLOCK) })?;
+
+        let mut t = tcgetattr(&file)?;
+        unsafe { libc::cfmakeraw(&mut t) };
+        check(unsafe { libc::cfsetspeed(&mut t, libc::B9600) })?;
+        t.c_cflag |= libc::CS8 | libc::CREAD | libc::CLOCAL | libc::PARENB | libc::CMSPAR;
+        t.c_cflag &= !(libc::CSTOPB | libc::PARODD | libc::CRTSCTS);
+        t.c_iflag |= libc::INPCK | libc::PARMRK;
+        t.c_iflag &= !(libc::IGNPAR | libc::ISTRIP | libc::IXON | libc::IXOFF);
+        // reads return immediately, waiting is done with poll
+        t.c_cc[libc::VMIN] = 0;
+        t.c_cc[libc::VTIME] = 0;
+        check(unsafe { libc::tcsetattr(fd, libc::TCSANOW, &t) })?;
+        check(unsafe { libc::tcflush(fd, libc::TCIOFLUSH) })?;
+        Ok(UartMdb {
+            file,
+            decoder: Parmrk::default(),
+        })
+    }
+
+    /// Mode bit for following writes: mark parity sets it, space clears.
+    fn set_mode_bit(&mut self, mode: bool) -> Result<()> {
+        let mut t = tcgetattr(&self.file)?;
+        if mode {
+            t.c_cflag |= libc::PARODD;
+        } else {
+            t.c_cflag &= !libc::PARODD;
+        }
+        // TCSADRAIN: bytes already written keep their parity
+        check(unsafe { libc::tcsetattr(self.file.as_raw_fd(), libc::TCSADRAIN, &t) })?;
+        Ok(())
+    }
+
+    fn write_drain(&mut self, b: &[u8]) -> Result<()> {
+        self.file.write_all(b)?;
+        check(unsafe { libc::tcdrain(self.file.as_raw_fd()) })?;
+        Ok(())
+    }
+
+    /// Wait up to `timeout` for input, false on timeout.
+    fn poll_read(&self, timeout: Duration) -> Result<bool> {
+        let mut pfd = libc::pollfd {
+            fd: self.file.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        // round up so sub-millisecond timeout does not become 0
+        let ms = (timeout.as_micros() + 999) / 1000;
+        let ms = ms.min(libc::c_int::MAX as u128) as libc::c_int;
+        loop {
+            match check(unsafe { libc::poll(&mut pfd, 1, ms) }) {
+                Ok(n) => return Ok(n > 0),
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+}
+
+impl MdbBus for UartMdb {
+    fn tx(&mut self, request: &[u8], response: &mut Vec<u8>, timeout: Duration) -> Result<()> {
+        debug!("mdb uart tx request={:02x?} timeout={:?}", request, timeout);
+        transact(self, request, response, timeout)
+    }
+
+    /// Bus reset is TX held active, that is break condition.
+    fn bus_reset(&mut self, duration: Duration) -> Result<()> {
+        let fd = self.file.as_raw_fd();
+        check(unsafe { libc::ioctl(fd, libc::TIOCSBRK) })?;
+        std::thread::sleep(duration);
+        check(unsafe { libc::ioctl(fd, libc::TIOCCBRK) })?;
+        Ok(())
+    }
+
+    fn idle(&mut self) -> Result<()> {
+        check(unsafe { libc::tcdrain(self.file.as_raw_fd()) })?;
+        Ok(())
+    }
+
+    fn close(&mut self) -> Result<()> {
+        check(unsafe { libc::tcflush(self.file.as_raw_fd(), libc::TCIOFLUSH) })?;
+        Ok(())
+    }
+}
+
+impl Link for UartMdb {
+    fn send(&mut self, block: &[u8]) -> Result<()> {
+        // stale input would be taken for response
+        check(unsafe { libc::tcflush(self.file.as_raw_fd(), libc::TCIFLUSH) })?;
+        self.decoder = Parmrk::default();
+        self.set_mode_bit(true)?;
+        self.write_drain(&block[..1])?;
+        self.set_mode_bit(false)?;
+        self.write_drain(&block[1..])
+    }
+
+    fn send_control(&mut self, byte: u8, _timeout: Duration) -> Result<()> {
+        // parity is left at space after send
+        self.write_drain(&[byte])
+    }
+
+    fn receive(&mut self, timeout: Duration, response: &mut Vec<u8>) -> Result<u8> {
+        let mut wait = timeout;
+        let mut buf = [0u8; 64];
+        loop {
+            if !self.poll_read(wait)? {
+                return Err(ErrorKind::MdbTimeout(Stage::Receive, response.clone()).into());
+            }
+            let n = match self.file.read(&mut buf) {
+                Ok(n) => n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e.into()),
+            };
+            for (i, &b) in buf[..n].iter().enumerate() {
+                match self.decoder.push(b) {
+                    Some((x, true)) => {
+                        if i + 1 < n {
+                            debug!("mdb uart ignore after mode byte {:02x?}", &buf[i + 1..n]);
+                        }
+                        return Ok(x);
+                    }
+                    Some((x, false)) => response.push(x),
+                    None => (),
+                }
+            }
+            wait = RECEIVE_GAP;
+        }
+    }
+}
+
+/// Decoder state for input marked by PARMRK.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Parmrk {
+    #[default]
+    Data,
+    /// Got `ff`, next is `ff` for literal or `00` for marked byte.
+    Escape,
+    /// Got `ff 00`, next byte had parity error.
+    Marked,
+}
+
+impl Parmrk {
+    /// Feed one input byte, returns decoded byte and its mode bit.
+    pub fn push(&mut self, b: u8) -> Option<(u8, bool)> {
+        let (next, out) = match (*self, b) {
+            (Parmrk::Data, 0xff) => (Parmrk::Escape, None),
+            (Parmrk::Data, b) => (Parmrk::Data, Some((b, false))),
+            (Parmrk::Escape, 0x00) => (Parmrk::Marked, None),
+            // `ff ff` or invalid escape, keep the byte
+            (Parmrk::Escape, b) => (Parmrk::Data, Some((b, false))),
+            (Parmrk::Marked, b) => (Parmrk::Data, Some((b, true))),
+        };
+        *self = next;
+        out
+    }
+}
+
+fn tcgetattr(f: &File) -> io::Result<libc::termios> {
+    let mut t: libc::termios = unsafe { std::mem::zeroed() };
+    check(unsafe { libc::tcgetattr(f.as_raw_fd(), &mut t) })?;
+    Ok(t)
+}
+
+fn check(rc: libc::c_int) -> io::Result<libc::c_int> {
+    if rc < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(rc)
+}
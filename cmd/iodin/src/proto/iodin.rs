@@ -39,6 +39,10 @@ pub struct Request {
     pub arg_uint: u32,
     // @@protoc_insertion_point(field:iodin.Request.arg_bytes)
     pub arg_bytes: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:iodin.Request.id)
+    pub id: u32,
+    // message oneof groups
+    pub args: ::std::option::Option<request::Args>,
     // special fields
     // @@protoc_insertion_point(special_field:iodin.Request.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -55,44 +59,8028 @@ impl Request {
         ::std::default::Default::default()
     }
 
+    // .iodin.MdbOpen mdb_open = 10;
+
+    pub fn mdb_open(&self) -> &MdbOpen {
+        match self.args {
+            ::std::option::Option::Some(request::Args::MdbOpen(ref v)) => v,
+            _ => <MdbOpen as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_mdb_open(&mut self) {
+        self.args = ::std::option::Option::None;
+    }
+
+    pub fn has_mdb_open(&self) -> bool {
+        match self.args {
+            ::std::option::Option::Some(request::Args::MdbOpen(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mdb_open(&mut self, v: MdbOpen) {
+        self.args = ::std::option::Option::Some(request::Args::MdbOpen(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_mdb_open(&mut self) -> &mut MdbOpen {
+        if let ::std::option::Option::Some(request::Args::MdbOpen(_)) = self.args {
+        } else {
+            self.args = ::std::option::Option::Some(request::Args::MdbOpen(MdbOpen::new()));
+        }
+        match self.args {
+            ::std::option::Option::Some(request::Args::MdbOpen(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_mdb_open(&mut self) -> MdbOpen {
+        if self.has_mdb_open() {
+            match self.args.take() {
+                ::std::option::Option::Some(request::Args::MdbOpen(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            MdbOpen::new()
+        }
+    }
+
+    // .iodin.MdbReset mdb_reset = 11;
+
+    pub fn mdb_reset(&self) -> &MdbReset {
+        match self.args {
+            ::std::option::Option::Some(request::Args::MdbReset(ref v)) => v,
+            _ => <MdbReset as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_mdb_reset(&mut self) {
+        self.args = ::std::option::Option::None;
+    }
+
+    pub fn has_mdb_reset(&self) -> bool {
+        match self.args {
+            ::std::option::Option::Some(request::Args::MdbReset(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mdb_reset(&mut self, v: MdbReset) {
+        self.args = ::std::option::Option::Some(request::Args::MdbReset(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_mdb_reset(&mut self) -> &mut MdbReset {
+        if let ::std::option::Option::Some(request::Args::MdbReset(_)) = self.args {
+        } else {
+            self.args = ::std::option::Option::Some(request::Args::MdbReset(MdbReset::new()));
+        }
+        match self.args {
+            ::std::option::Option::Some(request::Args::MdbReset(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_mdb_reset(&mut self) -> MdbReset {
+        if self.has_mdb_reset() {
+            match self.args.take() {
+                ::std::option::Option::Some(request::Args::MdbReset(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            MdbReset::new()
+        }
+    }
+
+    // .iodin.MdbTx mdb_tx = 12;
+
+    pub fn mdb_tx(&self) -> &MdbTx {
+        match self.args {
+            ::std::option::Option::Some(request::Args::MdbTx(ref v)) => v,
+            _ => <MdbTx as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_mdb_tx(&mut self) {
+        self.args = ::std::option::Option::None;
+    }
+
+    pub fn has_mdb_tx(&self) -> bool {
+        match self.args {
+            ::std::option::Option::Some(request::Args::MdbTx(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mdb_tx(&mut self, v: MdbTx) {
+        self.args = ::std::option::Option::Some(request::Args::MdbTx(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_mdb_tx(&mut self) -> &mut MdbTx {
+        if let ::std::option::Option::Some(request::Args::MdbTx(_)) = self.args {
+        } else {
+            self.args = ::std::option::Option::Some(request::Args::MdbTx(MdbTx::new()));
+        }
+        match self.args {
+            ::std::option::Option::Some(request::Args::MdbTx(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_mdb_tx(&mut self) -> MdbTx {
+        if self.has_mdb_tx() {
+            match self.args.take() {
+                ::std::option::Option::Some(request::Args::MdbTx(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            MdbTx::new()
+        }
+    }
+
+    // .iodin.MdbBatch mdb_batch = 13;
+
+    pub fn mdb_batch(&self) -> &MdbBatch {
+        match self.args {
+            ::std::option::Option::Some(request::Args::MdbBatch(ref v)) => v,
+            _ => <MdbBatch as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_mdb_batch(&mut self) {
+        self.args = ::std::option::Option::None;
+    }
+
+    pub fn has_mdb_batch(&self) -> bool {
+        match self.args {
+            ::std::option::Option::Some(request::Args::MdbBatch(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mdb_batch(&mut self, v: MdbBatch) {
+        self.args = ::std::option::Option::Some(request::Args::MdbBatch(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_mdb_batch(&mut self) -> &mut MdbBatch {
+        if let ::std::option::Option::Some(request::Args::MdbBatch(_)) = self.args {
+        } else {
+            self.args = ::std::option::Option::Some(request::Args::MdbBatch(MdbBatch::new()));
+        }
+        match self.args {
+            ::std::option::Option::Some(request::Args::MdbBatch(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_mdb_batch(&mut self) -> MdbBatch {
+        if self.has_mdb_batch() {
+            match self.args.take() {
+                ::std::option::Option::Some(request::Args::MdbBatch(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            MdbBatch::new()
+        }
+    }
+
+    // .iodin.Subscribe subscribe = 14;
+
+    pub fn subscribe(&self) -> &Subscribe {
+        match self.args {
+            ::std::option::Option::Some(request::Args::Subscribe(ref v)) => v,
+            _ => <Subscribe as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_subscribe(&mut self) {
+        self.args = ::std::option::Option::None;
+    }
+
+    pub fn has_subscribe(&self) -> bool {
+        match self.args {
+            ::std::option::Option::Some(request::Args::Subscribe(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_subscribe(&mut self, v: Subscribe) {
+        self.args = ::std::option::Option::Some(request::Args::Subscribe(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_subscribe(&mut self) -> &mut Subscribe {
+        if let ::std::option::Option::Some(request::Args::Subscribe(_)) = self.args {
+        } else {
+            self.args = ::std::option::Option::Some(request::Args::Subscribe(Subscribe::new()));
+        }
+        match self.args {
+            ::std::option::Option::Some(request::Args::Subscribe(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_subscribe(&mut self) -> Subscribe {
+        if self.has_subscribe() {
+            match self.args.take() {
+                ::std::option::Option::Some(request::Args::Subscribe(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Subscribe::new()
+        }
+    }
+
+    // .iodin.GpioWatch gpio_watch = 15;
+
+    pub fn gpio_watch(&self) -> &GpioWatch {
+        match self.args {
+            ::std::option::Option::Some(request::Args::GpioWatch(ref v)) => v,
+            _ => <GpioWatch as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_gpio_watch(&mut self) {
+        self.args = ::std::option::Option::None;
+    }
+
+    pub fn has_gpio_watch(&self) -> bool {
+        match self.args {
+            ::std::option::Option::Some(request::Args::GpioWatch(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gpio_watch(&mut self, v: GpioWatch) {
+        self.args = ::std::option::Option::Some(request::Args::GpioWatch(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_gpio_watch(&mut self) -> &mut GpioWatch {
+        if let ::std::option::Option::Some(request::Args::GpioWatch(_)) = self.args {
+        } else {
+            self.args = ::std::option::Option::Some(request::Args::GpioWatch(GpioWatch::new()));
+        }
+        match self.args {
+            ::std::option::Option::Some(request::Args::GpioWatch(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_gpio_watch(&mut self) -> GpioWatch {
+        if self.has_gpio_watch() {
+            match self.args.take() {
+                ::std::option::Option::Some(request::Args::GpioWatch(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            GpioWatch::new()
+        }
+    }
+
+    // .iodin.MdbPoll mdb_poll = 16;
+
+    pub fn mdb_poll(&self) -> &MdbPoll {
+        match self.args {
+            ::std::option::Option::Some(request::Args::MdbPoll(ref v)) => v,
+            _ => <MdbPoll as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_mdb_poll(&mut self) {
+        self.args = ::std::option::Option::None;
+    }
+
+    pub fn has_mdb_poll(&self) -> bool {
+        match self.args {
+            ::std::option::Option::Some(request::Args::MdbPoll(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mdb_poll(&mut self, v: MdbPoll) {
+        self.args = ::std::option::Option::Some(request::Args::MdbPoll(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_mdb_poll(&mut self) -> &mut MdbPoll {
+        if let ::std::option::Option::Some(request::Args::MdbPoll(_)) = self.args {
+        } else {
+            self.args = ::std::option::Option::Some(request::Args::MdbPoll(MdbPoll::new()));
+        }
+        match self.args {
+            ::std::option::Option::Some(request::Args::MdbPoll(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_mdb_poll(&mut self) -> MdbPoll {
+        if self.has_mdb_poll() {
+            match self.args.take() {
+                ::std::option::Option::Some(request::Args::MdbPoll(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            MdbPoll::new()
+        }
+    }
+
+    // .iodin.MdbCoin mdb_coin = 17;
+
+    pub fn mdb_coin(&self) -> &MdbCoin {
+        match self.args {
+            ::std::option::Option::Some(request::Args::MdbCoin(ref v)) => v,
+            _ => <MdbCoin as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_mdb_coin(&mut self) {
+        self.args = ::std::option::Option::None;
+    }
+
+    pub fn has_mdb_coin(&self) -> bool {
+        match self.args {
+            ::std::option::Option::Some(request::Args::MdbCoin(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mdb_coin(&mut self, v: MdbCoin) {
+        self.args = ::std::option::Option::Some(request::Args::MdbCoin(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_mdb_coin(&mut self) -> &mut MdbCoin {
+        if let ::std::option::Option::Some(request::Args::MdbCoin(_)) = self.args {
+        } else {
+            self.args = ::std::option::Option::Some(request::Args::MdbCoin(MdbCoin::new()));
+        }
+        match self.args {
+            ::std::option::Option::Some(request::Args::MdbCoin(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_mdb_coin(&mut self) -> MdbCoin {
+        if self.has_mdb_coin() {
+            match self.args.take() {
+                ::std::option::Option::Some(request::Args::MdbCoin(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            MdbCoin::new()
+        }
+    }
+
+    // .iodin.MdbBill mdb_bill = 18;
+
+    pub fn mdb_bill(&self) -> &MdbBill {
+        match self.args {
+            ::std::option::Option::Some(request::Args::MdbBill(ref v)) => v,
+            _ => <MdbBill as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_mdb_bill(&mut self) {
+        self.args = ::std::option::Option::None;
+    }
+
+    pub fn has_mdb_bill(&self) -> bool {
+        match self.args {
+            ::std::option::Option::Some(request::Args::MdbBill(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mdb_bill(&mut self, v: MdbBill) {
+        self.args = ::std::option::Option::Some(request::Args::MdbBill(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_mdb_bill(&mut self) -> &mut MdbBill {
+        if let ::std::option::Option::Some(request::Args::MdbBill(_)) = self.args {
+        } else {
+            self.args = ::std::option::Option::Some(request::Args::MdbBill(MdbBill::new()));
+        }
+        match self.args {
+            ::std::option::Option::Some(request::Args::MdbBill(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_mdb_bill(&mut self) -> MdbBill {
+        if self.has_mdb_bill() {
+            match self.args.take() {
+                ::std::option::Option::Some(request::Args::MdbBill(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            MdbBill::new()
+        }
+    }
+
+    // .iodin.MdbCashless mdb_cashless = 19;
+
+    pub fn mdb_cashless(&self) -> &MdbCashless {
+        match self.args {
+            ::std::option::Option::Some(request::Args::MdbCashless(ref v)) => v,
+            _ => <MdbCashless as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_mdb_cashless(&mut self) {
+        self.args = ::std::option::Option::None;
+    }
+
+    pub fn has_mdb_cashless(&self) -> bool {
+        match self.args {
+            ::std::option::Option::Some(request::Args::MdbCashless(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mdb_cashless(&mut self, v: MdbCashless) {
+        self.args = ::std::option::Option::Some(request::Args::MdbCashless(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_mdb_cashless(&mut self) -> &mut MdbCashless {
+        if let ::std::option::Option::Some(request::Args::MdbCashless(_)) = self.args {
+        } else {
+            self.args = ::std::option::Option::Some(request::Args::MdbCashless(MdbCashless::new()));
+        }
+        match self.args {
+            ::std::option::Option::Some(request::Args::MdbCashless(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_mdb_cashless(&mut self) -> MdbCashless {
+        if self.has_mdb_cashless() {
+            match self.args.take() {
+                ::std::option::Option::Some(request::Args::MdbCashless(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            MdbCashless::new()
+        }
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(16);
+        let mut oneofs = ::std::vec::Vec::with_capacity(1);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "version",
+            |m: &Request| { &m.version },
+            |m: &mut Request| { &mut m.version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "command",
+            |m: &Request| { &m.command },
+            |m: &mut Request| { &mut m.command },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "timeout",
+            |m: &Request| { &m.timeout },
+            |m: &mut Request| { &mut m.timeout },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "arg_uint",
+            |m: &Request| { &m.arg_uint },
+            |m: &mut Request| { &mut m.arg_uint },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "arg_bytes",
+            |m: &Request| { &m.arg_bytes },
+            |m: &mut Request| { &mut m.arg_bytes },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &Request| { &m.id },
+            |m: &mut Request| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, MdbOpen>(
+            "mdb_open",
+            Request::has_mdb_open,
+            Request::mdb_open,
+            Request::mut_mdb_open,
+            Request::set_mdb_open,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, MdbReset>(
+            "mdb_reset",
+            Request::has_mdb_reset,
+            Request::mdb_reset,
+            Request::mut_mdb_reset,
+            Request::set_mdb_reset,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, MdbTx>(
+            "mdb_tx",
+            Request::has_mdb_tx,
+            Request::mdb_tx,
+            Request::mut_mdb_tx,
+            Request::set_mdb_tx,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, MdbBatch>(
+            "mdb_batch",
+            Request::has_mdb_batch,
+            Request::mdb_batch,
+            Request::mut_mdb_batch,
+            Request::set_mdb_batch,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, Subscribe>(
+            "subscribe",
+            Request::has_subscribe,
+            Request::subscribe,
+            Request::mut_subscribe,
+            Request::set_subscribe,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, GpioWatch>(
+            "gpio_watch",
+            Request::has_gpio_watch,
+            Request::gpio_watch,
+            Request::mut_gpio_watch,
+            Request::set_gpio_watch,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, MdbPoll>(
+            "mdb_poll",
+            Request::has_mdb_poll,
+            Request::mdb_poll,
+            Request::mut_mdb_poll,
+            Request::set_mdb_poll,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, MdbCoin>(
+            "mdb_coin",
+            Request::has_mdb_coin,
+            Request::mdb_coin,
+            Request::mut_mdb_coin,
+            Request::set_mdb_coin,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, MdbBill>(
+            "mdb_bill",
+            Request::has_mdb_bill,
+            Request::mdb_bill,
+            Request::mut_mdb_bill,
+            Request::set_mdb_bill,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, MdbCashless>(
+            "mdb_cashless",
+            Request::has_mdb_cashless,
+            Request::mdb_cashless,
+            Request::mut_mdb_cashless,
+            Request::set_mdb_cashless,
+        ));
+        oneofs.push(request::Args::generated_oneof_descriptor_data());
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Request>(
+            "Request",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Request {
+    const NAME: &'static str = "Request";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.version = is.read_uint32()?;
+                },
+                16 => {
+                    self.command = is.read_enum_or_unknown()?;
+                },
+                24 => {
+                    self.timeout = is.read_uint32()?;
+                },
+                32 => {
+                    self.arg_uint = is.read_uint32()?;
+                },
+                42 => {
+                    self.arg_bytes = is.read_bytes()?;
+                },
+                48 => {
+                    self.id = is.read_uint32()?;
+                },
+                82 => {
+                    self.args = ::std::option::Option::Some(request::Args::MdbOpen(is.read_message()?));
+                },
+                90 => {
+                    self.args = ::std::option::Option::Some(request::Args::MdbReset(is.read_message()?));
+                },
+                98 => {
+                    self.args = ::std::option::Option::Some(request::Args::MdbTx(is.read_message()?));
+                },
+                106 => {
+                    self.args = ::std::option::Option::Some(request::Args::MdbBatch(is.read_message()?));
+                },
+                114 => {
+                    self.args = ::std::option::Option::Some(request::Args::Subscribe(is.read_message()?));
+                },
+                122 => {
+                    self.args = ::std::option::Option::Some(request::Args::GpioWatch(is.read_message()?));
+                },
+                130 => {
+                    self.args = ::std::option::Option::Some(request::Args::MdbPoll(is.read_message()?));
+                },
+                138 => {
+                    self.args = ::std::option::Option::Some(request::Args::MdbCoin(is.read_message()?));
+                },
+                146 => {
+                    self.args = ::std::option::Option::Some(request::Args::MdbBill(is.read_message()?));
+                },
+                154 => {
+                    self.args = ::std::option::Option::Some(request::Args::MdbCashless(is.read_message()?));
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.version != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.version);
+        }
+        if self.command != ::protobuf::EnumOrUnknown::new(request::Command::INVALID) {
+            my_size += ::protobuf::rt::int32_size(2, self.command.value());
+        }
+        if self.timeout != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.timeout);
+        }
+        if self.arg_uint != 0 {
+            my_size += ::protobuf::rt::uint32_size(4, self.arg_uint);
+        }
+        if !self.arg_bytes.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(5, &self.arg_bytes);
+        }
+        if self.id != 0 {
+            my_size += ::protobuf::rt::uint32_size(6, self.id);
+        }
+        if let ::std::option::Option::Some(ref v) = self.args {
+            match v {
+                &request::Args::MdbOpen(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Args::MdbReset(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Args::MdbTx(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Args::MdbBatch(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Args::Subscribe(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Args::GpioWatch(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Args::MdbPoll(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Args::MdbCoin(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Args::MdbBill(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Args::MdbCashless(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.version != 0 {
+            os.write_uint32(1, self.version)?;
+        }
+        if self.command != ::protobuf::EnumOrUnknown::new(request::Command::INVALID) {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.command))?;
+        }
+        if self.timeout != 0 {
+            os.write_uint32(3, self.timeout)?;
+        }
+        if self.arg_uint != 0 {
+            os.write_uint32(4, self.arg_uint)?;
+        }
+        if !self.arg_bytes.is_empty() {
+            os.write_bytes(5, &self.arg_bytes)?;
+        }
+        if self.id != 0 {
+            os.write_uint32(6, self.id)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.args {
+            match v {
+                &request::Args::MdbOpen(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(10, v, os)?;
+                },
+                &request::Args::MdbReset(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(11, v, os)?;
+                },
+                &request::Args::MdbTx(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(12, v, os)?;
+                },
+                &request::Args::MdbBatch(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(13, v, os)?;
+                },
+                &request::Args::Subscribe(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(14, v, os)?;
+                },
+                &request::Args::GpioWatch(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(15, v, os)?;
+                },
+                &request::Args::MdbPoll(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(16, v, os)?;
+                },
+                &request::Args::MdbCoin(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(17, v, os)?;
+                },
+                &request::Args::MdbBill(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(18, v, os)?;
+                },
+                &request::Args::MdbCashless(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(19, v, os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Request {
+        Request::new()
+    }
+
+    fn clear(&mut self) {
+        self.version = 0;
+        self.command = ::protobuf::EnumOrUnknown::new(request::Command::INVALID);
+        self.timeout = 0;
+        self.arg_uint = 0;
+        self.arg_bytes.clear();
+        self.id = 0;
+        self.args = ::std::option::Option::None;
+        self.args = ::std::option::Option::None;
+        self.args = ::std::option::Option::None;
+        self.args = ::std::option::Option::None;
+        self.args = ::std::option::Option::None;
+        self.args = ::std::option::Option::None;
+        self.args = ::std::option::Option::None;
+        self.args = ::std::option::Option::None;
+        self.args = ::std::option::Option::None;
+        self.args = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Request {
+        static instance: Request = Request {
+            version: 0,
+            command: ::protobuf::EnumOrUnknown::from_i32(0),
+            timeout: 0,
+            arg_uint: 0,
+            arg_bytes: ::std::vec::Vec::new(),
+            id: 0,
+            args: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Request {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Request").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Request {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Request {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `Request`
+pub mod request {
+
+    #[derive(Clone,PartialEq,Debug)]
+    #[non_exhaustive]
+    // @@protoc_insertion_point(oneof:iodin.Request.args)
+    pub enum Args {
+        // @@protoc_insertion_point(oneof_field:iodin.Request.mdb_open)
+        MdbOpen(super::MdbOpen),
+        // @@protoc_insertion_point(oneof_field:iodin.Request.mdb_reset)
+        MdbReset(super::MdbReset),
+        // @@protoc_insertion_point(oneof_field:iodin.Request.mdb_tx)
+        MdbTx(super::MdbTx),
+        // @@protoc_insertion_point(oneof_field:iodin.Request.mdb_batch)
+        MdbBatch(super::MdbBatch),
+        // @@protoc_insertion_point(oneof_field:iodin.Request.subscribe)
+        Subscribe(super::Subscribe),
+        // @@protoc_insertion_point(oneof_field:iodin.Request.gpio_watch)
+        GpioWatch(super::GpioWatch),
+        // @@protoc_insertion_point(oneof_field:iodin.Request.mdb_poll)
+        MdbPoll(super::MdbPoll),
+        // @@protoc_insertion_point(oneof_field:iodin.Request.mdb_coin)
+        MdbCoin(super::MdbCoin),
+        // @@protoc_insertion_point(oneof_field:iodin.Request.mdb_bill)
+        MdbBill(super::MdbBill),
+        // @@protoc_insertion_point(oneof_field:iodin.Request.mdb_cashless)
+        MdbCashless(super::MdbCashless),
+    }
+
+    impl ::protobuf::Oneof for Args {
+    }
+
+    impl ::protobuf::OneofFull for Args {
+        fn descriptor() -> ::protobuf::reflect::OneofDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::OneofDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| <super::Request as ::protobuf::MessageFull>::descriptor().oneof_by_name("args").unwrap()).clone()
+        }
+    }
+
+    impl Args {
+        pub(in super) fn generated_oneof_descriptor_data() -> ::protobuf::reflect::GeneratedOneofDescriptorData {
+            ::protobuf::reflect::GeneratedOneofDescriptorData::new::<Args>("args")
+        }
+    }
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:iodin.Request.Command)
+    pub enum Command {
+        // @@protoc_insertion_point(enum_value:iodin.Request.Command.INVALID)
+        INVALID = 0,
+        // @@protoc_insertion_point(enum_value:iodin.Request.Command.MDB_OPEN)
+        MDB_OPEN = 1,
+        // @@protoc_insertion_point(enum_value:iodin.Request.Command.MDB_RESET)
+        MDB_RESET = 2,
+        // @@protoc_insertion_point(enum_value:iodin.Request.Command.MDB_TX)
+        MDB_TX = 3,
+        // @@protoc_insertion_point(enum_value:iodin.Request.Command.HELLO)
+        HELLO = 4,
+        // @@protoc_insertion_point(enum_value:iodin.Request.Command.GET_INFO)
+        GET_INFO = 5,
+        // @@protoc_insertion_point(enum_value:iodin.Request.Command.MDB_BATCH)
+        MDB_BATCH = 6,
+        // @@protoc_insertion_point(enum_value:iodin.Request.Command.SUBSCRIBE)
+        SUBSCRIBE = 7,
+        // @@protoc_insertion_point(enum_value:iodin.Request.Command.GPIO_WATCH)
+        GPIO_WATCH = 8,
+        // @@protoc_insertion_point(enum_value:iodin.Request.Command.MDB_POLL_ADD)
+        MDB_POLL_ADD = 9,
+        // @@protoc_insertion_point(enum_value:iodin.Request.Command.MDB_POLL_REMOVE)
+        MDB_POLL_REMOVE = 10,
+        // @@protoc_insertion_point(enum_value:iodin.Request.Command.MDB_COIN)
+        MDB_COIN = 11,
+        // @@protoc_insertion_point(enum_value:iodin.Request.Command.MDB_BILL)
+        MDB_BILL = 12,
+        // @@protoc_insertion_point(enum_value:iodin.Request.Command.MDB_CASHLESS)
+        MDB_CASHLESS = 13,
+        // @@protoc_insertion_point(enum_value:iodin.Request.Command.STOP)
+        STOP = 100,
+    }
+
+    impl ::protobuf::Enum for Command {
+        const NAME: &'static str = "Command";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Command> {
+            match value {
+                0 => ::std::option::Option::Some(Command::INVALID),
+                1 => ::std::option::Option::Some(Command::MDB_OPEN),
+                2 => ::std::option::Option::Some(Command::MDB_RESET),
+                3 => ::std::option::Option::Some(Command::MDB_TX),
+                4 => ::std::option::Option::Some(Command::HELLO),
+                5 => ::std::option::Option::Some(Command::GET_INFO),
+                6 => ::std::option::Option::Some(Command::MDB_BATCH),
+                7 => ::std::option::Option::Some(Command::SUBSCRIBE),
+                8 => ::std::option::Option::Some(Command::GPIO_WATCH),
+                9 => ::std::option::Option::Some(Command::MDB_POLL_ADD),
+                10 => ::std::option::Option::Some(Command::MDB_POLL_REMOVE),
+                11 => ::std::option::Option::Some(Command::MDB_COIN),
+                12 => ::std::option::Option::Some(Command::MDB_BILL),
+                13 => ::std::option::Option::Some(Command::MDB_CASHLESS),
+                100 => ::std::option::Option::Some(Command::STOP),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Command] = &[
+            Command::INVALID,
+            Command::MDB_OPEN,
+            Command::MDB_RESET,
+            Command::MDB_TX,
+            Command::HELLO,
+            Command::GET_INFO,
+            Command::MDB_BATCH,
+            Command::SUBSCRIBE,
+            Command::GPIO_WATCH,
+            Command::MDB_POLL_ADD,
+            Command::MDB_POLL_REMOVE,
+            Command::MDB_COIN,
+            Command::MDB_BILL,
+            Command::MDB_CASHLESS,
+            Command::STOP,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Command {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("Request.Command").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = match self {
+                Command::INVALID => 0,
+                Command::MDB_OPEN => 1,
+                Command::MDB_RESET => 2,
+                Command::MDB_TX => 3,
+                Command::HELLO => 4,
+                Command::GET_INFO => 5,
+                Command::MDB_BATCH => 6,
+                Command::SUBSCRIBE => 7,
+                Command::GPIO_WATCH => 8,
+                Command::MDB_POLL_ADD => 9,
+                Command::MDB_POLL_REMOVE => 10,
+                Command::MDB_COIN => 11,
+                Command::MDB_BILL => 12,
+                Command::MDB_CASHLESS => 13,
+                Command::STOP => 14,
+            };
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Command {
+        fn default() -> Self {
+            Command::INVALID
+        }
+    }
+
+    impl Command {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Command>("Request.Command")
+        }
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.MdbOpen)
+pub struct MdbOpen {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.MdbOpen.rx_pin)
+    pub rx_pin: u32,
+    // @@protoc_insertion_point(field:iodin.MdbOpen.tx_pin)
+    pub tx_pin: u32,
+    // @@protoc_insertion_point(field:iodin.MdbOpen.options)
+    pub options: ::protobuf::MessageField<MdbOptions>,
+    // @@protoc_insertion_point(field:iodin.MdbOpen.transport)
+    pub transport: ::protobuf::EnumOrUnknown<mdb_open::Transport>,
+    // @@protoc_insertion_point(field:iodin.MdbOpen.device)
+    pub device: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.MdbOpen.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MdbOpen {
+    fn default() -> &'a MdbOpen {
+        <MdbOpen as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MdbOpen {
+    pub fn new() -> MdbOpen {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "rx_pin",
+            |m: &MdbOpen| { &m.rx_pin },
+            |m: &mut MdbOpen| { &mut m.rx_pin },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tx_pin",
+            |m: &MdbOpen| { &m.tx_pin },
+            |m: &mut MdbOpen| { &mut m.tx_pin },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, MdbOptions>(
+            "options",
+            |m: &MdbOpen| { &m.options },
+            |m: &mut MdbOpen| { &mut m.options },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "transport",
+            |m: &MdbOpen| { &m.transport },
+            |m: &mut MdbOpen| { &mut m.transport },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "device",
+            |m: &MdbOpen| { &m.device },
+            |m: &mut MdbOpen| { &mut m.device },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MdbOpen>(
+            "MdbOpen",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MdbOpen {
+    const NAME: &'static str = "MdbOpen";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.rx_pin = is.read_uint32()?;
+                },
+                16 => {
+                    self.tx_pin = is.read_uint32()?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.options)?;
+                },
+                32 => {
+                    self.transport = is.read_enum_or_unknown()?;
+                },
+                42 => {
+                    self.device = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.rx_pin != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.rx_pin);
+        }
+        if self.tx_pin != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.tx_pin);
+        }
+        if let Some(v) = self.options.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.transport != ::protobuf::EnumOrUnknown::new(mdb_open::Transport::DEFAULT) {
+            my_size += ::protobuf::rt::int32_size(4, self.transport.value());
+        }
+        if !self.device.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.device);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.rx_pin != 0 {
+            os.write_uint32(1, self.rx_pin)?;
+        }
+        if self.tx_pin != 0 {
+            os.write_uint32(2, self.tx_pin)?;
+        }
+        if let Some(v) = self.options.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        if self.transport != ::protobuf::EnumOrUnknown::new(mdb_open::Transport::DEFAULT) {
+            os.write_enum(4, ::protobuf::EnumOrUnknown::value(&self.transport))?;
+        }
+        if !self.device.is_empty() {
+            os.write_string(5, &self.device)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MdbOpen {
+        MdbOpen::new()
+    }
+
+    fn clear(&mut self) {
+        self.rx_pin = 0;
+        self.tx_pin = 0;
+        self.options.clear();
+        self.transport = ::protobuf::EnumOrUnknown::new(mdb_open::Transport::DEFAULT);
+        self.device.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MdbOpen {
+        static instance: MdbOpen = MdbOpen {
+            rx_pin: 0,
+            tx_pin: 0,
+            options: ::protobuf::MessageField::none(),
+            transport: ::protobuf::EnumOrUnknown::from_i32(0),
+            device: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for MdbOpen {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MdbOpen").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MdbOpen {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MdbOpen {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `MdbOpen`
+pub mod mdb_open {
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:iodin.MdbOpen.Transport)
+    pub enum Transport {
+        // @@protoc_insertion_point(enum_value:iodin.MdbOpen.Transport.DEFAULT)
+        DEFAULT = 0,
+        // @@protoc_insertion_point(enum_value:iodin.MdbOpen.Transport.GPIO)
+        GPIO = 1,
+        // @@protoc_insertion_point(enum_value:iodin.MdbOpen.Transport.SIM)
+        SIM = 2,
+        // @@protoc_insertion_point(enum_value:iodin.MdbOpen.Transport.UART)
+        UART = 3,
+    }
+
+    impl ::protobuf::Enum for Transport {
+        const NAME: &'static str = "Transport";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Transport> {
+            match value {
+                0 => ::std::option::Option::Some(Transport::DEFAULT),
+                1 => ::std::option::Option::Some(Transport::GPIO),
+                2 => ::std::option::Option::Some(Transport::SIM),
+                3 => ::std::option::Option::Some(Transport::UART),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Transport] = &[
+            Transport::DEFAULT,
+            Transport::GPIO,
+            Transport::SIM,
+            Transport::UART,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Transport {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("MdbOpen.Transport").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Transport {
+        fn default() -> Self {
+            Transport::DEFAULT
+        }
+    }
+
+    impl Transport {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Transport>("MdbOpen.Transport")
+        }
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.MdbOptions)
+pub struct MdbOptions {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.MdbOptions.wait_step_us)
+    pub wait_step_us: u32,
+    // @@protoc_insertion_point(field:iodin.MdbOptions.faults)
+    pub faults: ::protobuf::MessageField<MdbFaults>,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.MdbOptions.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MdbOptions {
+    fn default() -> &'a MdbOptions {
+        <MdbOptions as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MdbOptions {
+    pub fn new() -> MdbOptions {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "wait_step_us",
+            |m: &MdbOptions| { &m.wait_step_us },
+            |m: &mut MdbOptions| { &mut m.wait_step_us },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, MdbFaults>(
+            "faults",
+            |m: &MdbOptions| { &m.faults },
+            |m: &mut MdbOptions| { &mut m.faults },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MdbOptions>(
+            "MdbOptions",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MdbOptions {
+    const NAME: &'static str = "MdbOptions";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.wait_step_us = is.read_uint32()?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.faults)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.wait_step_us != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.wait_step_us);
+        }
+        if let Some(v) = self.faults.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.wait_step_us != 0 {
+            os.write_uint32(1, self.wait_step_us)?;
+        }
+        if let Some(v) = self.faults.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MdbOptions {
+        MdbOptions::new()
+    }
+
+    fn clear(&mut self) {
+        self.wait_step_us = 0;
+        self.faults.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MdbOptions {
+        static instance: MdbOptions = MdbOptions {
+            wait_step_us: 0,
+            faults: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for MdbOptions {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MdbOptions").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MdbOptions {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MdbOptions {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.MdbFaults)
+pub struct MdbFaults {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.MdbFaults.seed)
+    pub seed: u64,
+    // @@protoc_insertion_point(field:iodin.MdbFaults.drop)
+    pub drop: u32,
+    // @@protoc_insertion_point(field:iodin.MdbFaults.checksum)
+    pub checksum: u32,
+    // @@protoc_insertion_point(field:iodin.MdbFaults.nak)
+    pub nak: u32,
+    // @@protoc_insertion_point(field:iodin.MdbFaults.gap)
+    pub gap: u32,
+    // @@protoc_insertion_point(field:iodin.MdbFaults.garbage)
+    pub garbage: u32,
+    // @@protoc_insertion_point(field:iodin.MdbFaults.gap_ms)
+    pub gap_ms: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.MdbFaults.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MdbFaults {
+    fn default() -> &'a MdbFaults {
+        <MdbFaults as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MdbFaults {
+    pub fn new() -> MdbFaults {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(7);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "seed",
+            |m: &MdbFaults| { &m.seed },
+            |m: &mut MdbFaults| { &mut m.seed },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "drop",
+            |m: &MdbFaults| { &m.drop },
+            |m: &mut MdbFaults| { &mut m.drop },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "checksum",
+            |m: &MdbFaults| { &m.checksum },
+            |m: &mut MdbFaults| { &mut m.checksum },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "nak",
+            |m: &MdbFaults| { &m.nak },
+            |m: &mut MdbFaults| { &mut m.nak },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "gap",
+            |m: &MdbFaults| { &m.gap },
+            |m: &mut MdbFaults| { &mut m.gap },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "garbage",
+            |m: &MdbFaults| { &m.garbage },
+            |m: &mut MdbFaults| { &mut m.garbage },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "gap_ms",
+            |m: &MdbFaults| { &m.gap_ms },
+            |m: &mut MdbFaults| { &mut m.gap_ms },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MdbFaults>(
+            "MdbFaults",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MdbFaults {
+    const NAME: &'static str = "MdbFaults";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.seed = is.read_uint64()?;
+                },
+                16 => {
+                    self.drop = is.read_uint32()?;
+                },
+                24 => {
+                    self.checksum = is.read_uint32()?;
+                },
+                32 => {
+                    self.nak = is.read_uint32()?;
+                },
+                40 => {
+                    self.gap = is.read_uint32()?;
+                },
+                48 => {
+                    self.garbage = is.read_uint32()?;
+                },
+                56 => {
+                    self.gap_ms = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.seed != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.seed);
+        }
+        if self.drop != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.drop);
+        }
+        if self.checksum != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.checksum);
+        }
+        if self.nak != 0 {
+            my_size += ::protobuf::rt::uint32_size(4, self.nak);
+        }
+        if self.gap != 0 {
+            my_size += ::protobuf::rt::uint32_size(5, self.gap);
+        }
+        if self.garbage != 0 {
+            my_size += ::protobuf::rt::uint32_size(6, self.garbage);
+        }
+        if self.gap_ms != 0 {
+            my_size += ::protobuf::rt::uint32_size(7, self.gap_ms);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.seed != 0 {
+            os.write_uint64(1, self.seed)?;
+        }
+        if self.drop != 0 {
+            os.write_uint32(2, self.drop)?;
+        }
+        if self.checksum != 0 {
+            os.write_uint32(3, self.checksum)?;
+        }
+        if self.nak != 0 {
+            os.write_uint32(4, self.nak)?;
+        }
+        if self.gap != 0 {
+            os.write_uint32(5, self.gap)?;
+        }
+        if self.garbage != 0 {
+            os.write_uint32(6, self.garbage)?;
+        }
+        if self.gap_ms != 0 {
+            os.write_uint32(7, self.gap_ms)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MdbFaults {
+        MdbFaults::new()
+    }
+
+    fn clear(&mut self) {
+        self.seed = 0;
+        self.drop = 0;
+        self.checksum = 0;
+        self.nak = 0;
+        self.gap = 0;
+        self.garbage = 0;
+        self.gap_ms = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MdbFaults {
+        static instance: MdbFaults = MdbFaults {
+            seed: 0,
+            drop: 0,
+            checksum: 0,
+            nak: 0,
+            gap: 0,
+            garbage: 0,
+            gap_ms: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for MdbFaults {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MdbFaults").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MdbFaults {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MdbFaults {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.RetryPolicy)
+pub struct RetryPolicy {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.RetryPolicy.max_attempts)
+    pub max_attempts: u32,
+    // @@protoc_insertion_point(field:iodin.RetryPolicy.retry_on)
+    pub retry_on: ::std::vec::Vec<::protobuf::EnumOrUnknown<error_detail::Kind>>,
+    // @@protoc_insertion_point(field:iodin.RetryPolicy.backoff_ms)
+    pub backoff_ms: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.RetryPolicy.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a RetryPolicy {
+    fn default() -> &'a RetryPolicy {
+        <RetryPolicy as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RetryPolicy {
+    pub fn new() -> RetryPolicy {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "max_attempts",
+            |m: &RetryPolicy| { &m.max_attempts },
+            |m: &mut RetryPolicy| { &mut m.max_attempts },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "retry_on",
+            |m: &RetryPolicy| { &m.retry_on },
+            |m: &mut RetryPolicy| { &mut m.retry_on },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "backoff_ms",
+            |m: &RetryPolicy| { &m.backoff_ms },
+            |m: &mut RetryPolicy| { &mut m.backoff_ms },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RetryPolicy>(
+            "RetryPolicy",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for RetryPolicy {
+    const NAME: &'static str = "RetryPolicy";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.max_attempts = is.read_uint32()?;
+                },
+                16 => {
+                    self.retry_on.push(is.read_enum_or_unknown()?);
+                },
+                18 => {
+                    ::protobuf::rt::read_repeated_packed_enum_or_unknown_into(is, &mut self.retry_on)?
+                },
+                24 => {
+                    self.backoff_ms = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.max_attempts != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.max_attempts);
+        }
+        for value in &self.retry_on {
+            my_size += ::protobuf::rt::int32_size(2, value.value());
+        };
+        if self.backoff_ms != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.backoff_ms);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.max_attempts != 0 {
+            os.write_uint32(1, self.max_attempts)?;
+        }
+        for v in &self.retry_on {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(v))?;
+        };
+        if self.backoff_ms != 0 {
+            os.write_uint32(3, self.backoff_ms)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RetryPolicy {
+        RetryPolicy::new()
+    }
+
+    fn clear(&mut self) {
+        self.max_attempts = 0;
+        self.retry_on.clear();
+        self.backoff_ms = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RetryPolicy {
+        static instance: RetryPolicy = RetryPolicy {
+            max_attempts: 0,
+            retry_on: ::std::vec::Vec::new(),
+            backoff_ms: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for RetryPolicy {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RetryPolicy").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RetryPolicy {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RetryPolicy {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.MdbReset)
+pub struct MdbReset {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.MdbReset.duration_ms)
+    pub duration_ms: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.MdbReset.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MdbReset {
+    fn default() -> &'a MdbReset {
+        <MdbReset as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MdbReset {
+    pub fn new() -> MdbReset {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "duration_ms",
+            |m: &MdbReset| { &m.duration_ms },
+            |m: &mut MdbReset| { &mut m.duration_ms },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MdbReset>(
+            "MdbReset",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MdbReset {
+    const NAME: &'static str = "MdbReset";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.duration_ms = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.duration_ms != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.duration_ms);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.duration_ms != 0 {
+            os.write_uint32(1, self.duration_ms)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MdbReset {
+        MdbReset::new()
+    }
+
+    fn clear(&mut self) {
+        self.duration_ms = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MdbReset {
+        static instance: MdbReset = MdbReset {
+            duration_ms: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for MdbReset {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MdbReset").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MdbReset {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MdbReset {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.MdbTx)
+pub struct MdbTx {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.MdbTx.frame)
+    pub frame: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:iodin.MdbTx.timeout)
+    pub timeout: u32,
+    // @@protoc_insertion_point(field:iodin.MdbTx.retry)
+    pub retry: ::protobuf::MessageField<RetryPolicy>,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.MdbTx.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MdbTx {
+    fn default() -> &'a MdbTx {
+        <MdbTx as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MdbTx {
+    pub fn new() -> MdbTx {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "frame",
+            |m: &MdbTx| { &m.frame },
+            |m: &mut MdbTx| { &mut m.frame },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "timeout",
+            |m: &MdbTx| { &m.timeout },
+            |m: &mut MdbTx| { &mut m.timeout },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, RetryPolicy>(
+            "retry",
+            |m: &MdbTx| { &m.retry },
+            |m: &mut MdbTx| { &mut m.retry },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MdbTx>(
+            "MdbTx",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MdbTx {
+    const NAME: &'static str = "MdbTx";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.frame = is.read_bytes()?;
+                },
+                16 => {
+                    self.timeout = is.read_uint32()?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.retry)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.frame.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.frame);
+        }
+        if self.timeout != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.timeout);
+        }
+        if let Some(v) = self.retry.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.frame.is_empty() {
+            os.write_bytes(1, &self.frame)?;
+        }
+        if self.timeout != 0 {
+            os.write_uint32(2, self.timeout)?;
+        }
+        if let Some(v) = self.retry.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MdbTx {
+        MdbTx::new()
+    }
+
+    fn clear(&mut self) {
+        self.frame.clear();
+        self.timeout = 0;
+        self.retry.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MdbTx {
+        static instance: MdbTx = MdbTx {
+            frame: ::std::vec::Vec::new(),
+            timeout: 0,
+            retry: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for MdbTx {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MdbTx").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MdbTx {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MdbTx {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.Response)
+pub struct Response {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.Response.version)
+    pub version: u32,
+    // @@protoc_insertion_point(field:iodin.Response.status)
+    pub status: ::protobuf::EnumOrUnknown<response::Status>,
+    // @@protoc_insertion_point(field:iodin.Response.error)
+    pub error: ::std::string::String,
+    // @@protoc_insertion_point(field:iodin.Response.data_bytes)
+    pub data_bytes: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:iodin.Response.id)
+    pub id: u32,
+    // @@protoc_insertion_point(field:iodin.Response.hello)
+    pub hello: ::protobuf::MessageField<Hello>,
+    // @@protoc_insertion_point(field:iodin.Response.info)
+    pub info: ::protobuf::MessageField<Info>,
+    // @@protoc_insertion_point(field:iodin.Response.error_detail)
+    pub error_detail: ::protobuf::MessageField<ErrorDetail>,
+    // @@protoc_insertion_point(field:iodin.Response.batch)
+    pub batch: ::std::vec::Vec<BatchItem>,
+    // @@protoc_insertion_point(field:iodin.Response.event)
+    pub event: ::protobuf::MessageField<Event>,
+    // @@protoc_insertion_point(field:iodin.Response.attempts)
+    pub attempts: u32,
+    // @@protoc_insertion_point(field:iodin.Response.coin)
+    pub coin: ::protobuf::MessageField<MdbCoinResult>,
+    // @@protoc_insertion_point(field:iodin.Response.bill)
+    pub bill: ::protobuf::MessageField<MdbBillResult>,
+    // @@protoc_insertion_point(field:iodin.Response.cashless)
+    pub cashless: ::protobuf::MessageField<MdbCashlessResult>,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.Response.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Response {
+    fn default() -> &'a Response {
+        <Response as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Response {
+    pub fn new() -> Response {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(14);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "version",
+            |m: &Response| { &m.version },
+            |m: &mut Response| { &mut m.version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "status",
+            |m: &Response| { &m.status },
+            |m: &mut Response| { &mut m.status },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "error",
+            |m: &Response| { &m.error },
+            |m: &mut Response| { &mut m.error },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "data_bytes",
+            |m: &Response| { &m.data_bytes },
+            |m: &mut Response| { &mut m.data_bytes },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &Response| { &m.id },
+            |m: &mut Response| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Hello>(
+            "hello",
+            |m: &Response| { &m.hello },
+            |m: &mut Response| { &mut m.hello },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Info>(
+            "info",
+            |m: &Response| { &m.info },
+            |m: &mut Response| { &mut m.info },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ErrorDetail>(
+            "error_detail",
+            |m: &Response| { &m.error_detail },
+            |m: &mut Response| { &mut m.error_detail },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "batch",
+            |m: &Response| { &m.batch },
+            |m: &mut Response| { &mut m.batch },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Event>(
+            "event",
+            |m: &Response| { &m.event },
+            |m: &mut Response| { &mut m.event },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "attempts",
+            |m: &Response| { &m.attempts },
+            |m: &mut Response| { &mut m.attempts },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, MdbCoinResult>(
+            "coin",
+            |m: &Response| { &m.coin },
+            |m: &mut Response| { &mut m.coin },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, MdbBillResult>(
+            "bill",
+            |m: &Response| { &m.bill },
+            |m: &mut Response| { &mut m.bill },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, MdbCashlessResult>(
+            "cashless",
+            |m: &Response| { &m.cashless },
+            |m: &mut Response| { &mut m.cashless },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Response>(
+            "Response",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Response {
+    const NAME: &'static str = "Response";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.version = is.read_uint32()?;
+                },
+                16 => {
+                    self.status = is.read_enum_or_unknown()?;
+                },
+                26 => {
+                    self.error = is.read_string()?;
+                },
+                34 => {
+                    self.data_bytes = is.read_bytes()?;
+                },
+                40 => {
+                    self.id = is.read_uint32()?;
+                },
+                50 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.hello)?;
+                },
+                58 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.info)?;
+                },
+                66 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.error_detail)?;
+                },
+                74 => {
+                    self.batch.push(is.read_message()?);
+                },
+                82 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.event)?;
+                },
+                88 => {
+                    self.attempts = is.read_uint32()?;
+                },
+                98 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.coin)?;
+                },
+                106 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.bill)?;
+                },
+                114 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.cashless)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.version != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.version);
+        }
+        if self.status != ::protobuf::EnumOrUnknown::new(response::Status::INVALID) {
+            my_size += ::protobuf::rt::int32_size(2, self.status.value());
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.error);
+        }
+        if !self.data_bytes.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.data_bytes);
+        }
+        if self.id != 0 {
+            my_size += ::protobuf::rt::uint32_size(5, self.id);
+        }
+        if let Some(v) = self.hello.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.info.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.error_detail.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        for value in &self.batch {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if let Some(v) = self.event.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.attempts != 0 {
+            my_size += ::protobuf::rt::uint32_size(11, self.attempts);
+        }
+        if let Some(v) = self.coin.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.bill.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.cashless.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.version != 0 {
+            os.write_uint32(1, self.version)?;
+        }
+        if self.status != ::protobuf::EnumOrUnknown::new(response::Status::INVALID) {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.status))?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(3, &self.error)?;
+        }
+        if !self.data_bytes.is_empty() {
+            os.write_bytes(4, &self.data_bytes)?;
+        }
+        if self.id != 0 {
+            os.write_uint32(5, self.id)?;
+        }
+        if let Some(v) = self.hello.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
+        }
+        if let Some(v) = self.info.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(7, v, os)?;
+        }
+        if let Some(v) = self.error_detail.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(8, v, os)?;
+        }
+        for v in &self.batch {
+            ::protobuf::rt::write_message_field_with_cached_size(9, v, os)?;
+        };
+        if let Some(v) = self.event.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(10, v, os)?;
+        }
+        if self.attempts != 0 {
+            os.write_uint32(11, self.attempts)?;
+        }
+        if let Some(v) = self.coin.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(12, v, os)?;
+        }
+        if let Some(v) = self.bill.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(13, v, os)?;
+        }
+        if let Some(v) = self.cashless.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(14, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Response {
+        Response::new()
+    }
+
+    fn clear(&mut self) {
+        self.version = 0;
+        self.status = ::protobuf::EnumOrUnknown::new(response::Status::INVALID);
+        self.error.clear();
+        self.data_bytes.clear();
+        self.id = 0;
+        self.hello.clear();
+        self.info.clear();
+        self.error_detail.clear();
+        self.batch.clear();
+        self.event.clear();
+        self.attempts = 0;
+        self.coin.clear();
+        self.bill.clear();
+        self.cashless.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Response {
+        static instance: Response = Response {
+            version: 0,
+            status: ::protobuf::EnumOrUnknown::from_i32(0),
+            error: ::std::string::String::new(),
+            data_bytes: ::std::vec::Vec::new(),
+            id: 0,
+            hello: ::protobuf::MessageField::none(),
+            info: ::protobuf::MessageField::none(),
+            error_detail: ::protobuf::MessageField::none(),
+            batch: ::std::vec::Vec::new(),
+            event: ::protobuf::MessageField::none(),
+            attempts: 0,
+            coin: ::protobuf::MessageField::none(),
+            bill: ::protobuf::MessageField::none(),
+            cashless: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Response {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Response").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Response {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Response {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `Response`
+pub mod response {
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:iodin.Response.Status)
+    pub enum Status {
+        // @@protoc_insertion_point(enum_value:iodin.Response.Status.INVALID)
+        INVALID = 0,
+        // @@protoc_insertion_point(enum_value:iodin.Response.Status.OK)
+        OK = 1,
+        // @@protoc_insertion_point(enum_value:iodin.Response.Status.ERR_INPUT)
+        ERR_INPUT = 2,
+        // @@protoc_insertion_point(enum_value:iodin.Response.Status.ERR_INTERNAL)
+        ERR_INTERNAL = 3,
+        // @@protoc_insertion_point(enum_value:iodin.Response.Status.ERR_CHECKSUM)
+        ERR_CHECKSUM = 4,
+        // @@protoc_insertion_point(enum_value:iodin.Response.Status.ERR_TIMEOUT)
+        ERR_TIMEOUT = 5,
+        // @@protoc_insertion_point(enum_value:iodin.Response.Status.ERR_HARDWARE)
+        ERR_HARDWARE = 6,
+        // @@protoc_insertion_point(enum_value:iodin.Response.Status.ERR_NAK)
+        ERR_NAK = 7,
+    }
+
+    impl ::protobuf::Enum for Status {
+        const NAME: &'static str = "Status";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Status> {
+            match value {
+                0 => ::std::option::Option::Some(Status::INVALID),
+                1 => ::std::option::Option::Some(Status::OK),
+                2 => ::std::option::Option::Some(Status::ERR_INPUT),
+                3 => ::std::option::Option::Some(Status::ERR_INTERNAL),
+                4 => ::std::option::Option::Some(Status::ERR_CHECKSUM),
+                5 => ::std::option::Option::Some(Status::ERR_TIMEOUT),
+                6 => ::std::option::Option::Some(Status::ERR_HARDWARE),
+                7 => ::std::option::Option::Some(Status::ERR_NAK),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Status] = &[
+            Status::INVALID,
+            Status::OK,
+            Status::ERR_INPUT,
+            Status::ERR_INTERNAL,
+            Status::ERR_CHECKSUM,
+            Status::ERR_TIMEOUT,
+            Status::ERR_HARDWARE,
+            Status::ERR_NAK,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Status {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("Response.Status").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Status {
+        fn default() -> Self {
+            Status::INVALID
+        }
+    }
+
+    impl Status {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Status>("Response.Status")
+        }
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.Subscribe)
+pub struct Subscribe {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.Subscribe.kinds)
+    pub kinds: ::std::vec::Vec<::protobuf::EnumOrUnknown<event::Kind>>,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.Subscribe.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Subscribe {
+    fn default() -> &'a Subscribe {
+        <Subscribe as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Subscribe {
+    pub fn new() -> Subscribe {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "kinds",
+            |m: &Subscribe| { &m.kinds },
+            |m: &mut Subscribe| { &mut m.kinds },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Subscribe>(
+            "Subscribe",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Subscribe {
+    const NAME: &'static str = "Subscribe";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.kinds.push(is.read_enum_or_unknown()?);
+                },
+                10 => {
+                    ::protobuf::rt::read_repeated_packed_enum_or_unknown_into(is, &mut self.kinds)?
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.kinds {
+            my_size += ::protobuf::rt::int32_size(1, value.value());
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.kinds {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(v))?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Subscribe {
+        Subscribe::new()
+    }
+
+    fn clear(&mut self) {
+        self.kinds.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Subscribe {
+        static instance: Subscribe = Subscribe {
+            kinds: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Subscribe {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Subscribe").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Subscribe {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Subscribe {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.MdbPoll)
+pub struct MdbPoll {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.MdbPoll.address)
+    pub address: u32,
+    // @@protoc_insertion_point(field:iodin.MdbPoll.interval_ms)
+    pub interval_ms: u32,
+    // @@protoc_insertion_point(field:iodin.MdbPoll.timeout_ms)
+    pub timeout_ms: u32,
+    // @@protoc_insertion_point(field:iodin.MdbPoll.frame)
+    pub frame: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.MdbPoll.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MdbPoll {
+    fn default() -> &'a MdbPoll {
+        <MdbPoll as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MdbPoll {
+    pub fn new() -> MdbPoll {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "address",
+            |m: &MdbPoll| { &m.address },
+            |m: &mut MdbPoll| { &mut m.address },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "interval_ms",
+            |m: &MdbPoll| { &m.interval_ms },
+            |m: &mut MdbPoll| { &mut m.interval_ms },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "timeout_ms",
+            |m: &MdbPoll| { &m.timeout_ms },
+            |m: &mut MdbPoll| { &mut m.timeout_ms },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "frame",
+            |m: &MdbPoll| { &m.frame },
+            |m: &mut MdbPoll| { &mut m.frame },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MdbPoll>(
+            "MdbPoll",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MdbPoll {
+    const NAME: &'static str = "MdbPoll";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.address = is.read_uint32()?;
+                },
+                16 => {
+                    self.interval_ms = is.read_uint32()?;
+                },
+                24 => {
+                    self.timeout_ms = is.read_uint32()?;
+                },
+                34 => {
+                    self.frame = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.address != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.address);
+        }
+        if self.interval_ms != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.interval_ms);
+        }
+        if self.timeout_ms != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.timeout_ms);
+        }
+        if !self.frame.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.frame);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.address != 0 {
+            os.write_uint32(1, self.address)?;
+        }
+        if self.interval_ms != 0 {
+            os.write_uint32(2, self.interval_ms)?;
+        }
+        if self.timeout_ms != 0 {
+            os.write_uint32(3, self.timeout_ms)?;
+        }
+        if !self.frame.is_empty() {
+            os.write_bytes(4, &self.frame)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MdbPoll {
+        MdbPoll::new()
+    }
+
+    fn clear(&mut self) {
+        self.address = 0;
+        self.interval_ms = 0;
+        self.timeout_ms = 0;
+        self.frame.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MdbPoll {
+        static instance: MdbPoll = MdbPoll {
+            address: 0,
+            interval_ms: 0,
+            timeout_ms: 0,
+            frame: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for MdbPoll {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MdbPoll").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MdbPoll {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MdbPoll {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.GpioWatch)
+pub struct GpioWatch {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.GpioWatch.pin)
+    pub pin: u32,
+    // @@protoc_insertion_point(field:iodin.GpioWatch.enable)
+    pub enable: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.GpioWatch.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GpioWatch {
+    fn default() -> &'a GpioWatch {
+        <GpioWatch as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GpioWatch {
+    pub fn new() -> GpioWatch {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "pin",
+            |m: &GpioWatch| { &m.pin },
+            |m: &mut GpioWatch| { &mut m.pin },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "enable",
+            |m: &GpioWatch| { &m.enable },
+            |m: &mut GpioWatch| { &mut m.enable },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GpioWatch>(
+            "GpioWatch",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GpioWatch {
+    const NAME: &'static str = "GpioWatch";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.pin = is.read_uint32()?;
+                },
+                16 => {
+                    self.enable = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.pin != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.pin);
+        }
+        if self.enable != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.pin != 0 {
+            os.write_uint32(1, self.pin)?;
+        }
+        if self.enable != false {
+            os.write_bool(2, self.enable)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GpioWatch {
+        GpioWatch::new()
+    }
+
+    fn clear(&mut self) {
+        self.pin = 0;
+        self.enable = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GpioWatch {
+        static instance: GpioWatch = GpioWatch {
+            pin: 0,
+            enable: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GpioWatch {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GpioWatch").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GpioWatch {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GpioWatch {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.Event)
+pub struct Event {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.Event.kind)
+    pub kind: ::protobuf::EnumOrUnknown<event::Kind>,
+    // @@protoc_insertion_point(field:iodin.Event.time_us)
+    pub time_us: u64,
+    // @@protoc_insertion_point(field:iodin.Event.data_bytes)
+    pub data_bytes: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:iodin.Event.mdb_request)
+    pub mdb_request: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:iodin.Event.error)
+    pub error: ::std::string::String,
+    // @@protoc_insertion_point(field:iodin.Event.error_detail)
+    pub error_detail: ::protobuf::MessageField<ErrorDetail>,
+    // @@protoc_insertion_point(field:iodin.Event.gpio)
+    pub gpio: u32,
+    // @@protoc_insertion_point(field:iodin.Event.level)
+    pub level: u32,
+    // @@protoc_insertion_point(field:iodin.Event.tick)
+    pub tick: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.Event.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Event {
+    fn default() -> &'a Event {
+        <Event as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Event {
+    pub fn new() -> Event {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(9);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "kind",
+            |m: &Event| { &m.kind },
+            |m: &mut Event| { &mut m.kind },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "time_us",
+            |m: &Event| { &m.time_us },
+            |m: &mut Event| { &mut m.time_us },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "data_bytes",
+            |m: &Event| { &m.data_bytes },
+            |m: &mut Event| { &mut m.data_bytes },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "mdb_request",
+            |m: &Event| { &m.mdb_request },
+            |m: &mut Event| { &mut m.mdb_request },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "error",
+            |m: &Event| { &m.error },
+            |m: &mut Event| { &mut m.error },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ErrorDetail>(
+            "error_detail",
+            |m: &Event| { &m.error_detail },
+            |m: &mut Event| { &mut m.error_detail },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "gpio",
+            |m: &Event| { &m.gpio },
+            |m: &mut Event| { &mut m.gpio },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "level",
+            |m: &Event| { &m.level },
+            |m: &mut Event| { &mut m.level },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tick",
+            |m: &Event| { &m.tick },
+            |m: &mut Event| { &mut m.tick },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Event>(
+            "Event",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Event {
+    const NAME: &'static str = "Event";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.kind = is.read_enum_or_unknown()?;
+                },
+                16 => {
+                    self.time_us = is.read_uint64()?;
+                },
+                26 => {
+                    self.data_bytes = is.read_bytes()?;
+                },
+                34 => {
+                    self.mdb_request = is.read_bytes()?;
+                },
+                42 => {
+                    self.error = is.read_string()?;
+                },
+                50 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.error_detail)?;
+                },
+                56 => {
+                    self.gpio = is.read_uint32()?;
+                },
+                64 => {
+                    self.level = is.read_uint32()?;
+                },
+                72 => {
+                    self.tick = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.kind != ::protobuf::EnumOrUnknown::new(event::Kind::INVALID) {
+            my_size += ::protobuf::rt::int32_size(1, self.kind.value());
+        }
+        if self.time_us != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.time_us);
+        }
+        if !self.data_bytes.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.data_bytes);
+        }
+        if !self.mdb_request.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.mdb_request);
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.error);
+        }
+        if let Some(v) = self.error_detail.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.gpio != 0 {
+            my_size += ::protobuf::rt::uint32_size(7, self.gpio);
+        }
+        if self.level != 0 {
+            my_size += ::protobuf::rt::uint32_size(8, self.level);
+        }
+        if self.tick != 0 {
+            my_size += ::protobuf::rt::uint32_size(9, self.tick);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.kind != ::protobuf::EnumOrUnknown::new(event::Kind::INVALID) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.kind))?;
+        }
+        if self.time_us != 0 {
+            os.write_uint64(2, self.time_us)?;
+        }
+        if !self.data_bytes.is_empty() {
+            os.write_bytes(3, &self.data_bytes)?;
+        }
+        if !self.mdb_request.is_empty() {
+            os.write_bytes(4, &self.mdb_request)?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(5, &self.error)?;
+        }
+        if let Some(v) = self.error_detail.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
+        }
+        if self.gpio != 0 {
+            os.write_uint32(7, self.gpio)?;
+        }
+        if self.level != 0 {
+            os.write_uint32(8, self.level)?;
+        }
+        if self.tick != 0 {
+            os.write_uint32(9, self.tick)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Event {
+        Event::new()
+    }
+
+    fn clear(&mut self) {
+        self.kind = ::protobuf::EnumOrUnknown::new(event::Kind::INVALID);
+        self.time_us = 0;
+        self.data_bytes.clear();
+        self.mdb_request.clear();
+        self.error.clear();
+        self.error_detail.clear();
+        self.gpio = 0;
+        self.level = 0;
+        self.tick = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Event {
+        static instance: Event = Event {
+            kind: ::protobuf::EnumOrUnknown::from_i32(0),
+            time_us: 0,
+            data_bytes: ::std::vec::Vec::new(),
+            mdb_request: ::std::vec::Vec::new(),
+            error: ::std::string::String::new(),
+            error_detail: ::protobuf::MessageField::none(),
+            gpio: 0,
+            level: 0,
+            tick: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Event {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Event").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Event {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Event {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `Event`
+pub mod event {
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:iodin.Event.Kind)
+    pub enum Kind {
+        // @@protoc_insertion_point(enum_value:iodin.Event.Kind.INVALID)
+        INVALID = 0,
+        // @@protoc_insertion_point(enum_value:iodin.Event.Kind.MDB_POLL)
+        MDB_POLL = 1,
+        // @@protoc_insertion_point(enum_value:iodin.Event.Kind.GPIO_EDGE)
+        GPIO_EDGE = 2,
+        // @@protoc_insertion_point(enum_value:iodin.Event.Kind.BUS_ERROR)
+        BUS_ERROR = 3,
+        // @@protoc_insertion_point(enum_value:iodin.Event.Kind.WATCHDOG)
+        WATCHDOG = 4,
+    }
+
+    impl ::protobuf::Enum for Kind {
+        const NAME: &'static str = "Kind";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Kind> {
+            match value {
+                0 => ::std::option::Option::Some(Kind::INVALID),
+                1 => ::std::option::Option::Some(Kind::MDB_POLL),
+                2 => ::std::option::Option::Some(Kind::GPIO_EDGE),
+                3 => ::std::option::Option::Some(Kind::BUS_ERROR),
+                4 => ::std::option::Option::Some(Kind::WATCHDOG),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Kind] = &[
+            Kind::INVALID,
+            Kind::MDB_POLL,
+            Kind::GPIO_EDGE,
+            Kind::BUS_ERROR,
+            Kind::WATCHDOG,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Kind {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("Event.Kind").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Kind {
+        fn default() -> Self {
+            Kind::INVALID
+        }
+    }
+
+    impl Kind {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Kind>("Event.Kind")
+        }
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.BatchItem)
+pub struct BatchItem {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.BatchItem.status)
+    pub status: ::protobuf::EnumOrUnknown<response::Status>,
+    // @@protoc_insertion_point(field:iodin.BatchItem.error)
+    pub error: ::std::string::String,
+    // @@protoc_insertion_point(field:iodin.BatchItem.data_bytes)
+    pub data_bytes: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:iodin.BatchItem.error_detail)
+    pub error_detail: ::protobuf::MessageField<ErrorDetail>,
+    // @@protoc_insertion_point(field:iodin.BatchItem.attempts)
+    pub attempts: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.BatchItem.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a BatchItem {
+    fn default() -> &'a BatchItem {
+        <BatchItem as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BatchItem {
+    pub fn new() -> BatchItem {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "status",
+            |m: &BatchItem| { &m.status },
+            |m: &mut BatchItem| { &mut m.status },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "error",
+            |m: &BatchItem| { &m.error },
+            |m: &mut BatchItem| { &mut m.error },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "data_bytes",
+            |m: &BatchItem| { &m.data_bytes },
+            |m: &mut BatchItem| { &mut m.data_bytes },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ErrorDetail>(
+            "error_detail",
+            |m: &BatchItem| { &m.error_detail },
+            |m: &mut BatchItem| { &mut m.error_detail },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "attempts",
+            |m: &BatchItem| { &m.attempts },
+            |m: &mut BatchItem| { &mut m.attempts },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<BatchItem>(
+            "BatchItem",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for BatchItem {
+    const NAME: &'static str = "BatchItem";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.status = is.read_enum_or_unknown()?;
+                },
+                18 => {
+                    self.error = is.read_string()?;
+                },
+                26 => {
+                    self.data_bytes = is.read_bytes()?;
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.error_detail)?;
+                },
+                40 => {
+                    self.attempts = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.status != ::protobuf::EnumOrUnknown::new(response::Status::INVALID) {
+            my_size += ::protobuf::rt::int32_size(1, self.status.value());
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.error);
+        }
+        if !self.data_bytes.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.data_bytes);
+        }
+        if let Some(v) = self.error_detail.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.attempts != 0 {
+            my_size += ::protobuf::rt::uint32_size(5, self.attempts);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.status != ::protobuf::EnumOrUnknown::new(response::Status::INVALID) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.status))?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(2, &self.error)?;
+        }
+        if !self.data_bytes.is_empty() {
+            os.write_bytes(3, &self.data_bytes)?;
+        }
+        if let Some(v) = self.error_detail.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        if self.attempts != 0 {
+            os.write_uint32(5, self.attempts)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> BatchItem {
+        BatchItem::new()
+    }
+
+    fn clear(&mut self) {
+        self.status = ::protobuf::EnumOrUnknown::new(response::Status::INVALID);
+        self.error.clear();
+        self.data_bytes.clear();
+        self.error_detail.clear();
+        self.attempts = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static BatchItem {
+        static instance: BatchItem = BatchItem {
+            status: ::protobuf::EnumOrUnknown::from_i32(0),
+            error: ::std::string::String::new(),
+            data_bytes: ::std::vec::Vec::new(),
+            error_detail: ::protobuf::MessageField::none(),
+            attempts: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for BatchItem {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("BatchItem").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for BatchItem {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BatchItem {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.ErrorDetail)
+pub struct ErrorDetail {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.ErrorDetail.kind)
+    pub kind: ::protobuf::EnumOrUnknown<error_detail::Kind>,
+    // @@protoc_insertion_point(field:iodin.ErrorDetail.stage)
+    pub stage: ::protobuf::EnumOrUnknown<error_detail::Stage>,
+    // @@protoc_insertion_point(field:iodin.ErrorDetail.checksum_received)
+    pub checksum_received: u32,
+    // @@protoc_insertion_point(field:iodin.ErrorDetail.checksum_computed)
+    pub checksum_computed: u32,
+    // @@protoc_insertion_point(field:iodin.ErrorDetail.partial_response)
+    pub partial_response: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:iodin.ErrorDetail.invalid_byte)
+    pub invalid_byte: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.ErrorDetail.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ErrorDetail {
+    fn default() -> &'a ErrorDetail {
+        <ErrorDetail as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ErrorDetail {
+    pub fn new() -> ErrorDetail {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "kind",
+            |m: &ErrorDetail| { &m.kind },
+            |m: &mut ErrorDetail| { &mut m.kind },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "stage",
+            |m: &ErrorDetail| { &m.stage },
+            |m: &mut ErrorDetail| { &mut m.stage },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "checksum_received",
+            |m: &ErrorDetail| { &m.checksum_received },
+            |m: &mut ErrorDetail| { &mut m.checksum_received },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "checksum_computed",
+            |m: &ErrorDetail| { &m.checksum_computed },
+            |m: &mut ErrorDetail| { &mut m.checksum_computed },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "partial_response",
+            |m: &ErrorDetail| { &m.partial_response },
+            |m: &mut ErrorDetail| { &mut m.partial_response },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "invalid_byte",
+            |m: &ErrorDetail| { &m.invalid_byte },
+            |m: &mut ErrorDetail| { &mut m.invalid_byte },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ErrorDetail>(
+            "ErrorDetail",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ErrorDetail {
+    const NAME: &'static str = "ErrorDetail";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.kind = is.read_enum_or_unknown()?;
+                },
+                16 => {
+                    self.stage = is.read_enum_or_unknown()?;
+                },
+                24 => {
+                    self.checksum_received = is.read_uint32()?;
+                },
+                32 => {
+                    self.checksum_computed = is.read_uint32()?;
+                },
+                42 => {
+                    self.partial_response = is.read_bytes()?;
+                },
+                48 => {
+                    self.invalid_byte = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.kind != ::protobuf::EnumOrUnknown::new(error_detail::Kind::INVALID) {
+            my_size += ::protobuf::rt::int32_size(1, self.kind.value());
+        }
+        if self.stage != ::protobuf::EnumOrUnknown::new(error_detail::Stage::NONE) {
+            my_size += ::protobuf::rt::int32_size(2, self.stage.value());
+        }
+        if self.checksum_received != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.checksum_received);
+        }
+        if self.checksum_computed != 0 {
+            my_size += ::protobuf::rt::uint32_size(4, self.checksum_computed);
+        }
+        if !self.partial_response.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(5, &self.partial_response);
+        }
+        if self.invalid_byte != 0 {
+            my_size += ::protobuf::rt::uint32_size(6, self.invalid_byte);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.kind != ::protobuf::EnumOrUnknown::new(error_detail::Kind::INVALID) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.kind))?;
+        }
+        if self.stage != ::protobuf::EnumOrUnknown::new(error_detail::Stage::NONE) {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.stage))?;
+        }
+        if self.checksum_received != 0 {
+            os.write_uint32(3, self.checksum_received)?;
+        }
+        if self.checksum_computed != 0 {
+            os.write_uint32(4, self.checksum_computed)?;
+        }
+        if !self.partial_response.is_empty() {
+            os.write_bytes(5, &self.partial_response)?;
+        }
+        if self.invalid_byte != 0 {
+            os.write_uint32(6, self.invalid_byte)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ErrorDetail {
+        ErrorDetail::new()
+    }
+
+    fn clear(&mut self) {
+        self.kind = ::protobuf::EnumOrUnknown::new(error_detail::Kind::INVALID);
+        self.stage = ::protobuf::EnumOrUnknown::new(error_detail::Stage::NONE);
+        self.checksum_received = 0;
+        self.checksum_computed = 0;
+        self.partial_response.clear();
+        self.invalid_byte = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ErrorDetail {
+        static instance: ErrorDetail = ErrorDetail {
+            kind: ::protobuf::EnumOrUnknown::from_i32(0),
+            stage: ::protobuf::EnumOrUnknown::from_i32(0),
+            checksum_received: 0,
+            checksum_computed: 0,
+            partial_response: ::std::vec::Vec::new(),
+            invalid_byte: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ErrorDetail {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ErrorDetail").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ErrorDetail {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ErrorDetail {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `ErrorDetail`
+pub mod error_detail {
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:iodin.ErrorDetail.Kind)
+    pub enum Kind {
+        // @@protoc_insertion_point(enum_value:iodin.ErrorDetail.Kind.INVALID)
+        INVALID = 0,
+        // @@protoc_insertion_point(enum_value:iodin.ErrorDetail.Kind.OTHER)
+        OTHER = 1,
+        // @@protoc_insertion_point(enum_value:iodin.ErrorDetail.Kind.INPUT)
+        INPUT = 2,
+        // @@protoc_insertion_point(enum_value:iodin.ErrorDetail.Kind.IO)
+        IO = 3,
+        // @@protoc_insertion_point(enum_value:iodin.ErrorDetail.Kind.MDB_NAK)
+        MDB_NAK = 4,
+        // @@protoc_insertion_point(enum_value:iodin.ErrorDetail.Kind.MDB_INVALID_RESPONSE)
+        MDB_INVALID_RESPONSE = 5,
+        // @@protoc_insertion_point(enum_value:iodin.ErrorDetail.Kind.MDB_CHECKSUM)
+        MDB_CHECKSUM = 6,
+        // @@protoc_insertion_point(enum_value:iodin.ErrorDetail.Kind.MDB_TIMEOUT)
+        MDB_TIMEOUT = 7,
+    }
+
+    impl ::protobuf::Enum for Kind {
+        const NAME: &'static str = "Kind";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Kind> {
+            match value {
+                0 => ::std::option::Option::Some(Kind::INVALID),
+                1 => ::std::option::Option::Some(Kind::OTHER),
+                2 => ::std::option::Option::Some(Kind::INPUT),
+                3 => ::std::option::Option::Some(Kind::IO),
+                4 => ::std::option::Option::Some(Kind::MDB_NAK),
+                5 => ::std::option::Option::Some(Kind::MDB_INVALID_RESPONSE),
+                6 => ::std::option::Option::Some(Kind::MDB_CHECKSUM),
+                7 => ::std::option::Option::Some(Kind::MDB_TIMEOUT),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Kind] = &[
+            Kind::INVALID,
+            Kind::OTHER,
+            Kind::INPUT,
+            Kind::IO,
+            Kind::MDB_NAK,
+            Kind::MDB_INVALID_RESPONSE,
+            Kind::MDB_CHECKSUM,
+            Kind::MDB_TIMEOUT,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Kind {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("ErrorDetail.Kind").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Kind {
+        fn default() -> Self {
+            Kind::INVALID
+        }
+    }
+
+    impl Kind {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Kind>("ErrorDetail.Kind")
+        }
+    }
+
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:iodin.ErrorDetail.Stage)
+    pub enum Stage {
+        // @@protoc_insertion_point(enum_value:iodin.ErrorDetail.Stage.NONE)
+        NONE = 0,
+        // @@protoc_insertion_point(enum_value:iodin.ErrorDetail.Stage.SEND)
+        SEND = 1,
+        // @@protoc_insertion_point(enum_value:iodin.ErrorDetail.Stage.RECEIVE)
+        RECEIVE = 2,
+        // @@protoc_insertion_point(enum_value:iodin.ErrorDetail.Stage.ACK)
+        ACK = 3,
+    }
+
+    impl ::protobuf::Enum for Stage {
+        const NAME: &'static str = "Stage";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Stage> {
+            match value {
+                0 => ::std::option::Option::Some(Stage::NONE),
+                1 => ::std::option::Option::Some(Stage::SEND),
+                2 => ::std::option::Option::Some(Stage::RECEIVE),
+                3 => ::std::option::Option::Some(Stage::ACK),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Stage] = &[
+            Stage::NONE,
+            Stage::SEND,
+            Stage::RECEIVE,
+            Stage::ACK,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Stage {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("ErrorDetail.Stage").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Stage {
+        fn default() -> Self {
+            Stage::NONE
+        }
+    }
+
+    impl Stage {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Stage>("ErrorDetail.Stage")
+        }
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.MdbBatch)
+pub struct MdbBatch {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.MdbBatch.items)
+    pub items: ::std::vec::Vec<MdbTx>,
+    // @@protoc_insertion_point(field:iodin.MdbBatch.stop_on_error)
+    pub stop_on_error: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.MdbBatch.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MdbBatch {
+    fn default() -> &'a MdbBatch {
+        <MdbBatch as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MdbBatch {
+    pub fn new() -> MdbBatch {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "items",
+            |m: &MdbBatch| { &m.items },
+            |m: &mut MdbBatch| { &mut m.items },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "stop_on_error",
+            |m: &MdbBatch| { &m.stop_on_error },
+            |m: &mut MdbBatch| { &mut m.stop_on_error },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MdbBatch>(
+            "MdbBatch",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MdbBatch {
+    const NAME: &'static str = "MdbBatch";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.items.push(is.read_message()?);
+                },
+                16 => {
+                    self.stop_on_error = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if self.stop_on_error != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.items {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        if self.stop_on_error != false {
+            os.write_bool(2, self.stop_on_error)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MdbBatch {
+        MdbBatch::new()
+    }
+
+    fn clear(&mut self) {
+        self.items.clear();
+        self.stop_on_error = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MdbBatch {
+        static instance: MdbBatch = MdbBatch {
+            items: ::std::vec::Vec::new(),
+            stop_on_error: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for MdbBatch {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MdbBatch").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MdbBatch {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MdbBatch {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.Hello)
+pub struct Hello {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.Hello.protocol_version)
+    pub protocol_version: u32,
+    // @@protoc_insertion_point(field:iodin.Hello.protocol_version_min)
+    pub protocol_version_min: u32,
+    // @@protoc_insertion_point(field:iodin.Hello.commands)
+    pub commands: ::std::vec::Vec<::protobuf::EnumOrUnknown<request::Command>>,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.Hello.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Hello {
+    fn default() -> &'a Hello {
+        <Hello as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Hello {
+    pub fn new() -> Hello {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "protocol_version",
+            |m: &Hello| { &m.protocol_version },
+            |m: &mut Hello| { &mut m.protocol_version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "protocol_version_min",
+            |m: &Hello| { &m.protocol_version_min },
+            |m: &mut Hello| { &mut m.protocol_version_min },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "commands",
+            |m: &Hello| { &m.commands },
+            |m: &mut Hello| { &mut m.commands },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Hello>(
+            "Hello",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Hello {
+    const NAME: &'static str = "Hello";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.protocol_version = is.read_uint32()?;
+                },
+                16 => {
+                    self.protocol_version_min = is.read_uint32()?;
+                },
+                24 => {
+                    self.commands.push(is.read_enum_or_unknown()?);
+                },
+                26 => {
+                    ::protobuf::rt::read_repeated_packed_enum_or_unknown_into(is, &mut self.commands)?
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.protocol_version != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.protocol_version);
+        }
+        if self.protocol_version_min != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.protocol_version_min);
+        }
+        for value in &self.commands {
+            my_size += ::protobuf::rt::int32_size(3, value.value());
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.protocol_version != 0 {
+            os.write_uint32(1, self.protocol_version)?;
+        }
+        if self.protocol_version_min != 0 {
+            os.write_uint32(2, self.protocol_version_min)?;
+        }
+        for v in &self.commands {
+            os.write_enum(3, ::protobuf::EnumOrUnknown::value(v))?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Hello {
+        Hello::new()
+    }
+
+    fn clear(&mut self) {
+        self.protocol_version = 0;
+        self.protocol_version_min = 0;
+        self.commands.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Hello {
+        static instance: Hello = Hello {
+            protocol_version: 0,
+            protocol_version_min: 0,
+            commands: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Hello {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Hello").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Hello {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Hello {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.Info)
+pub struct Info {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.Info.crate_version)
+    pub crate_version: ::std::string::String,
+    // @@protoc_insertion_point(field:iodin.Info.build_id)
+    pub build_id: ::std::string::String,
+    // @@protoc_insertion_point(field:iodin.Info.mock)
+    pub mock: bool,
+    // @@protoc_insertion_point(field:iodin.Info.pigpio_version)
+    pub pigpio_version: u32,
+    // @@protoc_insertion_point(field:iodin.Info.hardware_revision)
+    pub hardware_revision: u32,
+    // @@protoc_insertion_point(field:iodin.Info.mdb_open)
+    pub mdb_open: bool,
+    // @@protoc_insertion_point(field:iodin.Info.mdb_rx_pin)
+    pub mdb_rx_pin: u32,
+    // @@protoc_insertion_point(field:iodin.Info.mdb_tx_pin)
+    pub mdb_tx_pin: u32,
+    // @@protoc_insertion_point(field:iodin.Info.protocol_version)
+    pub protocol_version: u32,
+    // @@protoc_insertion_point(field:iodin.Info.commands)
+    pub commands: ::std::vec::Vec<::protobuf::EnumOrUnknown<request::Command>>,
+    // @@protoc_insertion_point(field:iodin.Info.mdb_polls)
+    pub mdb_polls: ::std::vec::Vec<MdbPoll>,
+    // @@protoc_insertion_point(field:iodin.Info.mdb_transport)
+    pub mdb_transport: ::protobuf::EnumOrUnknown<mdb_open::Transport>,
+    // @@protoc_insertion_point(field:iodin.Info.mdb_device)
+    pub mdb_device: ::std::string::String,
+    // @@protoc_insertion_point(field:iodin.Info.mdb_faults)
+    pub mdb_faults: ::protobuf::MessageField<MdbFaults>,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.Info.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Info {
+    fn default() -> &'a Info {
+        <Info as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Info {
+    pub fn new() -> Info {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(14);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "crate_version",
+            |m: &Info| { &m.crate_version },
+            |m: &mut Info| { &mut m.crate_version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "build_id",
+            |m: &Info| { &m.build_id },
+            |m: &mut Info| { &mut m.build_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "mock",
+            |m: &Info| { &m.mock },
+            |m: &mut Info| { &mut m.mock },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "pigpio_version",
+            |m: &Info| { &m.pigpio_version },
+            |m: &mut Info| { &mut m.pigpio_version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "hardware_revision",
+            |m: &Info| { &m.hardware_revision },
+            |m: &mut Info| { &mut m.hardware_revision },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "mdb_open",
+            |m: &Info| { &m.mdb_open },
+            |m: &mut Info| { &mut m.mdb_open },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "mdb_rx_pin",
+            |m: &Info| { &m.mdb_rx_pin },
+            |m: &mut Info| { &mut m.mdb_rx_pin },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "mdb_tx_pin",
+            |m: &Info| { &m.mdb_tx_pin },
+            |m: &mut Info| { &mut m.mdb_tx_pin },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "protocol_version",
+            |m: &Info| { &m.protocol_version },
+            |m: &mut Info| { &mut m.protocol_version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "commands",
+            |m: &Info| { &m.commands },
+            |m: &mut Info| { &mut m.commands },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "mdb_polls",
+            |m: &Info| { &m.mdb_polls },
+            |m: &mut Info| { &mut m.mdb_polls },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "mdb_transport",
+            |m: &Info| { &m.mdb_transport },
+            |m: &mut Info| { &mut m.mdb_transport },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "mdb_device",
+            |m: &Info| { &m.mdb_device },
+            |m: &mut Info| { &mut m.mdb_device },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, MdbFaults>(
+            "mdb_faults",
+            |m: &Info| { &m.mdb_faults },
+            |m: &mut Info| { &mut m.mdb_faults },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Info>(
+            "Info",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Info {
+    const NAME: &'static str = "Info";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.crate_version = is.read_string()?;
+                },
+                18 => {
+                    self.build_id = is.read_string()?;
+                },
+                24 => {
+                    self.mock = is.read_bool()?;
+                },
+                32 => {
+                    self.pigpio_version = is.read_uint32()?;
+                },
+                40 => {
+                    self.hardware_revision = is.read_uint32()?;
+                },
+                48 => {
+                    self.mdb_open = is.read_bool()?;
+                },
+                56 => {
+                    self.mdb_rx_pin = is.read_uint32()?;
+                },
+                64 => {
+                    self.mdb_tx_pin = is.read_uint32()?;
+                },
+                72 => {
+                    self.protocol_version = is.read_uint32()?;
+                },
+                80 => {
+                    self.commands.push(is.read_enum_or_unknown()?);
+                },
+                82 => {
+                    ::protobuf::rt::read_repeated_packed_enum_or_unknown_into(is, &mut self.commands)?
+                },
+                90 => {
+                    self.mdb_polls.push(is.read_message()?);
+                },
+                96 => {
+                    self.mdb_transport = is.read_enum_or_unknown()?;
+                },
+                106 => {
+                    self.mdb_device = is.read_string()?;
+                },
+                114 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.mdb_faults)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.crate_version.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.crate_version);
+        }
+        if !self.build_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.build_id);
+        }
+        if self.mock != false {
+            my_size += 1 + 1;
+        }
+        if self.pigpio_version != 0 {
+            my_size += ::protobuf::rt::uint32_size(4, self.pigpio_version);
+        }
+        if self.hardware_revision != 0 {
+            my_size += ::protobuf::rt::uint32_size(5, self.hardware_revision);
+        }
+        if self.mdb_open != false {
+            my_size += 1 + 1;
+        }
+        if self.mdb_rx_pin != 0 {
+            my_size += ::protobuf::rt::uint32_size(7, self.mdb_rx_pin);
+        }
+        if self.mdb_tx_pin != 0 {
+            my_size += ::protobuf::rt::uint32_size(8, self.mdb_tx_pin);
+        }
+        if self.protocol_version != 0 {
+            my_size += ::protobuf::rt::uint32_size(9, self.protocol_version);
+        }
+        for value in &self.commands {
+            my_size += ::protobuf::rt::int32_size(10, value.value());
+        };
+        for value in &self.mdb_polls {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if self.mdb_transport != ::protobuf::EnumOrUnknown::new(mdb_open::Transport::DEFAULT) {
+            my_size += ::protobuf::rt::int32_size(12, self.mdb_transport.value());
+        }
+        if !self.mdb_device.is_empty() {
+            my_size += ::protobuf::rt::string_size(13, &self.mdb_device);
+        }
+        if let Some(v) = self.mdb_faults.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.crate_version.is_empty() {
+            os.write_string(1, &self.crate_version)?;
+        }
+        if !self.build_id.is_empty() {
+            os.write_string(2, &self.build_id)?;
+        }
+        if self.mock != false {
+            os.write_bool(3, self.mock)?;
+        }
+        if self.pigpio_version != 0 {
+            os.write_uint32(4, self.pigpio_version)?;
+        }
+        if self.hardware_revision != 0 {
+            os.write_uint32(5, self.hardware_revision)?;
+        }
+        if self.mdb_open != false {
+            os.write_bool(6, self.mdb_open)?;
+        }
+        if self.mdb_rx_pin != 0 {
+            os.write_uint32(7, self.mdb_rx_pin)?;
+        }
+        if self.mdb_tx_pin != 0 {
+            os.write_uint32(8, self.mdb_tx_pin)?;
+        }
+        if self.protocol_version != 0 {
+            os.write_uint32(9, self.protocol_version)?;
+        }
+        for v in &self.commands {
+            os.write_enum(10, ::protobuf::EnumOrUnknown::value(v))?;
+        };
+        for v in &self.mdb_polls {
+            ::protobuf::rt::write_message_field_with_cached_size(11, v, os)?;
+        };
+        if self.mdb_transport != ::protobuf::EnumOrUnknown::new(mdb_open::Transport::DEFAULT) {
+            os.write_enum(12, ::protobuf::EnumOrUnknown::value(&self.mdb_transport))?;
+        }
+        if !self.mdb_device.is_empty() {
+            os.write_string(13, &self.mdb_device)?;
+        }
+        if let Some(v) = self.mdb_faults.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(14, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Info {
+        Info::new()
+    }
+
+    fn clear(&mut self) {
+        self.crate_version.clear();
+        self.build_id.clear();
+        self.mock = false;
+        self.pigpio_version = 0;
+        self.hardware_revision = 0;
+        self.mdb_open = false;
+        self.mdb_rx_pin = 0;
+        self.mdb_tx_pin = 0;
+        self.protocol_version = 0;
+        self.commands.clear();
+        self.mdb_polls.clear();
+        self.mdb_transport = ::protobuf::EnumOrUnknown::new(mdb_open::Transport::DEFAULT);
+        self.mdb_device.clear();
+        self.mdb_faults.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Info {
+        static instance: Info = Info {
+            crate_version: ::std::string::String::new(),
+            build_id: ::std::string::String::new(),
+            mock: false,
+            pigpio_version: 0,
+            hardware_revision: 0,
+            mdb_open: false,
+            mdb_rx_pin: 0,
+            mdb_tx_pin: 0,
+            protocol_version: 0,
+            commands: ::std::vec::Vec::new(),
+            mdb_polls: ::std::vec::Vec::new(),
+            mdb_transport: ::protobuf::EnumOrUnknown::from_i32(0),
+            mdb_device: ::std::string::String::new(),
+            mdb_faults: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Info {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Info").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Info {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Info {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.MdbCoin)
+pub struct MdbCoin {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.MdbCoin.op)
+    pub op: ::protobuf::EnumOrUnknown<mdb_coin::Op>,
+    // @@protoc_insertion_point(field:iodin.MdbCoin.coin_enable)
+    pub coin_enable: u32,
+    // @@protoc_insertion_point(field:iodin.MdbCoin.manual_dispense_enable)
+    pub manual_dispense_enable: u32,
+    // @@protoc_insertion_point(field:iodin.MdbCoin.coin_type)
+    pub coin_type: u32,
+    // @@protoc_insertion_point(field:iodin.MdbCoin.count)
+    pub count: u32,
+    // @@protoc_insertion_point(field:iodin.MdbCoin.timeout)
+    pub timeout: u32,
+    // @@protoc_insertion_point(field:iodin.MdbCoin.retry)
+    pub retry: ::protobuf::MessageField<RetryPolicy>,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.MdbCoin.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MdbCoin {
+    fn default() -> &'a MdbCoin {
+        <MdbCoin as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MdbCoin {
+    pub fn new() -> MdbCoin {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(7);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "op",
+            |m: &MdbCoin| { &m.op },
+            |m: &mut MdbCoin| { &mut m.op },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "coin_enable",
+            |m: &MdbCoin| { &m.coin_enable },
+            |m: &mut MdbCoin| { &mut m.coin_enable },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "manual_dispense_enable",
+            |m: &MdbCoin| { &m.manual_dispense_enable },
+            |m: &mut MdbCoin| { &mut m.manual_dispense_enable },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "coin_type",
+            |m: &MdbCoin| { &m.coin_type },
+            |m: &mut MdbCoin| { &mut m.coin_type },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "count",
+            |m: &MdbCoin| { &m.count },
+            |m: &mut MdbCoin| { &mut m.count },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "timeout",
+            |m: &MdbCoin| { &m.timeout },
+            |m: &mut MdbCoin| { &mut m.timeout },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, RetryPolicy>(
+            "retry",
+            |m: &MdbCoin| { &m.retry },
+            |m: &mut MdbCoin| { &mut m.retry },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MdbCoin>(
+            "MdbCoin",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MdbCoin {
+    const NAME: &'static str = "MdbCoin";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.op = is.read_enum_or_unknown()?;
+                },
+                16 => {
+                    self.coin_enable = is.read_uint32()?;
+                },
+                24 => {
+                    self.manual_dispense_enable = is.read_uint32()?;
+                },
+                32 => {
+                    self.coin_type = is.read_uint32()?;
+                },
+                40 => {
+                    self.count = is.read_uint32()?;
+                },
+                48 => {
+                    self.timeout = is.read_uint32()?;
+                },
+                58 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.retry)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.op != ::protobuf::EnumOrUnknown::new(mdb_coin::Op::INVALID) {
+            my_size += ::protobuf::rt::int32_size(1, self.op.value());
+        }
+        if self.coin_enable != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.coin_enable);
+        }
+        if self.manual_dispense_enable != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.manual_dispense_enable);
+        }
+        if self.coin_type != 0 {
+            my_size += ::protobuf::rt::uint32_size(4, self.coin_type);
+        }
+        if self.count != 0 {
+            my_size += ::protobuf::rt::uint32_size(5, self.count);
+        }
+        if self.timeout != 0 {
+            my_size += ::protobuf::rt::uint32_size(6, self.timeout);
+        }
+        if let Some(v) = self.retry.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.op != ::protobuf::EnumOrUnknown::new(mdb_coin::Op::INVALID) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.op))?;
+        }
+        if self.coin_enable != 0 {
+            os.write_uint32(2, self.coin_enable)?;
+        }
+        if self.manual_dispense_enable != 0 {
+            os.write_uint32(3, self.manual_dispense_enable)?;
+        }
+        if self.coin_type != 0 {
+            os.write_uint32(4, self.coin_type)?;
+        }
+        if self.count != 0 {
+            os.write_uint32(5, self.count)?;
+        }
+        if self.timeout != 0 {
+            os.write_uint32(6, self.timeout)?;
+        }
+        if let Some(v) = self.retry.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(7, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MdbCoin {
+        MdbCoin::new()
+    }
+
+    fn clear(&mut self) {
+        self.op = ::protobuf::EnumOrUnknown::new(mdb_coin::Op::INVALID);
+        self.coin_enable = 0;
+        self.manual_dispense_enable = 0;
+        self.coin_type = 0;
+        self.count = 0;
+        self.timeout = 0;
+        self.retry.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MdbCoin {
+        static instance: MdbCoin = MdbCoin {
+            op: ::protobuf::EnumOrUnknown::from_i32(0),
+            coin_enable: 0,
+            manual_dispense_enable: 0,
+            coin_type: 0,
+            count: 0,
+            timeout: 0,
+            retry: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for MdbCoin {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MdbCoin").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MdbCoin {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MdbCoin {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `MdbCoin`
+pub mod mdb_coin {
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:iodin.MdbCoin.Op)
+    pub enum Op {
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoin.Op.INVALID)
+        INVALID = 0,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoin.Op.RESET)
+        RESET = 1,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoin.Op.SETUP)
+        SETUP = 2,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoin.Op.TUBE_STATUS)
+        TUBE_STATUS = 3,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoin.Op.COIN_TYPE)
+        COIN_TYPE = 4,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoin.Op.DISPENSE)
+        DISPENSE = 5,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoin.Op.POLL)
+        POLL = 6,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoin.Op.IDENTIFICATION)
+        IDENTIFICATION = 7,
+    }
+
+    impl ::protobuf::Enum for Op {
+        const NAME: &'static str = "Op";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Op> {
+            match value {
+                0 => ::std::option::Option::Some(Op::INVALID),
+                1 => ::std::option::Option::Some(Op::RESET),
+                2 => ::std::option::Option::Some(Op::SETUP),
+                3 => ::std::option::Option::Some(Op::TUBE_STATUS),
+                4 => ::std::option::Option::Some(Op::COIN_TYPE),
+                5 => ::std::option::Option::Some(Op::DISPENSE),
+                6 => ::std::option::Option::Some(Op::POLL),
+                7 => ::std::option::Option::Some(Op::IDENTIFICATION),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Op] = &[
+            Op::INVALID,
+            Op::RESET,
+            Op::SETUP,
+            Op::TUBE_STATUS,
+            Op::COIN_TYPE,
+            Op::DISPENSE,
+            Op::POLL,
+            Op::IDENTIFICATION,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Op {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("MdbCoin.Op").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Op {
+        fn default() -> Self {
+            Op::INVALID
+        }
+    }
+
+    impl Op {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Op>("MdbCoin.Op")
+        }
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.MdbCoinSetup)
+pub struct MdbCoinSetup {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.MdbCoinSetup.feature_level)
+    pub feature_level: u32,
+    // @@protoc_insertion_point(field:iodin.MdbCoinSetup.country_code)
+    pub country_code: u32,
+    // @@protoc_insertion_point(field:iodin.MdbCoinSetup.scaling_factor)
+    pub scaling_factor: u32,
+    // @@protoc_insertion_point(field:iodin.MdbCoinSetup.decimal_places)
+    pub decimal_places: u32,
+    // @@protoc_insertion_point(field:iodin.MdbCoinSetup.coin_routing)
+    pub coin_routing: u32,
+    // @@protoc_insertion_point(field:iodin.MdbCoinSetup.credit)
+    pub credit: ::std::vec::Vec<u32>,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.MdbCoinSetup.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MdbCoinSetup {
+    fn default() -> &'a MdbCoinSetup {
+        <MdbCoinSetup as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MdbCoinSetup {
+    pub fn new() -> MdbCoinSetup {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "feature_level",
+            |m: &MdbCoinSetup| { &m.feature_level },
+            |m: &mut MdbCoinSetup| { &mut m.feature_level },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "country_code",
+            |m: &MdbCoinSetup| { &m.country_code },
+            |m: &mut MdbCoinSetup| { &mut m.country_code },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "scaling_factor",
+            |m: &MdbCoinSetup| { &m.scaling_factor },
+            |m: &mut MdbCoinSetup| { &mut m.scaling_factor },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "decimal_places",
+            |m: &MdbCoinSetup| { &m.decimal_places },
+            |m: &mut MdbCoinSetup| { &mut m.decimal_places },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "coin_routing",
+            |m: &MdbCoinSetup| { &m.coin_routing },
+            |m: &mut MdbCoinSetup| { &mut m.coin_routing },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "credit",
+            |m: &MdbCoinSetup| { &m.credit },
+            |m: &mut MdbCoinSetup| { &mut m.credit },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MdbCoinSetup>(
+            "MdbCoinSetup",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MdbCoinSetup {
+    const NAME: &'static str = "MdbCoinSetup";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.feature_level = is.read_uint32()?;
+                },
+                16 => {
+                    self.country_code = is.read_uint32()?;
+                },
+                24 => {
+                    self.scaling_factor = is.read_uint32()?;
+                },
+                32 => {
+                    self.decimal_places = is.read_uint32()?;
+                },
+                40 => {
+                    self.coin_routing = is.read_uint32()?;
+                },
+                50 => {
+                    is.read_repeated_packed_uint32_into(&mut self.credit)?;
+                },
+                48 => {
+                    self.credit.push(is.read_uint32()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.feature_level != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.feature_level);
+        }
+        if self.country_code != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.country_code);
+        }
+        if self.scaling_factor != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.scaling_factor);
+        }
+        if self.decimal_places != 0 {
+            my_size += ::protobuf::rt::uint32_size(4, self.decimal_places);
+        }
+        if self.coin_routing != 0 {
+            my_size += ::protobuf::rt::uint32_size(5, self.coin_routing);
+        }
+        for value in &self.credit {
+            my_size += ::protobuf::rt::uint32_size(6, *value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.feature_level != 0 {
+            os.write_uint32(1, self.feature_level)?;
+        }
+        if self.country_code != 0 {
+            os.write_uint32(2, self.country_code)?;
+        }
+        if self.scaling_factor != 0 {
+            os.write_uint32(3, self.scaling_factor)?;
+        }
+        if self.decimal_places != 0 {
+            os.write_uint32(4, self.decimal_places)?;
+        }
+        if self.coin_routing != 0 {
+            os.write_uint32(5, self.coin_routing)?;
+        }
+        for v in &self.credit {
+            os.write_uint32(6, *v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MdbCoinSetup {
+        MdbCoinSetup::new()
+    }
+
+    fn clear(&mut self) {
+        self.feature_level = 0;
+        self.country_code = 0;
+        self.scaling_factor = 0;
+        self.decimal_places = 0;
+        self.coin_routing = 0;
+        self.credit.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MdbCoinSetup {
+        static instance: MdbCoinSetup = MdbCoinSetup {
+            feature_level: 0,
+            country_code: 0,
+            scaling_factor: 0,
+            decimal_places: 0,
+            coin_routing: 0,
+            credit: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for MdbCoinSetup {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MdbCoinSetup").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MdbCoinSetup {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MdbCoinSetup {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.MdbCoinTubes)
+pub struct MdbCoinTubes {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.MdbCoinTubes.full)
+    pub full: u32,
+    // @@protoc_insertion_point(field:iodin.MdbCoinTubes.count)
+    pub count: ::std::vec::Vec<u32>,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.MdbCoinTubes.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MdbCoinTubes {
+    fn default() -> &'a MdbCoinTubes {
+        <MdbCoinTubes as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MdbCoinTubes {
+    pub fn new() -> MdbCoinTubes {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "full",
+            |m: &MdbCoinTubes| { &m.full },
+            |m: &mut MdbCoinTubes| { &mut m.full },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "count",
+            |m: &MdbCoinTubes| { &m.count },
+            |m: &mut MdbCoinTubes| { &mut m.count },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MdbCoinTubes>(
+            "MdbCoinTubes",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MdbCoinTubes {
+    const NAME: &'static str = "MdbCoinTubes";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.full = is.read_uint32()?;
+                },
+                18 => {
+                    is.read_repeated_packed_uint32_into(&mut self.count)?;
+                },
+                16 => {
+                    self.count.push(is.read_uint32()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.full != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.full);
+        }
+        for value in &self.count {
+            my_size += ::protobuf::rt::uint32_size(2, *value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.full != 0 {
+            os.write_uint32(1, self.full)?;
+        }
+        for v in &self.count {
+            os.write_uint32(2, *v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MdbCoinTubes {
+        MdbCoinTubes::new()
+    }
+
+    fn clear(&mut self) {
+        self.full = 0;
+        self.count.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MdbCoinTubes {
+        static instance: MdbCoinTubes = MdbCoinTubes {
+            full: 0,
+            count: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for MdbCoinTubes {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MdbCoinTubes").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MdbCoinTubes {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MdbCoinTubes {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.MdbCoinEvent)
+pub struct MdbCoinEvent {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.MdbCoinEvent.kind)
+    pub kind: ::protobuf::EnumOrUnknown<mdb_coin_event::Kind>,
+    // @@protoc_insertion_point(field:iodin.MdbCoinEvent.coin_type)
+    pub coin_type: u32,
+    // @@protoc_insertion_point(field:iodin.MdbCoinEvent.routing)
+    pub routing: ::protobuf::EnumOrUnknown<mdb_coin_event::Routing>,
+    // @@protoc_insertion_point(field:iodin.MdbCoinEvent.tube_count)
+    pub tube_count: u32,
+    // @@protoc_insertion_point(field:iodin.MdbCoinEvent.count)
+    pub count: u32,
+    // @@protoc_insertion_point(field:iodin.MdbCoinEvent.status)
+    pub status: ::protobuf::EnumOrUnknown<mdb_coin_event::Status>,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.MdbCoinEvent.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MdbCoinEvent {
+    fn default() -> &'a MdbCoinEvent {
+        <MdbCoinEvent as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MdbCoinEvent {
+    pub fn new() -> MdbCoinEvent {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "kind",
+            |m: &MdbCoinEvent| { &m.kind },
+            |m: &mut MdbCoinEvent| { &mut m.kind },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "coin_type",
+            |m: &MdbCoinEvent| { &m.coin_type },
+            |m: &mut MdbCoinEvent| { &mut m.coin_type },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "routing",
+            |m: &MdbCoinEvent| { &m.routing },
+            |m: &mut MdbCoinEvent| { &mut m.routing },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "tube_count",
+            |m: &MdbCoinEvent| { &m.tube_count },
+            |m: &mut MdbCoinEvent| { &mut m.tube_count },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "count",
+            |m: &MdbCoinEvent| { &m.count },
+            |m: &mut MdbCoinEvent| { &mut m.count },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "status",
+            |m: &MdbCoinEvent| { &m.status },
+            |m: &mut MdbCoinEvent| { &mut m.status },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MdbCoinEvent>(
+            "MdbCoinEvent",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MdbCoinEvent {
+    const NAME: &'static str = "MdbCoinEvent";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.kind = is.read_enum_or_unknown()?;
+                },
+                16 => {
+                    self.coin_type = is.read_uint32()?;
+                },
+                24 => {
+                    self.routing = is.read_enum_or_unknown()?;
+                },
+                32 => {
+                    self.tube_count = is.read_uint32()?;
+                },
+                40 => {
+                    self.count = is.read_uint32()?;
+                },
+                48 => {
+                    self.status = is.read_enum_or_unknown()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.kind != ::protobuf::EnumOrUnknown::new(mdb_coin_event::Kind::INVALID) {
+            my_size += ::protobuf::rt::int32_size(1, self.kind.value());
+        }
+        if self.coin_type != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.coin_type);
+        }
+        if self.routing != ::protobuf::EnumOrUnknown::new(mdb_coin_event::Routing::CASH_BOX) {
+            my_size += ::protobuf::rt::int32_size(3, self.routing.value());
+        }
+        if self.tube_count != 0 {
+            my_size += ::protobuf::rt::uint32_size(4, self.tube_count);
+        }
+        if self.count != 0 {
+            my_size += ::protobuf::rt::uint32_size(5, self.count);
+        }
+        if self.status != ::protobuf::EnumOrUnknown::new(mdb_coin_event::Status::STATUS_NONE) {
+            my_size += ::protobuf::rt::int32_size(6, self.status.value());
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.kind != ::protobuf::EnumOrUnknown::new(mdb_coin_event::Kind::INVALID) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.kind))?;
+        }
+        if self.coin_type != 0 {
+            os.write_uint32(2, self.coin_type)?;
+        }
+        if self.routing != ::protobuf::EnumOrUnknown::new(mdb_coin_event::Routing::CASH_BOX) {
+            os.write_enum(3, ::protobuf::EnumOrUnknown::value(&self.routing))?;
+        }
+        if self.tube_count != 0 {
+            os.write_uint32(4, self.tube_count)?;
+        }
+        if self.count != 0 {
+            os.write_uint32(5, self.count)?;
+        }
+        if self.status != ::protobuf::EnumOrUnknown::new(mdb_coin_event::Status::STATUS_NONE) {
+            os.write_enum(6, ::protobuf::EnumOrUnknown::value(&self.status))?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MdbCoinEvent {
+        MdbCoinEvent::new()
+    }
+
+    fn clear(&mut self) {
+        self.kind = ::protobuf::EnumOrUnknown::new(mdb_coin_event::Kind::INVALID);
+        self.coin_type = 0;
+        self.routing = ::protobuf::EnumOrUnknown::new(mdb_coin_event::Routing::CASH_BOX);
+        self.tube_count = 0;
+        self.count = 0;
+        self.status = ::protobuf::EnumOrUnknown::new(mdb_coin_event::Status::STATUS_NONE);
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MdbCoinEvent {
+        static instance: MdbCoinEvent = MdbCoinEvent {
+            kind: ::protobuf::EnumOrUnknown::from_i32(0),
+            coin_type: 0,
+            routing: ::protobuf::EnumOrUnknown::from_i32(0),
+            tube_count: 0,
+            count: 0,
+            status: ::protobuf::EnumOrUnknown::from_i32(0),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for MdbCoinEvent {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MdbCoinEvent").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MdbCoinEvent {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MdbCoinEvent {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `MdbCoinEvent`
+pub mod mdb_coin_event {
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:iodin.MdbCoinEvent.Kind)
+    pub enum Kind {
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoinEvent.Kind.INVALID)
+        INVALID = 0,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoinEvent.Kind.DEPOSITED)
+        DEPOSITED = 1,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoinEvent.Kind.DISPENSED)
+        DISPENSED = 2,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoinEvent.Kind.SLUG)
+        SLUG = 3,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoinEvent.Kind.STATUS)
+        STATUS = 4,
+    }
+
+    impl ::protobuf::Enum for Kind {
+        const NAME: &'static str = "Kind";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Kind> {
+            match value {
+                0 => ::std::option::Option::Some(Kind::INVALID),
+                1 => ::std::option::Option::Some(Kind::DEPOSITED),
+                2 => ::std::option::Option::Some(Kind::DISPENSED),
+                3 => ::std::option::Option::Some(Kind::SLUG),
+                4 => ::std::option::Option::Some(Kind::STATUS),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Kind] = &[
+            Kind::INVALID,
+            Kind::DEPOSITED,
+            Kind::DISPENSED,
+            Kind::SLUG,
+            Kind::STATUS,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Kind {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("MdbCoinEvent.Kind").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Kind {
+        fn default() -> Self {
+            Kind::INVALID
+        }
+    }
+
+    impl Kind {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Kind>("MdbCoinEvent.Kind")
+        }
+    }
+
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:iodin.MdbCoinEvent.Routing)
+    pub enum Routing {
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoinEvent.Routing.CASH_BOX)
+        CASH_BOX = 0,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoinEvent.Routing.TUBES)
+        TUBES = 1,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoinEvent.Routing.NOT_USED)
+        NOT_USED = 2,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoinEvent.Routing.REJECT)
+        REJECT = 3,
+    }
+
+    impl ::protobuf::Enum for Routing {
+        const NAME: &'static str = "Routing";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Routing> {
+            match value {
+                0 => ::std::option::Option::Some(Routing::CASH_BOX),
+                1 => ::std::option::Option::Some(Routing::TUBES),
+                2 => ::std::option::Option::Some(Routing::NOT_USED),
+                3 => ::std::option::Option::Some(Routing::REJECT),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Routing] = &[
+            Routing::CASH_BOX,
+            Routing::TUBES,
+            Routing::NOT_USED,
+            Routing::REJECT,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Routing {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("MdbCoinEvent.Routing").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Routing {
+        fn default() -> Self {
+            Routing::CASH_BOX
+        }
+    }
+
+    impl Routing {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Routing>("MdbCoinEvent.Routing")
+        }
+    }
+
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:iodin.MdbCoinEvent.Status)
+    pub enum Status {
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoinEvent.Status.STATUS_NONE)
+        STATUS_NONE = 0,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoinEvent.Status.ESCROW_REQUEST)
+        ESCROW_REQUEST = 1,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoinEvent.Status.PAYOUT_BUSY)
+        PAYOUT_BUSY = 2,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoinEvent.Status.NO_CREDIT)
+        NO_CREDIT = 3,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoinEvent.Status.DEFECTIVE_TUBE_SENSOR)
+        DEFECTIVE_TUBE_SENSOR = 4,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoinEvent.Status.DOUBLE_ARRIVAL)
+        DOUBLE_ARRIVAL = 5,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoinEvent.Status.ACCEPTOR_UNPLUGGED)
+        ACCEPTOR_UNPLUGGED = 6,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoinEvent.Status.TUBE_JAM)
+        TUBE_JAM = 7,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoinEvent.Status.ROM_CHECKSUM_ERROR)
+        ROM_CHECKSUM_ERROR = 8,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoinEvent.Status.ROUTING_ERROR)
+        ROUTING_ERROR = 9,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoinEvent.Status.BUSY)
+        BUSY = 10,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoinEvent.Status.JUST_RESET)
+        JUST_RESET = 11,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoinEvent.Status.COIN_JAM)
+        COIN_JAM = 12,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCoinEvent.Status.CREDITED_COIN_REMOVAL)
+        CREDITED_COIN_REMOVAL = 13,
+    }
+
+    impl ::protobuf::Enum for Status {
+        const NAME: &'static str = "Status";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Status> {
+            match value {
+                0 => ::std::option::Option::Some(Status::STATUS_NONE),
+                1 => ::std::option::Option::Some(Status::ESCROW_REQUEST),
+                2 => ::std::option::Option::Some(Status::PAYOUT_BUSY),
+                3 => ::std::option::Option::Some(Status::NO_CREDIT),
+                4 => ::std::option::Option::Some(Status::DEFECTIVE_TUBE_SENSOR),
+                5 => ::std::option::Option::Some(Status::DOUBLE_ARRIVAL),
+                6 => ::std::option::Option::Some(Status::ACCEPTOR_UNPLUGGED),
+                7 => ::std::option::Option::Some(Status::TUBE_JAM),
+                8 => ::std::option::Option::Some(Status::ROM_CHECKSUM_ERROR),
+                9 => ::std::option::Option::Some(Status::ROUTING_ERROR),
+                10 => ::std::option::Option::Some(Status::BUSY),
+                11 => ::std::option::Option::Some(Status::JUST_RESET),
+                12 => ::std::option::Option::Some(Status::COIN_JAM),
+                13 => ::std::option::Option::Some(Status::CREDITED_COIN_REMOVAL),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Status] = &[
+            Status::STATUS_NONE,
+            Status::ESCROW_REQUEST,
+            Status::PAYOUT_BUSY,
+            Status::NO_CREDIT,
+            Status::DEFECTIVE_TUBE_SENSOR,
+            Status::DOUBLE_ARRIVAL,
+            Status::ACCEPTOR_UNPLUGGED,
+            Status::TUBE_JAM,
+            Status::ROM_CHECKSUM_ERROR,
+            Status::ROUTING_ERROR,
+            Status::BUSY,
+            Status::JUST_RESET,
+            Status::COIN_JAM,
+            Status::CREDITED_COIN_REMOVAL,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Status {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("MdbCoinEvent.Status").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Status {
+        fn default() -> Self {
+            Status::STATUS_NONE
+        }
+    }
+
+    impl Status {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Status>("MdbCoinEvent.Status")
+        }
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.MdbIdentification)
+pub struct MdbIdentification {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.MdbIdentification.manufacturer)
+    pub manufacturer: ::std::string::String,
+    // @@protoc_insertion_point(field:iodin.MdbIdentification.serial)
+    pub serial: ::std::string::String,
+    // @@protoc_insertion_point(field:iodin.MdbIdentification.model)
+    pub model: ::std::string::String,
+    // @@protoc_insertion_point(field:iodin.MdbIdentification.software_version)
+    pub software_version: u32,
+    // @@protoc_insertion_point(field:iodin.MdbIdentification.features)
+    pub features: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.MdbIdentification.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MdbIdentification {
+    fn default() -> &'a MdbIdentification {
+        <MdbIdentification as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MdbIdentification {
+    pub fn new() -> MdbIdentification {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "manufacturer",
+            |m: &MdbIdentification| { &m.manufacturer },
+            |m: &mut MdbIdentification| { &mut m.manufacturer },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "serial",
+            |m: &MdbIdentification| { &m.serial },
+            |m: &mut MdbIdentification| { &mut m.serial },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "model",
+            |m: &MdbIdentification| { &m.model },
+            |m: &mut MdbIdentification| { &mut m.model },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "software_version",
+            |m: &MdbIdentification| { &m.software_version },
+            |m: &mut MdbIdentification| { &mut m.software_version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "features",
+            |m: &MdbIdentification| { &m.features },
+            |m: &mut MdbIdentification| { &mut m.features },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MdbIdentification>(
+            "MdbIdentification",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MdbIdentification {
+    const NAME: &'static str = "MdbIdentification";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.manufacturer = is.read_string()?;
+                },
+                18 => {
+                    self.serial = is.read_string()?;
+                },
+                26 => {
+                    self.model = is.read_string()?;
+                },
+                32 => {
+                    self.software_version = is.read_uint32()?;
+                },
+                40 => {
+                    self.features = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.manufacturer.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.manufacturer);
+        }
+        if !self.serial.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.serial);
+        }
+        if !self.model.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.model);
+        }
+        if self.software_version != 0 {
+            my_size += ::protobuf::rt::uint32_size(4, self.software_version);
+        }
+        if self.features != 0 {
+            my_size += ::protobuf::rt::uint32_size(5, self.features);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.manufacturer.is_empty() {
+            os.write_string(1, &self.manufacturer)?;
+        }
+        if !self.serial.is_empty() {
+            os.write_string(2, &self.serial)?;
+        }
+        if !self.model.is_empty() {
+            os.write_string(3, &self.model)?;
+        }
+        if self.software_version != 0 {
+            os.write_uint32(4, self.software_version)?;
+        }
+        if self.features != 0 {
+            os.write_uint32(5, self.features)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MdbIdentification {
+        MdbIdentification::new()
+    }
+
+    fn clear(&mut self) {
+        self.manufacturer.clear();
+        self.serial.clear();
+        self.model.clear();
+        self.software_version = 0;
+        self.features = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MdbIdentification {
+        static instance: MdbIdentification = MdbIdentification {
+            manufacturer: ::std::string::String::new(),
+            serial: ::std::string::String::new(),
+            model: ::std::string::String::new(),
+            software_version: 0,
+            features: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for MdbIdentification {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MdbIdentification").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MdbIdentification {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MdbIdentification {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.MdbCoinResult)
+pub struct MdbCoinResult {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.MdbCoinResult.setup)
+    pub setup: ::protobuf::MessageField<MdbCoinSetup>,
+    // @@protoc_insertion_point(field:iodin.MdbCoinResult.tubes)
+    pub tubes: ::protobuf::MessageField<MdbCoinTubes>,
+    // @@protoc_insertion_point(field:iodin.MdbCoinResult.events)
+    pub events: ::std::vec::Vec<MdbCoinEvent>,
+    // @@protoc_insertion_point(field:iodin.MdbCoinResult.identification)
+    pub identification: ::protobuf::MessageField<MdbIdentification>,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.MdbCoinResult.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MdbCoinResult {
+    fn default() -> &'a MdbCoinResult {
+        <MdbCoinResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MdbCoinResult {
+    pub fn new() -> MdbCoinResult {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, MdbCoinSetup>(
+            "setup",
+            |m: &MdbCoinResult| { &m.setup },
+            |m: &mut MdbCoinResult| { &mut m.setup },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, MdbCoinTubes>(
+            "tubes",
+            |m: &MdbCoinResult| { &m.tubes },
+            |m: &mut MdbCoinResult| { &mut m.tubes },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "events",
+            |m: &MdbCoinResult| { &m.events },
+            |m: &mut MdbCoinResult| { &mut m.events },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, MdbIdentification>(
+            "identification",
+            |m: &MdbCoinResult| { &m.identification },
+            |m: &mut MdbCoinResult| { &mut m.identification },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MdbCoinResult>(
+            "MdbCoinResult",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MdbCoinResult {
+    const NAME: &'static str = "MdbCoinResult";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.setup)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.tubes)?;
+                },
+                26 => {
+                    self.events.push(is.read_message()?);
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.identification)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.setup.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.tubes.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        for value in &self.events {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if let Some(v) = self.identification.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.setup.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if let Some(v) = self.tubes.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        for v in &self.events {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        };
+        if let Some(v) = self.identification.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MdbCoinResult {
+        MdbCoinResult::new()
+    }
+
+    fn clear(&mut self) {
+        self.setup.clear();
+        self.tubes.clear();
+        self.events.clear();
+        self.identification.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MdbCoinResult {
+        static instance: MdbCoinResult = MdbCoinResult {
+            setup: ::protobuf::MessageField::none(),
+            tubes: ::protobuf::MessageField::none(),
+            events: ::std::vec::Vec::new(),
+            identification: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for MdbCoinResult {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MdbCoinResult").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MdbCoinResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MdbCoinResult {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.MdbBill)
+pub struct MdbBill {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.MdbBill.op)
+    pub op: ::protobuf::EnumOrUnknown<mdb_bill::Op>,
+    // @@protoc_insertion_point(field:iodin.MdbBill.security_mask)
+    pub security_mask: u32,
+    // @@protoc_insertion_point(field:iodin.MdbBill.bill_enable)
+    pub bill_enable: u32,
+    // @@protoc_insertion_point(field:iodin.MdbBill.escrow_enable)
+    pub escrow_enable: u32,
+    // @@protoc_insertion_point(field:iodin.MdbBill.stack)
+    pub stack: bool,
+    // @@protoc_insertion_point(field:iodin.MdbBill.timeout)
+    pub timeout: u32,
+    // @@protoc_insertion_point(field:iodin.MdbBill.retry)
+    pub retry: ::protobuf::MessageField<RetryPolicy>,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.MdbBill.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MdbBill {
+    fn default() -> &'a MdbBill {
+        <MdbBill as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MdbBill {
+    pub fn new() -> MdbBill {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(7);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "op",
+            |m: &MdbBill| { &m.op },
+            |m: &mut MdbBill| { &mut m.op },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "security_mask",
+            |m: &MdbBill| { &m.security_mask },
+            |m: &mut MdbBill| { &mut m.security_mask },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "bill_enable",
+            |m: &MdbBill| { &m.bill_enable },
+            |m: &mut MdbBill| { &mut m.bill_enable },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "escrow_enable",
+            |m: &MdbBill| { &m.escrow_enable },
+            |m: &mut MdbBill| { &mut m.escrow_enable },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "stack",
+            |m: &MdbBill| { &m.stack },
+            |m: &mut MdbBill| { &mut m.stack },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "timeout",
+            |m: &MdbBill| { &m.timeout },
+            |m: &mut MdbBill| { &mut m.timeout },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, RetryPolicy>(
+            "retry",
+            |m: &MdbBill| { &m.retry },
+            |m: &mut MdbBill| { &mut m.retry },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MdbBill>(
+            "MdbBill",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MdbBill {
+    const NAME: &'static str = "MdbBill";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.op = is.read_enum_or_unknown()?;
+                },
+                16 => {
+                    self.security_mask = is.read_uint32()?;
+                },
+                24 => {
+                    self.bill_enable = is.read_uint32()?;
+                },
+                32 => {
+                    self.escrow_enable = is.read_uint32()?;
+                },
+                40 => {
+                    self.stack = is.read_bool()?;
+                },
+                48 => {
+                    self.timeout = is.read_uint32()?;
+                },
+                58 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.retry)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.op != ::protobuf::EnumOrUnknown::new(mdb_bill::Op::INVALID) {
+            my_size += ::protobuf::rt::int32_size(1, self.op.value());
+        }
+        if self.security_mask != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.security_mask);
+        }
+        if self.bill_enable != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.bill_enable);
+        }
+        if self.escrow_enable != 0 {
+            my_size += ::protobuf::rt::uint32_size(4, self.escrow_enable);
+        }
+        if self.stack != false {
+            my_size += 1 + 1;
+        }
+        if self.timeout != 0 {
+            my_size += ::protobuf::rt::uint32_size(6, self.timeout);
+        }
+        if let Some(v) = self.retry.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.op != ::protobuf::EnumOrUnknown::new(mdb_bill::Op::INVALID) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.op))?;
+        }
+        if self.security_mask != 0 {
+            os.write_uint32(2, self.security_mask)?;
+        }
+        if self.bill_enable != 0 {
+            os.write_uint32(3, self.bill_enable)?;
+        }
+        if self.escrow_enable != 0 {
+            os.write_uint32(4, self.escrow_enable)?;
+        }
+        if self.stack != false {
+            os.write_bool(5, self.stack)?;
+        }
+        if self.timeout != 0 {
+            os.write_uint32(6, self.timeout)?;
+        }
+        if let Some(v) = self.retry.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(7, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MdbBill {
+        MdbBill::new()
+    }
+
+    fn clear(&mut self) {
+        self.op = ::protobuf::EnumOrUnknown::new(mdb_bill::Op::INVALID);
+        self.security_mask = 0;
+        self.bill_enable = 0;
+        self.escrow_enable = 0;
+        self.stack = false;
+        self.timeout = 0;
+        self.retry.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MdbBill {
+        static instance: MdbBill = MdbBill {
+            op: ::protobuf::EnumOrUnknown::from_i32(0),
+            security_mask: 0,
+            bill_enable: 0,
+            escrow_enable: 0,
+            stack: false,
+            timeout: 0,
+            retry: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for MdbBill {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MdbBill").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MdbBill {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MdbBill {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `MdbBill`
+pub mod mdb_bill {
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:iodin.MdbBill.Op)
+    pub enum Op {
+        // @@protoc_insertion_point(enum_value:iodin.MdbBill.Op.INVALID)
+        INVALID = 0,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBill.Op.RESET)
+        RESET = 1,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBill.Op.SETUP)
+        SETUP = 2,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBill.Op.SECURITY)
+        SECURITY = 3,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBill.Op.BILL_TYPE)
+        BILL_TYPE = 4,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBill.Op.ESCROW)
+        ESCROW = 5,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBill.Op.STACKER)
+        STACKER = 6,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBill.Op.POLL)
+        POLL = 7,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBill.Op.IDENTIFICATION)
+        IDENTIFICATION = 8,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBill.Op.IDENTIFICATION_FEATURES)
+        IDENTIFICATION_FEATURES = 9,
+    }
+
+    impl ::protobuf::Enum for Op {
+        const NAME: &'static str = "Op";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Op> {
+            match value {
+                0 => ::std::option::Option::Some(Op::INVALID),
+                1 => ::std::option::Option::Some(Op::RESET),
+                2 => ::std::option::Option::Some(Op::SETUP),
+                3 => ::std::option::Option::Some(Op::SECURITY),
+                4 => ::std::option::Option::Some(Op::BILL_TYPE),
+                5 => ::std::option::Option::Some(Op::ESCROW),
+                6 => ::std::option::Option::Some(Op::STACKER),
+                7 => ::std::option::Option::Some(Op::POLL),
+                8 => ::std::option::Option::Some(Op::IDENTIFICATION),
+                9 => ::std::option::Option::Some(Op::IDENTIFICATION_FEATURES),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Op] = &[
+            Op::INVALID,
+            Op::RESET,
+            Op::SETUP,
+            Op::SECURITY,
+            Op::BILL_TYPE,
+            Op::ESCROW,
+            Op::STACKER,
+            Op::POLL,
+            Op::IDENTIFICATION,
+            Op::IDENTIFICATION_FEATURES,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Op {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("MdbBill.Op").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Op {
+        fn default() -> Self {
+            Op::INVALID
+        }
+    }
+
+    impl Op {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Op>("MdbBill.Op")
+        }
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.MdbBillSetup)
+pub struct MdbBillSetup {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.MdbBillSetup.feature_level)
+    pub feature_level: u32,
+    // @@protoc_insertion_point(field:iodin.MdbBillSetup.country_code)
+    pub country_code: u32,
+    // @@protoc_insertion_point(field:iodin.MdbBillSetup.scaling_factor)
+    pub scaling_factor: u32,
+    // @@protoc_insertion_point(field:iodin.MdbBillSetup.decimal_places)
+    pub decimal_places: u32,
+    // @@protoc_insertion_point(field:iodin.MdbBillSetup.stacker_capacity)
+    pub stacker_capacity: u32,
+    // @@protoc_insertion_point(field:iodin.MdbBillSetup.security_levels)
+    pub security_levels: u32,
+    // @@protoc_insertion_point(field:iodin.MdbBillSetup.escrow)
+    pub escrow: bool,
+    // @@protoc_insertion_point(field:iodin.MdbBillSetup.credit)
+    pub credit: ::std::vec::Vec<u32>,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.MdbBillSetup.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MdbBillSetup {
+    fn default() -> &'a MdbBillSetup {
+        <MdbBillSetup as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MdbBillSetup {
+    pub fn new() -> MdbBillSetup {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(8);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "feature_level",
+            |m: &MdbBillSetup| { &m.feature_level },
+            |m: &mut MdbBillSetup| { &mut m.feature_level },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "country_code",
+            |m: &MdbBillSetup| { &m.country_code },
+            |m: &mut MdbBillSetup| { &mut m.country_code },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "scaling_factor",
+            |m: &MdbBillSetup| { &m.scaling_factor },
+            |m: &mut MdbBillSetup| { &mut m.scaling_factor },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "decimal_places",
+            |m: &MdbBillSetup| { &m.decimal_places },
+            |m: &mut MdbBillSetup| { &mut m.decimal_places },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "stacker_capacity",
+            |m: &MdbBillSetup| { &m.stacker_capacity },
+            |m: &mut MdbBillSetup| { &mut m.stacker_capacity },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "security_levels",
+            |m: &MdbBillSetup| { &m.security_levels },
+            |m: &mut MdbBillSetup| { &mut m.security_levels },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "escrow",
+            |m: &MdbBillSetup| { &m.escrow },
+            |m: &mut MdbBillSetup| { &mut m.escrow },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "credit",
+            |m: &MdbBillSetup| { &m.credit },
+            |m: &mut MdbBillSetup| { &mut m.credit },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MdbBillSetup>(
+            "MdbBillSetup",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MdbBillSetup {
+    const NAME: &'static str = "MdbBillSetup";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.feature_level = is.read_uint32()?;
+                },
+                16 => {
+                    self.country_code = is.read_uint32()?;
+                },
+                24 => {
+                    self.scaling_factor = is.read_uint32()?;
+                },
+                32 => {
+                    self.decimal_places = is.read_uint32()?;
+                },
+                40 => {
+                    self.stacker_capacity = is.read_uint32()?;
+                },
+                48 => {
+                    self.security_levels = is.read_uint32()?;
+                },
+                56 => {
+                    self.escrow = is.read_bool()?;
+                },
+                66 => {
+                    is.read_repeated_packed_uint32_into(&mut self.credit)?;
+                },
+                64 => {
+                    self.credit.push(is.read_uint32()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.feature_level != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.feature_level);
+        }
+        if self.country_code != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.country_code);
+        }
+        if self.scaling_factor != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.scaling_factor);
+        }
+        if self.decimal_places != 0 {
+            my_size += ::protobuf::rt::uint32_size(4, self.decimal_places);
+        }
+        if self.stacker_capacity != 0 {
+            my_size += ::protobuf::rt::uint32_size(5, self.stacker_capacity);
+        }
+        if self.security_levels != 0 {
+            my_size += ::protobuf::rt::uint32_size(6, self.security_levels);
+        }
+        if self.escrow != false {
+            my_size += 1 + 1;
+        }
+        for value in &self.credit {
+            my_size += ::protobuf::rt::uint32_size(8, *value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.feature_level != 0 {
+            os.write_uint32(1, self.feature_level)?;
+        }
+        if self.country_code != 0 {
+            os.write_uint32(2, self.country_code)?;
+        }
+        if self.scaling_factor != 0 {
+            os.write_uint32(3, self.scaling_factor)?;
+        }
+        if self.decimal_places != 0 {
+            os.write_uint32(4, self.decimal_places)?;
+        }
+        if self.stacker_capacity != 0 {
+            os.write_uint32(5, self.stacker_capacity)?;
+        }
+        if self.security_levels != 0 {
+            os.write_uint32(6, self.security_levels)?;
+        }
+        if self.escrow != false {
+            os.write_bool(7, self.escrow)?;
+        }
+        for v in &self.credit {
+            os.write_uint32(8, *v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MdbBillSetup {
+        MdbBillSetup::new()
+    }
+
+    fn clear(&mut self) {
+        self.feature_level = 0;
+        self.country_code = 0;
+        self.scaling_factor = 0;
+        self.decimal_places = 0;
+        self.stacker_capacity = 0;
+        self.security_levels = 0;
+        self.escrow = false;
+        self.credit.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MdbBillSetup {
+        static instance: MdbBillSetup = MdbBillSetup {
+            feature_level: 0,
+            country_code: 0,
+            scaling_factor: 0,
+            decimal_places: 0,
+            stacker_capacity: 0,
+            security_levels: 0,
+            escrow: false,
+            credit: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for MdbBillSetup {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MdbBillSetup").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MdbBillSetup {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MdbBillSetup {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.MdbBillStacker)
+pub struct MdbBillStacker {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.MdbBillStacker.full)
+    pub full: bool,
+    // @@protoc_insertion_point(field:iodin.MdbBillStacker.count)
+    pub count: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.MdbBillStacker.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MdbBillStacker {
+    fn default() -> &'a MdbBillStacker {
+        <MdbBillStacker as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MdbBillStacker {
+    pub fn new() -> MdbBillStacker {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "full",
+            |m: &MdbBillStacker| { &m.full },
+            |m: &mut MdbBillStacker| { &mut m.full },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "count",
+            |m: &MdbBillStacker| { &m.count },
+            |m: &mut MdbBillStacker| { &mut m.count },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MdbBillStacker>(
+            "MdbBillStacker",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MdbBillStacker {
+    const NAME: &'static str = "MdbBillStacker";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.full = is.read_bool()?;
+                },
+                16 => {
+                    self.count = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.full != false {
+            my_size += 1 + 1;
+        }
+        if self.count != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.count);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.full != false {
+            os.write_bool(1, self.full)?;
+        }
+        if self.count != 0 {
+            os.write_uint32(2, self.count)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MdbBillStacker {
+        MdbBillStacker::new()
+    }
+
+    fn clear(&mut self) {
+        self.full = false;
+        self.count = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MdbBillStacker {
+        static instance: MdbBillStacker = MdbBillStacker {
+            full: false,
+            count: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for MdbBillStacker {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MdbBillStacker").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MdbBillStacker {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MdbBillStacker {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.MdbBillEvent)
+pub struct MdbBillEvent {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.MdbBillEvent.kind)
+    pub kind: ::protobuf::EnumOrUnknown<mdb_bill_event::Kind>,
+    // @@protoc_insertion_point(field:iodin.MdbBillEvent.bill_type)
+    pub bill_type: u32,
+    // @@protoc_insertion_point(field:iodin.MdbBillEvent.routing)
+    pub routing: ::protobuf::EnumOrUnknown<mdb_bill_event::Routing>,
+    // @@protoc_insertion_point(field:iodin.MdbBillEvent.count)
+    pub count: u32,
+    // @@protoc_insertion_point(field:iodin.MdbBillEvent.status)
+    pub status: ::protobuf::EnumOrUnknown<mdb_bill_event::Status>,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.MdbBillEvent.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MdbBillEvent {
+    fn default() -> &'a MdbBillEvent {
+        <MdbBillEvent as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MdbBillEvent {
+    pub fn new() -> MdbBillEvent {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "kind",
+            |m: &MdbBillEvent| { &m.kind },
+            |m: &mut MdbBillEvent| { &mut m.kind },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "bill_type",
+            |m: &MdbBillEvent| { &m.bill_type },
+            |m: &mut MdbBillEvent| { &mut m.bill_type },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "routing",
+            |m: &MdbBillEvent| { &m.routing },
+            |m: &mut MdbBillEvent| { &mut m.routing },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "count",
+            |m: &MdbBillEvent| { &m.count },
+            |m: &mut MdbBillEvent| { &mut m.count },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "status",
+            |m: &MdbBillEvent| { &m.status },
+            |m: &mut MdbBillEvent| { &mut m.status },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MdbBillEvent>(
+            "MdbBillEvent",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MdbBillEvent {
+    const NAME: &'static str = "MdbBillEvent";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.kind = is.read_enum_or_unknown()?;
+                },
+                16 => {
+                    self.bill_type = is.read_uint32()?;
+                },
+                24 => {
+                    self.routing = is.read_enum_or_unknown()?;
+                },
+                32 => {
+                    self.count = is.read_uint32()?;
+                },
+                40 => {
+                    self.status = is.read_enum_or_unknown()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.kind != ::protobuf::EnumOrUnknown::new(mdb_bill_event::Kind::INVALID) {
+            my_size += ::protobuf::rt::int32_size(1, self.kind.value());
+        }
+        if self.bill_type != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.bill_type);
+        }
+        if self.routing != ::protobuf::EnumOrUnknown::new(mdb_bill_event::Routing::STACKED) {
+            my_size += ::protobuf::rt::int32_size(3, self.routing.value());
+        }
+        if self.count != 0 {
+            my_size += ::protobuf::rt::uint32_size(4, self.count);
+        }
+        if self.status != ::protobuf::EnumOrUnknown::new(mdb_bill_event::Status::STATUS_NONE) {
+            my_size += ::protobuf::rt::int32_size(5, self.status.value());
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.kind != ::protobuf::EnumOrUnknown::new(mdb_bill_event::Kind::INVALID) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.kind))?;
+        }
+        if self.bill_type != 0 {
+            os.write_uint32(2, self.bill_type)?;
+        }
+        if self.routing != ::protobuf::EnumOrUnknown::new(mdb_bill_event::Routing::STACKED) {
+            os.write_enum(3, ::protobuf::EnumOrUnknown::value(&self.routing))?;
+        }
+        if self.count != 0 {
+            os.write_uint32(4, self.count)?;
+        }
+        if self.status != ::protobuf::EnumOrUnknown::new(mdb_bill_event::Status::STATUS_NONE) {
+            os.write_enum(5, ::protobuf::EnumOrUnknown::value(&self.status))?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MdbBillEvent {
+        MdbBillEvent::new()
+    }
+
+    fn clear(&mut self) {
+        self.kind = ::protobuf::EnumOrUnknown::new(mdb_bill_event::Kind::INVALID);
+        self.bill_type = 0;
+        self.routing = ::protobuf::EnumOrUnknown::new(mdb_bill_event::Routing::STACKED);
+        self.count = 0;
+        self.status = ::protobuf::EnumOrUnknown::new(mdb_bill_event::Status::STATUS_NONE);
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MdbBillEvent {
+        static instance: MdbBillEvent = MdbBillEvent {
+            kind: ::protobuf::EnumOrUnknown::from_i32(0),
+            bill_type: 0,
+            routing: ::protobuf::EnumOrUnknown::from_i32(0),
+            count: 0,
+            status: ::protobuf::EnumOrUnknown::from_i32(0),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for MdbBillEvent {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MdbBillEvent").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MdbBillEvent {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MdbBillEvent {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `MdbBillEvent`
+pub mod mdb_bill_event {
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:iodin.MdbBillEvent.Kind)
+    pub enum Kind {
+        // @@protoc_insertion_point(enum_value:iodin.MdbBillEvent.Kind.INVALID)
+        INVALID = 0,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBillEvent.Kind.BILL)
+        BILL = 1,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBillEvent.Kind.DISABLED_ATTEMPTS)
+        DISABLED_ATTEMPTS = 2,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBillEvent.Kind.STATUS)
+        STATUS = 3,
+    }
+
+    impl ::protobuf::Enum for Kind {
+        const NAME: &'static str = "Kind";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Kind> {
+            match value {
+                0 => ::std::option::Option::Some(Kind::INVALID),
+                1 => ::std::option::Option::Some(Kind::BILL),
+                2 => ::std::option::Option::Some(Kind::DISABLED_ATTEMPTS),
+                3 => ::std::option::Option::Some(Kind::STATUS),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Kind] = &[
+            Kind::INVALID,
+            Kind::BILL,
+            Kind::DISABLED_ATTEMPTS,
+            Kind::STATUS,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Kind {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("MdbBillEvent.Kind").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Kind {
+        fn default() -> Self {
+            Kind::INVALID
+        }
+    }
+
+    impl Kind {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Kind>("MdbBillEvent.Kind")
+        }
+    }
+
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:iodin.MdbBillEvent.Routing)
+    pub enum Routing {
+        // @@protoc_insertion_point(enum_value:iodin.MdbBillEvent.Routing.STACKED)
+        STACKED = 0,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBillEvent.Routing.ESCROW)
+        ESCROW = 1,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBillEvent.Routing.RETURNED)
+        RETURNED = 2,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBillEvent.Routing.TO_RECYCLER)
+        TO_RECYCLER = 3,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBillEvent.Routing.DISABLED_REJECTED)
+        DISABLED_REJECTED = 4,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBillEvent.Routing.TO_RECYCLER_MANUAL)
+        TO_RECYCLER_MANUAL = 5,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBillEvent.Routing.DISPENSED_MANUAL)
+        DISPENSED_MANUAL = 6,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBillEvent.Routing.RECYCLER_TO_CASH_BOX)
+        RECYCLER_TO_CASH_BOX = 7,
+    }
+
+    impl ::protobuf::Enum for Routing {
+        const NAME: &'static str = "Routing";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Routing> {
+            match value {
+                0 => ::std::option::Option::Some(Routing::STACKED),
+                1 => ::std::option::Option::Some(Routing::ESCROW),
+                2 => ::std::option::Option::Some(Routing::RETURNED),
+                3 => ::std::option::Option::Some(Routing::TO_RECYCLER),
+                4 => ::std::option::Option::Some(Routing::DISABLED_REJECTED),
+                5 => ::std::option::Option::Some(Routing::TO_RECYCLER_MANUAL),
+                6 => ::std::option::Option::Some(Routing::DISPENSED_MANUAL),
+                7 => ::std::option::Option::Some(Routing::RECYCLER_TO_CASH_BOX),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Routing] = &[
+            Routing::STACKED,
+            Routing::ESCROW,
+            Routing::RETURNED,
+            Routing::TO_RECYCLER,
+            Routing::DISABLED_REJECTED,
+            Routing::TO_RECYCLER_MANUAL,
+            Routing::DISPENSED_MANUAL,
+            Routing::RECYCLER_TO_CASH_BOX,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Routing {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("MdbBillEvent.Routing").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Routing {
+        fn default() -> Self {
+            Routing::STACKED
+        }
+    }
+
+    impl Routing {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Routing>("MdbBillEvent.Routing")
+        }
+    }
+
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:iodin.MdbBillEvent.Status)
+    pub enum Status {
+        // @@protoc_insertion_point(enum_value:iodin.MdbBillEvent.Status.STATUS_NONE)
+        STATUS_NONE = 0,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBillEvent.Status.DEFECTIVE_MOTOR)
+        DEFECTIVE_MOTOR = 1,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBillEvent.Status.SENSOR_PROBLEM)
+        SENSOR_PROBLEM = 2,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBillEvent.Status.BUSY)
+        BUSY = 3,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBillEvent.Status.ROM_CHECKSUM_ERROR)
+        ROM_CHECKSUM_ERROR = 4,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBillEvent.Status.JAMMED)
+        JAMMED = 5,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBillEvent.Status.JUST_RESET)
+        JUST_RESET = 6,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBillEvent.Status.BILL_REMOVED)
+        BILL_REMOVED = 7,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBillEvent.Status.CASH_BOX_REMOVED)
+        CASH_BOX_REMOVED = 8,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBillEvent.Status.DISABLED)
+        DISABLED = 9,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBillEvent.Status.INVALID_ESCROW_REQUEST)
+        INVALID_ESCROW_REQUEST = 10,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBillEvent.Status.BILL_REJECTED)
+        BILL_REJECTED = 11,
+        // @@protoc_insertion_point(enum_value:iodin.MdbBillEvent.Status.CREDITED_BILL_REMOVAL)
+        CREDITED_BILL_REMOVAL = 12,
+    }
+
+    impl ::protobuf::Enum for Status {
+        const NAME: &'static str = "Status";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Status> {
+            match value {
+                0 => ::std::option::Option::Some(Status::STATUS_NONE),
+                1 => ::std::option::Option::Some(Status::DEFECTIVE_MOTOR),
+                2 => ::std::option::Option::Some(Status::SENSOR_PROBLEM),
+                3 => ::std::option::Option::Some(Status::BUSY),
+                4 => ::std::option::Option::Some(Status::ROM_CHECKSUM_ERROR),
+                5 => ::std::option::Option::Some(Status::JAMMED),
+                6 => ::std::option::Option::Some(Status::JUST_RESET),
+                7 => ::std::option::Option::Some(Status::BILL_REMOVED),
+                8 => ::std::option::Option::Some(Status::CASH_BOX_REMOVED),
+                9 => ::std::option::Option::Some(Status::DISABLED),
+                10 => ::std::option::Option::Some(Status::INVALID_ESCROW_REQUEST),
+                11 => ::std::option::Option::Some(Status::BILL_REJECTED),
+                12 => ::std::option::Option::Some(Status::CREDITED_BILL_REMOVAL),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Status] = &[
+            Status::STATUS_NONE,
+            Status::DEFECTIVE_MOTOR,
+            Status::SENSOR_PROBLEM,
+            Status::BUSY,
+            Status::ROM_CHECKSUM_ERROR,
+            Status::JAMMED,
+            Status::JUST_RESET,
+            Status::BILL_REMOVED,
+            Status::CASH_BOX_REMOVED,
+            Status::DISABLED,
+            Status::INVALID_ESCROW_REQUEST,
+            Status::BILL_REJECTED,
+            Status::CREDITED_BILL_REMOVAL,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Status {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("MdbBillEvent.Status").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Status {
+        fn default() -> Self {
+            Status::STATUS_NONE
+        }
+    }
+
+    impl Status {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Status>("MdbBillEvent.Status")
+        }
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.MdbBillResult)
+pub struct MdbBillResult {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.MdbBillResult.setup)
+    pub setup: ::protobuf::MessageField<MdbBillSetup>,
+    // @@protoc_insertion_point(field:iodin.MdbBillResult.stacker)
+    pub stacker: ::protobuf::MessageField<MdbBillStacker>,
+    // @@protoc_insertion_point(field:iodin.MdbBillResult.events)
+    pub events: ::std::vec::Vec<MdbBillEvent>,
+    // @@protoc_insertion_point(field:iodin.MdbBillResult.identification)
+    pub identification: ::protobuf::MessageField<MdbIdentification>,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.MdbBillResult.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MdbBillResult {
+    fn default() -> &'a MdbBillResult {
+        <MdbBillResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MdbBillResult {
+    pub fn new() -> MdbBillResult {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, MdbBillSetup>(
+            "setup",
+            |m: &MdbBillResult| { &m.setup },
+            |m: &mut MdbBillResult| { &mut m.setup },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, MdbBillStacker>(
+            "stacker",
+            |m: &MdbBillResult| { &m.stacker },
+            |m: &mut MdbBillResult| { &mut m.stacker },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "events",
+            |m: &MdbBillResult| { &m.events },
+            |m: &mut MdbBillResult| { &mut m.events },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, MdbIdentification>(
+            "identification",
+            |m: &MdbBillResult| { &m.identification },
+            |m: &mut MdbBillResult| { &mut m.identification },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MdbBillResult>(
+            "MdbBillResult",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MdbBillResult {
+    const NAME: &'static str = "MdbBillResult";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.setup)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.stacker)?;
+                },
+                26 => {
+                    self.events.push(is.read_message()?);
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.identification)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.setup.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.stacker.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        for value in &self.events {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if let Some(v) = self.identification.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.setup.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if let Some(v) = self.stacker.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        for v in &self.events {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        };
+        if let Some(v) = self.identification.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MdbBillResult {
+        MdbBillResult::new()
+    }
+
+    fn clear(&mut self) {
+        self.setup.clear();
+        self.stacker.clear();
+        self.events.clear();
+        self.identification.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MdbBillResult {
+        static instance: MdbBillResult = MdbBillResult {
+            setup: ::protobuf::MessageField::none(),
+            stacker: ::protobuf::MessageField::none(),
+            events: ::std::vec::Vec::new(),
+            identification: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for MdbBillResult {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MdbBillResult").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MdbBillResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MdbBillResult {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.MdbCashless)
+pub struct MdbCashless {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.MdbCashless.op)
+    pub op: ::protobuf::EnumOrUnknown<mdb_cashless::Op>,
+    // @@protoc_insertion_point(field:iodin.MdbCashless.address)
+    pub address: u32,
+    // @@protoc_insertion_point(field:iodin.MdbCashless.display_columns)
+    pub display_columns: u32,
+    // @@protoc_insertion_point(field:iodin.MdbCashless.display_rows)
+    pub display_rows: u32,
+    // @@protoc_insertion_point(field:iodin.MdbCashless.max_price)
+    pub max_price: u32,
+    // @@protoc_insertion_point(field:iodin.MdbCashless.min_price)
+    pub min_price: u32,
+    // @@protoc_insertion_point(field:iodin.MdbCashless.price)
+    pub price: u32,
+    // @@protoc_insertion_point(field:iodin.MdbCashless.item)
+    pub item: u32,
+    // @@protoc_insertion_point(field:iodin.MdbCashless.timeout)
+    pub timeout: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.MdbCashless.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MdbCashless {
+    fn default() -> &'a MdbCashless {
+        <MdbCashless as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MdbCashless {
+    pub fn new() -> MdbCashless {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(9);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "op",
+            |m: &MdbCashless| { &m.op },
+            |m: &mut MdbCashless| { &mut m.op },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "address",
+            |m: &MdbCashless| { &m.address },
+            |m: &mut MdbCashless| { &mut m.address },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "display_columns",
+            |m: &MdbCashless| { &m.display_columns },
+            |m: &mut MdbCashless| { &mut m.display_columns },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "display_rows",
+            |m: &MdbCashless| { &m.display_rows },
+            |m: &mut MdbCashless| { &mut m.display_rows },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "max_price",
+            |m: &MdbCashless| { &m.max_price },
+            |m: &mut MdbCashless| { &mut m.max_price },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "min_price",
+            |m: &MdbCashless| { &m.min_price },
+            |m: &mut MdbCashless| { &mut m.min_price },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "price",
+            |m: &MdbCashless| { &m.price },
+            |m: &mut MdbCashless| { &mut m.price },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "item",
+            |m: &MdbCashless| { &m.item },
+            |m: &mut MdbCashless| { &mut m.item },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "timeout",
+            |m: &MdbCashless| { &m.timeout },
+            |m: &mut MdbCashless| { &mut m.timeout },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MdbCashless>(
+            "MdbCashless",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MdbCashless {
+    const NAME: &'static str = "MdbCashless";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.op = is.read_enum_or_unknown()?;
+                },
+                16 => {
+                    self.address = is.read_uint32()?;
+                },
+                24 => {
+                    self.display_columns = is.read_uint32()?;
+                },
+                32 => {
+                    self.display_rows = is.read_uint32()?;
+                },
+                40 => {
+                    self.max_price = is.read_uint32()?;
+                },
+                48 => {
+                    self.min_price = is.read_uint32()?;
+                },
+                56 => {
+                    self.price = is.read_uint32()?;
+                },
+                64 => {
+                    self.item = is.read_uint32()?;
+                },
+                72 => {
+                    self.timeout = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.op != ::protobuf::EnumOrUnknown::new(mdb_cashless::Op::INVALID) {
+            my_size += ::protobuf::rt::int32_size(1, self.op.value());
+        }
+        if self.address != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.address);
+        }
+        if self.display_columns != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.display_columns);
+        }
+        if self.display_rows != 0 {
+            my_size += ::protobuf::rt::uint32_size(4, self.display_rows);
+        }
+        if self.max_price != 0 {
+            my_size += ::protobuf::rt::uint32_size(5, self.max_price);
+        }
+        if self.min_price != 0 {
+            my_size += ::protobuf::rt::uint32_size(6, self.min_price);
+        }
+        if self.price != 0 {
+            my_size += ::protobuf::rt::uint32_size(7, self.price);
+        }
+        if self.item != 0 {
+            my_size += ::protobuf::rt::uint32_size(8, self.item);
+        }
+        if self.timeout != 0 {
+            my_size += ::protobuf::rt::uint32_size(9, self.timeout);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.op != ::protobuf::EnumOrUnknown::new(mdb_cashless::Op::INVALID) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.op))?;
+        }
+        if self.address != 0 {
+            os.write_uint32(2, self.address)?;
+        }
+        if self.display_columns != 0 {
+            os.write_uint32(3, self.display_columns)?;
+        }
+        if self.display_rows != 0 {
+            os.write_uint32(4, self.display_rows)?;
+        }
+        if self.max_price != 0 {
+            os.write_uint32(5, self.max_price)?;
+        }
+        if self.min_price != 0 {
+            os.write_uint32(6, self.min_price)?;
+        }
+        if self.price != 0 {
+            os.write_uint32(7, self.price)?;
+        }
+        if self.item != 0 {
+            os.write_uint32(8, self.item)?;
+        }
+        if self.timeout != 0 {
+            os.write_uint32(9, self.timeout)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MdbCashless {
+        MdbCashless::new()
+    }
+
+    fn clear(&mut self) {
+        self.op = ::protobuf::EnumOrUnknown::new(mdb_cashless::Op::INVALID);
+        self.address = 0;
+        self.display_columns = 0;
+        self.display_rows = 0;
+        self.max_price = 0;
+        self.min_price = 0;
+        self.price = 0;
+        self.item = 0;
+        self.timeout = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MdbCashless {
+        static instance: MdbCashless = MdbCashless {
+            op: ::protobuf::EnumOrUnknown::from_i32(0),
+            address: 0,
+            display_columns: 0,
+            display_rows: 0,
+            max_price: 0,
+            min_price: 0,
+            price: 0,
+            item: 0,
+            timeout: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for MdbCashless {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MdbCashless").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MdbCashless {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MdbCashless {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `MdbCashless`
+pub mod mdb_cashless {
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    // @@protoc_insertion_point(enum:iodin.MdbCashless.Op)
+    pub enum Op {
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashless.Op.INVALID)
+        INVALID = 0,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashless.Op.RESET)
+        RESET = 1,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashless.Op.SETUP)
+        SETUP = 2,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashless.Op.ENABLE)
+        ENABLE = 3,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashless.Op.DISABLE)
+        DISABLE = 4,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashless.Op.CANCEL)
+        CANCEL = 5,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashless.Op.POLL)
+        POLL = 6,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashless.Op.VEND_REQUEST)
+        VEND_REQUEST = 7,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashless.Op.VEND_CANCEL)
+        VEND_CANCEL = 8,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashless.Op.VEND_SUCCESS)
+        VEND_SUCCESS = 9,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashless.Op.VEND_FAILURE)
+        VEND_FAILURE = 10,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashless.Op.SESSION_COMPLETE)
+        SESSION_COMPLETE = 11,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashless.Op.IDENTIFICATION)
+        IDENTIFICATION = 12,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashless.Op.STATE)
+        STATE = 13,
+    }
+
+    impl ::protobuf::Enum for Op {
+        const NAME: &'static str = "Op";
+
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Op> {
+            match value {
+                0 => ::std::option::Option::Some(Op::INVALID),
+                1 => ::std::option::Option::Some(Op::RESET),
+                2 => ::std::option::Option::Some(Op::SETUP),
+                3 => ::std::option::Option::Some(Op::ENABLE),
+                4 => ::std::option::Option::Some(Op::DISABLE),
+                5 => ::std::option::Option::Some(Op::CANCEL),
+                6 => ::std::option::Option::Some(Op::POLL),
+                7 => ::std::option::Option::Some(Op::VEND_REQUEST),
+                8 => ::std::option::Option::Some(Op::VEND_CANCEL),
+                9 => ::std::option::Option::Some(Op::VEND_SUCCESS),
+                10 => ::std::option::Option::Some(Op::VEND_FAILURE),
+                11 => ::std::option::Option::Some(Op::SESSION_COMPLETE),
+                12 => ::std::option::Option::Some(Op::IDENTIFICATION),
+                13 => ::std::option::Option::Some(Op::STATE),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        const VALUES: &'static [Op] = &[
+            Op::INVALID,
+            Op::RESET,
+            Op::SETUP,
+            Op::ENABLE,
+            Op::DISABLE,
+            Op::CANCEL,
+            Op::POLL,
+            Op::VEND_REQUEST,
+            Op::VEND_CANCEL,
+            Op::VEND_SUCCESS,
+            Op::VEND_FAILURE,
+            Op::SESSION_COMPLETE,
+            Op::IDENTIFICATION,
+            Op::STATE,
+        ];
+    }
+
+    impl ::protobuf::EnumFull for Op {
+        fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("MdbCashless.Op").unwrap()).clone()
+        }
+
+        fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+            let index = *self as usize;
+            Self::enum_descriptor().value_by_index(index)
+        }
+    }
+
+    impl ::std::default::Default for Op {
+        fn default() -> Self {
+            Op::INVALID
+        }
+    }
+
+    impl Op {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Op>("MdbCashless.Op")
+        }
+    }
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.MdbCashlessSetup)
+pub struct MdbCashlessSetup {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.MdbCashlessSetup.feature_level)
+    pub feature_level: u32,
+    // @@protoc_insertion_point(field:iodin.MdbCashlessSetup.country_code)
+    pub country_code: u32,
+    // @@protoc_insertion_point(field:iodin.MdbCashlessSetup.scale_factor)
+    pub scale_factor: u32,
+    // @@protoc_insertion_point(field:iodin.MdbCashlessSetup.decimal_places)
+    pub decimal_places: u32,
+    // @@protoc_insertion_point(field:iodin.MdbCashlessSetup.max_response_time)
+    pub max_response_time: u32,
+    // @@protoc_insertion_point(field:iodin.MdbCashlessSetup.options)
+    pub options: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.MdbCashlessSetup.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MdbCashlessSetup {
+    fn default() -> &'a MdbCashlessSetup {
+        <MdbCashlessSetup as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MdbCashlessSetup {
+    pub fn new() -> MdbCashlessSetup {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "feature_level",
+            |m: &MdbCashlessSetup| { &m.feature_level },
+            |m: &mut MdbCashlessSetup| { &mut m.feature_level },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "country_code",
+            |m: &MdbCashlessSetup| { &m.country_code },
+            |m: &mut MdbCashlessSetup| { &mut m.country_code },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "scale_factor",
+            |m: &MdbCashlessSetup| { &m.scale_factor },
+            |m: &mut MdbCashlessSetup| { &mut m.scale_factor },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "decimal_places",
+            |m: &MdbCashlessSetup| { &m.decimal_places },
+            |m: &mut MdbCashlessSetup| { &mut m.decimal_places },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "max_response_time",
+            |m: &MdbCashlessSetup| { &m.max_response_time },
+            |m: &mut MdbCashlessSetup| { &mut m.max_response_time },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "options",
+            |m: &MdbCashlessSetup| { &m.options },
+            |m: &mut MdbCashlessSetup| { &mut m.options },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MdbCashlessSetup>(
+            "MdbCashlessSetup",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MdbCashlessSetup {
+    const NAME: &'static str = "MdbCashlessSetup";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.feature_level = is.read_uint32()?;
+                },
+                16 => {
+                    self.country_code = is.read_uint32()?;
+                },
+                24 => {
+                    self.scale_factor = is.read_uint32()?;
+                },
+                32 => {
+                    self.decimal_places = is.read_uint32()?;
+                },
+                40 => {
+                    self.max_response_time = is.read_uint32()?;
+                },
+                48 => {
+                    self.options = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.feature_level != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.feature_level);
+        }
+        if self.country_code != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.country_code);
+        }
+        if self.scale_factor != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.scale_factor);
+        }
+        if self.decimal_places != 0 {
+            my_size += ::protobuf::rt::uint32_size(4, self.decimal_places);
+        }
+        if self.max_response_time != 0 {
+            my_size += ::protobuf::rt::uint32_size(5, self.max_response_time);
+        }
+        if self.options != 0 {
+            my_size += ::protobuf::rt::uint32_size(6, self.options);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.feature_level != 0 {
+            os.write_uint32(1, self.feature_level)?;
+        }
+        if self.country_code != 0 {
+            os.write_uint32(2, self.country_code)?;
+        }
+        if self.scale_factor != 0 {
+            os.write_uint32(3, self.scale_factor)?;
+        }
+        if self.decimal_places != 0 {
+            os.write_uint32(4, self.decimal_places)?;
+        }
+        if self.max_response_time != 0 {
+            os.write_uint32(5, self.max_response_time)?;
+        }
+        if self.options != 0 {
+            os.write_uint32(6, self.options)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MdbCashlessSetup {
+        MdbCashlessSetup::new()
+    }
+
+    fn clear(&mut self) {
+        self.feature_level = 0;
+        self.country_code = 0;
+        self.scale_factor = 0;
+        self.decimal_places = 0;
+        self.max_response_time = 0;
+        self.options = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MdbCashlessSetup {
+        static instance: MdbCashlessSetup = MdbCashlessSetup {
+            feature_level: 0,
+            country_code: 0,
+            scale_factor: 0,
+            decimal_places: 0,
+            max_response_time: 0,
+            options: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for MdbCashlessSetup {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MdbCashlessSetup").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MdbCashlessSetup {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MdbCashlessSetup {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default,Debug)]
+// @@protoc_insertion_point(message:iodin.MdbCashlessEvent)
+pub struct MdbCashlessEvent {
+    // message fields
+    // @@protoc_insertion_point(field:iodin.MdbCashlessEvent.kind)
+    pub kind: ::protobuf::EnumOrUnknown<mdb_cashless_event::Kind>,
+    // @@protoc_insertion_point(field:iodin.MdbCashlessEvent.amount)
+    pub amount: u32,
+    // @@protoc_insertion_point(field:iodin.MdbCashlessEvent.code)
+    pub code: u32,
+    // @@protoc_insertion_point(field:iodin.MdbCashlessEvent.display_time)
+    pub display_time: u32,
+    // @@protoc_insertion_point(field:iodin.MdbCashlessEvent.display_data)
+    pub display_data: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:iodin.MdbCashlessEvent.setup)
+    pub setup: ::protobuf::MessageField<MdbCashlessSetup>,
+    // @@protoc_insertion_point(field:iodin.MdbCashlessEvent.identification)
+    pub identification: ::protobuf::MessageField<MdbIdentification>,
+    // @@protoc_insertion_point(field:iodin.MdbCashlessEvent.timeout)
+    pub timeout: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:iodin.MdbCashlessEvent.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MdbCashlessEvent {
+    fn default() -> &'a MdbCashlessEvent {
+        <MdbCashlessEvent as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MdbCashlessEvent {
+    pub fn new() -> MdbCashlessEvent {
+        ::std::default::Default::default()
+    }
+
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut fields = ::std::vec::Vec::with_capacity(8);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "version",
-            |m: &Request| { &m.version },
-            |m: &mut Request| { &mut m.version },
+            "kind",
+            |m: &MdbCashlessEvent| { &m.kind },
+            |m: &mut MdbCashlessEvent| { &mut m.kind },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "command",
-            |m: &Request| { &m.command },
-            |m: &mut Request| { &mut m.command },
+            "amount",
+            |m: &MdbCashlessEvent| { &m.amount },
+            |m: &mut MdbCashlessEvent| { &mut m.amount },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "timeout",
-            |m: &Request| { &m.timeout },
-            |m: &mut Request| { &mut m.timeout },
+            "code",
+            |m: &MdbCashlessEvent| { &m.code },
+            |m: &mut MdbCashlessEvent| { &mut m.code },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "arg_uint",
-            |m: &Request| { &m.arg_uint },
-            |m: &mut Request| { &mut m.arg_uint },
+            "display_time",
+            |m: &MdbCashlessEvent| { &m.display_time },
+            |m: &mut MdbCashlessEvent| { &mut m.display_time },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "arg_bytes",
-            |m: &Request| { &m.arg_bytes },
-            |m: &mut Request| { &mut m.arg_bytes },
+            "display_data",
+            |m: &MdbCashlessEvent| { &m.display_data },
+            |m: &mut MdbCashlessEvent| { &mut m.display_data },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Request>(
-            "Request",
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, MdbCashlessSetup>(
+            "setup",
+            |m: &MdbCashlessEvent| { &m.setup },
+            |m: &mut MdbCashlessEvent| { &mut m.setup },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, MdbIdentification>(
+            "identification",
+            |m: &MdbCashlessEvent| { &m.identification },
+            |m: &mut MdbCashlessEvent| { &mut m.identification },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "timeout",
+            |m: &MdbCashlessEvent| { &m.timeout },
+            |m: &mut MdbCashlessEvent| { &mut m.timeout },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MdbCashlessEvent>(
+            "MdbCashlessEvent",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for Request {
-    const NAME: &'static str = "Request";
+impl ::protobuf::Message for MdbCashlessEvent {
+    const NAME: &'static str = "MdbCashlessEvent";
 
     fn is_initialized(&self) -> bool {
         true
@@ -102,19 +8090,28 @@ impl ::protobuf::Message for Request {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 8 => {
-                    self.version = is.read_uint32()?;
+                    self.kind = is.read_enum_or_unknown()?;
                 },
                 16 => {
-                    self.command = is.read_enum_or_unknown()?;
+                    self.amount = is.read_uint32()?;
                 },
                 24 => {
-                    self.timeout = is.read_uint32()?;
+                    self.code = is.read_uint32()?;
                 },
                 32 => {
-                    self.arg_uint = is.read_uint32()?;
+                    self.display_time = is.read_uint32()?;
                 },
                 42 => {
-                    self.arg_bytes = is.read_bytes()?;
+                    self.display_data = is.read_bytes()?;
+                },
+                50 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.setup)?;
+                },
+                58 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.identification)?;
+                },
+                64 => {
+                    self.timeout = is.read_bool()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -128,20 +8125,31 @@ impl ::protobuf::Message for Request {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if self.version != 0 {
-            my_size += ::protobuf::rt::uint32_size(1, self.version);
+        if self.kind != ::protobuf::EnumOrUnknown::new(mdb_cashless_event::Kind::INVALID) {
+            my_size += ::protobuf::rt::int32_size(1, self.kind.value());
         }
-        if self.command != ::protobuf::EnumOrUnknown::new(request::Command::INVALID) {
-            my_size += ::protobuf::rt::int32_size(2, self.command.value());
+        if self.amount != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.amount);
         }
-        if self.timeout != 0 {
-            my_size += ::protobuf::rt::uint32_size(3, self.timeout);
+        if self.code != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.code);
         }
-        if self.arg_uint != 0 {
-            my_size += ::protobuf::rt::uint32_size(4, self.arg_uint);
+        if self.display_time != 0 {
+            my_size += ::protobuf::rt::uint32_size(4, self.display_time);
         }
-        if !self.arg_bytes.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(5, &self.arg_bytes);
+        if !self.display_data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(5, &self.display_data);
+        }
+        if let Some(v) = self.setup.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.identification.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.timeout != false {
+            my_size += 1 + 1;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -149,20 +8157,29 @@ impl ::protobuf::Message for Request {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.version != 0 {
-            os.write_uint32(1, self.version)?;
+        if self.kind != ::protobuf::EnumOrUnknown::new(mdb_cashless_event::Kind::INVALID) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.kind))?;
         }
-        if self.command != ::protobuf::EnumOrUnknown::new(request::Command::INVALID) {
-            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.command))?;
+        if self.amount != 0 {
+            os.write_uint32(2, self.amount)?;
         }
-        if self.timeout != 0 {
-            os.write_uint32(3, self.timeout)?;
+        if self.code != 0 {
+            os.write_uint32(3, self.code)?;
         }
-        if self.arg_uint != 0 {
-            os.write_uint32(4, self.arg_uint)?;
+        if self.display_time != 0 {
+            os.write_uint32(4, self.display_time)?;
         }
-        if !self.arg_bytes.is_empty() {
-            os.write_bytes(5, &self.arg_bytes)?;
+        if !self.display_data.is_empty() {
+            os.write_bytes(5, &self.display_data)?;
+        }
+        if let Some(v) = self.setup.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
+        }
+        if let Some(v) = self.identification.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(7, v, os)?;
+        }
+        if self.timeout != false {
+            os.write_bool(8, self.timeout)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -176,185 +8193,224 @@ impl ::protobuf::Message for Request {
         &mut self.special_fields
     }
 
-    fn new() -> Request {
-        Request::new()
+    fn new() -> MdbCashlessEvent {
+        MdbCashlessEvent::new()
     }
 
     fn clear(&mut self) {
-        self.version = 0;
-        self.command = ::protobuf::EnumOrUnknown::new(request::Command::INVALID);
-        self.timeout = 0;
-        self.arg_uint = 0;
-        self.arg_bytes.clear();
+        self.kind = ::protobuf::EnumOrUnknown::new(mdb_cashless_event::Kind::INVALID);
+        self.amount = 0;
+        self.code = 0;
+        self.display_time = 0;
+        self.display_data.clear();
+        self.setup.clear();
+        self.identification.clear();
+        self.timeout = false;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static Request {
-        static instance: Request = Request {
-            version: 0,
-            command: ::protobuf::EnumOrUnknown::from_i32(0),
-            timeout: 0,
-            arg_uint: 0,
-            arg_bytes: ::std::vec::Vec::new(),
+    fn default_instance() -> &'static MdbCashlessEvent {
+        static instance: MdbCashlessEvent = MdbCashlessEvent {
+            kind: ::protobuf::EnumOrUnknown::from_i32(0),
+            amount: 0,
+            code: 0,
+            display_time: 0,
+            display_data: ::std::vec::Vec::new(),
+            setup: ::protobuf::MessageField::none(),
+            identification: ::protobuf::MessageField::none(),
+            timeout: false,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for Request {
+impl ::protobuf::MessageFull for MdbCashlessEvent {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("Request").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MdbCashlessEvent").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for Request {
+impl ::std::fmt::Display for MdbCashlessEvent {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Request {
+impl ::protobuf::reflect::ProtobufValue for MdbCashlessEvent {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-/// Nested message and enums of message `Request`
-pub mod request {
+/// Nested message and enums of message `MdbCashlessEvent`
+pub mod mdb_cashless_event {
     #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
-    // @@protoc_insertion_point(enum:iodin.Request.Command)
-    pub enum Command {
-        // @@protoc_insertion_point(enum_value:iodin.Request.Command.INVALID)
+    // @@protoc_insertion_point(enum:iodin.MdbCashlessEvent.Kind)
+    pub enum Kind {
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashlessEvent.Kind.INVALID)
         INVALID = 0,
-        // @@protoc_insertion_point(enum_value:iodin.Request.Command.MDB_OPEN)
-        MDB_OPEN = 1,
-        // @@protoc_insertion_point(enum_value:iodin.Request.Command.MDB_RESET)
-        MDB_RESET = 2,
-        // @@protoc_insertion_point(enum_value:iodin.Request.Command.MDB_TX)
-        MDB_TX = 3,
-        // @@protoc_insertion_point(enum_value:iodin.Request.Command.STOP)
-        STOP = 100,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashlessEvent.Kind.JUST_RESET)
+        JUST_RESET = 1,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashlessEvent.Kind.READER_CONFIG)
+        READER_CONFIG = 2,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashlessEvent.Kind.DISPLAY_REQUEST)
+        DISPLAY_REQUEST = 3,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashlessEvent.Kind.BEGIN_SESSION)
+        BEGIN_SESSION = 4,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashlessEvent.Kind.SESSION_CANCEL_REQUEST)
+        SESSION_CANCEL_REQUEST = 5,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashlessEvent.Kind.VEND_APPROVED)
+        VEND_APPROVED = 6,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashlessEvent.Kind.VEND_DENIED)
+        VEND_DENIED = 7,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashlessEvent.Kind.END_SESSION)
+        END_SESSION = 8,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashlessEvent.Kind.CANCELLED)
+        CANCELLED = 9,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashlessEvent.Kind.PERIPHERAL_ID)
+        PERIPHERAL_ID = 10,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashlessEvent.Kind.MALFUNCTION)
+        MALFUNCTION = 11,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashlessEvent.Kind.OUT_OF_SEQUENCE)
+        OUT_OF_SEQUENCE = 12,
     }
 
-    impl ::protobuf::Enum for Command {
-        const NAME: &'static str = "Command";
+    impl ::protobuf::Enum for Kind {
+        const NAME: &'static str = "Kind";
 
         fn value(&self) -> i32 {
             *self as i32
         }
 
-        fn from_i32(value: i32) -> ::std::option::Option<Command> {
+        fn from_i32(value: i32) -> ::std::option::Option<Kind> {
             match value {
-                0 => ::std::option::Option::Some(Command::INVALID),
-                1 => ::std::option::Option::Some(Command::MDB_OPEN),
-                2 => ::std::option::Option::Some(Command::MDB_RESET),
-                3 => ::std::option::Option::Some(Command::MDB_TX),
-                100 => ::std::option::Option::Some(Command::STOP),
+                0 => ::std::option::Option::Some(Kind::INVALID),
+                1 => ::std::option::Option::Some(Kind::JUST_RESET),
+                2 => ::std::option::Option::Some(Kind::READER_CONFIG),
+                3 => ::std::option::Option::Some(Kind::DISPLAY_REQUEST),
+                4 => ::std::option::Option::Some(Kind::BEGIN_SESSION),
+                5 => ::std::option::Option::Some(Kind::SESSION_CANCEL_REQUEST),
+                6 => ::std::option::Option::Some(Kind::VEND_APPROVED),
+                7 => ::std::option::Option::Some(Kind::VEND_DENIED),
+                8 => ::std::option::Option::Some(Kind::END_SESSION),
+                9 => ::std::option::Option::Some(Kind::CANCELLED),
+                10 => ::std::option::Option::Some(Kind::PERIPHERAL_ID),
+                11 => ::std::option::Option::Some(Kind::MALFUNCTION),
+                12 => ::std::option::Option::Some(Kind::OUT_OF_SEQUENCE),
                 _ => ::std::option::Option::None
             }
         }
 
-        const VALUES: &'static [Command] = &[
-            Command::INVALID,
-            Command::MDB_OPEN,
-            Command::MDB_RESET,
-            Command::MDB_TX,
-            Command::STOP,
+        const VALUES: &'static [Kind] = &[
+            Kind::INVALID,
+            Kind::JUST_RESET,
+            Kind::READER_CONFIG,
+            Kind::DISPLAY_REQUEST,
+            Kind::BEGIN_SESSION,
+            Kind::SESSION_CANCEL_REQUEST,
+            Kind::VEND_APPROVED,
+            Kind::VEND_DENIED,
+            Kind::END_SESSION,
+            Kind::CANCELLED,
+            Kind::PERIPHERAL_ID,
+            Kind::MALFUNCTION,
+            Kind::OUT_OF_SEQUENCE,
         ];
     }
 
-    impl ::protobuf::EnumFull for Command {
+    impl ::protobuf::EnumFull for Kind {
         fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
             static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
-            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("Request.Command").unwrap()).clone()
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("MdbCashlessEvent.Kind").unwrap()).clone()
         }
 
         fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
-            let index = match self {
-                Command::INVALID => 0,
-                Command::MDB_OPEN => 1,
-                Command::MDB_RESET => 2,
-                Command::MDB_TX => 3,
-                Command::STOP => 4,
-            };
+            let index = *self as usize;
             Self::enum_descriptor().value_by_index(index)
         }
     }
 
-    impl ::std::default::Default for Command {
+    impl ::std::default::Default for Kind {
         fn default() -> Self {
-            Command::INVALID
+            Kind::INVALID
         }
     }
 
-    impl Command {
+    impl Kind {
         pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
-            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Command>("Request.Command")
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Kind>("MdbCashlessEvent.Kind")
         }
     }
 }
 
 #[derive(PartialEq,Clone,Default,Debug)]
-// @@protoc_insertion_point(message:iodin.Response)
-pub struct Response {
+// @@protoc_insertion_point(message:iodin.MdbCashlessResult)
+pub struct MdbCashlessResult {
     // message fields
-    // @@protoc_insertion_point(field:iodin.Response.version)
-    pub version: u32,
-    // @@protoc_insertion_point(field:iodin.Response.status)
-    pub status: ::protobuf::EnumOrUnknown<response::Status>,
-    // @@protoc_insertion_point(field:iodin.Response.error)
-    pub error: ::std::string::String,
-    // @@protoc_insertion_point(field:iodin.Response.data_bytes)
-    pub data_bytes: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:iodin.MdbCashlessResult.state)
+    pub state: ::protobuf::EnumOrUnknown<mdb_cashless_result::State>,
+    // @@protoc_insertion_point(field:iodin.MdbCashlessResult.setup)
+    pub setup: ::protobuf::MessageField<MdbCashlessSetup>,
+    // @@protoc_insertion_point(field:iodin.MdbCashlessResult.events)
+    pub events: ::std::vec::Vec<MdbCashlessEvent>,
+    // @@protoc_insertion_point(field:iodin.MdbCashlessResult.identification)
+    pub identification: ::protobuf::MessageField<MdbIdentification>,
+    // @@protoc_insertion_point(field:iodin.MdbCashlessResult.funds)
+    pub funds: u32,
     // special fields
-    // @@protoc_insertion_point(special_field:iodin.Response.special_fields)
+    // @@protoc_insertion_point(special_field:iodin.MdbCashlessResult.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a Response {
-    fn default() -> &'a Response {
-        <Response as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a MdbCashlessResult {
+    fn default() -> &'a MdbCashlessResult {
+        <MdbCashlessResult as ::protobuf::Message>::default_instance()
     }
 }
 
-impl Response {
-    pub fn new() -> Response {
+impl MdbCashlessResult {
+    pub fn new() -> MdbCashlessResult {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut fields = ::std::vec::Vec::with_capacity(5);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "version",
-            |m: &Response| { &m.version },
-            |m: &mut Response| { &mut m.version },
+            "state",
+            |m: &MdbCashlessResult| { &m.state },
+            |m: &mut MdbCashlessResult| { &mut m.state },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "status",
-            |m: &Response| { &m.status },
-            |m: &mut Response| { &mut m.status },
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, MdbCashlessSetup>(
+            "setup",
+            |m: &MdbCashlessResult| { &m.setup },
+            |m: &mut MdbCashlessResult| { &mut m.setup },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "error",
-            |m: &Response| { &m.error },
-            |m: &mut Response| { &mut m.error },
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "events",
+            |m: &MdbCashlessResult| { &m.events },
+            |m: &mut MdbCashlessResult| { &mut m.events },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, MdbIdentification>(
+            "identification",
+            |m: &MdbCashlessResult| { &m.identification },
+            |m: &mut MdbCashlessResult| { &mut m.identification },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "data_bytes",
-            |m: &Response| { &m.data_bytes },
-            |m: &mut Response| { &mut m.data_bytes },
+            "funds",
+            |m: &MdbCashlessResult| { &m.funds },
+            |m: &mut MdbCashlessResult| { &mut m.funds },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Response>(
-            "Response",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MdbCashlessResult>(
+            "MdbCashlessResult",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for Response {
-    const NAME: &'static str = "Response";
+impl ::protobuf::Message for MdbCashlessResult {
+    const NAME: &'static str = "MdbCashlessResult";
 
     fn is_initialized(&self) -> bool {
         true
@@ -364,16 +8420,19 @@ impl ::protobuf::Message for Response {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 8 => {
-                    self.version = is.read_uint32()?;
+                    self.state = is.read_enum_or_unknown()?;
                 },
-                16 => {
-                    self.status = is.read_enum_or_unknown()?;
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.setup)?;
                 },
                 26 => {
-                    self.error = is.read_string()?;
+                    self.events.push(is.read_message()?);
                 },
                 34 => {
-                    self.data_bytes = is.read_bytes()?;
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.identification)?;
+                },
+                40 => {
+                    self.funds = is.read_uint32()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -387,17 +8446,23 @@ impl ::protobuf::Message for Response {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if self.version != 0 {
-            my_size += ::protobuf::rt::uint32_size(1, self.version);
+        if self.state != ::protobuf::EnumOrUnknown::new(mdb_cashless_result::State::INACTIVE) {
+            my_size += ::protobuf::rt::int32_size(1, self.state.value());
         }
-        if self.status != ::protobuf::EnumOrUnknown::new(response::Status::INVALID) {
-            my_size += ::protobuf::rt::int32_size(2, self.status.value());
+        if let Some(v) = self.setup.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
-        if !self.error.is_empty() {
-            my_size += ::protobuf::rt::string_size(3, &self.error);
+        for value in &self.events {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if let Some(v) = self.identification.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
         }
-        if !self.data_bytes.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(4, &self.data_bytes);
+        if self.funds != 0 {
+            my_size += ::protobuf::rt::uint32_size(5, self.funds);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -405,17 +8470,20 @@ impl ::protobuf::Message for Response {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.version != 0 {
-            os.write_uint32(1, self.version)?;
+        if self.state != ::protobuf::EnumOrUnknown::new(mdb_cashless_result::State::INACTIVE) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.state))?;
         }
-        if self.status != ::protobuf::EnumOrUnknown::new(response::Status::INVALID) {
-            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.status))?;
+        if let Some(v) = self.setup.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
         }
-        if !self.error.is_empty() {
-            os.write_string(3, &self.error)?;
+        for v in &self.events {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        };
+        if let Some(v) = self.identification.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
         }
-        if !self.data_bytes.is_empty() {
-            os.write_bytes(4, &self.data_bytes)?;
+        if self.funds != 0 {
+            os.write_uint32(5, self.funds)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -429,103 +8497,105 @@ impl ::protobuf::Message for Response {
         &mut self.special_fields
     }
 
-    fn new() -> Response {
-        Response::new()
+    fn new() -> MdbCashlessResult {
+        MdbCashlessResult::new()
     }
 
     fn clear(&mut self) {
-        self.version = 0;
-        self.status = ::protobuf::EnumOrUnknown::new(response::Status::INVALID);
-        self.error.clear();
-        self.data_bytes.clear();
+        self.state = ::protobuf::EnumOrUnknown::new(mdb_cashless_result::State::INACTIVE);
+        self.setup.clear();
+        self.events.clear();
+        self.identification.clear();
+        self.funds = 0;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static Response {
-        static instance: Response = Response {
-            version: 0,
-            status: ::protobuf::EnumOrUnknown::from_i32(0),
-            error: ::std::string::String::new(),
-            data_bytes: ::std::vec::Vec::new(),
+    fn default_instance() -> &'static MdbCashlessResult {
+        static instance: MdbCashlessResult = MdbCashlessResult {
+            state: ::protobuf::EnumOrUnknown::from_i32(0),
+            setup: ::protobuf::MessageField::none(),
+            events: ::std::vec::Vec::new(),
+            identification: ::protobuf::MessageField::none(),
+            funds: 0,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for Response {
+impl ::protobuf::MessageFull for MdbCashlessResult {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("Response").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MdbCashlessResult").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for Response {
+impl ::std::fmt::Display for MdbCashlessResult {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Response {
+impl ::protobuf::reflect::ProtobufValue for MdbCashlessResult {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-/// Nested message and enums of message `Response`
-pub mod response {
+/// Nested message and enums of message `MdbCashlessResult`
+pub mod mdb_cashless_result {
     #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
-    // @@protoc_insertion_point(enum:iodin.Response.Status)
-    pub enum Status {
-        // @@protoc_insertion_point(enum_value:iodin.Response.Status.INVALID)
-        INVALID = 0,
-        // @@protoc_insertion_point(enum_value:iodin.Response.Status.OK)
-        OK = 1,
-        // @@protoc_insertion_point(enum_value:iodin.Response.Status.ERR_INPUT)
-        ERR_INPUT = 2,
-        // @@protoc_insertion_point(enum_value:iodin.Response.Status.ERR_INTERNAL)
-        ERR_INTERNAL = 3,
-        // @@protoc_insertion_point(enum_value:iodin.Response.Status.ERR_CHECKSUM)
-        ERR_CHECKSUM = 4,
-        // @@protoc_insertion_point(enum_value:iodin.Response.Status.ERR_TIMEOUT)
-        ERR_TIMEOUT = 5,
-        // @@protoc_insertion_point(enum_value:iodin.Response.Status.ERR_HARDWARE)
-        ERR_HARDWARE = 6,
+    // @@protoc_insertion_point(enum:iodin.MdbCashlessResult.State)
+    pub enum State {
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashlessResult.State.INACTIVE)
+        INACTIVE = 0,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashlessResult.State.DISABLED)
+        DISABLED = 1,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashlessResult.State.ENABLED)
+        ENABLED = 2,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashlessResult.State.SESSION_IDLE)
+        SESSION_IDLE = 3,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashlessResult.State.VEND_REQUESTED)
+        VEND_REQUESTED = 4,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashlessResult.State.VEND_APPROVED)
+        VEND_APPROVED = 5,
+        // @@protoc_insertion_point(enum_value:iodin.MdbCashlessResult.State.SESSION_END)
+        SESSION_END = 6,
     }
 
-    impl ::protobuf::Enum for Status {
-        const NAME: &'static str = "Status";
+    impl ::protobuf::Enum for State {
+        const NAME: &'static str = "State";
 
         fn value(&self) -> i32 {
             *self as i32
         }
 
-        fn from_i32(value: i32) -> ::std::option::Option<Status> {
+        fn from_i32(value: i32) -> ::std::option::Option<State> {
             match value {
-                0 => ::std::option::Option::Some(Status::INVALID),
-                1 => ::std::option::Option::Some(Status::OK),
-                2 => ::std::option::Option::Some(Status::ERR_INPUT),
-                3 => ::std::option::Option::Some(Status::ERR_INTERNAL),
-                4 => ::std::option::Option::Some(Status::ERR_CHECKSUM),
-                5 => ::std::option::Option::Some(Status::ERR_TIMEOUT),
-                6 => ::std::option::Option::Some(Status::ERR_HARDWARE),
+                0 => ::std::option::Option::Some(State::INACTIVE),
+                1 => ::std::option::Option::Some(State::DISABLED),
+                2 => ::std::option::Option::Some(State::ENABLED),
+                3 => ::std::option::Option::Some(State::SESSION_IDLE),
+                4 => ::std::option::Option::Some(State::VEND_REQUESTED),
+                5 => ::std::option::Option::Some(State::VEND_APPROVED),
+                6 => ::std::option::Option::Some(State::SESSION_END),
                 _ => ::std::option::Option::None
             }
         }
 
-        const VALUES: &'static [Status] = &[
-            Status::INVALID,
-            Status::OK,
-            Status::ERR_INPUT,
-            Status::ERR_INTERNAL,
-            Status::ERR_CHECKSUM,
-            Status::ERR_TIMEOUT,
-            Status::ERR_HARDWARE,
+        const VALUES: &'static [State] = &[
+            State::INACTIVE,
+            State::DISABLED,
+            State::ENABLED,
+            State::SESSION_IDLE,
+            State::VEND_REQUESTED,
+            State::VEND_APPROVED,
+            State::SESSION_END,
         ];
     }
 
-    impl ::protobuf::EnumFull for Status {
+    impl ::protobuf::EnumFull for State {
         fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
             static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
-            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("Response.Status").unwrap()).clone()
+            descriptor.get(|| super::file_descriptor().enum_by_package_relative_name("MdbCashlessResult.State").unwrap()).clone()
         }
 
         fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
@@ -534,34 +8604,255 @@ pub mod response {
         }
     }
 
-    impl ::std::default::Default for Status {
+    impl ::std::default::Default for State {
         fn default() -> Self {
-            Status::INVALID
+            State::INACTIVE
         }
     }
 
-    impl Status {
+    impl State {
         pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
-            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Status>("Response.Status")
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new::<State>("MdbCashlessResult.State")
         }
     }
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0biodin.proto\x12\x05iodin\"\xf2\x01\n\x07Request\x12\x18\n\x07versi\
+    \n\x0biodin.proto\x12\x05iodin\"\x80\x07\n\x07Request\x12\x18\n\x07versi\
     on\x18\x01\x20\x01(\rR\x07version\x120\n\x07command\x18\x02\x20\x01(\x0e\
     2\x16.iodin.Request.CommandR\x07command\x12\x18\n\x07timeout\x18\x03\x20\
     \x01(\rR\x07timeout\x12\x19\n\x08arg_uint\x18\x04\x20\x01(\rR\x07argUint\
-    \x12\x1b\n\targ_bytes\x18\x05\x20\x01(\x0cR\x08argBytes\"I\n\x07Command\
-    \x12\x0b\n\x07INVALID\x10\0\x12\x0c\n\x08MDB_OPEN\x10\x01\x12\r\n\tMDB_R\
-    ESET\x10\x02\x12\n\n\x06MDB_TX\x10\x03\x12\x08\n\x04STOP\x10d\"\xfe\x01\
-    \n\x08Response\x12\x18\n\x07version\x18\x01\x20\x01(\rR\x07version\x12.\
-    \n\x06status\x18\x02\x20\x01(\x0e2\x16.iodin.Response.StatusR\x06status\
-    \x12\x14\n\x05error\x18\x03\x20\x01(\tR\x05error\x12\x1d\n\ndata_bytes\
-    \x18\x04\x20\x01(\x0cR\tdataBytes\"s\n\x06Status\x12\x0b\n\x07INVALID\
-    \x10\0\x12\x06\n\x02OK\x10\x01\x12\r\n\tERR_INPUT\x10\x02\x12\x10\n\x0cE\
-    RR_INTERNAL\x10\x03\x12\x10\n\x0cERR_CHECKSUM\x10\x04\x12\x0f\n\x0bERR_T\
-    IMEOUT\x10\x05\x12\x10\n\x0cERR_HARDWARE\x10\x06b\x06proto3\
+    \x12\x1b\n\targ_bytes\x18\x05\x20\x01(\x0cR\x08argBytes\x12\x0e\n\x02id\
+    \x18\x06\x20\x01(\rR\x02id\x12+\n\x08mdb_open\x18\n\x20\x01(\x0b2\x0e.io\
+    din.MdbOpenH\0R\x07mdbOpen\x12.\n\tmdb_reset\x18\x0b\x20\x01(\x0b2\x0f.i\
+    odin.MdbResetH\0R\x08mdbReset\x12%\n\x06mdb_tx\x18\x0c\x20\x01(\x0b2\x0c\
+    .iodin.MdbTxH\0R\x05mdbTx\x12.\n\tmdb_batch\x18\r\x20\x01(\x0b2\x0f.iodi\
+    n.MdbBatchH\0R\x08mdbBatch\x120\n\tsubscribe\x18\x0e\x20\x01(\x0b2\x10.i\
+    odin.SubscribeH\0R\tsubscribe\x121\n\ngpio_watch\x18\x0f\x20\x01(\x0b2\
+    \x10.iodin.GpioWatchH\0R\tgpioWatch\x12+\n\x08mdb_poll\x18\x10\x20\x01(\
+    \x0b2\x0e.iodin.MdbPollH\0R\x07mdbPoll\x12+\n\x08mdb_coin\x18\x11\x20\
+    \x01(\x0b2\x0e.iodin.MdbCoinH\0R\x07mdbCoin\x12+\n\x08mdb_bill\x18\x12\
+    \x20\x01(\x0b2\x0e.iodin.MdbBillH\0R\x07mdbBill\x127\n\x0cmdb_cashless\
+    \x18\x13\x20\x01(\x0b2\x12.iodin.MdbCashlessH\0R\x0bmdbCashless\"\xe5\
+    \x01\n\x07Command\x12\x0b\n\x07INVALID\x10\0\x12\x0c\n\x08MDB_OPEN\x10\
+    \x01\x12\r\n\tMDB_RESET\x10\x02\x12\n\n\x06MDB_TX\x10\x03\x12\t\n\x05HEL\
+    LO\x10\x04\x12\x0c\n\x08GET_INFO\x10\x05\x12\r\n\tMDB_BATCH\x10\x06\x12\
+    \r\n\tSUBSCRIBE\x10\x07\x12\x0e\n\nGPIO_WATCH\x10\x08\x12\x10\n\x0cMDB_P\
+    OLL_ADD\x10\t\x12\x13\n\x0fMDB_POLL_REMOVE\x10\n\x12\x0c\n\x08MDB_COIN\
+    \x10\x0b\x12\x0c\n\x08MDB_BILL\x10\x0c\x12\x10\n\x0cMDB_CASHLESS\x10\r\
+    \x12\x08\n\x04STOP\x10dB\x06\n\x04args\"\xeb\x01\n\x07MdbOpen\x12\x15\n\
+    \x06rx_pin\x18\x01\x20\x01(\rR\x05rxPin\x12\x15\n\x06tx_pin\x18\x02\x20\
+    \x01(\rR\x05txPin\x12+\n\x07options\x18\x03\x20\x01(\x0b2\x11.iodin.MdbO\
+    ptionsR\x07options\x126\n\ttransport\x18\x04\x20\x01(\x0e2\x18.iodin.Mdb\
+    Open.TransportR\ttransport\x12\x16\n\x06device\x18\x05\x20\x01(\tR\x06de\
+    vice\"5\n\tTransport\x12\x0b\n\x07DEFAULT\x10\0\x12\x08\n\x04GPIO\x10\
+    \x01\x12\x07\n\x03SIM\x10\x02\x12\x08\n\x04UART\x10\x03\"X\n\nMdbOptions\
+    \x12\x20\n\x0cwait_step_us\x18\x01\x20\x01(\rR\nwaitStepUs\x12(\n\x06fau\
+    lts\x18\x03\x20\x01(\x0b2\x10.iodin.MdbFaultsR\x06faults\"\xa4\x01\n\tMd\
+    bFaults\x12\x12\n\x04seed\x18\x01\x20\x01(\x04R\x04seed\x12\x12\n\x04dro\
+    p\x18\x02\x20\x01(\rR\x04drop\x12\x1a\n\x08checksum\x18\x03\x20\x01(\rR\
+    \x08checksum\x12\x10\n\x03nak\x18\x04\x20\x01(\rR\x03nak\x12\x10\n\x03ga\
+    p\x18\x05\x20\x01(\rR\x03gap\x12\x18\n\x07garbage\x18\x06\x20\x01(\rR\
+    \x07garbage\x12\x15\n\x06gap_ms\x18\x07\x20\x01(\rR\x05gapMs\"\x83\x01\n\
+    \x0bRetryPolicy\x12!\n\x0cmax_attempts\x18\x01\x20\x01(\rR\x0bmaxAttempt\
+    s\x122\n\x08retry_on\x18\x02\x20\x03(\x0e2\x17.iodin.ErrorDetail.KindR\
+    \x07retryOn\x12\x1d\n\nbackoff_ms\x18\x03\x20\x01(\rR\tbackoffMs\"+\n\
+    \x08MdbReset\x12\x1f\n\x0bduration_ms\x18\x01\x20\x01(\rR\ndurationMs\"a\
+    \n\x05MdbTx\x12\x14\n\x05frame\x18\x01\x20\x01(\x0cR\x05frame\x12\x18\n\
+    \x07timeout\x18\x02\x20\x01(\rR\x07timeout\x12(\n\x05retry\x18\x03\x20\
+    \x01(\x0b2\x12.iodin.RetryPolicyR\x05retry\"\x8a\x05\n\x08Response\x12\
+    \x18\n\x07version\x18\x01\x20\x01(\rR\x07version\x12.\n\x06status\x18\
+    \x02\x20\x01(\x0e2\x16.iodin.Response.StatusR\x06status\x12\x14\n\x05err\
+    or\x18\x03\x20\x01(\tR\x05error\x12\x1d\n\ndata_bytes\x18\x04\x20\x01(\
+    \x0cR\tdataBytes\x12\x0e\n\x02id\x18\x05\x20\x01(\rR\x02id\x12\"\n\x05he\
+    llo\x18\x06\x20\x01(\x0b2\x0c.iodin.HelloR\x05hello\x12\x1f\n\x04info\
+    \x18\x07\x20\x01(\x0b2\x0b.iodin.InfoR\x04info\x125\n\x0cerror_detail\
+    \x18\x08\x20\x01(\x0b2\x12.iodin.ErrorDetailR\x0berrorDetail\x12&\n\x05b\
+    atch\x18\t\x20\x03(\x0b2\x10.iodin.BatchItemR\x05batch\x12\"\n\x05event\
+    \x18\n\x20\x01(\x0b2\x0c.iodin.EventR\x05event\x12\x1a\n\x08attempts\x18\
+    \x0b\x20\x01(\rR\x08attempts\x12(\n\x04coin\x18\x0c\x20\x01(\x0b2\x14.io\
+    din.MdbCoinResultR\x04coin\x12(\n\x04bill\x18\r\x20\x01(\x0b2\x14.iodin.\
+    MdbBillResultR\x04bill\x124\n\x08cashless\x18\x0e\x20\x01(\x0b2\x18.iodi\
+    n.MdbCashlessResultR\x08cashless\"\x80\x01\n\x06Status\x12\x0b\n\x07INVA\
+    LID\x10\0\x12\x06\n\x02OK\x10\x01\x12\r\n\tERR_INPUT\x10\x02\x12\x10\n\
+    \x0cERR_INTERNAL\x10\x03\x12\x10\n\x0cERR_CHECKSUM\x10\x04\x12\x0f\n\x0b\
+    ERR_TIMEOUT\x10\x05\x12\x10\n\x0cERR_HARDWARE\x10\x06\x12\x0b\n\x07ERR_N\
+    AK\x10\x07\"4\n\tSubscribe\x12'\n\x05kinds\x18\x01\x20\x03(\x0e2\x11.iod\
+    in.Event.KindR\x05kinds\"y\n\x07MdbPoll\x12\x18\n\x07address\x18\x01\x20\
+    \x01(\rR\x07address\x12\x1f\n\x0binterval_ms\x18\x02\x20\x01(\rR\ninterv\
+    alMs\x12\x1d\n\ntimeout_ms\x18\x03\x20\x01(\rR\ttimeoutMs\x12\x14\n\x05f\
+    rame\x18\x04\x20\x01(\x0cR\x05frame\"5\n\tGpioWatch\x12\x10\n\x03pin\x18\
+    \x01\x20\x01(\rR\x03pin\x12\x16\n\x06enable\x18\x02\x20\x01(\x08R\x06ena\
+    ble\"\xe1\x02\n\x05Event\x12%\n\x04kind\x18\x01\x20\x01(\x0e2\x11.iodin.\
+    Event.KindR\x04kind\x12\x17\n\x07time_us\x18\x02\x20\x01(\x04R\x06timeUs\
+    \x12\x1d\n\ndata_bytes\x18\x03\x20\x01(\x0cR\tdataBytes\x12\x1f\n\x0bmdb\
+    _request\x18\x04\x20\x01(\x0cR\nmdbRequest\x12\x14\n\x05error\x18\x05\
+    \x20\x01(\tR\x05error\x125\n\x0cerror_detail\x18\x06\x20\x01(\x0b2\x12.i\
+    odin.ErrorDetailR\x0berrorDetail\x12\x12\n\x04gpio\x18\x07\x20\x01(\rR\
+    \x04gpio\x12\x14\n\x05level\x18\x08\x20\x01(\rR\x05level\x12\x12\n\x04ti\
+    ck\x18\t\x20\x01(\rR\x04tick\"M\n\x04Kind\x12\x0b\n\x07INVALID\x10\0\x12\
+    \x0c\n\x08MDB_POLL\x10\x01\x12\r\n\tGPIO_EDGE\x10\x02\x12\r\n\tBUS_ERROR\
+    \x10\x03\x12\x0c\n\x08WATCHDOG\x10\x04\"\xc3\x01\n\tBatchItem\x12.\n\x06\
+    status\x18\x01\x20\x01(\x0e2\x16.iodin.Response.StatusR\x06status\x12\
+    \x14\n\x05error\x18\x02\x20\x01(\tR\x05error\x12\x1d\n\ndata_bytes\x18\
+    \x03\x20\x01(\x0cR\tdataBytes\x125\n\x0cerror_detail\x18\x04\x20\x01(\
+    \x0b2\x12.iodin.ErrorDetailR\x0berrorDetail\x12\x1a\n\x08attempts\x18\
+    \x05\x20\x01(\rR\x08attempts\"\xc2\x03\n\x0bErrorDetail\x12+\n\x04kind\
+    \x18\x01\x20\x01(\x0e2\x17.iodin.ErrorDetail.KindR\x04kind\x12.\n\x05sta\
+    ge\x18\x02\x20\x01(\x0e2\x18.iodin.ErrorDetail.StageR\x05stage\x12+\n\
+    \x11checksum_received\x18\x03\x20\x01(\rR\x10checksumReceived\x12+\n\x11\
+    checksum_computed\x18\x04\x20\x01(\rR\x10checksumComputed\x12)\n\x10part\
+    ial_response\x18\x05\x20\x01(\x0cR\x0fpartialResponse\x12!\n\x0cinvalid_\
+    byte\x18\x06\x20\x01(\rR\x0binvalidByte\"{\n\x04Kind\x12\x0b\n\x07INVALI\
+    D\x10\0\x12\t\n\x05OTHER\x10\x01\x12\t\n\x05INPUT\x10\x02\x12\x06\n\x02I\
+    O\x10\x03\x12\x0b\n\x07MDB_NAK\x10\x04\x12\x18\n\x14MDB_INVALID_RESPONSE\
+    \x10\x05\x12\x10\n\x0cMDB_CHECKSUM\x10\x06\x12\x0f\n\x0bMDB_TIMEOUT\x10\
+    \x07\"1\n\x05Stage\x12\x08\n\x04NONE\x10\0\x12\x08\n\x04SEND\x10\x01\x12\
+    \x0b\n\x07RECEIVE\x10\x02\x12\x07\n\x03ACK\x10\x03\"R\n\x08MdbBatch\x12\
+    \"\n\x05items\x18\x01\x20\x03(\x0b2\x0c.iodin.MdbTxR\x05items\x12\"\n\rs\
+    top_on_error\x18\x02\x20\x01(\x08R\x0bstopOnError\"\x98\x01\n\x05Hello\
+    \x12)\n\x10protocol_version\x18\x01\x20\x01(\rR\x0fprotocolVersion\x120\
+    \n\x14protocol_version_min\x18\x02\x20\x01(\rR\x12protocolVersionMin\x12\
+    2\n\x08commands\x18\x03\x20\x03(\x0e2\x16.iodin.Request.CommandR\x08comm\
+    ands\"\xa0\x04\n\x04Info\x12#\n\rcrate_version\x18\x01\x20\x01(\tR\x0ccr\
+    ateVersion\x12\x19\n\x08build_id\x18\x02\x20\x01(\tR\x07buildId\x12\x12\
+    \n\x04mock\x18\x03\x20\x01(\x08R\x04mock\x12%\n\x0epigpio_version\x18\
+    \x04\x20\x01(\rR\rpigpioVersion\x12+\n\x11hardware_revision\x18\x05\x20\
+    \x01(\rR\x10hardwareRevision\x12\x19\n\x08mdb_open\x18\x06\x20\x01(\x08R\
+    \x07mdbOpen\x12\x1c\n\nmdb_rx_pin\x18\x07\x20\x01(\rR\x08mdbRxPin\x12\
+    \x1c\n\nmdb_tx_pin\x18\x08\x20\x01(\rR\x08mdbTxPin\x12)\n\x10protocol_ve\
+    rsion\x18\t\x20\x01(\rR\x0fprotocolVersion\x122\n\x08commands\x18\n\x20\
+    \x03(\x0e2\x16.iodin.Request.CommandR\x08commands\x12+\n\tmdb_polls\x18\
+    \x0b\x20\x03(\x0b2\x0e.iodin.MdbPollR\x08mdbPolls\x12=\n\rmdb_transport\
+    \x18\x0c\x20\x01(\x0e2\x18.iodin.MdbOpen.TransportR\x0cmdbTransport\x12\
+    \x1d\n\nmdb_device\x18\r\x20\x01(\tR\tmdbDevice\x12/\n\nmdb_faults\x18\
+    \x0e\x20\x01(\x0b2\x10.iodin.MdbFaultsR\tmdbFaults\"\xef\x02\n\x07MdbCoi\
+    n\x12!\n\x02op\x18\x01\x20\x01(\x0e2\x11.iodin.MdbCoin.OpR\x02op\x12\x1f\
+    \n\x0bcoin_enable\x18\x02\x20\x01(\rR\ncoinEnable\x124\n\x16manual_dispe\
+    nse_enable\x18\x03\x20\x01(\rR\x14manualDispenseEnable\x12\x1b\n\tcoin_t\
+    ype\x18\x04\x20\x01(\rR\x08coinType\x12\x14\n\x05count\x18\x05\x20\x01(\
+    \rR\x05count\x12\x18\n\x07timeout\x18\x06\x20\x01(\rR\x07timeout\x12(\n\
+    \x05retry\x18\x07\x20\x01(\x0b2\x12.iodin.RetryPolicyR\x05retry\"s\n\x02\
+    Op\x12\x0b\n\x07INVALID\x10\0\x12\t\n\x05RESET\x10\x01\x12\t\n\x05SETUP\
+    \x10\x02\x12\x0f\n\x0bTUBE_STATUS\x10\x03\x12\r\n\tCOIN_TYPE\x10\x04\x12\
+    \x0c\n\x08DISPENSE\x10\x05\x12\x08\n\x04POLL\x10\x06\x12\x12\n\x0eIDENTI\
+    FICATION\x10\x07\"\xdf\x01\n\x0cMdbCoinSetup\x12#\n\rfeature_level\x18\
+    \x01\x20\x01(\rR\x0cfeatureLevel\x12!\n\x0ccountry_code\x18\x02\x20\x01(\
+    \rR\x0bcountryCode\x12%\n\x0escaling_factor\x18\x03\x20\x01(\rR\rscaling\
+    Factor\x12%\n\x0edecimal_places\x18\x04\x20\x01(\rR\rdecimalPlaces\x12!\
+    \n\x0ccoin_routing\x18\x05\x20\x01(\rR\x0bcoinRouting\x12\x16\n\x06credi\
+    t\x18\x06\x20\x03(\rR\x06credit\"8\n\x0cMdbCoinTubes\x12\x12\n\x04full\
+    \x18\x01\x20\x01(\rR\x04full\x12\x14\n\x05count\x18\x02\x20\x03(\rR\x05c\
+    ount\"\x93\x05\n\x0cMdbCoinEvent\x12,\n\x04kind\x18\x01\x20\x01(\x0e2\
+    \x18.iodin.MdbCoinEvent.KindR\x04kind\x12\x1b\n\tcoin_type\x18\x02\x20\
+    \x01(\rR\x08coinType\x125\n\x07routing\x18\x03\x20\x01(\x0e2\x1b.iodin.M\
+    dbCoinEvent.RoutingR\x07routing\x12\x1d\n\ntube_count\x18\x04\x20\x01(\r\
+    R\ttubeCount\x12\x14\n\x05count\x18\x05\x20\x01(\rR\x05count\x122\n\x06s\
+    tatus\x18\x06\x20\x01(\x0e2\x1a.iodin.MdbCoinEvent.StatusR\x06status\"G\
+    \n\x04Kind\x12\x0b\n\x07INVALID\x10\0\x12\r\n\tDEPOSITED\x10\x01\x12\r\n\
+    \tDISPENSED\x10\x02\x12\x08\n\x04SLUG\x10\x03\x12\n\n\x06STATUS\x10\x04\
+    \"<\n\x07Routing\x12\x0c\n\x08CASH_BOX\x10\0\x12\t\n\x05TUBES\x10\x01\
+    \x12\x0c\n\x08NOT_USED\x10\x02\x12\n\n\x06REJECT\x10\x03\"\x90\x02\n\x06\
+    Status\x12\x0f\n\x0bSTATUS_NONE\x10\0\x12\x12\n\x0eESCROW_REQUEST\x10\
+    \x01\x12\x0f\n\x0bPAYOUT_BUSY\x10\x02\x12\r\n\tNO_CREDIT\x10\x03\x12\x19\
+    \n\x15DEFECTIVE_TUBE_SENSOR\x10\x04\x12\x12\n\x0eDOUBLE_ARRIVAL\x10\x05\
+    \x12\x16\n\x12ACCEPTOR_UNPLUGGED\x10\x06\x12\x0c\n\x08TUBE_JAM\x10\x07\
+    \x12\x16\n\x12ROM_CHECKSUM_ERROR\x10\x08\x12\x11\n\rROUTING_ERROR\x10\t\
+    \x12\x08\n\x04BUSY\x10\n\x12\x0e\n\nJUST_RESET\x10\x0b\x12\x0c\n\x08COIN\
+    _JAM\x10\x0c\x12\x19\n\x15CREDITED_COIN_REMOVAL\x10\r\"\xac\x01\n\x11Mdb\
+    Identification\x12\"\n\x0cmanufacturer\x18\x01\x20\x01(\tR\x0cmanufactur\
+    er\x12\x16\n\x06serial\x18\x02\x20\x01(\tR\x06serial\x12\x14\n\x05model\
+    \x18\x03\x20\x01(\tR\x05model\x12)\n\x10software_version\x18\x04\x20\x01\
+    (\rR\x0fsoftwareVersion\x12\x1a\n\x08features\x18\x05\x20\x01(\rR\x08fea\
+    tures\"\xd4\x01\n\rMdbCoinResult\x12)\n\x05setup\x18\x01\x20\x01(\x0b2\
+    \x13.iodin.MdbCoinSetupR\x05setup\x12)\n\x05tubes\x18\x02\x20\x01(\x0b2\
+    \x13.iodin.MdbCoinTubesR\x05tubes\x12+\n\x06events\x18\x03\x20\x03(\x0b2\
+    \x13.iodin.MdbCoinEventR\x06events\x12@\n\x0eidentification\x18\x04\x20\
+    \x01(\x0b2\x18.iodin.MdbIdentificationR\x0eidentification\"\x8c\x03\n\
+    \x07MdbBill\x12!\n\x02op\x18\x01\x20\x01(\x0e2\x11.iodin.MdbBill.OpR\x02\
+    op\x12#\n\rsecurity_mask\x18\x02\x20\x01(\rR\x0csecurityMask\x12\x1f\n\
+    \x0bbill_enable\x18\x03\x20\x01(\rR\nbillEnable\x12#\n\rescrow_enable\
+    \x18\x04\x20\x01(\rR\x0cescrowEnable\x12\x14\n\x05stack\x18\x05\x20\x01(\
+    \x08R\x05stack\x12\x18\n\x07timeout\x18\x06\x20\x01(\rR\x07timeout\x12(\
+    \n\x05retry\x18\x07\x20\x01(\x0b2\x12.iodin.RetryPolicyR\x05retry\"\x98\
+    \x01\n\x02Op\x12\x0b\n\x07INVALID\x10\0\x12\t\n\x05RESET\x10\x01\x12\t\n\
+    \x05SETUP\x10\x02\x12\x0c\n\x08SECURITY\x10\x03\x12\r\n\tBILL_TYPE\x10\
+    \x04\x12\n\n\x06ESCROW\x10\x05\x12\x0b\n\x07STACKER\x10\x06\x12\x08\n\
+    \x04POLL\x10\x07\x12\x12\n\x0eIDENTIFICATION\x10\x08\x12\x1b\n\x17IDENTI\
+    FICATION_FEATURES\x10\t\"\xa8\x02\n\x0cMdbBillSetup\x12#\n\rfeature_leve\
+    l\x18\x01\x20\x01(\rR\x0cfeatureLevel\x12!\n\x0ccountry_code\x18\x02\x20\
+    \x01(\rR\x0bcountryCode\x12%\n\x0escaling_factor\x18\x03\x20\x01(\rR\rsc\
+    alingFactor\x12%\n\x0edecimal_places\x18\x04\x20\x01(\rR\rdecimalPlaces\
+    \x12)\n\x10stacker_capacity\x18\x05\x20\x01(\rR\x0fstackerCapacity\x12'\
+    \n\x0fsecurity_levels\x18\x06\x20\x01(\rR\x0esecurityLevels\x12\x16\n\
+    \x06escrow\x18\x07\x20\x01(\x08R\x06escrow\x12\x16\n\x06credit\x18\x08\
+    \x20\x03(\rR\x06credit\":\n\x0eMdbBillStacker\x12\x12\n\x04full\x18\x01\
+    \x20\x01(\x08R\x04full\x12\x14\n\x05count\x18\x02\x20\x01(\rR\x05count\"\
+    \xc2\x05\n\x0cMdbBillEvent\x12,\n\x04kind\x18\x01\x20\x01(\x0e2\x18.iodi\
+    n.MdbBillEvent.KindR\x04kind\x12\x1b\n\tbill_type\x18\x02\x20\x01(\rR\
+    \x08billType\x125\n\x07routing\x18\x03\x20\x01(\x0e2\x1b.iodin.MdbBillEv\
+    ent.RoutingR\x07routing\x12\x14\n\x05count\x18\x04\x20\x01(\rR\x05count\
+    \x122\n\x06status\x18\x05\x20\x01(\x0e2\x1a.iodin.MdbBillEvent.StatusR\
+    \x06status\"@\n\x04Kind\x12\x0b\n\x07INVALID\x10\0\x12\x08\n\x04BILL\x10\
+    \x01\x12\x15\n\x11DISABLED_ATTEMPTS\x10\x02\x12\n\n\x06STATUS\x10\x03\"\
+    \xa0\x01\n\x07Routing\x12\x0b\n\x07STACKED\x10\0\x12\n\n\x06ESCROW\x10\
+    \x01\x12\x0c\n\x08RETURNED\x10\x02\x12\x0f\n\x0bTO_RECYCLER\x10\x03\x12\
+    \x15\n\x11DISABLED_REJECTED\x10\x04\x12\x16\n\x12TO_RECYCLER_MANUAL\x10\
+    \x05\x12\x14\n\x10DISPENSED_MANUAL\x10\x06\x12\x18\n\x14RECYCLER_TO_CASH\
+    _BOX\x10\x07\"\x80\x02\n\x06Status\x12\x0f\n\x0bSTATUS_NONE\x10\0\x12\
+    \x13\n\x0fDEFECTIVE_MOTOR\x10\x01\x12\x12\n\x0eSENSOR_PROBLEM\x10\x02\
+    \x12\x08\n\x04BUSY\x10\x03\x12\x16\n\x12ROM_CHECKSUM_ERROR\x10\x04\x12\n\
+    \n\x06JAMMED\x10\x05\x12\x0e\n\nJUST_RESET\x10\x06\x12\x10\n\x0cBILL_REM\
+    OVED\x10\x07\x12\x14\n\x10CASH_BOX_REMOVED\x10\x08\x12\x0c\n\x08DISABLED\
+    \x10\t\x12\x1a\n\x16INVALID_ESCROW_REQUEST\x10\n\x12\x11\n\rBILL_REJECTE\
+    D\x10\x0b\x12\x19\n\x15CREDITED_BILL_REMOVAL\x10\x0c\"\xda\x01\n\rMdbBil\
+    lResult\x12)\n\x05setup\x18\x01\x20\x01(\x0b2\x13.iodin.MdbBillSetupR\
+    \x05setup\x12/\n\x07stacker\x18\x02\x20\x01(\x0b2\x15.iodin.MdbBillStack\
+    erR\x07stacker\x12+\n\x06events\x18\x03\x20\x03(\x0b2\x13.iodin.MdbBillE\
+    ventR\x06events\x12@\n\x0eidentification\x18\x04\x20\x01(\x0b2\x18.iodin\
+    .MdbIdentificationR\x0eidentification\"\xed\x03\n\x0bMdbCashless\x12%\n\
+    \x02op\x18\x01\x20\x01(\x0e2\x15.iodin.MdbCashless.OpR\x02op\x12\x18\n\
+    \x07address\x18\x02\x20\x01(\rR\x07address\x12'\n\x0fdisplay_columns\x18\
+    \x03\x20\x01(\rR\x0edisplayColumns\x12!\n\x0cdisplay_rows\x18\x04\x20\
+    \x01(\rR\x0bdisplayRows\x12\x1b\n\tmax_price\x18\x05\x20\x01(\rR\x08maxP\
+    rice\x12\x1b\n\tmin_price\x18\x06\x20\x01(\rR\x08minPrice\x12\x14\n\x05p\
+    rice\x18\x07\x20\x01(\rR\x05price\x12\x12\n\x04item\x18\x08\x20\x01(\rR\
+    \x04item\x12\x18\n\x07timeout\x18\t\x20\x01(\rR\x07timeout\"\xd2\x01\n\
+    \x02Op\x12\x0b\n\x07INVALID\x10\0\x12\t\n\x05RESET\x10\x01\x12\t\n\x05SE\
+    TUP\x10\x02\x12\n\n\x06ENABLE\x10\x03\x12\x0b\n\x07DISABLE\x10\x04\x12\n\
+    \n\x06CANCEL\x10\x05\x12\x08\n\x04POLL\x10\x06\x12\x10\n\x0cVEND_REQUEST\
+    \x10\x07\x12\x0f\n\x0bVEND_CANCEL\x10\x08\x12\x10\n\x0cVEND_SUCCESS\x10\
+    \t\x12\x10\n\x0cVEND_FAILURE\x10\n\x12\x14\n\x10SESSION_COMPLETE\x10\x0b\
+    \x12\x12\n\x0eIDENTIFICATION\x10\x0c\x12\t\n\x05STATE\x10\r\"\xea\x01\n\
+    \x10MdbCashlessSetup\x12#\n\rfeature_level\x18\x01\x20\x01(\rR\x0cfeatur\
+    eLevel\x12!\n\x0ccountry_code\x18\x02\x20\x01(\rR\x0bcountryCode\x12!\n\
+    \x0cscale_factor\x18\x03\x20\x01(\rR\x0bscaleFactor\x12%\n\x0edecimal_pl\
+    aces\x18\x04\x20\x01(\rR\rdecimalPlaces\x12*\n\x11max_response_time\x18\
+    \x05\x20\x01(\rR\x0fmaxResponseTime\x12\x18\n\x07options\x18\x06\x20\x01\
+    (\rR\x07options\"\xbb\x04\n\x10MdbCashlessEvent\x120\n\x04kind\x18\x01\
+    \x20\x01(\x0e2\x1c.iodin.MdbCashlessEvent.KindR\x04kind\x12\x16\n\x06amo\
+    unt\x18\x02\x20\x01(\rR\x06amount\x12\x12\n\x04code\x18\x03\x20\x01(\rR\
+    \x04code\x12!\n\x0cdisplay_time\x18\x04\x20\x01(\rR\x0bdisplayTime\x12!\
+    \n\x0cdisplay_data\x18\x05\x20\x01(\x0cR\x0bdisplayData\x12-\n\x05setup\
+    \x18\x06\x20\x01(\x0b2\x17.iodin.MdbCashlessSetupR\x05setup\x12@\n\x0eid\
+    entification\x18\x07\x20\x01(\x0b2\x18.iodin.MdbIdentificationR\x0eident\
+    ification\x12\x18\n\x07timeout\x18\x08\x20\x01(\x08R\x07timeout\"\xf7\
+    \x01\n\x04Kind\x12\x0b\n\x07INVALID\x10\0\x12\x0e\n\nJUST_RESET\x10\x01\
+    \x12\x11\n\rREADER_CONFIG\x10\x02\x12\x13\n\x0fDISPLAY_REQUEST\x10\x03\
+    \x12\x11\n\rBEGIN_SESSION\x10\x04\x12\x1a\n\x16SESSION_CANCEL_REQUEST\
+    \x10\x05\x12\x11\n\rVEND_APPROVED\x10\x06\x12\x0f\n\x0bVEND_DENIED\x10\
+    \x07\x12\x0f\n\x0bEND_SESSION\x10\x08\x12\r\n\tCANCELLED\x10\t\x12\x11\n\
+    \rPERIPHERAL_ID\x10\n\x12\x0f\n\x0bMALFUNCTION\x10\x0b\x12\x13\n\x0fOUT_\
+    OF_SEQUENCE\x10\x0c\"\xfd\x02\n\x11MdbCashlessResult\x124\n\x05state\x18\
+    \x01\x20\x01(\x0e2\x1e.iodin.MdbCashlessResult.StateR\x05state\x12-\n\
+    \x05setup\x18\x02\x20\x01(\x0b2\x17.iodin.MdbCashlessSetupR\x05setup\x12\
+    /\n\x06events\x18\x03\x20\x03(\x0b2\x17.iodin.MdbCashlessEventR\x06event\
+    s\x12@\n\x0eidentification\x18\x04\x20\x01(\x0b2\x18.iodin.MdbIdentifica\
+    tionR\x0eidentification\x12\x14\n\x05funds\x18\x05\x20\x01(\rR\x05funds\
+    \"z\n\x05State\x12\x0c\n\x08INACTIVE\x10\0\x12\x0c\n\x08DISABLED\x10\x01\
+    \x12\x0b\n\x07ENABLED\x10\x02\x12\x10\n\x0cSESSION_IDLE\x10\x03\x12\x12\
+    \n\x0eVEND_REQUESTED\x10\x04\x12\x11\n\rVEND_APPROVED\x10\x05\x12\x0f\n\
+    \x0bSESSION_END\x10\x06b\x06proto3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -579,12 +8870,57 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
     file_descriptor.get(|| {
         let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
             let mut deps = ::std::vec::Vec::with_capacity(0);
-            let mut messages = ::std::vec::Vec::with_capacity(2);
+            let mut messages = ::std::vec::Vec::with_capacity(32);
             messages.push(Request::generated_message_descriptor_data());
+            messages.push(MdbOpen::generated_message_descriptor_data());
+            messages.push(MdbOptions::generated_message_descriptor_data());
+            messages.push(MdbFaults::generated_message_descriptor_data());
+            messages.push(RetryPolicy::generated_message_descriptor_data());
+            messages.push(MdbReset::generated_message_descriptor_data());
+            messages.push(MdbTx::generated_message_descriptor_data());
             messages.push(Response::generated_message_descriptor_data());
-            let mut enums = ::std::vec::Vec::with_capacity(2);
+            messages.push(Subscribe::generated_message_descriptor_data());
+            messages.push(MdbPoll::generated_message_descriptor_data());
+            messages.push(GpioWatch::generated_message_descriptor_data());
+            messages.push(Event::generated_message_descriptor_data());
+            messages.push(BatchItem::generated_message_descriptor_data());
+            messages.push(ErrorDetail::generated_message_descriptor_data());
+            messages.push(MdbBatch::generated_message_descriptor_data());
+            messages.push(Hello::generated_message_descriptor_data());
+            messages.push(Info::generated_message_descriptor_data());
+            messages.push(MdbCoin::generated_message_descriptor_data());
+            messages.push(MdbCoinSetup::generated_message_descriptor_data());
+            messages.push(MdbCoinTubes::generated_message_descriptor_data());
+            messages.push(MdbCoinEvent::generated_message_descriptor_data());
+            messages.push(MdbIdentification::generated_message_descriptor_data());
+            messages.push(MdbCoinResult::generated_message_descriptor_data());
+            messages.push(MdbBill::generated_message_descriptor_data());
+            messages.push(MdbBillSetup::generated_message_descriptor_data());
+            messages.push(MdbBillStacker::generated_message_descriptor_data());
+            messages.push(MdbBillEvent::generated_message_descriptor_data());
+            messages.push(MdbBillResult::generated_message_descriptor_data());
+            messages.push(MdbCashless::generated_message_descriptor_data());
+            messages.push(MdbCashlessSetup::generated_message_descriptor_data());
+            messages.push(MdbCashlessEvent::generated_message_descriptor_data());
+            messages.push(MdbCashlessResult::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(17);
             enums.push(request::Command::generated_enum_descriptor_data());
+            enums.push(mdb_open::Transport::generated_enum_descriptor_data());
             enums.push(response::Status::generated_enum_descriptor_data());
+            enums.push(event::Kind::generated_enum_descriptor_data());
+            enums.push(error_detail::Kind::generated_enum_descriptor_data());
+            enums.push(error_detail::Stage::generated_enum_descriptor_data());
+            enums.push(mdb_coin::Op::generated_enum_descriptor_data());
+            enums.push(mdb_coin_event::Kind::generated_enum_descriptor_data());
+            enums.push(mdb_coin_event::Routing::generated_enum_descriptor_data());
+            enums.push(mdb_coin_event::Status::generated_enum_descriptor_data());
+            enums.push(mdb_bill::Op::generated_enum_descriptor_data());
+            enums.push(mdb_bill_event::Kind::generated_enum_descriptor_data());
+            enums.push(mdb_bill_event::Routing::generated_enum_descriptor_data());
+            enums.push(mdb_bill_event::Status::generated_enum_descriptor_data());
+            enums.push(mdb_cashless::Op::generated_enum_descriptor_data());
+            enums.push(mdb_cashless_event::Kind::generated_enum_descriptor_data());
+            enums.push(mdb_cashless_result::State::generated_enum_descriptor_data());
             ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
                 file_descriptor_proto(),
                 deps,
@@ -1,18 +1,106 @@
 use crate::error::*;
+use crate::event;
 use crate::mdb;
-use crate::proto::iodin::request::Command;
+use crate::proto::iodin::mdb_open::Transport;
+use crate::proto::iodin::request::{Args, Command};
 use crate::proto::iodin::response::Status;
 use crate::proto::iodin::*;
-use std::convert::TryInto;
+use std::collections::HashMap;
+use std::convert::{TryFrom, TryInto};
 use std::io;
-use std::time::Duration;
+use std::os::unix::fs::FileTypeExt;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
 
+/// Protocol version implemented by server.
+/// Version 1 is original protocol without negotiation, `Request.version=0` is treated as 1.
+pub const PROTOCOL_VERSION: u32 = 2;
+pub const PROTOCOL_VERSION_MIN: u32 = 1;
+
+/// Commands reported by HELLO.
+pub const COMMANDS: &[Command] = &[
+    Command::MDB_OPEN,
+    Command::MDB_RESET,
+    Command::MDB_TX,
+    Command::MDB_BATCH,
+    Command::SUBSCRIBE,
+    Command::GPIO_WATCH,
+    Command::MDB_POLL_ADD,
+    Command::MDB_POLL_REMOVE,
+    Command::MDB_COIN,
+    Command::MDB_BILL,
+    Command::MDB_CASHLESS,
+    Command::HELLO,
+    Command::GET_INFO,
+    Command::STOP,
+];
+
+/// Limit on number of frames in MDB_BATCH.
+pub const BATCH_MAX_LENGTH: usize = 16;
+
+/// Default limit on request frame length, override with env `iodin_max_frame`.
+pub const FRAME_MAX_LENGTH: u32 = 4096;
+/// Ceiling for `iodin_max_frame`, session buffer grows up to frame length.
+pub const FRAME_MAX_LENGTH_LIMIT: u32 = 1 << 20;
+
+/// Default MDB response timeout when `Request.timeout` is zero.
 pub const MDB_TIMEOUT: Duration = Duration::from_millis(300);
+pub const MDB_TIMEOUT_MIN: Duration = Duration::from_millis(5);
+pub const MDB_TIMEOUT_MAX: Duration = Duration::from_millis(10_000);
+/// MDB_RESET holds server lock, spec minimum is 100ms.
+pub const MDB_RESET_MAX: Duration = Duration::from_millis(1000);
+
+/// MDB transaction exceeding its timeout by this much emits WATCHDOG event.
+pub const WATCHDOG_SLACK: Duration = Duration::from_millis(100);
+
+/// Limits on RetryPolicy.
+pub const RETRY_ATTEMPTS_MAX: u32 = 10;
+pub const RETRY_BACKOFF_MAX: Duration = Duration::from_millis(1000);
+/// Retryable errors when RetryPolicy.retry_on is empty.
+/// Peripheral rejected NAKed request, after timeout or checksum error it may have acted on it.
+const RETRY_ON_DEFAULT: &[error_detail::Kind] = &[error_detail::Kind::MDB_NAK];
+/// RetryPolicy.retry_on may only list MDB bus errors, input or IO errors repeat forever.
+const RETRY_ON_ALLOWED: &[error_detail::Kind] = &[
+    error_detail::Kind::MDB_NAK,
+    error_detail::Kind::MDB_INVALID_RESPONSE,
+    error_detail::Kind::MDB_CHECKSUM,
+    error_detail::Kind::MDB_TIMEOUT,
+];
+
+/// Limits on MDB_POLL_ADD jobs.
+pub const POLL_JOBS_MAX: usize = 8;
+pub const POLL_INTERVAL_MIN: Duration = Duration::from_millis(10);
+pub const POLL_INTERVAL_MAX: Duration = Duration::from_millis(60_000);
+/// Poller sleeps at most this long so new jobs start soon enough.
+const POLL_IDLE: Duration = Duration::from_millis(20);
+
+struct PollJob {
+    poll: MdbPoll,
+    interval: Duration,
+    next: Instant,
+}
 
 pub struct Server {
-    mdb: Option<mdb::GpioMdb>,
+    mdb: Option<Box<dyn mdb::MdbBus>>,
+    // MDB_OPEN args of open bus, transport is resolved
+    mdb_args: MdbOpen,
     mock: bool,
     running: bool,
+    // set by shutdown, hardware is released and other sessions must not use it
+    stopped: bool,
+    max_frame: u32,
+    events: event::Hub,
+    // session executing current request, 0 outside of stream session
+    session: u64,
+    gpio_watches: HashMap<u16, event::GpioWatch>,
+    poll_jobs: Vec<PollJob>,
+    // cashless session state by reader address, lost with bus
+    cashless: HashMap<u8, mdb::cashless::Cashless>,
+    // unix socket created by `listen_unix`, removed on shutdown
+    listen_path: Option<PathBuf>,
 }
 
 impl Server {
@@ -20,120 +108,1145 @@ impl Server {
         if !mock {
             pigpio::init(pigpio::PI_DISABLE_FIFO_IF | pigpio::PI_DISABLE_SOCK_IF)?;
         }
+        let max_frame = match std::env::var("iodin_max_frame") {
+            Ok(s) => parse_max_frame(&s)?,
+            Err(_) => FRAME_MAX_LENGTH,
+        };
         Ok(Server {
             mdb: None,
-            mock: mock,
+            mdb_args: MdbOpen::new(),
+            mock,
             running: false,
+            stopped: false,
+            max_frame,
+            events: event::Hub::new(),
+            session: 0,
+            gpio_watches: HashMap::new(),
+            poll_jobs: Vec::new(),
+            cashless: HashMap::new(),
+            listen_path: None,
         })
     }
 
-    pub fn run(&mut self, mut r: &mut dyn io::Read, mut w: &mut dyn io::Write) -> Result<()> {
-        use protobuf::Message;
-
-        let mut is = protobuf::CodedInputStream::new(&mut r);
-        let mut os = protobuf::CodedOutputStream::new(&mut w);
-        self.running = true;
-        while self.running {
-            let mut request = Request::new();
-            let mut response = Response::new();
-
-            let msglen = is.read_fixed32()?;
-            let old_limit = is.push_limit(msglen.into())?;
-            match request.merge_from(&mut is) {
-                Err(e) => {
-                    error!("error protobuf parse: {}", e);
-                    response.status = Status::ERR_INPUT.into();
-                    response.error = e.to_string();
-                }
-                Ok(()) => {
-                    if let Err(e) = self.exec(&request, &mut response) {
-                        error!("error: {}", e);
-                        for e in e.iter().skip(1) {
-                            error!("caused by: {}", e);
-                        }
+    /// Session loop for one of many clients sharing hardware state.
+    /// Server is locked for duration of each request attempt, STOP ends only this session.
+    pub fn serve(
+        server: &Mutex<Server>,
+        r: &mut dyn io::Read,
+        w: &mut (dyn io::Write + Send),
+    ) -> Result<()> {
+        let (max_frame, events) = {
+            let s = server.lock().map_err(|_| "server mutex poisoned")?;
+            (s.max_frame, s.events.clone())
+        };
+        session(r, w, max_frame, &events, |id, request, response| {
+            let mut running = true;
+            let mut poisoned = false;
+            // invalid policy is not applied, exec rejects it on first attempt
+            let policy = match request_retry(request) {
+                p if check_retry(&p).is_ok() => p,
+                _ => RetryPolicy::new(),
+            };
+            // lock is released during retry backoff
+            let mut attempts = 0;
+            let result = with_retry(&policy, &mut attempts, || {
+                let mut s = match server.lock() {
+                    Ok(s) => s,
+                    Err(_) => {
+                        poisoned = true;
+                        return Err("server mutex poisoned".into());
                     }
-                }
+                };
+                s.running = true;
+                s.session = id;
+                let r = s.exec(request, response);
+                running = s.running;
+                r
+            });
+            response.attempts = attempts;
+            if poisoned {
+                return Err("server mutex poisoned".into());
+            }
+            if let Err(e) = result {
+                log_error(request, &e);
             }
-            is.pop_limit(old_limit);
+            Ok(running)
+        })
+    }
 
-            os.write_fixed32_no_tag(response.compute_size().try_into().unwrap())?;
-            response.write_to(&mut os)?;
-            os.flush()?;
+    /// Release hardware: close MDB bus, leave TX idle, terminate pigpio.
+    /// Server must not be used after shutdown.
+    #[cold]
+    pub fn shutdown(&mut self) -> Result<()> {
+        self.running = false;
+        self.stopped = true;
+        let mut mdb = self.mdb.take();
+        self.gpio_watches.clear();
+        self.poll_jobs.clear();
+        self.cashless.clear();
+        let r = match &mut mdb {
+            Some(m) => m.idle().and_then(|_| m.close()),
+            None => Ok(()),
+        };
+        // Drop waves before pigpio is gone.
+        drop(mdb);
+        if !self.mock {
+            pigpio::terminate();
+        }
+        if let Some(path) = self.listen_path.take() {
+            if let Err(e) = std::fs::remove_file(&path) {
+                error!("error remove unix={}: {}", path.display(), e);
+            }
         }
-        Ok(())
+        r
     }
 
+    /// Execute request once, retry per its RetryPolicy is done by `serve`
+    /// so server is not locked during backoff.
     pub fn exec(&mut self, request: &Request, response: &mut Response) -> Result<()> {
         // debug!("exec {:x?}", request);
-        match request.command.enum_value_or_default() {
-            Command::INVALID => {
-                response.status = Status::ERR_INPUT.into();
-                response.error = "invalid command".to_string();
-                return Err(response.error.clone().into());
+        // nothing from failed attempt is left
+        *response = Response::new();
+        response.id = request.id;
+        let result = if self.stopped {
+            Err("server is shut down".into())
+        } else {
+            self.dispatch(request, response)
+        };
+        match result {
+            Ok(data) => {
+                response.status = Status::OK.into();
+                response.data_bytes = data;
+                Ok(())
+            }
+            Err(e) => {
+                response.status = error_status(&e).into();
+                response.error = e.to_string();
+                response.error_detail = Some(error_detail(&e)).into();
+                Err(e)
+            }
+        }
+    }
+
+    /// Handle request, returning `Response.data_bytes`. Response status is derived from result.
+    fn dispatch(&mut self, request: &Request, response: &mut Response) -> Result<Vec<u8>> {
+        let command = request_command(request)?;
+        let version = match request.version {
+            0 => PROTOCOL_VERSION_MIN,
+            v => v,
+        };
+        response.version = PROTOCOL_VERSION;
+        // HELLO must work for any client so it can learn what to downgrade to.
+        if command != Command::HELLO {
+            if !(PROTOCOL_VERSION_MIN..=PROTOCOL_VERSION).contains(&version) {
+                let e = format!(
+                    "unsupported protocol version={} server supports {}..{}",
+                    version, PROTOCOL_VERSION_MIN, PROTOCOL_VERSION
+                );
+                return Err(ErrorKind::InvalidInput(e).into());
+            }
+            response.version = version;
+        }
+        match command {
+            Command::INVALID => Err(ErrorKind::InvalidInput("invalid command".to_string()).into()),
+            Command::HELLO => {
+                let mut hello = Hello::new();
+                hello.protocol_version = PROTOCOL_VERSION;
+                hello.protocol_version_min = PROTOCOL_VERSION_MIN;
+                hello.commands = COMMANDS.iter().map(|&c| c.into()).collect();
+                response.hello = Some(hello).into();
+                Ok(Vec::new())
+            }
+            Command::GET_INFO => {
+                response.info = Some(self.info()).into();
+                Ok(Vec::new())
             }
             Command::STOP => {
                 self.running = false;
-                response.status = Status::OK.into();
-                return Ok(());
+                Ok(Vec::new())
             }
-            Command::MDB_OPEN => {
-                self.mdb = None;
+            Command::MDB_OPEN => self.mdb_open(request),
+            Command::MDB_RESET => self.mdb_reset(request),
+            Command::MDB_TX => self.mdb_tx(request),
+            Command::MDB_BATCH => self.mdb_batch(request, response),
+            Command::SUBSCRIBE => self.subscribe(request),
+            Command::GPIO_WATCH => self.gpio_watch(request),
+            Command::MDB_POLL_ADD => self.mdb_poll_add(request),
+            Command::MDB_POLL_REMOVE => self.mdb_poll_remove(request),
+            Command::MDB_COIN => self.mdb_coin(request, response),
+            Command::MDB_BILL => self.mdb_bill(request, response),
+            Command::MDB_CASHLESS => self.mdb_cashless(request, response),
+        }
+    }
+
+    fn info(&self) -> Info {
+        let mut info = Info::new();
+        info.crate_version = env!("CARGO_PKG_VERSION").to_string();
+        info.build_id = env!("IODIN_BUILD_ID").to_string();
+        info.mock = self.mock;
+        info.pigpio_version = pigpio::version();
+        info.hardware_revision = pigpio::hardware_revision();
+        if self.mdb.is_some() {
+            info.mdb_open = true;
+            info.mdb_rx_pin = self.mdb_args.rx_pin;
+            info.mdb_tx_pin = self.mdb_args.tx_pin;
+            info.mdb_transport = self.mdb_args.transport;
+            info.mdb_device = self.mdb_args.device.clone();
+            info.mdb_faults = self.mdb_args.options.faults.clone();
+        }
+        info.protocol_version = PROTOCOL_VERSION;
+        info.commands = COMMANDS.iter().map(|&c| c.into()).collect();
+        info.mdb_polls = self.poll_jobs.iter().map(|j| j.poll.clone()).collect();
+        info
+    }
+
+    fn mdb(&mut self) -> Result<&mut dyn mdb::MdbBus> {
+        match &mut self.mdb {
+            Some(m) => Ok(m.as_mut()),
+            None => Err(ErrorKind::InvalidInput("must mdb_open".to_string()).into()),
+        }
+    }
+
+    fn mdb_open(&mut self, request: &Request) -> Result<Vec<u8>> {
+        self.mdb = None;
+        self.cashless.clear();
+        let mut open = match &request.args {
+            Some(Args::MdbOpen(o)) => o.clone(),
+            // legacy arg_bytes=[rx, tx]
+            _ => {
                 if request.arg_bytes.len() != 2 {
-                    response.status = Status::ERR_INPUT.into();
-                    response.error = "invalid arg_bytes".to_string();
-                    return Err(response.error.clone().into());
-                }
-                let (rx, tx) = (request.arg_bytes[0], request.arg_bytes[1]);
-                match mdb::GpioMdb::new(rx.into(), tx.into()) {
-                    Ok(m) => {
-                        self.mdb = Some(m);
-                        response.status = Status::OK.into();
-                    }
-                    Err(e) => {
-                        response.status = Status::ERR_HARDWARE.into();
-                        response.error = e.to_string();
-                        return Err(e);
-                    }
+                    return Err(ErrorKind::InvalidInput("invalid arg_bytes".to_string()).into());
                 }
+                let mut o = MdbOpen::new();
+                o.rx_pin = request.arg_bytes[0].into();
+                o.tx_pin = request.arg_bytes[1].into();
+                o
             }
-            Command::MDB_RESET => match &mut self.mdb {
-                None => {
-                    response.status = Status::ERR_INPUT.into();
-                    response.error = "must mdb_open".to_string();
-                    return Err(response.error.clone().into());
+        };
+        let wait_step = match open.options.wait_step_us {
+            0 => None,
+            x => Some(x),
+        };
+        let transport = match open.transport.enum_value() {
+            Ok(Transport::DEFAULT) if self.mock => Transport::SIM,
+            Ok(Transport::DEFAULT) => Transport::GPIO,
+            Ok(t) => t,
+            Err(x) => {
+                let e = format!("unknown transport={}", x);
+                return Err(ErrorKind::InvalidInput(e).into());
+            }
+        };
+        // fault injection is for testing VMC against simulated bus, never real hardware
+        let faults = match open.options.faults.as_ref() {
+            Some(_) if transport != Transport::SIM => {
+                let e = format!("mdb faults require SIM transport, got {:?}", transport);
+                return Err(ErrorKind::InvalidInput(e).into());
+            }
+            Some(f) => Some(f.clone()),
+            None if transport != Transport::SIM => None,
+            None => match std::env::var("iodin_mdb_faults") {
+                Ok(s) => Some(mdb::fault::parse(&s)?),
+                Err(_) => None,
+            },
+        };
+        if let Some(f) = &faults {
+            mdb::fault::check(f)?;
+        }
+        let m: Box<dyn mdb::MdbBus> = match transport {
+            Transport::GPIO if self.mock => {
+                let e = "GPIO transport is not available in mock mode".to_string();
+                return Err(ErrorKind::InvalidInput(e).into());
+            }
+            Transport::GPIO => Box::new(mdb::GpioMdb::new(
+                pin(open.rx_pin)?,
+                pin(open.tx_pin)?,
+                wait_step,
+            )?),
+            Transport::UART if open.device.is_empty() => {
+                let e = "UART transport requires device".to_string();
+                return Err(ErrorKind::InvalidInput(e).into());
+            }
+            Transport::UART => Box::new(mdb::uart::UartMdb::open(Path::new(&open.device))?),
+            Transport::SIM | Transport::DEFAULT => {
+                with_faults(mdb::sim::SimBus::vending(), faults.clone())
+            }
+        };
+        open.transport = transport.into();
+        open.options.mut_or_insert_default().faults = faults.into();
+        info!("mdb open transport={:?}", transport);
+        self.mdb = Some(m);
+        self.mdb_args = open;
+        Ok(Vec::new())
+    }
+
+    fn mdb_reset(&mut self, request: &Request) -> Result<Vec<u8>> {
+        let ms = match &request.args {
+            Some(Args::MdbReset(r)) => r.duration_ms,
+            _ => request.arg_uint,
+        };
+        let duration = Duration::from_millis(ms.into());
+        if duration > MDB_RESET_MAX {
+            let e = format!("reset duration={}ms exceeds max={:?}", ms, MDB_RESET_MAX);
+            return Err(ErrorKind::InvalidInput(e).into());
+        }
+        self.mdb()?.bus_reset(duration)?;
+        // bus reset resets every peripheral
+        self.cashless.clear();
+        Ok(Vec::new())
+    }
+
+    fn mdb_tx(&mut self, request: &Request) -> Result<Vec<u8>> {
+        let (frame, timeout_ms) = match &request.args {
+            Some(Args::MdbTx(t)) => {
+                if let Some(p) = t.retry.as_ref() {
+                    check_retry(p)?;
                 }
-                Some(m) => {
-                    if let Err(e) = m.bus_reset(Duration::from_millis(request.arg_uint.into())) {
-                        response.status = Status::ERR_HARDWARE.into();
-                        response.error = e.to_string();
-                        return Err(e);
-                    }
-                    response.status = Status::OK.into();
+                match t.timeout {
+                    0 => (&t.frame, request.timeout),
+                    x => (&t.frame, x),
                 }
+            }
+            _ => (&request.arg_bytes, request.timeout),
+        };
+        self.mdb_tx_event(frame, timeout_ms)
+    }
+
+    /// Transaction that emits BUS_ERROR if it failed.
+    fn mdb_tx_event(&mut self, frame: &[u8], timeout_ms: u32) -> Result<Vec<u8>> {
+        let result = self.mdb_tx_frame(frame, timeout_ms);
+        match &result {
+            Err(e) if error_status(e) != Status::ERR_INPUT => {
+                let mut event = Event::new();
+                event.kind = event::Kind::BUS_ERROR.into();
+                event.mdb_request = frame.to_vec();
+                event.error = e.to_string();
+                event.error_detail = Some(error_detail(e)).into();
+                self.events.emit(event);
+            }
+            _ => (),
+        }
+        result
+    }
+
+    /// Single transaction attempt.
+    fn mdb_tx_frame(&mut self, frame: &[u8], timeout_ms: u32) -> Result<Vec<u8>> {
+        let m = self.mdb()?;
+        let timeout = mdb_timeout(timeout_ms)?;
+        mdb::check_request(frame)?;
+        let mut mdb_response = Vec::with_capacity(mdb::BLOCK_MAX_LENGTH);
+        let start = Instant::now();
+        let result = m.tx(frame, &mut mdb_response, timeout);
+        let elapsed = start.elapsed();
+        if elapsed > timeout + WATCHDOG_SLACK {
+            let mut event = Event::new();
+            event.kind = event::Kind::WATCHDOG.into();
+            event.mdb_request = frame.to_vec();
+            event.error = format!("MDB tx took {:?} with timeout {:?}", elapsed, timeout);
+            self.events.emit(event);
+        }
+        result.map(|_| mdb_response)
+    }
+
+    /// Transaction for peripheral drivers, single attempt.
+    /// Driver ops are retried by `serve` as whole request, see `driver_retry`.
+    fn driver_tx(&mut self, timeout_ms: u32) -> impl FnMut(&[u8]) -> Result<Vec<u8>> + '_ {
+        move |frame: &[u8]| self.mdb_tx_event(frame, timeout_ms)
+    }
+
+    /// Coin changer command, result in `Response.coin`.
+    fn mdb_coin(&mut self, request: &Request, response: &mut Response) -> Result<Vec<u8>> {
+        use crate::proto::iodin::mdb_coin::Op;
+        use mdb::coin;
+
+        let (args, timeout_ms) = driver_args::<MdbCoin>(request)?;
+        let mut result = MdbCoinResult::new();
+        let mut tx = self.driver_tx(timeout_ms);
+        match args.op.enum_value() {
+            Ok(Op::RESET) => coin::reset(&mut tx)?,
+            Ok(Op::SETUP) => result.setup = Some(coin::setup(&mut tx)?).into(),
+            Ok(Op::TUBE_STATUS) => result.tubes = Some(coin::tube_status(&mut tx)?).into(),
+            Ok(Op::COIN_TYPE) => coin::coin_type(
+                &mut tx,
+                arg("coin_enable", args.coin_enable)?,
+                arg("manual_dispense_enable", args.manual_dispense_enable)?,
+            )?,
+            Ok(Op::DISPENSE) => coin::dispense(
+                &mut tx,
+                arg("coin_type", args.coin_type)?,
+                arg("count", args.count)?,
+            )?,
+            Ok(Op::POLL) => result.events = coin::poll(&mut tx)?,
+            Ok(Op::IDENTIFICATION) => {
+                result.identification = Some(coin::identification(&mut tx)?).into()
+            }
+            Ok(Op::INVALID) | Err(_) => {
+                let e = format!("invalid mdb_coin op={:?}", args.op);
+                return Err(ErrorKind::InvalidInput(e).into());
+            }
+        }
+        response.coin = Some(result).into();
+        Ok(Vec::new())
+    }
+
+    /// Bill validator command, result in `Response.bill`.
+    fn mdb_bill(&mut self, request: &Request, response: &mut Response) -> Result<Vec<u8>> {
+        use crate::proto::iodin::mdb_bill::Op;
+        use mdb::bill;
+
+        let (args, timeout_ms) = driver_args::<MdbBill>(request)?;
+        let mut result = MdbBillResult::new();
+        let mut tx = self.driver_tx(timeout_ms);
+        match args.op.enum_value() {
+            Ok(Op::RESET) => bill::reset(&mut tx)?,
+            Ok(Op::SETUP) => result.setup = Some(bill::setup(&mut tx)?).into(),
+            Ok(Op::SECURITY) => bill::security(&mut tx, arg("security_mask", args.security_mask)?)?,
+            Ok(Op::BILL_TYPE) => bill::bill_type(
+                &mut tx,
+                arg("bill_enable", args.bill_enable)?,
+                arg("escrow_enable", args.escrow_enable)?,
+            )?,
+            Ok(Op::ESCROW) => bill::escrow(&mut tx, args.stack)?,
+            Ok(Op::STACKER) => result.stacker = Some(bill::stacker(&mut tx)?).into(),
+            Ok(Op::POLL) => result.events = bill::poll(&mut tx)?,
+            Ok(Op::IDENTIFICATION) => {
+                result.identification = Some(bill::identification(&mut tx, false)?).into()
+            }
+            Ok(Op::IDENTIFICATION_FEATURES) => {
+                result.identification = Some(bill::identification(&mut tx, true)?).into()
+            }
+            Ok(Op::INVALID) | Err(_) => {
+                let e = format!("invalid mdb_bill op={:?}", args.op);
+                return Err(ErrorKind::InvalidInput(e).into());
+            }
+        }
+        response.bill = Some(result).into();
+        Ok(Vec::new())
+    }
+
+    /// Reader session state survives between requests, see `mdb::cashless`.
+    fn mdb_cashless(&mut self, request: &Request, response: &mut Response) -> Result<Vec<u8>> {
+        use crate::proto::iodin::mdb_cashless::Op;
+        use mdb::cashless::{self, Cashless, Command as C};
+
+        let (args, timeout_ms) = driver_args::<MdbCashless>(request)?;
+        let address = match args.address {
+            0 => cashless::ADDRESS_1,
+            a => arg("address", a)?,
+        };
+        let command = match args.op.enum_value() {
+            Ok(Op::RESET) => C::Reset,
+            Ok(Op::SETUP) => C::Setup {
+                columns: arg("display_columns", args.display_columns)?,
+                rows: arg("display_rows", args.display_rows)?,
+                max_price: match args.max_price {
+                    0 => 0xffff,
+                    x => arg("max_price", x)?,
+                },
+                min_price: arg("min_price", args.min_price)?,
+            },
+            Ok(Op::ENABLE) => C::Enable,
+            Ok(Op::DISABLE) => C::Disable,
+            Ok(Op::CANCEL) => C::Cancel,
+            Ok(Op::POLL) => C::Poll,
+            Ok(Op::VEND_REQUEST) => C::VendRequest {
+                price: arg("price", args.price)?,
+                item: arg("item", args.item)?,
             },
-            Command::MDB_TX => match &mut self.mdb {
-                None => {
-                    response.status = Status::ERR_INPUT.into();
-                    response.error = "must mdb_open".to_string();
-                    return Err(response.error.clone().into());
+            Ok(Op::VEND_CANCEL) => C::VendCancel,
+            Ok(Op::VEND_SUCCESS) => C::VendSuccess {
+                item: arg("item", args.item)?,
+            },
+            Ok(Op::VEND_FAILURE) => C::VendFailure,
+            Ok(Op::SESSION_COMPLETE) => C::SessionComplete,
+            Ok(Op::IDENTIFICATION) => C::Identification,
+            Ok(Op::STATE) => C::State,
+            Ok(Op::INVALID) | Err(_) => {
+                let e = format!("invalid mdb_cashless op={:?}", args.op);
+                return Err(ErrorKind::InvalidInput(e).into());
+            }
+        };
+        self.mdb()?;
+        let now = Instant::now();
+        let mut reader = match self.cashless.remove(&address) {
+            Some(r) => r,
+            None => Cashless::new(address, now)?,
+        };
+        let result = reader.exec(&mut self.driver_tx(timeout_ms), command, now);
+        self.cashless.insert(address, reader);
+        response.cashless = Some(result?).into();
+        Ok(Vec::new())
+    }
+
+    /// Execute frames back to back, results in `Response.batch`.
+    /// Returns first item error, so response status is OK only if all items succeeded.
+    fn mdb_batch(&mut self, request: &Request, response: &mut Response) -> Result<Vec<u8>> {
+        let batch = match &request.args {
+            Some(Args::MdbBatch(b)) => b,
+            _ => {
+                return Err(ErrorKind::InvalidInput(
+                    "MDB_BATCH requires mdb_batch args".to_string(),
+                )
+                .into())
+            }
+        };
+        if batch.items.len() > BATCH_MAX_LENGTH {
+            let e = format!(
+                "batch length={} exceeds max={}",
+                batch.items.len(),
+                BATCH_MAX_LENGTH
+            );
+            return Err(ErrorKind::InvalidInput(e).into());
+        }
+        if batch.items.iter().any(|tx| tx.retry.is_some()) {
+            let e = "MDB_BATCH items can not be retried".to_string();
+            return Err(ErrorKind::InvalidInput(e).into());
+        }
+        self.mdb()?;
+        let mut first_error: Option<Error> = None;
+        for (i, tx) in batch.items.iter().enumerate() {
+            let timeout_ms = if tx.timeout != 0 {
+                tx.timeout
+            } else {
+                request.timeout
+            };
+            let mut item = BatchItem::new();
+            item.attempts = 1;
+            match self.mdb_tx_event(&tx.frame, timeout_ms) {
+                Ok(data) => {
+                    item.status = Status::OK.into();
+                    item.data_bytes = data;
                 }
-                Some(m) => {
-                    let mut mdb_response = Vec::with_capacity(mdb::BLOCK_MAX_LENGTH);
-                    if self.mock {
-                        mdb_response.extend_from_slice(&request.arg_bytes);
-                    } else {
-                        if let Err(e) = m.tx(&request.arg_bytes, &mut mdb_response, MDB_TIMEOUT) {
-                            response.status = Status::ERR_HARDWARE.into();
-                            response.error = e.to_string();
-                            return Err(e);
-                        }
+                Err(e) => {
+                    item.status = error_status(&e).into();
+                    item.error = e.to_string();
+                    item.error_detail = Some(error_detail(&e)).into();
+                    debug!("mdb batch item={} error: {}", i, e);
+                    if first_error.is_none() {
+                        first_error = Some(e);
                     }
-                    response.status = Status::OK.into();
-                    response.data_bytes.append(&mut mdb_response);
                 }
-            },
+            }
+            let failed = item.status != Status::OK.into();
+            response.batch.push(item);
+            if failed && batch.stop_on_error {
+                break;
+            }
+        }
+        match first_error {
+            Some(e) => Err(e),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    fn mdb_poll_add(&mut self, request: &Request) -> Result<Vec<u8>> {
+        let mut poll = request_mdb_poll(request)?;
+        self.mdb()?;
+        let interval = Duration::from_millis(poll.interval_ms.into());
+        if interval < POLL_INTERVAL_MIN || interval > POLL_INTERVAL_MAX {
+            let e = format!(
+                "interval={}ms out of range {:?}..{:?}",
+                poll.interval_ms, POLL_INTERVAL_MIN, POLL_INTERVAL_MAX
+            );
+            return Err(ErrorKind::InvalidInput(e).into());
+        }
+        mdb_timeout(poll.timeout_ms)?;
+        if poll.frame.is_empty() {
+            poll.frame = vec![poll.address as u8 + 3];
+        }
+        mdb::check_request(&poll.frame)?;
+        // reader session events must go through MDB_CASHLESS state machine
+        let cashless = [mdb::cashless::ADDRESS_1, mdb::cashless::ADDRESS_2];
+        if cashless.contains(&(poll.address as u8)) || cashless.contains(&(poll.frame[0] & 0xf8)) {
+            let e = format!(
+                "cashless address={:#04x} can not be polled by server, use MDB_CASHLESS POLL",
+                poll.address
+            );
+            return Err(ErrorKind::InvalidInput(e).into());
+        }
+        self.poll_jobs.retain(|j| j.poll.address != poll.address);
+        if self.poll_jobs.len() >= POLL_JOBS_MAX {
+            let e = format!("poll jobs exceed max={}", POLL_JOBS_MAX);
+            return Err(ErrorKind::InvalidInput(e).into());
+        }
+        info!(
+            "mdb poll add address={:02x} frame={:02x?} interval={:?}",
+            poll.address, poll.frame, interval
+        );
+        self.poll_jobs.push(PollJob {
+            poll,
+            interval,
+            next: Instant::now(),
+        });
+        Ok(Vec::new())
+    }
+
+    /// Removing job that does not exist is not an error.
+    fn mdb_poll_remove(&mut self, request: &Request) -> Result<Vec<u8>> {
+        let address = request_mdb_poll(request)?.address;
+        self.poll_jobs.retain(|j| j.poll.address != address);
+        Ok(Vec::new())
+    }
+
+    /// Run poll jobs due at `now`, returns when next one is due.
+    /// Non-empty responses are emitted as MDB_POLL events, failures as BUS_ERROR.
+    pub fn poll(&mut self, now: Instant) -> Option<Instant> {
+        let mut due = Vec::new();
+        for j in self.poll_jobs.iter_mut().filter(|j| j.next <= now) {
+            // late job is not repeated to catch up
+            j.next = now + j.interval;
+            due.push((j.poll.frame.clone(), j.poll.timeout_ms));
+        }
+        for (frame, timeout_ms) in due {
+            match self.mdb_tx_event(&frame, timeout_ms) {
+                Ok(data) if data.is_empty() => (),
+                Ok(data) => {
+                    let mut event = Event::new();
+                    event.kind = event::Kind::MDB_POLL.into();
+                    event.mdb_request = frame;
+                    event.data_bytes = data;
+                    self.events.emit(event);
+                }
+                Err(e) => debug!("mdb poll frame={:02x?} error: {}", frame, e),
+            }
+        }
+        self.poll_jobs.iter().map(|j| j.next).min()
+    }
+
+    fn subscribe(&mut self, request: &Request) -> Result<Vec<u8>> {
+        let kinds: Vec<event::Kind> = match &request.args {
+            Some(Args::Subscribe(s)) => s.kinds.iter().map(|k| k.enum_value_or_default()).collect(),
+            _ => Vec::new(),
         };
-        assert_ne!(response.status.enum_value_or_default(), Status::INVALID);
-        Ok(())
+        self.events.subscribe(self.session, &kinds)?;
+        Ok(Vec::new())
     }
+
+    fn gpio_watch(&mut self, request: &Request) -> Result<Vec<u8>> {
+        let watch = match &request.args {
+            Some(Args::GpioWatch(w)) => w,
+            _ => {
+                return Err(ErrorKind::InvalidInput(
+                    "GPIO_WATCH requires gpio_watch args".to_string(),
+                )
+                .into())
+            }
+        };
+        let pin = pin(watch.pin)?;
+        // replacing watch must unregister old callback first
+        self.gpio_watches.remove(&pin);
+        if watch.enable {
+            let w = event::GpioWatch::new(self.events.clone(), pin, !self.mock)?;
+            self.gpio_watches.insert(pin, w);
+        }
+        Ok(Vec::new())
+    }
+}
+
+/// Wraps bus with fault injection when configured.
+fn with_faults<B>(bus: B, faults: Option<MdbFaults>) -> Box<dyn mdb::MdbBus>
+where
+    B: mdb::MdbBus + mdb::Link + 'static,
+{
+    match faults {
+        Some(f) => {
+            warn!("mdb fault injection enabled {:?}", f);
+            Box::new(mdb::fault::FaultBus::new(bus, f))
+        }
+        None => Box::new(bus),
+    }
+}
+
+/// Parse env `iodin_max_frame`, at most `FRAME_MAX_LENGTH_LIMIT`.
+pub fn parse_max_frame(s: &str) -> Result<u32> {
+    let max_frame: u32 = s.parse()?;
+    if max_frame > FRAME_MAX_LENGTH_LIMIT {
+        let e = format!(
+            "iodin_max_frame={} exceeds limit={}",
+            max_frame, FRAME_MAX_LENGTH_LIMIT
+        );
+        return Err(ErrorKind::InvalidInput(e).into());
+    }
+    Ok(max_frame)
+}
+
+/// Narrow request field to driver argument type.
+fn arg<T: TryFrom<u32>>(name: &str, x: u32) -> Result<T> {
+    T::try_from(x)
+        .map_err(|_| ErrorKind::InvalidInput(format!("{}={} out of range", name, x)).into())
+}
+
+fn check_retry(p: &RetryPolicy) -> Result<()> {
+    if p.max_attempts > RETRY_ATTEMPTS_MAX {
+        let e = format!(
+            "retry max_attempts={} exceeds max={}",
+            p.max_attempts, RETRY_ATTEMPTS_MAX
+        );
+        return Err(ErrorKind::InvalidInput(e).into());
+    }
+    if Duration::from_millis(p.backoff_ms.into()) > RETRY_BACKOFF_MAX {
+        let e = format!(
+            "retry backoff={}ms exceeds max={:?}",
+            p.backoff_ms, RETRY_BACKOFF_MAX
+        );
+        return Err(ErrorKind::InvalidInput(e).into());
+    }
+    for kind in &p.retry_on {
+        if !matches!(kind.enum_value(), Ok(k) if RETRY_ON_ALLOWED.contains(&k)) {
+            let e = format!("retry_on={:?} is not MDB bus error", kind);
+            return Err(ErrorKind::InvalidInput(e).into());
+        }
+    }
+    Ok(())
+}
+
+/// Retry policy of request, only MDB_TX and idempotent driver ops may be retried.
+fn request_retry(request: &Request) -> RetryPolicy {
+    match &request.args {
+        Some(Args::MdbTx(t)) => t.retry.clone().unwrap_or_default(),
+        Some(Args::MdbCoin(c)) => driver_retry(c),
+        Some(Args::MdbBill(b)) => driver_retry(b),
+        Some(Args::MdbCashless(c)) => driver_retry(c),
+        _ => RetryPolicy::new(),
+    }
+}
+
+/// Typed args of MDB_COIN, MDB_BILL and MDB_CASHLESS.
+trait DriverArgs: Sized {
+    const NAME: &'static str;
+    fn from_args(args: &Args) -> Option<&Self>;
+    /// Response timeout in milliseconds, 0 means `Request.timeout`.
+    fn timeout(&self) -> u32;
+    fn op(&self) -> String;
+    /// Policy requested by client, `None` for drivers that are never retried.
+    fn retry(&self) -> Option<&RetryPolicy>;
+    /// Op is safe to send again after lost or damaged response.
+    fn retryable(&self) -> bool;
+}
+
+impl DriverArgs for MdbCoin {
+    const NAME: &'static str = "mdb_coin";
+    fn from_args(args: &Args) -> Option<&Self> {
+        match args {
+            Args::MdbCoin(c) => Some(c),
+            _ => None,
+        }
+    }
+    fn timeout(&self) -> u32 {
+        self.timeout
+    }
+    fn op(&self) -> String {
+        format!("{:?}", self.op)
+    }
+    fn retry(&self) -> Option<&RetryPolicy> {
+        self.retry.as_ref()
+    }
+    fn retryable(&self) -> bool {
+        mdb::coin::retryable(self.op.enum_value_or_default())
+    }
+}
+
+impl DriverArgs for MdbBill {
+    const NAME: &'static str = "mdb_bill";
+    fn from_args(args: &Args) -> Option<&Self> {
+        match args {
+            Args::MdbBill(b) => Some(b),
+            _ => None,
+        }
+    }
+    fn timeout(&self) -> u32 {
+        self.timeout
+    }
+    fn op(&self) -> String {
+        format!("{:?}", self.op)
+    }
+    fn retry(&self) -> Option<&RetryPolicy> {
+        self.retry.as_ref()
+    }
+    fn retryable(&self) -> bool {
+        mdb::bill::retryable(self.op.enum_value_or_default())
+    }
+}
+
+impl DriverArgs for MdbCashless {
+    const NAME: &'static str = "mdb_cashless";
+    fn from_args(args: &Args) -> Option<&Self> {
+        match args {
+            Args::MdbCashless(c) => Some(c),
+            _ => None,
+        }
+    }
+    fn timeout(&self) -> u32 {
+        self.timeout
+    }
+    fn op(&self) -> String {
+        format!("{:?}", self.op)
+    }
+    // reader session state machine, see `mdb::cashless`
+    fn retry(&self) -> Option<&RetryPolicy> {
+        None
+    }
+    fn retryable(&self) -> bool {
+        false
+    }
+}
+
+/// Driver args and response timeout, rejects retry of op that is not idempotent.
+fn driver_args<T: DriverArgs>(request: &Request) -> Result<(&T, u32)> {
+    let args = match request.args.as_ref().and_then(T::from_args) {
+        Some(a) => a,
+        None => {
+            let e = format!("{} requires {} args", T::NAME.to_uppercase(), T::NAME);
+            return Err(ErrorKind::InvalidInput(e).into());
+        }
+    };
+    if let Some(p) = args.retry() {
+        if !args.retryable() {
+            let e = format!("{} op={} can not be retried", T::NAME, args.op());
+            return Err(ErrorKind::InvalidInput(e).into());
+        }
+        check_retry(p)?;
+    }
+    let timeout_ms = match args.timeout() {
+        0 => request.timeout,
+        t => t,
+    };
+    Ok((args, timeout_ms))
+}
+
+fn driver_retry<T: DriverArgs>(args: &T) -> RetryPolicy {
+    match args.retry() {
+        Some(p) if args.retryable() => p.clone(),
+        _ => RetryPolicy::new(),
+    }
+}
+
+fn log_error(request: &Request, e: &Error) {
+    error!("error id={}: {}", request.id, e);
+    for e in e.iter().skip(1) {
+        error!("error id={} caused by: {}", request.id, e);
+    }
+}
+
+/// Call `tx` until it succeeds, fails with error not retryable by `policy`
+/// or runs out of attempts. `attempts` counts calls to `tx`.
+/// Backoff sleeps between calls, so shared server must be locked inside `tx`, not around.
+pub fn with_retry<T, F>(policy: &RetryPolicy, attempts: &mut u32, mut tx: F) -> Result<T>
+where
+    F: FnMut() -> Result<T>,
+{
+    let max_attempts = policy.max_attempts.max(1);
+    *attempts = 0;
+    loop {
+        *attempts += 1;
+        let e = match tx() {
+            Ok(x) => return Ok(x),
+            Err(e) => e,
+        };
+        let kind = error_detail(&e).kind.enum_value_or_default();
+        let retryable = if policy.retry_on.is_empty() {
+            RETRY_ON_DEFAULT.contains(&kind)
+        } else {
+            policy.retry_on.contains(&kind.into())
+        };
+        if !retryable || *attempts >= max_attempts {
+            return Err(e);
+        }
+        debug!("mdb retry attempt={} after error: {}", attempts, e);
+        thread::sleep(Duration::from_millis(policy.backoff_ms.into()));
+    }
+}
+
+/// Command from `Request.command` or implied by typed `Request.args`.
+fn request_command(request: &Request) -> Result<Command> {
+    let command = request.command.enum_value_or_default();
+    let implied = match &request.args {
+        None => return Ok(command),
+        Some(Args::MdbOpen(_)) => Command::MDB_OPEN,
+        Some(Args::MdbReset(_)) => Command::MDB_RESET,
+        Some(Args::MdbTx(_)) => Command::MDB_TX,
+        Some(Args::MdbBatch(_)) => Command::MDB_BATCH,
+        Some(Args::Subscribe(_)) => Command::SUBSCRIBE,
+        Some(Args::GpioWatch(_)) => Command::GPIO_WATCH,
+        // same args for add and remove
+        Some(Args::MdbPoll(_)) if command == Command::MDB_POLL_REMOVE => Command::MDB_POLL_REMOVE,
+        Some(Args::MdbPoll(_)) => Command::MDB_POLL_ADD,
+        Some(Args::MdbCoin(_)) => Command::MDB_COIN,
+        Some(Args::MdbBill(_)) => Command::MDB_BILL,
+        Some(Args::MdbCashless(_)) => Command::MDB_CASHLESS,
+    };
+    if command != Command::INVALID && command != implied {
+        let e = format!(
+            "command={:?} does not match args for {:?}",
+            command, implied
+        );
+        return Err(ErrorKind::InvalidInput(e).into());
+    }
+    Ok(implied)
+}
+
+fn request_mdb_poll(request: &Request) -> Result<MdbPoll> {
+    let poll = match &request.args {
+        Some(Args::MdbPoll(p)) => p.clone(),
+        _ => {
+            return Err(
+                ErrorKind::InvalidInput("MDB_POLL_* requires mdb_poll args".to_string()).into(),
+            )
+        }
+    };
+    // 0x00 is VMC itself
+    if poll.address == 0 || poll.address > 0xf8 || poll.address & 0x07 != 0 {
+        let e = format!("invalid MDB address={:#x}", poll.address);
+        return Err(ErrorKind::InvalidInput(e).into());
+    }
+    Ok(poll)
+}
+
+fn pin(x: u32) -> Result<u16> {
+    if x > u16::MAX.into() {
+        return Err(ErrorKind::InvalidInput(format!("invalid pin={}", x)).into());
+    }
+    Ok(x as u16)
+}
+
+/// Response status for error returned by request handler.
+pub fn error_status(e: &Error) -> Status {
+    match e.kind() {
+        ErrorKind::InvalidInput(_) => Status::ERR_INPUT,
+        ErrorKind::MdbTimeout(..) => Status::ERR_TIMEOUT,
+        ErrorKind::MdbChecksum(..) => Status::ERR_CHECKSUM,
+        ErrorKind::MdbNak => Status::ERR_NAK,
+        ErrorKind::MdbInvalidResponse(_) | ErrorKind::MdbDecode(..) | ErrorKind::IoError(_) => {
+            Status::ERR_HARDWARE
+        }
+        _ => Status::ERR_INTERNAL,
+    }
+}
+
+/// Structured description of error for client.
+pub fn error_detail(e: &Error) -> ErrorDetail {
+    use crate::proto::iodin::error_detail::{Kind, Stage};
+
+    let mut d = ErrorDetail::new();
+    let (kind, stage) = match e.kind() {
+        ErrorKind::InvalidInput(_) => (Kind::INPUT, Stage::NONE),
+        ErrorKind::IoError(_) => (Kind::IO, Stage::NONE),
+        ErrorKind::MdbNak => (Kind::MDB_NAK, Stage::RECEIVE),
+        ErrorKind::MdbInvalidResponse(b) => {
+            d.invalid_byte = (*b).into();
+            (Kind::MDB_INVALID_RESPONSE, Stage::RECEIVE)
+        }
+        ErrorKind::MdbDecode(_, response) => {
+            d.partial_response = response.clone();
+            (Kind::MDB_INVALID_RESPONSE, Stage::RECEIVE)
+        }
+        ErrorKind::MdbChecksum(computed, received, response) => {
+            d.checksum_computed = (*computed).into();
+            d.checksum_received = (*received).into();
+            d.partial_response = response.clone();
+            (Kind::MDB_CHECKSUM, Stage::RECEIVE)
+        }
+        ErrorKind::MdbTimeout(stage, response) => {
+            d.partial_response = response.clone();
+            let stage = match stage {
+                mdb::Stage::Send => Stage::SEND,
+                mdb::Stage::Receive => Stage::RECEIVE,
+                mdb::Stage::Ack => Stage::ACK,
+            };
+            (Kind::MDB_TIMEOUT, stage)
+        }
+        _ => (Kind::OTHER, Stage::NONE),
+    };
+    d.kind = kind.into();
+    d.stage = stage.into();
+    d
+}
+
+/// Convert `Request.timeout` milliseconds to MDB response timeout, 0 means default.
+fn mdb_timeout(ms: u32) -> Result<Duration> {
+    if ms == 0 {
+        return Ok(MDB_TIMEOUT);
+    }
+    let t = Duration::from_millis(ms.into());
+    if t < MDB_TIMEOUT_MIN || t > MDB_TIMEOUT_MAX {
+        let e = format!(
+            "timeout={}ms out of range {:?}..{:?}",
+            ms, MDB_TIMEOUT_MIN, MDB_TIMEOUT_MAX
+        );
+        return Err(ErrorKind::InvalidInput(e).into());
+    }
+    Ok(t)
+}
+
+/// Length-prefixed protobuf framing loop.
+/// Responses and subscribed events are written by separate thread so events
+/// are delivered while waiting for next request.
+/// `exec` gets session id and returns false to end the session.
+fn session<F>(
+    r: &mut dyn io::Read,
+    w: &mut (dyn io::Write + Send),
+    max_frame: u32,
+    events: &event::Hub,
+    exec: F,
+) -> Result<()>
+where
+    F: FnMut(u64, &Request, &mut Response) -> Result<bool>,
+{
+    let (tx, rx) = mpsc::sync_channel(event::SESSION_QUEUE_LENGTH);
+    let id = events.register(tx.clone());
+    thread::scope(|scope| {
+        let writer = scope.spawn(move || write_loop(w, rx));
+        let result = read_loop(r, max_frame, id, exec, &tx);
+        // writer stops when all senders are gone
+        events.unregister(id);
+        drop(tx);
+        let written = match writer.join() {
+            Ok(r) => r,
+            Err(_) => Err("session writer panicked".into()),
+        };
+        // writer error explains why read_loop could not send response
+        written.and(result)
+    })
+}
+
+fn read_loop<F>(
+    r: &mut dyn io::Read,
+    max_frame: u32,
+    id: u64,
+    mut exec: F,
+    tx: &mpsc::SyncSender<Response>,
+) -> Result<()>
+where
+    F: FnMut(u64, &Request, &mut Response) -> Result<bool>,
+{
+    use protobuf::Message;
+    use std::io::{BufRead, Read};
+
+    let mut r = io::BufReader::new(r);
+    let mut frame = Vec::new();
+    let mut running = true;
+    while running {
+        let mut request = Request::new();
+        let mut response = Response::new();
+
+        // Error reading length means client is gone, nothing to recover.
+        let msglen = protobuf::CodedInputStream::from_buf_read(&mut r).read_fixed32()?;
+        if msglen > max_frame {
+            // Length is likely garbage (i.e. text written to pipe) so stream is out of sync.
+            // Drop what client sent so far, next write should start with a valid frame.
+            let skip = r.buffer().len();
+            r.consume(skip);
+            error!(
+                "error frame length={} > max={}, skipped {} bytes",
+                msglen, max_frame, skip
+            );
+            let e = format!("frame length={} exceeds max={}", msglen, max_frame);
+            session_error(&mut response, ErrorKind::InvalidInput(e).into());
+        } else {
+            // Read whole frame first so parse error does not desync stream.
+            frame.resize(msglen as usize, 0);
+            r.read_exact(&mut frame)?;
+            match request.merge_from_bytes(&frame) {
+                Err(e) => {
+                    error!("error protobuf parse: {}", e);
+                    session_error(&mut response, ErrorKind::InvalidInput(e.to_string()).into());
+                }
+                Ok(()) => running = exec(id, &request, &mut response)?,
+            }
+        }
+
+        tx.send(response).map_err(|_| "session writer stopped")?;
+    }
+    Ok(())
+}
+
+/// Response to frame that never reached `exec`.
+fn session_error(response: &mut Response, e: Error) {
+    response.status = error_status(&e).into();
+    response.error = e.to_string();
+    response.error_detail = Some(error_detail(&e)).into();
+}
+
+fn write_loop(mut w: &mut (dyn io::Write + Send), rx: mpsc::Receiver<Response>) -> Result<()> {
+    use protobuf::Message;
+
+    let mut os = protobuf::CodedOutputStream::new(&mut w);
+    for response in rx {
+        os.write_fixed32_no_tag(response.compute_size().try_into().unwrap())?;
+        response.write_to(&mut os)?;
+        os.flush()?;
+    }
+    Ok(())
+}
+
+/// Run MDB poll jobs in background for process lifetime.
+pub fn spawn_poller(server: Arc<Mutex<Server>>) -> thread::JoinHandle<()> {
+    thread::spawn(move || loop {
+        let next = match server.lock() {
+            Ok(mut s) => s.poll(Instant::now()),
+            Err(_) => {
+                error!("error poller: server mutex poisoned");
+                return;
+            }
+        };
+        let now = Instant::now();
+        let wait = match next {
+            Some(t) => t.saturating_duration_since(now).min(POLL_IDLE),
+            None => POLL_IDLE,
+        };
+        thread::sleep(wait);
+    })
+}
+
+/// Bind unix socket at `path` and serve each accepted connection
+/// in a separate thread, sharing `server` with other sessions.
+pub fn listen_unix(server: Arc<Mutex<Server>>, path: &Path) -> Result<thread::JoinHandle<()>> {
+    // stale socket from previous run would fail bind
+    if let Ok(meta) = std::fs::symlink_metadata(path) {
+        if !meta.file_type().is_socket() {
+            let e = format!("listen unix={} exists and is not a socket", path.display());
+            return Err(ErrorKind::InvalidInput(e).into());
+        }
+        if UnixStream::connect(path).is_ok() {
+            let e = format!(
+                "listen unix={} is in use by another process",
+                path.display()
+            );
+            return Err(ErrorKind::InvalidInput(e).into());
+        }
+        std::fs::remove_file(path)?;
+    }
+    let listener = UnixListener::bind(path)?;
+    info!("listen unix={}", path.display());
+    server
+        .lock()
+        .map_err(|_| "server mutex poisoned")?
+        .listen_path = Some(path.to_path_buf());
+    let handle = thread::spawn(move || {
+        for conn in listener.incoming() {
+            let stream = match conn {
+                Ok(s) => s,
+                Err(e) => {
+                    error!("error accept: {}", e);
+                    continue;
+                }
+            };
+            let server = server.clone();
+            thread::spawn(move || {
+                let mut r = match stream.try_clone() {
+                    Ok(s) => s,
+                    Err(e) => {
+                        error!("error unix stream clone: {}", e);
+                        return;
+                    }
+                };
+                let mut w = stream;
+                debug!("unix session begin");
+                if let Err(e) = Server::serve(&server, &mut r, &mut w) {
+                    // EOF is regular client disconnect
+                    debug!("unix session end: {}", e);
+                }
+            });
+        }
+    });
+    Ok(handle)
 }
@@ -0,0 +1,55 @@
+//! Graceful shutdown on SIGTERM/SIGINT.
+use crate::error::*;
+use crate::server::Server;
+use std::sync::{Arc, Mutex};
+use std::{io, mem, ptr, thread};
+
+/// Exit code when shutdown after signal failed to release hardware.
+pub const EXIT_SHUTDOWN_ERROR: i32 = 2;
+
+fn signal_set() -> libc::sigset_t {
+    unsafe {
+        let mut set: libc::sigset_t = mem::zeroed();
+        libc::sigemptyset(&mut set);
+        libc::sigaddset(&mut set, libc::SIGINT);
+        libc::sigaddset(&mut set, libc::SIGTERM);
+        set
+    }
+}
+
+/// Block SIGTERM/SIGINT in current thread and all threads spawned later.
+/// Must be called before any threads are started (including pigpio init)
+/// so signals are only received by `spawn_handler` thread.
+pub fn block() -> Result<()> {
+    let set = signal_set();
+    let rc = unsafe { libc::pthread_sigmask(libc::SIG_BLOCK, &set, ptr::null_mut()) };
+    if rc != 0 {
+        return Err(io::Error::from_raw_os_error(rc).into());
+    }
+    Ok(())
+}
+
+/// Wait for SIGTERM/SIGINT in background thread, then shutdown `server` and exit process.
+/// Server lock is taken first, so in-flight MDB transaction is finished before cleanup,
+/// and held until exit so other sessions can not run requests on released hardware.
+/// Exit code is 0 after clean shutdown, `EXIT_SHUTDOWN_ERROR` otherwise.
+pub fn spawn_handler(server: Arc<Mutex<Server>>) -> thread::JoinHandle<()> {
+    thread::spawn(move || {
+        let set = signal_set();
+        let mut sig: libc::c_int = 0;
+        while unsafe { libc::sigwait(&set, &mut sig) } != 0 {}
+        info!("signal={} shutdown", sig);
+        let mut s = match server.lock() {
+            Ok(s) => s,
+            Err(poison) => poison.into_inner(),
+        };
+        // process::exit does not run destructors, lock stays held
+        match s.shutdown() {
+            Ok(()) => std::process::exit(0),
+            Err(e) => {
+                error!("error shutdown: {}", e);
+                std::process::exit(EXIT_SHUTDOWN_ERROR);
+            }
+        }
+    })
+}
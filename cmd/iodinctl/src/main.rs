@@ -0,0 +1,832 @@
+//! Command line access to iodin: single command from arguments or REPL on stdin.
+extern crate iodin_client;
+extern crate protobuf;
+
+use iodin_client::proto::iodin::event::Kind as EventKind;
+use iodin_client::proto::iodin::mdb_open::Transport;
+use iodin_client::proto::iodin::request::Command;
+use iodin_client::proto::iodin::response::Status;
+use iodin_client::proto::iodin::*;
+use iodin_client::Client;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+use std::time::Duration;
+
+const USAGE: &str = "usage: iodinctl [-spawn PATH] [-connect SOCKET] [-mock] [COMMAND]
+
+Without -connect, spawns iodin (default PATH=iodin). Without COMMAND, reads commands from stdin.
+-mock runs iodin without hardware: MDB bus is simulated, coin changer, bill validator and
+cashless reader answer commands, but no coins, bills or cards are ever inserted.
+Exit code of last command: 0 OK, 1 client error, 2..7 Response.Status (2 ERR_INPUT,
+3 ERR_INTERNAL, 4 ERR_CHECKSUM, 5 ERR_TIMEOUT, 6 ERR_HARDWARE, 7 ERR_NAK),
+8 response without status, 64 usage error.
+
+commands:
+  hello
+  info
+  mdb open RX_PIN TX_PIN [transport=gpio|sim|uart] [device=PATH]
+                              [faults=drop=N,checksum=N,nak=N,gap=N,garbage=N,gap_ms=MS,seed=N]
+                              fault injection with transport=sim only, N is percent
+                              chance per response
+  mdb reset DURATION_MS
+  mdb tx HEX [timeout=MS] [retry=N] [backoff=MS]
+                              HEX like 0b or 0f00 or 0x0f 0x00, retry=N is total attempts
+  mdb batch HEX...            one frame per word
+  mdb poll ADDR INTERVAL_MS [HEX]   server side periodic POLL, events with subscribe mdb_poll
+  mdb unpoll ADDR
+  coin reset|setup|tubes|poll|id   coin changer at 08
+  coin enable HEX_MASK [HEX_MASK]  accepted coin types, manual dispense coin types
+  coin dispense TYPE COUNT
+  bill reset|setup|stacker|poll|id|id2  bill validator at 30, id2 with level 2 features
+  bill security HEX_MASK
+  bill enable HEX_MASK [HEX_MASK]  accepted bill types, escrow bill types
+  bill escrow stack|return
+  cashless reset|setup|enable|disable|cancel|poll|complete|id|state [addr=60]
+                              cashless reader at 10 or addr, complete ends session
+  cashless setup MAX_PRICE MIN_PRICE [COLUMNS ROWS]
+  cashless vend PRICE ITEM    request approval, then poll for result
+  cashless vend cancel|success ITEM|failure
+  subscribe [KIND...]         mdb_poll gpio_edge bus_error watchdog, none to unsubscribe
+  gpio watch PIN [off]
+  events [SECONDS]            print subscribed events received until timeout (default 1)
+  stop
+";
+
+const EXIT_CLIENT_ERROR: i32 = 1;
+/// Status values 2..7 are used as is.
+const EXIT_STATUS_INVALID: i32 = 8;
+/// Like sysexits.h EX_USAGE, distinct from Status values.
+const EXIT_USAGE: i32 = 64;
+
+fn main() {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let mut spawn_path = "iodin".to_string();
+    let mut connect_path: Option<String> = None;
+    let mut mock = false;
+    while !args.is_empty() && args[0].starts_with('-') {
+        let flag = args.remove(0);
+        match flag.as_str() {
+            "-spawn" if !args.is_empty() => spawn_path = args.remove(0),
+            "-connect" if !args.is_empty() => connect_path = Some(args.remove(0)),
+            "-mock" => mock = true,
+            _ => {
+                eprint!("{}", USAGE);
+                std::process::exit(EXIT_USAGE);
+            }
+        }
+    }
+
+    let client = match connect_path {
+        Some(p) => Client::connect(Path::new(&p)),
+        None => {
+            if mock {
+                std::env::set_var("iodin_mock", "1");
+            }
+            Client::spawn(Path::new(&spawn_path))
+        }
+    };
+    let mut client = match client {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(EXIT_CLIENT_ERROR);
+        }
+    };
+
+    let code = if args.is_empty() {
+        repl(&mut client)
+    } else {
+        run_line(&mut client, &args)
+    };
+    std::process::exit(code);
+}
+
+fn repl(client: &mut Client) -> i32 {
+    let stdin = io::stdin();
+    let mut code = 0;
+    loop {
+        eprint!("iodin> ");
+        let _ = io::stderr().flush();
+        let mut line = String::new();
+        match stdin.lock().read_line(&mut line) {
+            Ok(0) => return code,
+            Ok(_) => (),
+            Err(e) => {
+                eprintln!("error: {}", e);
+                return EXIT_CLIENT_ERROR;
+            }
+        }
+        let words: Vec<String> = line.split_whitespace().map(String::from).collect();
+        match words.first().map(String::as_str) {
+            None => continue,
+            Some("quit") | Some("exit") => return code,
+            Some("help") => {
+                eprint!("{}", USAGE);
+                continue;
+            }
+            _ => (),
+        }
+        code = run_line(client, &words);
+        if words[0] == "stop" && code == 0 {
+            return code;
+        }
+    }
+}
+
+/// Execute one command, print response and return exit code.
+fn run_line(client: &mut Client, words: &[String]) -> i32 {
+    if words[0] == "events" {
+        return print_events(client, words);
+    }
+    let request = match parse_command(words) {
+        Ok(r) => r,
+        Err(e) => {
+            eprintln!("error: {}\n{}", e, USAGE);
+            return EXIT_USAGE;
+        }
+    };
+    match client.call(request) {
+        Ok(response) => {
+            print_response(&response);
+            exit_code(response.status.enum_value_or_default())
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            EXIT_CLIENT_ERROR
+        }
+    }
+}
+
+fn exit_code(status: Status) -> i32 {
+    match status {
+        Status::OK => 0,
+        Status::INVALID => EXIT_STATUS_INVALID,
+        s => s as i32,
+    }
+}
+
+fn parse_command(words: &[String]) -> Result<Request, String> {
+    let words: Vec<&str> = words.iter().map(String::as_str).collect();
+    let mut request = Request::new();
+    let command = match words.as_slice() {
+        ["hello"] => Command::HELLO,
+        ["info"] => Command::GET_INFO,
+        ["stop"] => Command::STOP,
+        ["mdb", "open", rx, tx, rest @ ..] => {
+            let mut open = MdbOpen::new();
+            open.rx_pin = parse_num(rx)?;
+            open.tx_pin = parse_num(tx)?;
+            for w in rest {
+                if let Some(t) = w.strip_prefix("transport=") {
+                    open.transport = match t {
+                        "gpio" => Transport::GPIO,
+                        "sim" => Transport::SIM,
+                        "uart" => Transport::UART,
+                        _ => return Err(format!("unknown transport: {}", t)),
+                    }
+                    .into();
+                } else if let Some(d) = w.strip_prefix("device=") {
+                    open.device = d.to_string();
+                } else if let Some(f) = w.strip_prefix("faults=") {
+                    open.options.mut_or_insert_default().faults =
+                        Some(iodin_client::faults::parse(f)?).into();
+                } else {
+                    return Err(format!("unknown option: {}", w));
+                }
+            }
+            request.set_mdb_open(open);
+            Command::MDB_OPEN
+        }
+        ["mdb", "reset", ms] => {
+            let mut reset = MdbReset::new();
+            reset.duration_ms = parse_num(ms)?;
+            request.set_mdb_reset(reset);
+            Command::MDB_RESET
+        }
+        ["mdb", "tx", rest @ ..] if !rest.is_empty() => {
+            let mut tx = MdbTx::new();
+            let mut hex = String::new();
+            for w in rest {
+                if let Some(t) = w.strip_prefix("timeout=") {
+                    tx.timeout = parse_num(t)?;
+                } else if !parse_retry(w, &mut tx.retry)? {
+                    hex.push_str(w);
+                    hex.push(' ');
+                }
+            }
+            tx.frame = parse_hex(&hex)?;
+            request.set_mdb_tx(tx);
+            Command::MDB_TX
+        }
+        ["subscribe", kinds @ ..] => {
+            let mut sub = Subscribe::new();
+            for k in kinds {
+                sub.kinds.push(parse_event_kind(k)?.into());
+            }
+            request.set_subscribe(sub);
+            Command::SUBSCRIBE
+        }
+        ["gpio", "watch", pin, rest @ ..] if rest.len() <= 1 => {
+            let mut watch = GpioWatch::new();
+            watch.pin = parse_num(pin)?;
+            watch.enable = match rest {
+                [] | ["on"] => true,
+                ["off"] => false,
+                _ => return Err(format!("invalid gpio watch mode: {}", rest.join(" "))),
+            };
+            request.set_gpio_watch(watch);
+            Command::GPIO_WATCH
+        }
+        ["mdb", "poll", address, interval, frame @ ..] if frame.len() <= 1 => {
+            let mut poll = MdbPoll::new();
+            poll.address = parse_address(address)?;
+            poll.interval_ms = parse_num(interval)?;
+            if let [f] = frame {
+                poll.frame = parse_hex(f)?;
+            }
+            request.set_mdb_poll(poll);
+            Command::MDB_POLL_ADD
+        }
+        ["mdb", "unpoll", address] => {
+            let mut poll = MdbPoll::new();
+            poll.address = parse_address(address)?;
+            request.set_mdb_poll(poll);
+            Command::MDB_POLL_REMOVE
+        }
+        ["mdb", "batch", frames @ ..] if !frames.is_empty() => {
+            let mut batch = MdbBatch::new();
+            for f in frames {
+                let mut tx = MdbTx::new();
+                tx.frame = parse_hex(f)?;
+                batch.items.push(tx);
+            }
+            request.set_mdb_batch(batch);
+            Command::MDB_BATCH
+        }
+        ["coin", op, rest @ ..] => {
+            use iodin_client::proto::iodin::mdb_coin::Op;
+            let mut coin = MdbCoin::new();
+            let op = match (*op, rest) {
+                ("reset", []) => Op::RESET,
+                ("setup", []) => Op::SETUP,
+                ("tubes", []) => Op::TUBE_STATUS,
+                ("poll", []) => Op::POLL,
+                ("id", []) => Op::IDENTIFICATION,
+                ("enable", [mask, manual @ ..]) if manual.len() <= 1 => {
+                    coin.coin_enable = parse_mask(mask)?;
+                    if let [m] = manual {
+                        coin.manual_dispense_enable = parse_mask(m)?;
+                    }
+                    Op::COIN_TYPE
+                }
+                ("dispense", [coin_type, count]) => {
+                    coin.coin_type = parse_num(coin_type)?;
+                    coin.count = parse_num(count)?;
+                    Op::DISPENSE
+                }
+                _ => return Err(format!("unknown command: {}", words.join(" "))),
+            };
+            coin.op = op.into();
+            request.set_mdb_coin(coin);
+            Command::MDB_COIN
+        }
+        ["bill", op, rest @ ..] => {
+            use iodin_client::proto::iodin::mdb_bill::Op;
+            let mut bill = MdbBill::new();
+            let op = match (*op, rest) {
+                ("reset", []) => Op::RESET,
+                ("setup", []) => Op::SETUP,
+                ("stacker", []) => Op::STACKER,
+                ("poll", []) => Op::POLL,
+                ("id", []) => Op::IDENTIFICATION,
+                ("id2", []) => Op::IDENTIFICATION_FEATURES,
+                ("security", [mask]) => {
+                    bill.security_mask = parse_mask(mask)?;
+                    Op::SECURITY
+                }
+                ("enable", [mask, escrow @ ..]) if escrow.len() <= 1 => {
+                    bill.bill_enable = parse_mask(mask)?;
+                    if let [m] = escrow {
+                        bill.escrow_enable = parse_mask(m)?;
+                    }
+                    Op::BILL_TYPE
+                }
+                ("escrow", ["stack"]) => {
+                    bill.stack = true;
+                    Op::ESCROW
+                }
+                ("escrow", ["return"]) => Op::ESCROW,
+                _ => return Err(format!("unknown command: {}", words.join(" "))),
+            };
+            bill.op = op.into();
+            request.set_mdb_bill(bill);
+            Command::MDB_BILL
+        }
+        ["cashless", op, rest @ ..] => {
+            use iodin_client::proto::iodin::mdb_cashless::Op;
+            let mut cashless = MdbCashless::new();
+            let rest = match rest {
+                [args @ .., last] if last.starts_with("addr=") => {
+                    cashless.address = parse_address(&last["addr=".len()..])?;
+                    args
+                }
+                _ => rest,
+            };
+            let op = match (*op, rest) {
+                ("reset", []) => Op::RESET,
+                ("setup", []) => Op::SETUP,
+                ("setup", [max, min, display @ ..]) if matches!(display.len(), 0 | 2) => {
+                    cashless.max_price = parse_num(max)?;
+                    cashless.min_price = parse_num(min)?;
+                    if let [columns, rows] = display {
+                        cashless.display_columns = parse_num(columns)?;
+                        cashless.display_rows = parse_num(rows)?;
+                    }
+                    Op::SETUP
+                }
+                ("enable", []) => Op::ENABLE,
+                ("disable", []) => Op::DISABLE,
+                ("cancel", []) => Op::CANCEL,
+                ("poll", []) => Op::POLL,
+                ("complete", []) => Op::SESSION_COMPLETE,
+                ("id", []) => Op::IDENTIFICATION,
+                ("state", []) => Op::STATE,
+                ("vend", ["cancel"]) => Op::VEND_CANCEL,
+                ("vend", ["success", item]) => {
+                    cashless.item = parse_num(item)?;
+                    Op::VEND_SUCCESS
+                }
+                ("vend", ["failure"]) => Op::VEND_FAILURE,
+                ("vend", [price, item]) => {
+                    cashless.price = parse_num(price)?;
+                    cashless.item = parse_num(item)?;
+                    Op::VEND_REQUEST
+                }
+                _ => return Err(format!("unknown command: {}", words.join(" "))),
+            };
+            cashless.op = op.into();
+            request.set_mdb_cashless(cashless);
+            Command::MDB_CASHLESS
+        }
+        _ => return Err(format!("unknown command: {}", words.join(" "))),
+    };
+    request.command = command.into();
+    Ok(request)
+}
+
+fn parse_num<T: std::str::FromStr>(s: &str) -> Result<T, String> {
+    s.parse().map_err(|_| format!("invalid number: {}", s))
+}
+
+fn parse_mask(s: &str) -> Result<u32, String> {
+    u32::from_str_radix(s.trim_start_matches("0x"), 16).map_err(|_| format!("invalid mask: {}", s))
+}
+
+/// Apply `retry=N` or `backoff=MS` option, false if `w` is neither.
+fn parse_retry(w: &str, retry: &mut protobuf::MessageField<RetryPolicy>) -> Result<bool, String> {
+    if let Some(n) = w.strip_prefix("retry=") {
+        retry.mut_or_insert_default().max_attempts = parse_num(n)?;
+    } else if let Some(ms) = w.strip_prefix("backoff=") {
+        retry.mut_or_insert_default().backoff_ms = parse_num(ms)?;
+    } else {
+        return Ok(false);
+    }
+    Ok(true)
+}
+
+/// MDB address as hex byte, i.e. 08 or 0x30.
+fn parse_address(s: &str) -> Result<u32, String> {
+    match parse_hex(s)?.as_slice() {
+        [a] => Ok((*a).into()),
+        _ => Err(format!("invalid address: {}", s)),
+    }
+}
+
+fn parse_event_kind(s: &str) -> Result<EventKind, String> {
+    use protobuf::Enum;
+
+    EventKind::VALUES
+        .iter()
+        .find(|k| **k != EventKind::INVALID && format!("{:?}", k).eq_ignore_ascii_case(s))
+        .copied()
+        .ok_or_else(|| format!("unknown event kind: {}", s))
+}
+
+/// Whitespace separated words of hex digit pairs, each may have 0x prefix.
+fn parse_hex(s: &str) -> Result<Vec<u8>, String> {
+    let mut b = Vec::new();
+    for w in s.split_whitespace() {
+        let w = w.strip_prefix("0x").unwrap_or(w);
+        if w.is_empty() {
+            return Err(format!("invalid hex: {}", s));
+        }
+        for i in (0..w.len()).step_by(2) {
+            // odd length leaves last pair incomplete
+            let x = w.get(i..i + 2).and_then(|p| u8::from_str_radix(p, 16).ok());
+            b.push(x.ok_or_else(|| format!("invalid hex: {}", s))?);
+        }
+    }
+    if b.is_empty() {
+        return Err(format!("invalid hex: {}", s));
+    }
+    Ok(b)
+}
+
+fn hex(b: &[u8]) -> String {
+    b.iter()
+        .map(|x| format!("{:02x}", x))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn print_events(client: &mut Client, words: &[String]) -> i32 {
+    let seconds = match words {
+        [_] => 1,
+        [_, s] => match parse_num(s) {
+            Ok(x) => x,
+            Err(e) => {
+                eprintln!("error: {}\n{}", e, USAGE);
+                return EXIT_USAGE;
+            }
+        },
+        _ => {
+            eprint!("{}", USAGE);
+            return EXIT_USAGE;
+        }
+    };
+    let deadline = std::time::Instant::now() + Duration::from_secs(seconds);
+    loop {
+        let left = deadline.saturating_duration_since(std::time::Instant::now());
+        match client.recv_event(left) {
+            Ok(Some(e)) => print_event(&e),
+            Ok(None) => return 0,
+            Err(e) => {
+                eprintln!("error: {}", e);
+                return EXIT_CLIENT_ERROR;
+            }
+        }
+    }
+}
+
+fn print_event(e: &Event) {
+    let kind = e.kind.enum_value_or_default();
+    match kind {
+        EventKind::GPIO_EDGE => println!(
+            "event {} {:?} gpio={} level={} tick={}",
+            e.time_us, kind, e.gpio, e.level, e.tick
+        ),
+        EventKind::MDB_POLL => println!(
+            "event {} {:?} {} -> {}",
+            e.time_us,
+            kind,
+            hex(&e.mdb_request),
+            hex(&e.data_bytes)
+        ),
+        _ => println!(
+            "event {} {:?} {} {}",
+            e.time_us,
+            kind,
+            hex(&e.mdb_request),
+            e.error
+        ),
+    }
+}
+
+fn print_coin(c: &MdbCoinResult) {
+    if let Some(s) = c.setup.as_ref() {
+        println!(
+            "level: {} country: {:04x} scaling: {} decimals: {} routing: {:04x}",
+            s.feature_level, s.country_code, s.scaling_factor, s.decimal_places, s.coin_routing
+        );
+        println!("credit: {:?}", s.credit);
+    }
+    if let Some(t) = c.tubes.as_ref() {
+        println!("tubes: {:?} full: {:04x}", t.count, t.full);
+    }
+    for e in &c.events {
+        use iodin_client::proto::iodin::mdb_coin_event::Kind;
+        match e.kind.enum_value_or_default() {
+            Kind::DEPOSITED => println!(
+                "deposited: type={} routing={:?} tube={}",
+                e.coin_type,
+                e.routing.enum_value_or_default(),
+                e.tube_count
+            ),
+            Kind::DISPENSED => println!(
+                "dispensed: type={} count={} tube={}",
+                e.coin_type, e.count, e.tube_count
+            ),
+            Kind::SLUG => println!("slug: count={}", e.count),
+            _ => println!("status: {:?}", e.status.enum_value_or_default()),
+        }
+    }
+    if let Some(id) = c.identification.as_ref() {
+        print_identification(id);
+    }
+}
+
+fn print_bill(b: &MdbBillResult) {
+    if let Some(s) = b.setup.as_ref() {
+        println!(
+            "level: {} country: {:04x} scaling: {} decimals: {} stacker capacity: {} security: {:04x} escrow: {}",
+            s.feature_level,
+            s.country_code,
+            s.scaling_factor,
+            s.decimal_places,
+            s.stacker_capacity,
+            s.security_levels,
+            s.escrow
+        );
+        println!("credit: {:?}", s.credit);
+    }
+    if let Some(s) = b.stacker.as_ref() {
+        println!("stacker: {} full: {}", s.count, s.full);
+    }
+    for e in &b.events {
+        use iodin_client::proto::iodin::mdb_bill_event::Kind;
+        match e.kind.enum_value_or_default() {
+            Kind::BILL => println!(
+                "bill: type={} routing={:?}",
+                e.bill_type,
+                e.routing.enum_value_or_default()
+            ),
+            Kind::DISABLED_ATTEMPTS => println!("disabled attempts: {}", e.count),
+            _ => println!("status: {:?}", e.status.enum_value_or_default()),
+        }
+    }
+    if let Some(id) = b.identification.as_ref() {
+        print_identification(id);
+    }
+}
+
+fn print_cashless(c: &MdbCashlessResult) {
+    if let Some(s) = c.setup.as_ref() {
+        println!(
+            "level: {} country: {:04x} scale: {} decimals: {} max response: {}s options: {:02x}",
+            s.feature_level,
+            s.country_code,
+            s.scale_factor,
+            s.decimal_places,
+            s.max_response_time,
+            s.options
+        );
+    }
+    for e in &c.events {
+        use iodin_client::proto::iodin::mdb_cashless_event::Kind;
+        match e.kind.enum_value_or_default() {
+            Kind::BEGIN_SESSION => println!("begin session: funds={}", e.amount),
+            Kind::VEND_APPROVED => println!("vend approved: amount={}", e.amount),
+            Kind::VEND_DENIED if e.timeout => println!("vend denied: approval timeout"),
+            Kind::DISPLAY_REQUEST => println!(
+                "display: {} ({}00ms)",
+                String::from_utf8_lossy(&e.display_data),
+                e.display_time
+            ),
+            Kind::MALFUNCTION => println!("malfunction: code={:02x}", e.code),
+            // setup and identification are printed from result
+            Kind::READER_CONFIG | Kind::PERIPHERAL_ID => (),
+            k => println!("event: {:?}", k),
+        }
+    }
+    if let Some(id) = c.identification.as_ref() {
+        print_identification(id);
+    }
+    println!(
+        "state: {:?} funds: {}",
+        c.state.enum_value_or_default(),
+        c.funds
+    );
+}
+
+fn print_identification(id: &MdbIdentification) {
+    println!(
+        "manufacturer: {} serial: {} model: {} version: {:04x} features: {:08x}",
+        id.manufacturer, id.serial, id.model, id.software_version, id.features
+    );
+}
+
+fn print_response(r: &Response) {
+    let status = r.status.enum_value_or_default();
+    for (i, item) in r.batch.iter().enumerate() {
+        match item.status.enum_value_or_default() {
+            Status::OK => println!("{}: {}", i, hex(&item.data_bytes)),
+            s => println!("{}: {:?} {}", i, s, item.error),
+        }
+    }
+    if r.attempts > 1 {
+        println!("attempts: {}", r.attempts);
+    }
+    if status != Status::OK {
+        println!("status: {:?}", status);
+        println!("error: {}", r.error);
+        if let Some(d) = r.error_detail.as_ref() {
+            println!(
+                "detail: kind={:?} stage={:?}",
+                d.kind.enum_value_or_default(),
+                d.stage.enum_value_or_default()
+            );
+            if !d.partial_response.is_empty() {
+                println!("partial response: {}", hex(&d.partial_response));
+            }
+        }
+        return;
+    }
+    if let Some(h) = r.hello.as_ref() {
+        println!(
+            "protocol version: {} (min {})",
+            h.protocol_version, h.protocol_version_min
+        );
+        println!("commands: {:?}", commands(&h.commands));
+    }
+    if let Some(c) = r.coin.as_ref() {
+        print_coin(c);
+    }
+    if let Some(b) = r.bill.as_ref() {
+        print_bill(b);
+    }
+    if let Some(c) = r.cashless.as_ref() {
+        print_cashless(c);
+    }
+    if let Some(i) = r.info.as_ref() {
+        println!("version: {} build {}", i.crate_version, i.build_id);
+        println!("protocol version: {}", i.protocol_version);
+        println!("mock: {}", i.mock);
+        println!(
+            "pigpio: {} hardware revision: {:x}",
+            i.pigpio_version, i.hardware_revision
+        );
+        if i.mdb_open {
+            println!(
+                "mdb: transport={:?} rx={} tx={} device={}",
+                i.mdb_transport.enum_value_or_default(),
+                i.mdb_rx_pin,
+                i.mdb_tx_pin,
+                i.mdb_device
+            );
+            if let Some(f) = i.mdb_faults.as_ref() {
+                println!(
+                    "mdb faults: drop={}% checksum={}% nak={}% gap={}% garbage={}% gap_ms={} seed={}",
+                    f.drop, f.checksum, f.nak, f.gap, f.garbage, f.gap_ms, f.seed
+                );
+            }
+        } else {
+            println!("mdb: closed");
+        }
+        for p in &i.mdb_polls {
+            println!(
+                "mdb poll: address={:02x} frame={} interval={}ms",
+                p.address,
+                hex(&p.frame),
+                p.interval_ms
+            );
+        }
+        println!("commands: {:?}", commands(&i.commands));
+    }
+    if !r.data_bytes.is_empty() {
+        println!("{}", hex(&r.data_bytes));
+    } else if r.hello.is_none() && r.info.is_none() && r.batch.is_empty() {
+        println!("ok");
+    }
+}
+
+fn commands(cs: &[protobuf::EnumOrUnknown<Command>]) -> Vec<Command> {
+    cs.iter().map(|c| c.enum_value_or_default()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn exit_codes() {
+        assert_eq!(exit_code(Status::OK), 0);
+        assert_eq!(exit_code(Status::ERR_INPUT), 2);
+        assert_eq!(exit_code(Status::ERR_NAK), 7);
+        assert_eq!(exit_code(Status::INVALID), EXIT_STATUS_INVALID);
+        let codes = [EXIT_CLIENT_ERROR, EXIT_STATUS_INVALID, EXIT_USAGE];
+        use protobuf::Enum;
+        for s in Status::VALUES {
+            assert!(!codes.contains(&exit_code(*s)) || *s == Status::INVALID);
+        }
+    }
+
+    #[test]
+    fn parse_mdb_tx() {
+        let r = parse_command(&words("mdb tx 0f 00 timeout=50")).unwrap();
+        assert_eq!(r.command, Command::MDB_TX.into());
+        assert_eq!(r.mdb_tx().frame, vec![0x0f, 0x00]);
+        assert_eq!(r.mdb_tx().timeout, 50);
+        assert!(r.mdb_tx().retry.is_none());
+        let r = parse_command(&words("mdb tx 0b retry=3 backoff=10")).unwrap();
+        assert_eq!(r.mdb_tx().frame, vec![0x0b]);
+        assert_eq!(r.mdb_tx().retry.max_attempts, 3);
+        assert_eq!(r.mdb_tx().retry.backoff_ms, 10);
+        let r = parse_command(&words("mdb tx 0x0b")).unwrap();
+        assert_eq!(r.mdb_tx().frame, vec![0x0b]);
+        let r = parse_command(&words("mdb tx 0x0f 0x00")).unwrap();
+        assert_eq!(r.mdb_tx().frame, vec![0x0f, 0x00]);
+        assert!(parse_command(&words("mdb tx 0")).is_err());
+        assert!(parse_command(&words("mdb tx 0f 0")).is_err());
+        assert!(parse_command(&words("mdb tx 0x")).is_err());
+        assert!(parse_command(&words("mdb tx")).is_err());
+        let r = parse_command(&words("mdb open 15 14")).unwrap();
+        assert_eq!((r.mdb_open().rx_pin, r.mdb_open().tx_pin), (15, 14));
+        let r = parse_command(&words("mdb open 0 0 transport=sim")).unwrap();
+        assert_eq!(r.mdb_open().transport, Transport::SIM.into());
+        let r = parse_command(&words("mdb open 0 0 transport=uart device=/dev/ttyS0")).unwrap();
+        assert_eq!(r.mdb_open().transport, Transport::UART.into());
+        assert_eq!(r.mdb_open().device, "/dev/ttyS0");
+        assert!(parse_command(&words("mdb open 0 0 transport=usb")).is_err());
+        let r = parse_command(&words("mdb open 0 0 faults=nak=10,gap=5,gap_ms=20,seed=7")).unwrap();
+        let f = &r.mdb_open().options.faults;
+        assert_eq!((f.nak, f.gap, f.gap_ms, f.seed), (10, 5, 20, 7));
+        assert!(parse_command(&words("mdb open 0 0 faults=nak")).is_err());
+    }
+
+    #[test]
+    fn parse_coin() {
+        use iodin_client::proto::iodin::mdb_coin::Op;
+
+        let r = parse_command(&words("coin poll")).unwrap();
+        assert_eq!(r.command, Command::MDB_COIN.into());
+        assert_eq!(r.mdb_coin().op, Op::POLL.into());
+        let r = parse_command(&words("coin enable 0x0f 3")).unwrap();
+        assert_eq!(
+            (
+                r.mdb_coin().coin_enable,
+                r.mdb_coin().manual_dispense_enable
+            ),
+            (0x0f, 0x03)
+        );
+        let r = parse_command(&words("coin dispense 2 3")).unwrap();
+        assert_eq!((r.mdb_coin().coin_type, r.mdb_coin().count), (2, 3));
+        assert!(parse_command(&words("coin dispense 2")).is_err());
+        assert!(parse_command(&words("coin poll 1")).is_err());
+    }
+
+    #[test]
+    fn parse_bill() {
+        use iodin_client::proto::iodin::mdb_bill::Op;
+
+        let r = parse_command(&words("bill escrow stack")).unwrap();
+        assert_eq!(r.command, Command::MDB_BILL.into());
+        assert_eq!(
+            (r.mdb_bill().op, r.mdb_bill().stack),
+            (Op::ESCROW.into(), true)
+        );
+        let r = parse_command(&words("bill enable ffff 0002")).unwrap();
+        assert_eq!(
+            (r.mdb_bill().bill_enable, r.mdb_bill().escrow_enable),
+            (0xffff, 0x0002)
+        );
+        assert!(parse_command(&words("bill escrow")).is_err());
+    }
+
+    #[test]
+    fn parse_cashless() {
+        use iodin_client::proto::iodin::mdb_cashless::Op;
+
+        let r = parse_command(&words("cashless vend 150 3 addr=60")).unwrap();
+        assert_eq!(r.command, Command::MDB_CASHLESS.into());
+        let c = r.mdb_cashless();
+        assert_eq!(
+            (c.op, c.price, c.item, c.address),
+            (Op::VEND_REQUEST.into(), 150, 3, 0x60)
+        );
+        let r = parse_command(&words("cashless vend success 3")).unwrap();
+        assert_eq!(r.mdb_cashless().op, Op::VEND_SUCCESS.into());
+        let r = parse_command(&words("cashless setup 500 10 16 2")).unwrap();
+        assert_eq!(
+            (r.mdb_cashless().max_price, r.mdb_cashless().display_rows),
+            (500, 2)
+        );
+        assert!(parse_command(&words("cashless setup 500 10 16")).is_err());
+        assert!(parse_command(&words("cashless vend")).is_err());
+    }
+
+    #[test]
+    fn parse_subscribe() {
+        let r = parse_command(&words("subscribe mdb_poll BUS_ERROR")).unwrap();
+        assert_eq!(
+            r.subscribe().kinds,
+            vec![EventKind::MDB_POLL.into(), EventKind::BUS_ERROR.into()]
+        );
+        assert!(parse_command(&words("subscribe")).unwrap().has_subscribe());
+        assert!(parse_command(&words("subscribe invalid")).is_err());
+        let r = parse_command(&words("mdb poll 30 100")).unwrap();
+        assert_eq!(r.command, Command::MDB_POLL_ADD.into());
+        assert_eq!(
+            (r.mdb_poll().address, r.mdb_poll().interval_ms),
+            (0x30, 100)
+        );
+        let r = parse_command(&words("mdb unpoll 0x10")).unwrap();
+        assert_eq!(r.command, Command::MDB_POLL_REMOVE.into());
+        assert_eq!(r.mdb_poll().address, 0x10);
+        let r = parse_command(&words("gpio watch 4 off")).unwrap();
+        assert!(!r.gpio_watch().enable);
+    }
+}
@@ -25,10 +25,24 @@ pub fn check(rc: i32) -> io::Result<u32> {
 
 pub fn init(flag_interfaces: u32) -> io::Result<()> {
     check(unsafe { gpioCfgInterfaces(flag_interfaces) })?;
+    // Signals are handled by caller, pigpio handler would exit in the middle of wave.
+    check(unsafe { gpioCfgSetInternals(gpioCfgGetInternals() | PI_CFG_NOSIGHANDLER) })?;
     check(unsafe { gpioInitialise() })?;
     Ok(())
 }
 
+pub fn terminate() {
+    unsafe { gpioTerminate() };
+}
+
+pub fn version() -> u32 {
+    unsafe { gpioVersion() }
+}
+
+pub fn hardware_revision() -> u32 {
+    unsafe { gpioHardwareRevision() }
+}
+
 pub fn wave_tx_busy() -> io::Result<bool> {
     let rc = check(unsafe { gpioWaveTxBusy() })?;
     Ok(rc == 1)
@@ -1,3 +1,4 @@
+pub const PI_CFG_NOSIGHANDLER: u32 = 0;
 pub const PI_DISABLE_FIFO_IF: u32 = 0;
 pub const PI_DISABLE_SOCK_IF: u32 = 0;
 pub const PI_INPUT: u32 = 0;
@@ -7,9 +8,23 @@ pub const PI_WAVE_MODE_ONE_SHOT_SYNC: u32 = 0;
 pub unsafe fn gpioCfgInterfaces(_: u32) -> i32 {
     0
 }
+pub unsafe fn gpioCfgGetInternals() -> u32 {
+    0
+}
+pub unsafe fn gpioCfgSetInternals(_: u32) -> i32 {
+    0
+}
 pub unsafe fn gpioInitialise() -> i32 {
     0
 }
+pub unsafe fn gpioTerminate() {}
+
+pub unsafe fn gpioVersion() -> u32 {
+    0
+}
+pub unsafe fn gpioHardwareRevision() -> u32 {
+    0
+}
 
 pub unsafe fn gpioTick() -> u32 {
     0
@@ -64,3 +79,20 @@ pub unsafe fn gpioSetMode(_: u32, _: u32) -> i32 {
 pub unsafe fn gpioWrite(_: u32, _: u32) -> i32 {
     -1
 }
+
+#[allow(non_camel_case_types)]
+pub type gpioAlertFuncEx_t = ::std::option::Option<
+    unsafe extern "C" fn(
+        gpio: ::std::os::raw::c_int,
+        level: ::std::os::raw::c_int,
+        tick: u32,
+        userdata: *mut ::std::os::raw::c_void,
+    ),
+>;
+pub unsafe fn gpioSetAlertFuncEx(
+    _: u32,
+    _: gpioAlertFuncEx_t,
+    _: *mut ::std::os::raw::c_void,
+) -> i32 {
+    0
+}